@@ -0,0 +1,234 @@
+//! Generates the list of axioms from the source.
+//!
+//! An axiom is a function with body `axiom!()`.
+//! The statement is taken from the first line of its doc comment.
+//!
+//! The gate of an axiom combines the `#[cfg(...)]` attribute of the function
+//! with the attributes of the `mod` declarations of the modules containing it.
+//!
+//! Functions generated by macros are not seen by the scan.
+//! Macros that generate axioms list them instead, see `axiom::register`.
+//!
+//! Also generates the list of enabled features.
+
+use std::collections::HashMap;
+use std::env;
+use std::fs;
+use std::path::Path;
+
+struct Entry {
+    name: String,
+    statement: String,
+    cfg: Option<Cfg>,
+}
+
+/// Configuration predicate of a `#[cfg(...)]` attribute.
+#[derive(Clone)]
+enum Cfg {
+    Feature(String),
+    All(Vec<Cfg>),
+    Any(Vec<Cfg>),
+    Not(Box<Cfg>),
+    Other(String),
+}
+
+impl Cfg {
+    /// Generates the `axiom::Cfg` expression.
+    fn emit(&self) -> String {
+        fn list(xs: &[Cfg]) -> String {
+            xs.iter().map(|x| x.emit()).collect::<Vec<_>>().join(", ")
+        }
+        match self {
+            Cfg::Feature(f) => format!("Cfg::Feature({:?})", f),
+            Cfg::All(xs) => format!("Cfg::All(&[{}])", list(xs)),
+            Cfg::Any(xs) => format!("Cfg::Any(&[{}])", list(xs)),
+            Cfg::Not(x) => format!("Cfg::Not(&{})", x.emit()),
+            Cfg::Other(x) => format!("Cfg::Other({:?})", x),
+        }
+    }
+}
+
+/// Combines the gate of a parent with the gate of an item.
+fn and(a: Option<Cfg>, b: Option<Cfg>) -> Option<Cfg> {
+    match (a, b) {
+        (None, x) | (x, None) => x,
+        (Some(Cfg::All(mut xs)), Some(y)) => {xs.push(y); Some(Cfg::All(xs))}
+        (Some(x), Some(y)) => Some(Cfg::All(vec![x, y])),
+    }
+}
+
+fn main() {
+    println!("cargo:rerun-if-changed=src");
+
+    let mut entries = vec![];
+    visit(Path::new("src"), &[], None, &gates(Path::new("src/lib.rs")), &mut entries);
+    entries.sort_by(|a, b| a.name.cmp(&b.name));
+
+    let mut out = String::from("&[\n");
+    for e in &entries {
+        let cfg = match &e.cfg {
+            Some(c) => format!("Some(&{})", c.emit()),
+            None => "None".into(),
+        };
+        out.push_str(&format!("    AxiomInfo {{name: {:?}, statement: {:?}, cfg: {}}},\n",
+            e.name, e.statement, cfg));
+    }
+    out.push(']');
+    let out_dir = env::var("OUT_DIR").unwrap();
+    fs::write(Path::new(&out_dir).join("axioms.rs"), out).unwrap();
+
+    let mut enabled: Vec<String> = env::vars().filter_map(|(k, _)|
+        k.strip_prefix("CARGO_FEATURE_").map(|f| f.to_lowercase()))
+        .filter(|f| f != "default").collect();
+    enabled.sort();
+    fs::write(Path::new(&out_dir).join("features.rs"), format!("&{:?}", enabled)).unwrap();
+}
+
+/// Parses the predicate of a `#[cfg(...)]` attribute.
+fn cfg(t: &str) -> Option<Cfg> {
+    let t = t.strip_prefix("#[cfg(")?.strip_suffix(")]")?;
+    let (c, rest) = pred(t)?;
+    if rest.trim().is_empty() {Some(c)} else {None}
+}
+
+fn pred(t: &str) -> Option<(Cfg, &str)> {
+    let t = t.trim_start();
+    let end = t.find(|c: char| !(c.is_alphanumeric() || c == '_')).unwrap_or(t.len());
+    let (name, rest) = t.split_at(end);
+    let rest = rest.trim_start();
+    if let Some(rest) = rest.strip_prefix('=') {
+        let rest = rest.trim_start().strip_prefix('"')?;
+        let end = rest.find('"')?;
+        let value = &rest[..end];
+        let c = if name == "feature" {Cfg::Feature(value.into())}
+                else {Cfg::Other(format!("{} = {:?}", name, value))};
+        Some((c, &rest[end + 1..]))
+    } else if let Some(mut rest) = rest.strip_prefix('(') {
+        let mut args = vec![];
+        loop {
+            rest = rest.trim_start();
+            if let Some(r) = rest.strip_prefix(')') {rest = r; break}
+            let (c, r) = pred(rest)?;
+            args.push(c);
+            rest = r.trim_start();
+            rest = rest.strip_prefix(',').unwrap_or(rest);
+        }
+        let c = match name {
+            "all" => Cfg::All(args),
+            "any" => Cfg::Any(args),
+            "not" if args.len() == 1 => Cfg::Not(Box::new(args.pop()?)),
+            _ => return None,
+        };
+        Some((c, rest))
+    } else if name.is_empty() {None}
+    else {Some((Cfg::Other(name.into()), rest))}
+}
+
+/// Reads the gates of the modules declared in a file.
+fn gates(path: &Path) -> HashMap<String, Cfg> {
+    let mut res = HashMap::new();
+    let src = match fs::read_to_string(path) {Ok(x) => x, Err(_) => return res};
+    let mut gate = None;
+    for line in src.lines() {
+        let t = line.trim();
+        if let Some(c) = cfg(t) {gate = Some(c)}
+        else if t.starts_with("#[") || t.starts_with("///") {continue}
+        else {
+            let m = t.strip_prefix("pub ").unwrap_or(t).strip_prefix("mod ")
+                .and_then(|m| m.strip_suffix(';'));
+            if let (Some(m), Some(c)) = (m, gate.take()) {res.insert(m.trim().into(), c);}
+            gate = None;
+        }
+    }
+    res
+}
+
+fn visit(
+    dir: &Path,
+    module: &[String],
+    gate: Option<Cfg>,
+    decls: &HashMap<String, Cfg>,
+    entries: &mut Vec<Entry>
+) {
+    let mut paths: Vec<_> = fs::read_dir(dir).unwrap().map(|e| e.unwrap().path()).collect();
+    paths.sort();
+    for path in paths {
+        let name = path.file_stem().unwrap().to_string_lossy().into_owned();
+        let child_gate = if module.is_empty() && name == "lib" {gate.clone()}
+                         else {and(gate.clone(), decls.get(&name).cloned())};
+        let mut child = module.to_vec();
+        child.push(name);
+        if path.is_dir() {
+            let decl_file = if path.join("mod.rs").exists() {path.join("mod.rs")}
+                            else {path.with_extension("rs")};
+            visit(&path, &child, child_gate, &gates(&decl_file), entries);
+        } else if path.extension().map(|x| x == "rs").unwrap_or(false) {
+            if child.last().map(|x| x == "mod").unwrap_or(false) {continue}
+            let child = if module.is_empty() && child[0] == "lib" {vec![]} else {child};
+            scan(&fs::read_to_string(&path).unwrap(), &child.join("::"), child_gate, entries);
+        }
+    }
+}
+
+fn fn_name(t: &str) -> Option<&str> {
+    let mut t = t;
+    for prefix in ["pub(crate) ", "pub ", "const ", "unsafe "] {
+        if let Some(x) = t.strip_prefix(prefix) {t = x}
+    }
+    let t = t.strip_prefix("fn ")?;
+    let end = t.find(|c: char| !(c.is_alphanumeric() || c == '_')).unwrap_or(t.len());
+    Some(&t[..end])
+}
+
+fn scan(src: &str, module: &str, file_gate: Option<Cfg>, entries: &mut Vec<Entry>) {
+    // Current function: name, indention, statement, gate and whether it is recorded.
+    let mut cur: Option<(String, usize, String, Option<Cfg>, bool)> = None;
+    let mut doc: Option<String> = None;
+    let mut was_doc = false;
+    let mut gate = None;
+    let mut impl_header: Option<String> = None;
+    for line in src.lines() {
+        let t = line.trim();
+        if t.is_empty() {continue}
+        let indent = line.len() - line.trim_start().len();
+        let ends_cur = cur.as_ref().map(|c| indent <= c.1).unwrap_or(false);
+        if let Some(d) = t.strip_prefix("///") {
+            if ends_cur {cur = None}
+            if !was_doc {doc = Some(d.trim().to_string())}
+            was_doc = true;
+            continue;
+        }
+        was_doc = false;
+        if t.starts_with("#[") {
+            if ends_cur {cur = None}
+            if let Some(c) = cfg(t) {gate = Some(c)}
+            continue;
+        }
+        if let Some(name) = fn_name(t) {
+            // Functions generated by macros are registered by the macro, see `axiom::register`.
+            if name.is_empty() {cur = None}
+            else if cur.is_none() || ends_cur {
+                let statement = doc.take().or_else(|| impl_header.clone()).unwrap_or_default();
+                let gate = and(file_gate.clone(), gate.clone());
+                cur = Some((name.to_string(), indent, statement, gate, false));
+            }
+        } else if t.starts_with("impl") {
+            impl_header = Some(t.trim_end_matches('{').trim().to_string());
+            if ends_cur {cur = None}
+        } else if ends_cur && (t.starts_with('}') || t.starts_with("pub ")) {
+            cur = None;
+        }
+        doc = None;
+        gate = None;
+        if t.contains("axiom!()") {
+            if let Some(c) = cur.as_mut() {
+                if !c.4 {
+                    c.4 = true;
+                    let name = if module.is_empty() {c.0.clone()}
+                               else {format!("{}::{}", module, c.0)};
+                    entries.push(Entry {name, statement: c.2.clone(), cfg: c.3.clone()});
+                }
+            }
+        }
+    }
+}
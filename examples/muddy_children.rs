@@ -0,0 +1,63 @@
+/*
+
+Muddy children puzzle with two children.
+
+Two children play outside and the first one gets mud on the forehead.
+Each child can see the other, but not themselves.
+The father announces: "At least one of you is muddy."
+
+Before the announcement, the first child sees that the second child is clean.
+After the announcement, the first child knows that they are muddy.
+
+*/
+
+use prop::*;
+use epistemic::*;
+use Either::*;
+use std::rc::Rc;
+
+/// The first child.
+#[derive(Copy, Clone)]
+pub struct Child1;
+
+/// Name of the first child being muddy.
+#[derive(Copy, Clone)]
+pub struct Muddy1;
+
+/// Name of the second child being muddy.
+#[derive(Copy, Clone)]
+pub struct Muddy2;
+
+/// The first child is muddy.
+pub type M1 = Atomic<Muddy1>;
+
+/// The second child is muddy.
+pub type M2 = Atomic<Muddy2>;
+
+/// `m1 ⋁ m2`.
+pub type AtLeastOne = Or<M1, M2>;
+
+/// `¬m2  =>  ((m1 ⋁ m2) => m1)`.
+pub fn only_first(nm2: Not<M2>) -> Imply<AtLeastOne, M1> {
+    Rc::new(move |x| match x {
+        Left(m1) => m1,
+        Right(m2) => not::absurd(nm2.clone(), m2),
+    })
+}
+
+/// `K{1}(¬m2)  =>  [!(m1 ⋁ m2)]K{1}(m1)`.
+pub fn child1_knows(sees: Knows<Child1, Not<M2>>) -> Announce<AtLeastOne, Knows<Child1, M1>> {
+    let x: Knows<Child1, Imply<AtLeastOne, M1>> =
+        knows_modus_ponens(sees, knows_nec(tauto!(hooo::pow_to_imply(only_first))));
+    let x: Knows<Child1, Announce<AtLeastOne, M1>> =
+        knows_modus_ponens(x, knows_nec(tauto!(hooo::pow_to_imply(announce_atom_intro))));
+    announce_knows().1(x.map_any())
+}
+
+/// `K{1}(¬m2) ⋀ (m1 ⋁ m2)  =>  K{1}([!(m1 ⋁ m2)]m1)`.
+pub fn child1_learns(
+    sees: Knows<Child1, Not<M2>>,
+    father: AtLeastOne
+) -> Knows<Child1, Announce<AtLeastOne, M1>> {announce_knows_mp(child1_knows(sees), father)}
+
+fn main() {}
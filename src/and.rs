@@ -5,11 +5,15 @@
 use crate::*;
 
 /// `a ∧ b  =>  b ∧ a`.
+///
+/// The law [meet_symmetry](heyting::IsHeyting::meet_symmetry) of [Props](heyting::Props).
 pub fn symmetry<A: Prop, B: Prop>((f0, f1): And<A, B>) -> And<B, A> {
     (f1, f0)
 }
 
 /// `(a ∧ b) ∧ c  =>  a ∧ (b ∧ c)`.
+///
+/// The law [meet_assoc](heyting::IsHeyting::meet_assoc) of [Props](heyting::Props).
 pub fn assoc<A: Prop, B: Prop, C: Prop>(
     ((x0, x1), x2): And<And<A, B>, C>
 ) -> And<A, And<B, C>> {
@@ -24,6 +28,8 @@ pub fn rev_assoc<A: Prop, B: Prop, C: Prop>(
 }
 
 /// `a ∧ (b ∨ c)  =>  (a ∧ b) ∨ (a ∧ c)`.
+///
+/// The law [distrib](heyting::IsHeyting::distrib) of [Props](heyting::Props).
 pub fn distrib<A: Prop, B: Prop, C: Prop>(
     (a, x): And<A, Or<B, C>>
 ) -> Or<And<A, B>, And<A, C>> {
@@ -194,6 +200,8 @@ pub fn to_eq_neg<A: Prop, B: Prop>((f0, f1): And<Not<A>, Not<B>>) -> Eq<A, B> {
 pub fn to_or<A: Prop, B: Prop>((x, _): And<A, B>) -> Or<A, B> {Left(x)}
 
 /// `(a ∧ ¬a) => false`.
+///
+/// The law [non_contradiction](heyting::IsHeyting::non_contradiction) of [Props](heyting::Props).
 pub fn paradox<A: Prop>((a, na): And<A, Not<A>>) -> False {na(a)}
 
 /// `(¬¬a ∧ ¬a) => false`.
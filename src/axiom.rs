@@ -0,0 +1,187 @@
+//! # Axioms
+//!
+//! An axiom is a function without a proof, which body is `axiom!()`.
+//! The body assumes the returned proposition using the marker type [Axiom].
+//! When called at runtime, an axiom panics, since there is no value to return.
+//!
+//! All axioms in this library are tagged with `axiom!()`.
+//! This makes them enumerable at runtime ([ALL]), with name and statement
+//! taken from the first line of the doc comment.
+//!
+//! Axioms that are only enabled with some features record the gate ([AxiomInfo::cfg]),
+//! which combines the `#[cfg(...)]` attributes of the axiom and of the modules containing it.
+//!
+//! ### Audit
+//!
+//! Use [axioms] to list the axioms enabled by the current feature set.
+//! This is the complete list of unproven assumptions that a proof might depend on:
+//!
+//! ```rust
+//! for ax in prop::axioms() {
+//!     println!("{}", ax);
+//! }
+//! ```
+//!
+//! The enabled features are listed in [FEATURES], which does not include `default`.
+//!
+//! ### Axioms generated by macros
+//!
+//! [ALL] is generated by scanning the source of this library,
+//! which does not see functions generated by macros,
+//! e.g. the typing rules and induction principle of
+//! [define_inductive!](crate::define_inductive).
+//! Such macros emit the list of their axioms instead, which is added to the audit with [register]:
+//!
+//! ```rust
+//! use prop::*;
+//!
+//! define_inductive!{
+//!     /// Natural numbers.
+//!     type N: n_ty, n_is_const;
+//!     /// Induction on natural numbers.
+//!     induction n_ind<X>;
+//!     /// Zero.
+//!     Zero(): zero_ty, zero_is_const;
+//!     /// Successor.
+//!     Succ(n: rec): succ_ty, succ_is_const;
+//! }
+//!
+//! let n = prop::axioms().len();
+//! prop::axiom::register(N::AXIOMS);
+//! prop::axiom::register(N::AXIOMS);
+//! assert_eq!(prop::axioms().len(), n + 7);
+//! assert!(prop::axioms().iter().any(|ax| ax.name.ends_with("::n_ind")));
+//! ```
+
+use std::fmt;
+use std::marker::PhantomData;
+use std::sync::Mutex;
+
+/// Marker for an assumed proposition `T` without proof.
+pub struct Axiom<T>(PhantomData<T>);
+
+impl<T> Axiom<T> {
+    /// Assumes `T` without proof.
+    ///
+    /// Panics when called, since there is no value to return.
+    pub fn assume(location: &'static str) -> T {panic!("axiom has no proof: {}", location)}
+    /// Returns the statement as a Rust type.
+    pub fn statement() -> &'static str {std::any::type_name::<T>()}
+}
+
+/// Introduces an axiom in the body of a function.
+#[macro_export]
+macro_rules! axiom(() => {
+    $crate::axiom::Axiom::assume(concat!(module_path!(), ":", line!()))
+});
+
+/// Information about an axiom.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct AxiomInfo {
+    /// The path of the axiom, relative to the crate root.
+    ///
+    /// For axioms generated by macros, the path starts with the name of the crate.
+    pub name: &'static str,
+    /// The statement of the axiom.
+    pub statement: &'static str,
+    /// The configuration that enables the axiom, if any.
+    pub cfg: Option<&'static Cfg>,
+}
+
+/// Configuration predicate of a `#[cfg(...)]` attribute.
+///
+/// ```rust
+/// use prop::axiom::Cfg;
+///
+/// let c = Cfg::All(&[Cfg::Feature("a"), Cfg::Not(&Cfg::Feature("b"))]);
+/// assert_eq!(c.to_string(), "all(feature = \"a\", not(feature = \"b\"))");
+/// assert!(c.eval(&["a"]));
+/// assert!(!c.eval(&["a", "b"]));
+/// assert!(!c.eval(&[]));
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Cfg {
+    /// `feature = "x"`.
+    Feature(&'static str),
+    /// `all(..)`.
+    All(&'static [Cfg]),
+    /// `any(..)`.
+    Any(&'static [Cfg]),
+    /// `not(..)`.
+    Not(&'static Cfg),
+    /// Other predicates, e.g. `test`, which are disabled when compiling the library.
+    Other(&'static str),
+}
+
+impl Cfg {
+    /// Returns `true` if the predicate holds for the enabled features.
+    pub fn eval(&self, features: &[&str]) -> bool {
+        match self {
+            Cfg::Feature(f) => features.contains(f),
+            Cfg::All(xs) => xs.iter().all(|x| x.eval(features)),
+            Cfg::Any(xs) => xs.iter().any(|x| x.eval(features)),
+            Cfg::Not(x) => !x.eval(features),
+            Cfg::Other(_) => false,
+        }
+    }
+}
+
+impl fmt::Display for Cfg {
+    fn fmt(&self, w: &mut fmt::Formatter) -> fmt::Result {
+        fn list(w: &mut fmt::Formatter, name: &str, xs: &[Cfg]) -> fmt::Result {
+            write!(w, "{}(", name)?;
+            for (i, x) in xs.iter().enumerate() {
+                if i > 0 {write!(w, ", ")?}
+                write!(w, "{}", x)?;
+            }
+            write!(w, ")")
+        }
+        match self {
+            Cfg::Feature(f) => write!(w, "feature = {:?}", f),
+            Cfg::All(xs) => list(w, "all", xs),
+            Cfg::Any(xs) => list(w, "any", xs),
+            Cfg::Not(x) => write!(w, "not({})", x),
+            Cfg::Other(x) => write!(w, "{}", x),
+        }
+    }
+}
+
+impl AxiomInfo {
+    /// Returns `true` if the axiom is enabled by the current feature set.
+    pub fn is_enabled(&self) -> bool {
+        self.cfg.map(|c| c.eval(FEATURES)).unwrap_or(true)
+    }
+}
+
+impl fmt::Display for AxiomInfo {
+    fn fmt(&self, w: &mut fmt::Formatter) -> fmt::Result {
+        write!(w, "{}: {}", self.name, self.statement)?;
+        if let Some(c) = self.cfg {write!(w, " (cfg `{}`)", c)?}
+        Ok(())
+    }
+}
+
+/// All axioms in the library, including those that are disabled by features.
+pub static ALL: &[AxiomInfo] = include!(concat!(env!("OUT_DIR"), "/axioms.rs"));
+
+/// Features enabled when compiling the library, without `default`.
+pub static FEATURES: &[&str] = include!(concat!(env!("OUT_DIR"), "/features.rs"));
+
+/// Axioms added with [register].
+static REGISTERED: Mutex<Vec<&'static AxiomInfo>> = Mutex::new(Vec::new());
+
+/// Adds axioms generated by macros to the audit.
+///
+/// Axioms that are already listed, by name, are ignored.
+pub fn register(xs: &'static [AxiomInfo]) {
+    let mut reg = REGISTERED.lock().unwrap_or_else(|e| e.into_inner());
+    for x in xs {
+        if !ALL.iter().chain(reg.iter().copied()).any(|ax| ax.name == x.name) {reg.push(x)}
+    }
+}
+
+/// Returns the axioms enabled by the current feature set, including those added with [register].
+pub fn axioms() -> Vec<&'static AxiomInfo> {
+    let reg = REGISTERED.lock().unwrap_or_else(|e| e.into_inner());
+    ALL.iter().chain(reg.iter().copied()).filter(|ax| ax.is_enabled()).collect()
+}
@@ -0,0 +1,81 @@
+//! # Seshatic and Platonic Bias
+//!
+//! A proposition `a` is Platonic when it has a loop witness `a ~~ a` ([Platonic]),
+//! and Seshatic when it rejects the loop witness `¬(a ~~ a)` ([Seshatic]).
+//! For more information, see the [quality] module.
+//!
+//! The two biases are mutually exclusive ([absurd]).
+//! Under Excluded Middle for `~a`, every proposition has one of the two biases ([cover]).
+//! This holds for decidable propositions ([cover_dprop])
+//! and for propositions that are tautologically decidable ([cover_tauto_excm]).
+//!
+//! The biases are mirror images of each other under negation:
+//!
+//! - `seshatic(a) => platonic(¬a)` ([Seshatic::to_platonic_inv])
+//! - `platonic(¬a) => seshatic(a)` ([Platonic::to_seshatic_inv])
+//!
+//! Both can be transported into qubit propositions `~a` and `~¬a` (see [qubit::Qu]).
+
+use crate::*;
+use hooo::Tauto;
+use quality::{Aq, Q};
+use qubit::Qu;
+
+/// Platonic bias `a ~~ a`.
+#[derive(Clone)]
+pub struct Platonic<A>(pub Q<A, A>);
+
+/// Seshatic bias `¬(a ~~ a)`.
+#[derive(Clone)]
+pub struct Seshatic<A>(pub Not<Q<A, A>>);
+
+impl<A: Prop> Platonic<A> {
+    /// `platonic(a) => ~a`.
+    pub fn to_qu(self) -> Qu<A> {Qu::from_q(self.0)}
+    /// `~a => platonic(a)`.
+    pub fn from_qu(x: Qu<A>) -> Self {Platonic(x.to_q())}
+    /// `platonic(a) => ¬(a ~¬~ a)`.
+    pub fn to_naq(self) -> Not<Aq<A, A>> {quality::q_to_aq_sesh_left(self.0)}
+}
+
+impl<A: Prop> Platonic<Not<A>> {
+    /// `platonic(¬a) => seshatic(a)`.
+    pub fn to_seshatic_inv(self) -> Seshatic<A> {Seshatic(quality::q_inv_to_sesh(self.0))}
+}
+
+impl<A: Prop> Seshatic<A> {
+    /// `seshatic(a) => ¬~a`.
+    pub fn to_nqu(self) -> Not<Qu<A>> {
+        let f = self.0;
+        Rc::new(move |x: Qu<A>| f(x.to_q()))
+    }
+    /// `¬~a => seshatic(a)`.
+    pub fn from_nqu(x: Not<Qu<A>>) -> Self {Seshatic(Rc::new(move |q| x(Qu::from_q(q))))}
+    /// `seshatic(a) => ~¬a`.
+    pub fn to_qu_inv(self) -> Qu<Not<A>> {qubit::sesh_to_inv(self.to_nqu())}
+    /// `~¬a => seshatic(a)`.
+    pub fn from_qu_inv(x: Qu<Not<A>>) -> Self {Seshatic::from_nqu(qubit::inv_to_sesh(x))}
+    /// `seshatic(a) => platonic(¬a)`.
+    pub fn to_platonic_inv(self) -> Platonic<Not<A>> {Platonic(quality::sesh_to_q_inv(self.0))}
+    /// `(a ~¬~ a) => seshatic(a)`.
+    pub fn from_aq(x: Aq<A, A>) -> Self {Seshatic(quality::aq_to_sesh_left(x))}
+}
+
+/// `platonic(a) ⋀ seshatic(a) => false`.
+pub fn absurd<A: Prop>(Platonic(q): Platonic<A>, Seshatic(sesh): Seshatic<A>) -> False {sesh(q)}
+
+/// `(~a ⋁ ¬~a)  =>  platonic(a) ⋁ seshatic(a)`.
+pub fn cover<A: Prop>(x: ExcM<Qu<A>>) -> Or<Platonic<A>, Seshatic<A>> {
+    match x {
+        Left(qu) => Left(Platonic::from_qu(qu)),
+        Right(nqu) => Right(Seshatic::from_nqu(nqu)),
+    }
+}
+
+/// `platonic(a) ⋁ seshatic(a)` for decidable `a`.
+pub fn cover_dprop<A: DProp>() -> Or<Platonic<A>, Seshatic<A>> {cover(qubit::decide())}
+
+/// `(a ⋁ ¬a)^true  =>  platonic(a) ⋁ seshatic(a)`.
+pub fn cover_tauto_excm<A: Prop>(x: Tauto<ExcM<A>>) -> Or<Platonic<A>, Seshatic<A>> {
+    cover(qubit::decide_tauto_excm(x))
+}
@@ -0,0 +1,134 @@
+//! # Boolean Algebras under Decidability
+//!
+//! A Boolean algebra is a Heyting algebra (see [heyting]) where every element has a complement:
+//!
+//! `⊤ ≤ a ∨ ¬a`
+//!
+//! Propositions form a Boolean algebra when restricted to decidable propositions
+//! (`DProp`), since the complement law is Excluded Middle ([IsBoolean::excm]).
+//! The classical laws, e.g. double negation ([IsBoolean::dneg]) and De Morgan
+//! ([IsBoolean::de_morgan_meet]), are derived from the complement law.
+//!
+//! ### Normal forms
+//!
+//! At the reflected level (see [expr::Expr]), this module converts expressions into
+//! negation normal form ([nnf]), disjunctive normal form ([dnf])
+//! and conjunctive normal form ([cnf]).
+//!
+//! Each rewrite of the converter is a law of this module or of [heyting],
+//! such that the conversion is sound at the type level when atoms are `DProp`:
+//!
+//! - `¬¬a  ->  a`: [IsBoolean::dneg]
+//! - `¬(a ⋀ b)  ->  ¬a ⋁ ¬b`: [IsBoolean::de_morgan_meet]
+//! - `¬(a ⋁ b)  ->  ¬a ⋀ ¬b`: [IsBoolean::de_morgan_join]
+//! - `a => b  ->  ¬a ⋁ b`: [IsBoolean::material_imply]
+//! - `a ⋀ (b ⋁ c)  ->  (a ⋀ b) ⋁ (a ⋀ c)`: [IsHeyting::distrib]
+//! - `a ⋁ (b ⋀ c)  ->  (a ⋁ b) ⋀ (a ⋁ c)`: [IsBoolean::join_distrib]
+//!
+//! The converters are those of [rewrite], which also return a certificate of equivalence,
+//! and the rules of [rewrite] use these laws for their classical direction.
+//! At runtime, [is_sound] checks that an expression is equivalent to its normal forms.
+//!
+//! ```rust
+//! use prop::*;
+//! use prop::boolean::{cnf, dnf, is_sound, nnf, IsBoolean};
+//! use prop::heyting::Props;
+//!
+//! let e = expr!(Imply<A, And<B, C>>);
+//! assert_eq!(nnf(&e).to_string(), "¬A ⋁ (B ⋀ C)");
+//! assert_eq!(cnf(&e).to_string(), "(¬A ⋁ B) ⋀ (¬A ⋁ C)");
+//! assert_eq!(dnf(&expr!(Or<And<A, B>, C>)).to_string(), "(A ⋀ B) ⋁ C");
+//! assert_eq!(nnf(&expr!(Not<Or<A, Not<B>>>)).to_string(), "¬A ⋀ B");
+//! assert!(is_sound(&e) && is_sound(&expr!(Eq<A, B>)));
+//!
+//! // The first rewrite of `e`, at the type level.
+//! fn first<A: DProp, B: DProp, C: DProp>(x: Imply<A, And<B, C>>) -> Or<Not<A>, And<B, C>> {
+//!     Props::material_imply()(x)
+//! }
+//! ```
+
+use crate::*;
+use expr::Expr;
+use heyting::{IsHeyting, Neg, Props};
+
+/// Boolean algebra laws for decidable elements.
+pub trait IsBoolean: IsHeyting {
+    /// `⊤ ≤ a ∨ ¬a`.
+    fn excm<A: DProp>() -> Self::Le<Self::Top, Self::Join<A, Neg<Self, A>>>;
+
+    /// `b ≤ b ∧ (a ∨ ¬a)`.
+    fn meet_excm<A: DProp, B: Prop>() ->
+        Self::Le<B, Self::Meet<B, Self::Join<A, Neg<Self, A>>>>
+    {
+        Self::meet_intro(Self::le_refl(), Self::le_trans(Self::top(), Self::excm()))
+    }
+    /// `¬¬a ≤ a`.
+    fn dneg<A: DProp>() -> Self::Le<Neg<Self, Neg<Self, A>>, A> {
+        let x = Self::le_trans(Self::meet_excm::<A, _>(), Self::distrib());
+        let right = Self::le_trans(Self::eval(), Self::bot());
+        Self::le_trans(x, Self::join_elim(Self::meet_snd(), right))
+    }
+    /// `¬(a ∨ b) ≤ ¬a ∧ ¬b`.
+    fn de_morgan_join<A: Prop, B: Prop>() ->
+        Self::Le<Neg<Self, Self::Join<A, B>>, Self::Meet<Neg<Self, A>, Neg<Self, B>>>
+    {
+        let a = Self::le_trans(Self::meet_mono(Self::le_refl(), Self::join_inl()), Self::eval());
+        let b = Self::le_trans(Self::meet_mono(Self::le_refl(), Self::join_inr()), Self::eval());
+        Self::meet_intro(Self::curry(a), Self::curry(b))
+    }
+    /// `¬(a ∧ b) ≤ ¬a ∨ ¬b`.
+    fn de_morgan_meet<A: DProp, B: Prop>() ->
+        Self::Le<Neg<Self, Self::Meet<A, B>>, Self::Join<Neg<Self, A>, Neg<Self, B>>>
+    {
+        let x = Self::le_trans(Self::meet_excm::<A, _>(), Self::distrib());
+        // `(¬(a ∧ b) ∧ a) ∧ b ≤ ⊥`.
+        let nb = Self::curry(Self::le_trans(Self::meet_assoc(), Self::eval()));
+        let left = Self::le_trans(nb, Self::join_inr());
+        let right = Self::le_trans(Self::meet_snd(), Self::join_inl());
+        Self::le_trans(x, Self::join_elim(left, right))
+    }
+    /// `a → b ≤ ¬a ∨ b`.
+    fn material_imply<A: DProp, B: Prop>() ->
+        Self::Le<Self::Imp<A, B>, Self::Join<Neg<Self, A>, B>>
+    {
+        let x = Self::le_trans(Self::meet_excm::<A, _>(), Self::distrib());
+        let left = Self::le_trans(Self::eval(), Self::join_inr());
+        let right = Self::le_trans(Self::meet_snd(), Self::join_inl());
+        Self::le_trans(x, Self::join_elim(left, right))
+    }
+    /// `a ∨ (b ∧ c) ≤ (a ∨ b) ∧ (a ∨ c)`.
+    fn join_distrib<A: Prop, B: Prop, C: Prop>() -> Self::Le<
+        Self::Join<A, Self::Meet<B, C>>,
+        Self::Meet<Self::Join<A, B>, Self::Join<A, C>>
+    > {
+        Self::meet_intro(Self::join_mono(Self::le_refl(), Self::meet_fst()),
+                         Self::join_mono(Self::le_refl(), Self::meet_snd()))
+    }
+}
+
+impl IsBoolean for Props {
+    fn excm<A: DProp>() -> Imply<True, ExcM<A>> {A::decide().map_any()}
+}
+
+/// Converts into negation normal form, see [rewrite::to_nnf].
+///
+/// The result uses only atoms, negated atoms, `true`, `false`, `⋀` and `⋁`.
+pub fn nnf(e: &Expr) -> Expr {rewrite::to_nnf(e).to}
+
+/// Converts into disjunctive normal form, a disjunction of conjunctions of literals.
+///
+/// See [rewrite::to_dnf].
+pub fn dnf(e: &Expr) -> Expr {rewrite::to_dnf(e).to}
+
+/// Converts into conjunctive normal form, a conjunction of disjunctions of literals.
+///
+/// See [rewrite::to_cnf].
+pub fn cnf(e: &Expr) -> Expr {rewrite::to_cnf(e).to}
+
+/// Returns `true` if the expression is equivalent to its normal forms under all assignments.
+///
+/// Returns `false` when there are too many atoms to check (see [check::MAX_ATOMS]).
+pub fn is_sound(e: &Expr) -> bool {
+    vec![nnf(e), dnf(e), cnf(e)].into_iter()
+        .all(|x| check::check(&Expr::eq(e.clone(), x)).is_valid())
+}
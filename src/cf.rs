@@ -0,0 +1,103 @@
+//! # Counterfactual Conditionals
+//!
+//! The counterfactual conditional `a □→ b` ([WouldImply]) reads "if `a` were the case,
+//! then `b` would be the case".
+//! Unlike implication, a counterfactual is not trivially true when `a` is false,
+//! so it is an opaque proposition, in the same style as the [prov] module.
+//!
+//! The axioms follow Lewis' logic of counterfactuals:
+//!
+//! - Identity `a □→ a` ([cf_refl])
+//! - Weak centering `(a □→ b)  =>  (a => b)` ([cf_mp])
+//! - Strong centering `a ⋀ b  =>  (a □→ b)` ([cf_centering])
+//! - Strict conditional `(a => b)^true  =>  (a □→ b)` ([cf_strict])
+//! - Consequent closure `(a □→ b) ⋀ (b => c)^true  =>  (a □→ c)` ([cf_weaken])
+//! - Conjunction of consequents `(a □→ b) ⋀ (a □→ c)  =>  (a □→ (b ⋀ c))` ([cf_and])
+//! - Disjunction of antecedents `(a □→ c) ⋀ (b □→ c)  =>  ((a ⋁ b) □→ c)` ([cf_or])
+//! - Left logical equivalence `(a == b)^true ⋀ (a □→ c)  =>  (b □→ c)` ([cf_in_left_arg])
+//!
+//! Strengthening of the antecedent `(a □→ c)  =>  ((a ⋀ b) □→ c)` does not hold,
+//! which is what separates counterfactuals from strict conditionals.
+//!
+//! ### Translation into modal logic
+//!
+//! A strict conditional `□(a => b)` (see [modal::Nec]) implies the counterfactual
+//! ([nec_to_cf]). In particular, necessary consequents ([nec_cons_to_cf])
+//! and impossible antecedents ([para_to_cf]) give counterfactuals vacuously.
+//!
+//! This module is enabled with the `cf` feature.
+
+use crate::*;
+use hooo::{Para, Pow, Tauto, pow_to_imply_lift};
+use hooo::pow::PowExt;
+use modal::Nec;
+
+/// Counterfactual conditional `a □→ b`.
+#[derive(Copy, Clone)]
+pub struct WouldImply<A, B>(A, B);
+
+/// `a □→ a`.
+pub fn cf_refl<A: Prop>() -> WouldImply<A, A> {axiom!()}
+/// `(a □→ b)  =>  (a => b)`.
+///
+/// Weak centering.
+pub fn cf_mp<A: Prop, B: Prop>(_: WouldImply<A, B>) -> Imply<A, B> {axiom!()}
+/// `a ⋀ b  =>  (a □→ b)`.
+///
+/// Strong centering.
+pub fn cf_centering<A: Prop, B: Prop>(_: A, _: B) -> WouldImply<A, B> {axiom!()}
+/// `(a => b)^true  =>  (a □→ b)`.
+pub fn cf_strict<A: Prop, B: Prop>(_: Tauto<Imply<A, B>>) -> WouldImply<A, B> {axiom!()}
+/// `(a □→ b) ⋀ (b => c)^true  =>  (a □→ c)`.
+pub fn cf_weaken<A: Prop, B: Prop, C: Prop>(
+    _: WouldImply<A, B>,
+    _: Tauto<Imply<B, C>>
+) -> WouldImply<A, C> {axiom!()}
+/// `(a □→ b) ⋀ (a □→ c)  =>  (a □→ (b ⋀ c))`.
+pub fn cf_and<A: Prop, B: Prop, C: Prop>(
+    _: WouldImply<A, B>,
+    _: WouldImply<A, C>
+) -> WouldImply<A, And<B, C>> {axiom!()}
+/// `(a □→ c) ⋀ (b □→ c)  =>  ((a ⋁ b) □→ c)`.
+pub fn cf_or<A: Prop, B: Prop, C: Prop>(
+    _: WouldImply<A, C>,
+    _: WouldImply<B, C>
+) -> WouldImply<Or<A, B>, C> {axiom!()}
+/// `(a == b)^true ⋀ (a □→ c)  =>  (b □→ c)`.
+pub fn cf_in_left_arg<A: Prop, B: Prop, C: Prop>(
+    _: Tauto<Eq<A, B>>,
+    _: WouldImply<A, C>
+) -> WouldImply<B, C> {axiom!()}
+
+/// `(a □→ b) ⋀ a  =>  b`.
+pub fn cf_modus_ponens<A: Prop, B: Prop>(x: WouldImply<A, B>, a: A) -> B {cf_mp(x)(a)}
+/// `(a □→ (b ⋀ c))  =>  (a □→ b)`.
+pub fn cf_fst<A: Prop, B: Prop, C: Prop>(x: WouldImply<A, And<B, C>>) -> WouldImply<A, B> {
+    cf_weaken(x, pow_to_imply_lift(and::fst))
+}
+/// `(a □→ (b ⋀ c))  =>  (a □→ c)`.
+pub fn cf_snd<A: Prop, B: Prop, C: Prop>(x: WouldImply<A, And<B, C>>) -> WouldImply<A, C> {
+    cf_weaken(x, pow_to_imply_lift(and::snd))
+}
+/// `(a □→ b)  =>  (a □→ (a ⋀ b))`.
+pub fn cf_and_refl<A: Prop, B: Prop>(x: WouldImply<A, B>) -> WouldImply<A, And<A, B>> {
+    cf_and(cf_refl(), x)
+}
+/// `(a □→ b) ⋀ ¬b  =>  ¬a`.
+pub fn cf_modus_tollens<A: Prop, B: Prop>(x: WouldImply<A, B>, nb: Not<B>) -> Not<A> {
+    imply::modus_tollens(cf_mp(x))(nb)
+}
+
+/// `□(a => b)  =>  (a □→ b)`.
+pub fn nec_to_cf<A: Prop, B: Prop>(x: Nec<Imply<A, B>>) -> WouldImply<A, B> {cf_strict(x)}
+/// `□b  =>  (a □→ b)`.
+pub fn nec_cons_to_cf<A: Prop, B: Prop>(x: Nec<B>) -> WouldImply<A, B> {
+    fn f<A: Prop, B: Prop>(b: B) -> Imply<A, B> {b.map_any()}
+    cf_strict(x.trans(f))
+}
+/// `false^a  =>  (a □→ b)`.
+pub fn para_to_cf<A: Prop, B: Prop>(x: Para<A>) -> WouldImply<A, B> {
+    fn f<B: Prop>(x: False) -> B {match x {}}
+    let y: Pow<B, A> = x.trans(f);
+    cf_strict(pow_to_imply_lift(y))
+}
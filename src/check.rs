@@ -0,0 +1,149 @@
+//! # Counterexample Checker
+//!
+//! Before spending hours on a proof, it is useful to know whether the goal is a classical
+//! tautology at all. This module searches boolean assignments of a reflected goal
+//! (see [expr::Expr]) for counterexamples.
+//!
+//! Since IPL is weaker than classical logic, a counterexample means that the goal can not
+//! be proved in IPL either. No counterexample means the goal holds in classical logic,
+//! e.g. with `DProp` (it might still require Excluded Middle).
+//!
+//! Atoms that correspond to decidable propositions can be fixed in advance
+//! using [Checker::decide], which runs `Decidable::decide` to find the truth value.
+//!
+//! ```rust
+//! use prop::*;
+//! use prop::check::{check, counterexample, Checker, Report};
+//!
+//! assert_eq!(check(&expr!(Imply<A, A>)), Report::Valid);
+//! // Peirce's law holds classically.
+//! assert!(check(&expr!(Imply<Imply<Imply<A, B>, A>, A>)).is_valid());
+//!
+//! let a = counterexample(&expr!(Imply<A, B>)).unwrap();
+//! assert_eq!((a["A"], a["B"]), (true, false));
+//! assert_eq!(check(&expr!(Imply<A, B>)).to_string(), "counterexample: A = true, B = false");
+//!
+//! // Fixing `A` to false makes `A => B` valid.
+//! assert!(Checker::new().fix("A", false).check(&expr!(Imply<A, B>)).is_valid());
+//! // `True` is decided to be true.
+//! assert!(!Checker::new().decide::<True>("A").check(&expr!(Imply<A, B>)).is_valid());
+//!
+//! let checker = Checker {max_atoms: Some(1), ..Checker::new()};
+//! let report = checker.check(&expr!(Or<A, B>));
+//! assert_eq!(report, Report::TooManyAtoms {atoms: 2, max: 1});
+//! assert_eq!(report.to_string(), "too many atoms (2 > 1)");
+//!
+//! // The limit is capped, such that enumeration does not overflow.
+//! use prop::expr::Expr;
+//! let goal = (1..64).fold(Expr::atom("X0"), |e, i| Expr::or(e, Expr::atom(&format!("X{}", i))));
+//! let checker = Checker {max_atoms: Some(usize::MAX), ..Checker::new()};
+//! assert_eq!(checker.check(&goal), Report::TooManyAtoms {atoms: 64, max: 63});
+//! ```
+
+use crate::*;
+use expr::{Assignment, Expr};
+
+/// The maximum number of free atoms that are enumerated by default.
+pub const MAX_ATOMS: usize = 16;
+/// The upper limit of [Checker::max_atoms], since assignments are enumerated with `u64`.
+pub const LIMIT_ATOMS: usize = 63;
+
+/// The result of checking a goal.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Report {
+    /// No counterexample was found among all assignments.
+    Valid,
+    /// An assignment that makes the goal false.
+    Counterexample(Assignment),
+    /// There were too many free atoms to enumerate all assignments.
+    TooManyAtoms {
+        /// The number of free atoms.
+        atoms: usize,
+        /// The maximum number of free atoms to enumerate.
+        max: usize,
+    },
+}
+
+impl Report {
+    /// Returns `true` if no counterexample was found.
+    pub fn is_valid(&self) -> bool {matches!(self, Report::Valid)}
+}
+
+impl std::fmt::Display for Report {
+    fn fmt(&self, w: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Report::Valid => write!(w, "no counterexample"),
+            Report::Counterexample(a) => {
+                write!(w, "counterexample: ")?;
+                for (i, (k, v)) in a.iter().enumerate() {
+                    if i > 0 {write!(w, ", ")?}
+                    write!(w, "{} = {}", k, v)?;
+                }
+                Ok(())
+            }
+            Report::TooManyAtoms {atoms, max} => write!(w, "too many atoms ({} > {})", atoms, max),
+        }
+    }
+}
+
+/// Searches for counterexamples with some atoms fixed.
+#[derive(Clone, Debug, Default)]
+pub struct Checker {
+    /// Atoms with known truth values.
+    pub fixed: Assignment,
+    /// The maximum number of free atoms to enumerate.
+    ///
+    /// Defaults to [MAX_ATOMS] and is capped at [LIMIT_ATOMS].
+    pub max_atoms: Option<usize>,
+}
+
+impl Checker {
+    /// Creates a new checker.
+    pub fn new() -> Checker {Checker::default()}
+
+    /// Fixes the truth value of an atom.
+    pub fn fix(mut self, name: &str, value: bool) -> Checker {
+        self.fixed.insert(name.into(), value);
+        self
+    }
+
+    /// Fixes the truth value of an atom by deciding a decidable proposition.
+    pub fn decide<A: DProp>(self, name: &str) -> Checker {
+        let value = match A::decide() {
+            Left(_) => true,
+            Right(_) => false,
+        };
+        self.fix(name, value)
+    }
+
+    /// Checks a goal.
+    pub fn check(&self, goal: &Expr) -> Report {
+        profile!("check::check");
+        let free: Vec<String> = goal.atoms().into_iter()
+            .filter(|x| !self.fixed.contains_key(x)).collect();
+        let max = self.max_atoms.unwrap_or(MAX_ATOMS).min(LIMIT_ATOMS);
+        if free.len() > max {return Report::TooManyAtoms {atoms: free.len(), max}}
+
+        let mut assignment = self.fixed.clone();
+        for bits in 0..(1u64 << free.len()) {
+            for (i, x) in free.iter().enumerate() {
+                assignment.insert(x.clone(), (bits >> i) & 1 == 1);
+            }
+            if goal.eval(&assignment) == Some(false) {
+                return Report::Counterexample(assignment);
+            }
+        }
+        Report::Valid
+    }
+}
+
+/// Checks a goal for counterexamples.
+pub fn check(goal: &Expr) -> Report {Checker::new().check(goal)}
+
+/// Returns a counterexample of a goal, if any.
+pub fn counterexample(goal: &Expr) -> Option<Assignment> {
+    match check(goal) {
+        Report::Counterexample(a) => Some(a),
+        _ => None,
+    }
+}
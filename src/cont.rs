@@ -0,0 +1,128 @@
+//! # Continuation Monad
+//!
+//! A continuation `cont(r, a)` ([Cont]) is a proof of `(a => r) => r`.
+//! This is a monad with [unit()], [bind], [map] and [join].
+//!
+//! For `r == false`, the continuation monad is double negation `¬¬a` ([DN]).
+//! The call-by-name (Kolmogorov) translation puts `¬¬` in front of every sub-expression:
+//!
+//! - `a^N := ¬¬a` for atoms
+//! - `(a ⋀ b)^N := ¬¬(a^N ⋀ b^N)`
+//! - `(a ⋁ b)^N := ¬¬(a^N ⋁ b^N)`
+//! - `(a => b)^N := ¬¬(a^N => b^N)`
+//! - `(¬a)^N := ¬¬¬a^N`
+//!
+//! Since types can not be inspected, the translation is done manually using type aliases
+//! ([NAnd], [NOr], [NImply], [NNot]), or on reflected expressions with [translate].
+//!
+//! A translated proposition is stable, `¬¬a^N => a^N` ([stable]),
+//! so Double Negation holds in the image of the translation without [DProp].
+//! Excluded Middle is valid under `¬¬` ([excm]), therefore every proof that uses
+//! Excluded Middle as an assumption translates to a constructive proof ([classical]).
+//! This is Glivenko's theorem in the form that fits with the constructive core.
+//!
+//! For decidable propositions, the translation can be undone with [rev].
+
+use crate::*;
+use crate::expr::Expr;
+
+/// `(a => r) => r`.
+pub type Cont<R, A> = Imply<Imply<A, R>, R>;
+/// `¬¬a`.
+pub type DN<A> = Cont<False, A>;
+
+/// `¬¬(a ⋀ b)`.
+pub type NAnd<A, B> = DN<And<A, B>>;
+/// `¬¬(a ⋁ b)`.
+pub type NOr<A, B> = DN<Or<A, B>>;
+/// `¬¬(a => b)`.
+pub type NImply<A, B> = DN<Imply<A, B>>;
+/// `¬¬¬a`.
+pub type NNot<A> = DN<Not<A>>;
+
+/// `a => cont(r, a)`.
+pub fn unit<R: Prop, A: Prop>(a: A) -> Cont<R, A> {Rc::new(move |k| k(a.clone()))}
+/// `cont(r, a) ⋀ (a => cont(r, b))  =>  cont(r, b)`.
+pub fn bind<R: Prop, A: Prop, B: Prop>(x: Cont<R, A>, f: Imply<A, Cont<R, B>>) -> Cont<R, B> {
+    Rc::new(move |k: Imply<B, R>| {
+        let f = f.clone();
+        x(Rc::new(move |a| f(a)(k.clone())))
+    })
+}
+/// `(a => b) ⋀ cont(r, a)  =>  cont(r, b)`.
+pub fn map<R: Prop, A: Prop, B: Prop>(f: Imply<A, B>, x: Cont<R, A>) -> Cont<R, B> {
+    bind(x, Rc::new(move |a| unit(f(a))))
+}
+/// `cont(r, cont(r, a))  =>  cont(r, a)`.
+pub fn join<R: Prop, A: Prop>(x: Cont<R, Cont<R, A>>) -> Cont<R, A> {bind(x, Rc::new(|y| y))}
+/// `cont(r, a) ⋀ cont(r, b)  =>  cont(r, a ⋀ b)`.
+pub fn and<R: Prop, A: Prop, B: Prop>(x: Cont<R, A>, y: Cont<R, B>) -> Cont<R, And<A, B>> {
+    bind(x, Rc::new(move |a: A| map(Rc::new(move |b| (a.clone(), b)), y.clone())))
+}
+/// `cont(r, r)  =>  r`.
+pub fn run<R: Prop>(x: Cont<R, R>) -> R {x(Rc::new(|r| r))}
+
+/// `¬¬¬¬a => ¬¬a`.
+///
+/// Every translated proposition is stable.
+pub fn stable<A: Prop>(x: DN<DN<A>>) -> DN<A> {not::rev_triple(x)}
+/// `¬¬(a ⋁ ¬a)`.
+pub fn excm<A: Prop>() -> DN<ExcM<A>> {A::nnexcm()}
+/// `¬¬(¬¬a => a)`.
+pub fn dneg<A: Prop>() -> DN<Dneg<A>> {
+    map(Rc::new(|x: ExcM<A>| -> Dneg<A> {match x {
+        Left(a) => a.map_any(),
+        Right(na) => Rc::new(move |nna: Not<Not<A>>| not::absurd(nna, na.clone())),
+    }}), excm())
+}
+/// `((a ⋁ ¬a) => b)  =>  ¬¬b`.
+///
+/// Glivenko: A proof using Excluded Middle translates to a constructive proof of `¬¬b`.
+pub fn classical<A: Prop, B: Prop>(f: Imply<ExcM<A>, B>) -> DN<B> {map(f, excm())}
+/// `((¬¬a => a) => b)  =>  ¬¬b`.
+pub fn classical_dneg<A: Prop, B: Prop>(f: Imply<Dneg<A>, B>) -> DN<B> {map(f, dneg())}
+/// `¬¬a => a` for decidable `a`.
+pub fn rev<A: DProp>(x: DN<A>) -> A {not::rev_double(x)}
+/// `¬¬(a => b)  =>  (¬¬a => ¬¬b)`.
+pub fn imply<A: Prop, B: Prop>(x: NImply<A, B>) -> Imply<DN<A>, DN<B>> {
+    Rc::new(move |y: DN<A>| {
+        bind(x.clone(), Rc::new(move |f: Imply<A, B>| map(f, y.clone())))
+    })
+}
+/// `(¬¬a => ¬¬b)  =>  ¬¬(a => b)`.
+pub fn rev_imply<A: Prop, B: Prop>(x: Imply<DN<A>, DN<B>>) -> NImply<A, B> {
+    Rc::new(move |k: Not<Imply<A, B>>| {
+        let k2 = k.clone();
+        let nb: Not<B> = Rc::new(move |b: B| k2(b.map_any()));
+        let nna: DN<A> = Rc::new(move |na: Not<A>| {
+            k(Rc::new(move |a| not::absurd(na.clone(), a)))
+        });
+        x(nna)(nb)
+    })
+}
+/// `¬¬(a ⋁ b)  =>  ¬(¬a ⋀ ¬b)`.
+pub fn or<A: Prop, B: Prop>(x: NOr<A, B>) -> Not<And<Not<A>, Not<B>>> {
+    Rc::new(move |(na, nb)| x(Rc::new(move |y| match y {
+        Left(a) => na(a),
+        Right(b) => nb(b),
+    })))
+}
+
+/// Call-by-name translation of a reflected expression.
+///
+/// An expression is a classical tautology if and only if
+/// its translation is provable in IPL.
+pub fn translate(e: &Expr) -> Expr {
+    use Expr::*;
+
+    fn dn(a: Expr) -> Expr {!!a}
+
+    match e {
+        True | False | Atom(_) => dn(e.clone()),
+        Not(a) => dn(!translate(a)),
+        And(a, b) => dn(Expr::and(translate(a), translate(b))),
+        Or(a, b) => dn(Expr::or(translate(a), translate(b))),
+        Imply(a, b) => dn(Expr::imply(translate(a), translate(b))),
+        Eq(a, b) => dn(Expr::eq(translate(a), translate(b))),
+    }
+}
@@ -0,0 +1,104 @@
+//! # Negative Results
+//!
+//! This module collects standard non-theorems, such that they can be cited
+//! instead of being derived again.
+//!
+//! There are two kinds of negative results:
+//!
+//! - Refutations: A proof of `false^p` ([Para]) shows that `p` never holds
+//! - Non-provability: A countermodel in Kripke semantics (see [kripke]) shows
+//!   that `p` can not be proved in IPL ([non_theorems] and [countermodels])
+//!
+//! These are different, since classical tautologies such as Excluded Middle `a ⋁ ¬a`
+//! can not be proved in IPL, while they can not be refuted either ([para_not_excm]).
+//! Double negation of a classical tautology is always provable in IPL (Glivenko's theorem).
+//!
+//! ```rust
+//! use prop::counter::{countermodels, non_theorems};
+//!
+//! assert_eq!(countermodels(3).len(), non_theorems().len());
+//! ```
+//!
+//! ### Imaginary Inverse
+//!
+//! One can not prove `inv(f) ~~ inv(f)` without assumptions (see [fun::inv]).
+//! This is a non-provability result and not a refutation,
+//! since some functions have inverses, e.g. `inv(id{a}) ~~ inv(id{a})`
+//! follows from [fun::id_q].
+//! When `f` has no inverse, `false^(~inv(f))` refutes every quality with `inv(f)` ([para_inv_q]).
+
+use crate::*;
+use expr::Expr;
+use fun::Inv;
+use hooo::{pow_transitivity, Para};
+use kripke::{countermodel, Frame};
+use quality::Q;
+use qubit::Qu;
+
+/// `false^(a ⋀ ¬a)`.
+pub fn para_contra<A: Prop>() -> Para<And<A, Not<A>>> {|(a, na)| na(a)}
+/// `false^(a == ¬a)`.
+pub fn para_eq_not<A: Prop>() -> Para<Eq<A, Not<A>>> {
+    |(a_na, na_a)| {
+        let na: Not<A> = Rc::new(move |a: A| a_na(a.clone())(a));
+        na.clone()(na_a(na))
+    }
+}
+/// `false^(true => false)`.
+pub fn para_true_false() -> Para<Imply<True, False>> {|f| f(True)}
+/// `false^¬(a ⋁ ¬a)`.
+///
+/// Excluded Middle can not be refuted, although it can not be proved in IPL.
+pub fn para_not_excm<A: Prop>() -> Para<Not<ExcM<A>>> {|nexcm| A::nnexcm()(nexcm)}
+/// `false^¬(¬¬a => a)`.
+///
+/// Double negation elimination can not be refuted, although it can not be proved in IPL.
+pub fn para_not_dneg<A: Prop>() -> Para<Not<Dneg<A>>> {
+    |n| {
+        let n2 = n.clone();
+        let na: Not<A> = Rc::new(move |a: A| n2(a.map_any()));
+        n(Rc::new(move |nna| match nna(na.clone()) {}))
+    }
+}
+/// `false^¬(((a => b) => a) => a)`.
+///
+/// Peirce's law can not be refuted, although it can not be proved in IPL.
+pub fn para_not_peirce<A: Prop, B: Prop>() -> Para<Not<Imply<Imply<Imply<A, B>, A>, A>>> {
+    |n| {
+        let n2 = n.clone();
+        let na: Not<A> = Rc::new(move |a: A| n2(a.map_any()));
+        let ab: Imply<A, B> = Rc::new(move |a| match na(a) {});
+        n(Rc::new(move |f| f(ab.clone())))
+    }
+}
+/// `false^((a ~~ b) ⋀ ¬~a)`.
+pub fn para_q_not_qu<A: Prop, B: Prop>() -> Para<And<Q<A, B>, Not<Qu<A>>>> {
+    |(q, nqu)| nqu((q.1).0)
+}
+/// `false^(~inv(f))  =>  false^(inv(f) ~~ g)`.
+pub fn para_inv_q<F: Prop, G: Prop>(x: Para<Qu<Inv<F>>>) -> Para<Q<Inv<F>, G>> {
+    pow_transitivity(|q: Q<Inv<F>, G>| (q.1).0, x)
+}
+
+/// Classical tautologies that can not be proved in IPL.
+pub fn non_theorems() -> Vec<(&'static str, Expr)> {
+    vec![
+        ("excm", expr!(ExcM<A>)),
+        ("dneg", expr!(Dneg<A>)),
+        ("peirce", expr!(Imply<Imply<Imply<A, B>, A>, A>)),
+        ("weak_excm", expr!(Or<Not<A>, Not<Not<A>>>)),
+        ("de_morgan_and", expr!(Imply<Not<And<A, B>>, Or<Not<A>, Not<B>>>)),
+        ("imply_to_or", expr!(Imply<Imply<A, B>, Or<Not<A>, B>>)),
+        ("rev_modus_tollens", expr!(Imply<Imply<Not<A>, Not<B>>, Imply<B, A>>)),
+        ("linearity", expr!(Or<Imply<A, B>, Imply<B, A>>)),
+    ]
+}
+
+/// Searches for countermodels of the non-theorems with up to `max_worlds` worlds.
+///
+/// Returns the non-theorems that are shown to be not provable in IPL.
+pub fn countermodels(max_worlds: usize) -> Vec<(&'static str, Frame)> {
+    non_theorems().into_iter()
+        .filter_map(|(name, e)| countermodel(&e, max_worlds).map(|frame| (name, frame)))
+        .collect()
+}
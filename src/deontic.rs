@@ -0,0 +1,100 @@
+//! # Deontic Logic
+//!
+//! Obligation `O(a)` ([Obligatory]) means that `a` ought to be the case.
+//! Permission and prohibition are defined from obligation:
+//!
+//! - Permitted `P(a) := ¬O(¬a)` ([Permitted])
+//! - Forbidden `F(a) := O(¬a)` ([Forbidden])
+//!
+//! The axioms are those of Standard Deontic Logic (SDL):
+//!
+//! - Necessitation `a^true  =>  O(a)` ([obl_nec])
+//! - Distribution (K) `O(a => b)  =>  (O(a) => O(b))` ([obl_distrib])
+//! - Consistency (D) `O(a)  =>  P(a)` ([obl_d])
+//!
+//! Unlike necessity in the [modal] module, obligation does not imply truth,
+//! since obligations can be violated. Therefore, `O(a)` is an opaque proposition.
+//!
+//! ### Translation into modal logic
+//!
+//! Necessity implies obligation ([nec_to_obl]),
+//! and obligation implies possibility ("ought implies can", [obl_to_nnpos]).
+//! Dually, impossibility implies prohibition ([para_to_forbidden]).
+
+use crate::*;
+use hooo::{Para, Tauto};
+use modal::{Nec, NNPos};
+
+/// Obligation `O(a)`.
+#[derive(Copy, Clone)]
+pub struct Obligatory<A>(A);
+
+/// Permission `P(a) := ¬O(¬a)`.
+pub type Permitted<A> = Not<Obligatory<Not<A>>>;
+
+/// Prohibition `F(a) := O(¬a)`.
+pub type Forbidden<A> = Obligatory<Not<A>>;
+
+/// `a^true  =>  O(a)`.
+pub fn obl_nec<A: Prop>(_: Tauto<A>) -> Obligatory<A> {axiom!()}
+/// `O(a => b)  =>  (O(a) => O(b))`.
+pub fn obl_distrib<A: Prop, B: Prop>(
+    _: Obligatory<Imply<A, B>>
+) -> Imply<Obligatory<A>, Obligatory<B>> {axiom!()}
+/// `O(a)  =>  P(a)`.
+pub fn obl_d<A: Prop>(_: Obligatory<A>) -> Permitted<A> {axiom!()}
+
+/// `O(a => b) ⋀ O(a)  =>  O(b)`.
+pub fn obl_modus_ponens<A: Prop, B: Prop>(
+    x: Obligatory<Imply<A, B>>,
+    y: Obligatory<A>
+) -> Obligatory<B> {obl_distrib(x)(y)}
+/// `O(true)`.
+pub fn obl_true() -> Obligatory<True> {obl_nec(hooo::tr())}
+/// `¬O(false)`.
+pub fn obl_consistency(x: Obligatory<False>) -> False {
+    obl_d(x)(obl_nec(tauto!(imply::id())))
+}
+/// `O(a) ⋀ O(b)  =>  O(a ⋀ b)`.
+pub fn obl_and<A: Prop, B: Prop>(a: Obligatory<A>, b: Obligatory<B>) -> Obligatory<And<A, B>> {
+    fn f<A: Prop, B: Prop>(a: A) -> Imply<B, And<A, B>> {Rc::new(move |b| (a.clone(), b))}
+    obl_modus_ponens(obl_modus_ponens(obl_nec(tauto!(hooo::pow_to_imply(f))), a), b)
+}
+/// `O(a ⋀ b)  =>  O(a)`.
+pub fn obl_fst<A: Prop, B: Prop>(x: Obligatory<And<A, B>>) -> Obligatory<A> {
+    obl_modus_ponens(obl_nec(tauto!(Rc::new(and::fst))), x)
+}
+/// `O(a ⋀ b)  =>  O(b)`.
+pub fn obl_snd<A: Prop, B: Prop>(x: Obligatory<And<A, B>>) -> Obligatory<B> {
+    obl_modus_ponens(obl_nec(tauto!(Rc::new(and::snd))), x)
+}
+/// `(a => b)^true ⋀ O(a)  =>  O(b)`.
+pub fn obl_in_arg<A: Prop, B: Prop>(x: Tauto<Imply<A, B>>, y: Obligatory<A>) -> Obligatory<B> {
+    obl_modus_ponens(obl_nec(x), y)
+}
+/// `F(a) ⋀ P(a)  =>  false`.
+pub fn forbidden_permitted_absurd<A: Prop>(f: Forbidden<A>, p: Permitted<A>) -> False {p(f)}
+/// `O(a)  =>  F(¬a)`.
+pub fn obl_to_forbidden_not<A: Prop>(x: Obligatory<A>) -> Forbidden<Not<A>> {
+    obl_in_arg(tauto!(Rc::new(not::double)), x)
+}
+/// `O(a) ⋀ F(a)  =>  false`.
+pub fn obl_forbidden_absurd<A: Prop>(x: Obligatory<A>, f: Forbidden<A>) -> False {obl_d(x)(f)}
+
+/// `□a  =>  O(a)`.
+pub fn nec_to_obl<A: Prop>(x: Nec<A>) -> Obligatory<A> {obl_nec(x)}
+/// `O(a)  =>  ¬□¬a`.
+pub fn obl_to_nnecn<A: Prop>(x: Obligatory<A>) -> Not<Nec<Not<A>>> {
+    let p = obl_d(x);
+    Rc::new(move |nec_na| p(nec_to_obl(nec_na)))
+}
+/// `O(a)  =>  ¬¬◇a`.
+///
+/// Ought implies can.
+pub fn obl_to_nnpos<A: Prop>(x: Obligatory<A>) -> NNPos<A> {
+    modal::nnecn_to_nnpos(obl_to_nnecn(x))
+}
+/// `false^a  =>  F(a)`.
+pub fn para_to_forbidden<A: Prop>(x: Para<A>) -> Forbidden<A> {
+    obl_nec(hooo::para_to_tauto_not(x))
+}
@@ -0,0 +1,161 @@
+//! # Proposition Diff
+//!
+//! Type errors involving large propositions are hard to read,
+//! since it is not easy to see which sub-term mismatches.
+//! This module compares two reflected propositions (see [expr::Expr]) structurally
+//! and reports the places where they disagree.
+//!
+//! The common part is printed with holes `?1`, `?2`, ... at the mismatches,
+//! followed by what was expected and what was got at each hole:
+//!
+//! ```text
+//! skeleton: a ⋀ (?1 => c)
+//! ?1 at right.left:
+//!   expected: b
+//!        got: ¬b
+//! ```
+//!
+//! For example:
+//!
+//! ```rust
+//! use prop::*;
+//! use prop::diff::{diff, Step};
+//!
+//! let d = diff(&expr!(And<A, Imply<B, C>>), &expr!(And<A, Imply<Not<B>, C>>));
+//! assert_eq!(d.skeleton.to_string(), "A ⋀ (?1 => C)");
+//! assert_eq!(d.mismatches.len(), 1);
+//! assert_eq!(d.mismatches[0].path, vec![Step::Right, Step::Left]);
+//! assert_eq!(d.to_string(),
+//!     "skeleton: A ⋀ (?1 => C)\n?1 at right.left:\n  expected: B\n       got: ¬B");
+//!
+//! assert!(diff(&expr!(Or<A, B>), &expr!(Or<A, B>)).is_empty());
+//! assert_eq!(diff(&expr!(A), &expr!(B)).mismatches[0].path, vec![]);
+//! ```
+//!
+//! Holes do not collide with atoms of the propositions:
+//! When an atom starts with `?`, the holes use a longer prefix, e.g. `??1`.
+//!
+//! ```rust
+//! use prop::expr::Expr;
+//! use prop::diff::diff;
+//!
+//! let x = Expr::atom("?1");
+//! let d = diff(&Expr::and(x.clone(), Expr::atom("A")), &Expr::and(x, Expr::atom("B")));
+//! assert_eq!(d.hole, "??");
+//! assert_eq!(d.skeleton.to_string(), "?1 ⋀ ??1");
+//! assert_eq!(d.to_string(), "skeleton: ?1 ⋀ ??1\n??1 at right:\n  expected: A\n       got: B");
+//! ```
+
+use crate::*;
+use expr::Expr;
+use std::fmt;
+
+/// A step into a sub-term.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Step {
+    /// The argument of `¬a`.
+    Not,
+    /// The left argument of a binary operator.
+    Left,
+    /// The right argument of a binary operator.
+    Right,
+}
+
+impl fmt::Display for Step {
+    fn fmt(&self, w: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Step::Not => write!(w, "not"),
+            Step::Left => write!(w, "left"),
+            Step::Right => write!(w, "right"),
+        }
+    }
+}
+
+/// A place where two propositions disagree.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Mismatch {
+    /// The path from the root to the sub-term.
+    pub path: Vec<Step>,
+    /// The expected sub-term.
+    pub expected: Expr,
+    /// The sub-term that was got.
+    pub got: Expr,
+}
+
+/// The structural difference between two propositions.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Diff {
+    /// The common part, with holes `?1`, `?2`, ... at the mismatches.
+    pub skeleton: Expr,
+    /// The prefix of holes, which is `?` unless an atom starts with `?`.
+    pub hole: String,
+    /// The mismatches in order from left to right.
+    pub mismatches: Vec<Mismatch>,
+}
+
+impl Diff {
+    /// Returns `true` if the propositions are equal.
+    pub fn is_empty(&self) -> bool {self.mismatches.is_empty()}
+}
+
+/// Compares two propositions structurally.
+pub fn diff(expected: &Expr, got: &Expr) -> Diff {
+    let atoms = [expected.atoms(), got.atoms()].concat();
+    let mut hole = String::from("?");
+    while atoms.iter().any(|a| a.starts_with(&hole)) {hole.push('?')}
+    let mut mismatches = vec![];
+    let skeleton = walk(expected, got, &hole, &mut vec![], &mut mismatches);
+    Diff {skeleton, hole, mismatches}
+}
+
+fn walk(
+    expected: &Expr,
+    got: &Expr,
+    hole: &str,
+    path: &mut Vec<Step>,
+    res: &mut Vec<Mismatch>
+) -> Expr {
+    use Expr::*;
+
+    let mut sub = |step: Step, a: &Expr, b: &Expr, res: &mut Vec<Mismatch>| {
+        path.push(step);
+        let x = walk(a, b, hole, path, res);
+        path.pop();
+        x
+    };
+    match (expected, got) {
+        (True, True) => True,
+        (False, False) => False,
+        (Atom(a), Atom(b)) if a == b => Atom(a.clone()),
+        (Not(a), Not(b)) => !sub(Step::Not, a, b, res),
+        (And(a0, a1), And(b0, b1)) =>
+            Expr::and(sub(Step::Left, a0, b0, res), sub(Step::Right, a1, b1, res)),
+        (Or(a0, a1), Or(b0, b1)) =>
+            Expr::or(sub(Step::Left, a0, b0, res), sub(Step::Right, a1, b1, res)),
+        (Imply(a0, a1), Imply(b0, b1)) =>
+            Expr::imply(sub(Step::Left, a0, b0, res), sub(Step::Right, a1, b1, res)),
+        (Eq(a0, a1), Eq(b0, b1)) =>
+            Expr::eq(sub(Step::Left, a0, b0, res), sub(Step::Right, a1, b1, res)),
+        _ => {
+            res.push(Mismatch {path: path.clone(), expected: expected.clone(), got: got.clone()});
+            Atom(format!("{}{}", hole, res.len()))
+        }
+    }
+}
+
+impl fmt::Display for Diff {
+    fn fmt(&self, w: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_empty() {return write!(w, "no difference")}
+        write!(w, "skeleton: {}", self.skeleton)?;
+        for (i, m) in self.mismatches.iter().enumerate() {
+            write!(w, "\n{}{} at ", self.hole, i + 1)?;
+            if m.path.is_empty() {write!(w, "root")?}
+            for (j, step) in m.path.iter().enumerate() {
+                if j > 0 {write!(w, ".")?}
+                write!(w, "{}", step)?;
+            }
+            write!(w, ":\n  expected: {}\n       got: {}", m.expected, m.got)?;
+        }
+        Ok(())
+    }
+}
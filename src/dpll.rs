@@ -0,0 +1,158 @@
+//! # DPLL Solver
+//!
+//! A small DPLL solver for clauses (see [resolution::Clause]), enabled by the `dpll` feature.
+//! Unlike saturation by resolution ([resolution::refute]), the solver searches for a satisfying
+//! assignment by unit propagation and branching on atoms.
+//! Unlike the counterexample checker ([check]), it does not enumerate all assignments.
+//!
+//! Both outcomes are turned into results of the library:
+//!
+//! - Satisfiable: The assignment becomes a counterexample report ([check::Report])
+//! - Unsatisfiable: The search tree is replayed as a resolution refutation ([Refutation]),
+//!   where every step is [resolution::resolve] at the type level.
+//!   A refutation of the clauses `c` is a proof of `¬c`.
+//!
+//! To prove a goal `a`, the negated goal `¬a` is converted to conjunctive normal form
+//! (see [rewrite::to_cnf]), so the certificate is the same as for [resolution::prove]:
+//!
+//! ```rust
+//! use prop::expr;
+//! use prop::check::Report;
+//! use prop::dpll::prove;
+//!
+//! // Peirce's law.
+//! assert!(prove(&expr!(Imply<Imply<Imply<A, B>, A>, A>)).unwrap().check());
+//! match prove(&expr!(Imply<Or<A, B>, A>)) {
+//!     Err(Report::Counterexample(x)) => assert!(!x["A"] && x["B"]),
+//!     _ => unreachable!(),
+//! }
+//! ```
+
+use crate::*;
+use check::Report;
+use expr::{Assignment, Expr};
+use resolution::{clauses, resolvent, Certificate, Clause, Lit, Refutation, Resolve};
+use std::collections::BTreeSet;
+
+/// The result of solving clauses.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Answer {
+    /// An assignment that satisfies the clauses.
+    Sat(Assignment),
+    /// A refutation of the clauses.
+    Unsat(Refutation),
+}
+
+struct Solver {
+    /// The input clauses followed by derived clauses.
+    clauses: Vec<Clause>,
+    steps: Vec<Resolve>,
+    assignment: Assignment,
+    atoms: Vec<String>,
+}
+
+impl Solver {
+    fn value(&self, x: &Lit) -> Option<bool> {self.assignment.get(&x.atom).map(|&v| v == x.pos)}
+
+    /// Derives the resolvent of two clauses and returns its index.
+    fn resolve(&mut self, left: usize, right: usize, atom: &str) -> usize {
+        let c = resolvent(&self.clauses[left], &self.clauses[right], atom).expect("resolvable");
+        self.clauses.push(c);
+        self.steps.push(Resolve {left, right, atom: atom.into()});
+        self.clauses.len() - 1
+    }
+
+    /// Resolves a false clause with the reasons of propagated literals.
+    ///
+    /// The result is false without the propagated literals.
+    fn explain(&mut self, mut conflict: usize, props: &[(Lit, usize)]) -> usize {
+        for (x, reason) in props.iter().rev() {
+            if self.clauses[conflict].contains(&x.neg()) {
+                conflict = if x.pos {self.resolve(*reason, conflict, &x.atom)}
+                           else {self.resolve(conflict, *reason, &x.atom)};
+            }
+        }
+        for (x, _) in props {self.assignment.remove(&x.atom);}
+        conflict
+    }
+
+    /// Extends the assignment until all clauses are satisfied.
+    ///
+    /// Returns a clause that is false under the assignment before the call, if any.
+    fn search(&mut self) -> Result<(), usize> {
+        let mut props = vec![];
+        loop {
+            let mut changed = false;
+            for i in 0..self.clauses.len() {
+                let mut free = vec![];
+                if self.clauses[i].iter().any(|x| match self.value(x) {
+                    Some(v) => v,
+                    None => {free.push(x.clone()); false}
+                }) {continue}
+                match free.len() {
+                    0 => return Err(self.explain(i, &props)),
+                    1 => {
+                        let x = free.pop().unwrap();
+                        self.assignment.insert(x.atom.clone(), x.pos);
+                        props.push((x, i));
+                        changed = true;
+                    }
+                    _ => {}
+                }
+            }
+            if !changed {break}
+        }
+
+        let atom = match self.atoms.iter().find(|&x| !self.assignment.contains_key(x)) {
+            Some(x) => x.clone(),
+            None => return Ok(()),
+        };
+        let pos = Lit {atom: atom.clone(), pos: true};
+        self.assignment.insert(atom.clone(), true);
+        let left = match self.search() {
+            Ok(()) => return Ok(()),
+            Err(c) => c,
+        };
+        self.assignment.remove(&atom);
+        let c = if !self.clauses[left].contains(&pos.neg()) {left} else {
+            self.assignment.insert(atom.clone(), false);
+            let right = match self.search() {
+                Ok(()) => return Ok(()),
+                Err(c) => c,
+            };
+            self.assignment.remove(&atom);
+            if !self.clauses[right].contains(&pos) {right} else {self.resolve(right, left, &atom)}
+        };
+        Err(self.explain(c, &props))
+    }
+}
+
+/// Solves clauses.
+pub fn solve(input: Vec<Clause>) -> Answer {
+    profile!("dpll::solve");
+    let atoms: BTreeSet<String> = input.iter().flatten().map(|x| x.atom.clone()).collect();
+    let mut solver = Solver {
+        clauses: input.clone(),
+        steps: vec![],
+        assignment: Assignment::new(),
+        atoms: atoms.into_iter().collect(),
+    };
+    match solver.search() {
+        Ok(()) => Answer::Sat(solver.assignment),
+        Err(_) => Answer::Unsat(Refutation {input, steps: solver.steps}.trim()),
+    }
+}
+
+/// Proves a goal in classical logic, or returns a counterexample.
+pub fn prove(goal: &Expr) -> Result<Certificate, Report> {
+    let cnf = rewrite::to_cnf(&!goal.clone());
+    let input = clauses(&cnf.to).expect("conjunctive normal form");
+    match solve(input) {
+        Answer::Unsat(refutation) => Ok(Certificate {cnf, refutation}),
+        Answer::Sat(mut assignment) => {
+            // Atoms that are eliminated by the conversion can have any value.
+            for x in goal.atoms() {assignment.entry(x).or_insert(false);}
+            Err(Report::Counterexample(assignment))
+        }
+    }
+}
@@ -0,0 +1,146 @@
+//! # Epistemic Logic with Public Announcements
+//!
+//! Knowledge `K{i}(a)` ([Knows]) means that agent `i` knows `a`.
+//! Knowledge is modeled with the S5 axioms:
+//!
+//! - Necessitation `a^true  =>  K{i}(a)` ([knows_nec])
+//! - Distribution (K) `K{i}(a => b)  =>  (K{i}(a) => K{i}(b))` ([knows_distrib])
+//! - Truth (T) `K{i}(a)  =>  a` ([knows_t])
+//! - Positive introspection (4) `K{i}(a)  =>  K{i}(K{i}(a))` ([knows_four])
+//! - Negative introspection (5) `¬K{i}(a)  =>  K{i}(¬K{i}(a))` ([knows_five])
+//!
+//! ### Public announcements
+//!
+//! The dynamic operator `[!a]b` ([Announce]) means that after truthfully announcing `a`,
+//! `b` holds. It is reduced to the static language by the reduction axioms:
+//!
+//! - Atoms `[!a]p == (a => p)` ([announce_atom])
+//! - Negation `[!a]¬b == (a => ¬[!a]b)` ([announce_not])
+//! - Conjunction `[!a](b ⋀ c) == ([!a]b ⋀ [!a]c)` ([announce_and])
+//! - Knowledge `[!a]K{i}(b) == (a => K{i}([!a]b))` ([announce_knows])
+//! - Composition `[!a][!b]c == [!(a ⋀ [!a]b)]c` ([announce_comp])
+//!
+//! Atoms are propositions that are not changed by announcements, marked by the [Atom] trait.
+//! Since an announcement changes what agents know, epistemic propositions are not atoms.
+//! Therefore, [Atom] is sealed: New atoms are declared as [Atomic] propositions,
+//! e.g. `Atomic<Muddy>` for "the child is muddy".
+//!
+//! For a worked example, see `examples/muddy_children.rs`.
+
+use crate::*;
+use hooo::Tauto;
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// Implemented by atomic propositions, which are not changed by announcements.
+///
+/// This trait is sealed, such that it can not be implemented for epistemic propositions:
+///
+/// ```compile_fail
+/// use prop::epistemic::{Atom, Knows};
+///
+/// #[derive(Copy, Clone)]
+/// struct K1<P>(Knows<(), P>);
+/// impl<P: Atom> Atom for K1<P> {}
+/// ```
+pub trait Atom: Prop + sealed::Sealed {}
+
+/// Atomic proposition named by `n`.
+///
+/// It has no constructor, so it can only be assumed, e.g. as premise.
+#[derive(Copy, Clone)]
+pub struct Atomic<N>(N);
+
+impl sealed::Sealed for True {}
+impl sealed::Sealed for False {}
+impl<N: Prop> sealed::Sealed for Atomic<N> {}
+impl<A: Atom, B: Atom> sealed::Sealed for And<A, B> {}
+impl<A: Atom, B: Atom> sealed::Sealed for Or<A, B> {}
+impl<A: Atom, B: Atom> sealed::Sealed for Imply<A, B> {}
+
+impl Atom for True {}
+impl Atom for False {}
+impl<N: Prop> Atom for Atomic<N> {}
+impl<A: Atom, B: Atom> Atom for And<A, B> {}
+impl<A: Atom, B: Atom> Atom for Or<A, B> {}
+impl<A: Atom, B: Atom> Atom for Imply<A, B> {}
+
+/// Knowledge `K{i}(a)`.
+#[derive(Copy, Clone)]
+pub struct Knows<I, A>(I, A);
+
+/// Public announcement `[!a]b`.
+#[derive(Copy, Clone)]
+pub struct Announce<A, B>(A, B);
+
+/// `a^true  =>  K{i}(a)`.
+pub fn knows_nec<I: Prop, A: Prop>(_: Tauto<A>) -> Knows<I, A> {axiom!()}
+/// `K{i}(a => b)  =>  (K{i}(a) => K{i}(b))`.
+pub fn knows_distrib<I: Prop, A: Prop, B: Prop>(
+    _: Knows<I, Imply<A, B>>
+) -> Imply<Knows<I, A>, Knows<I, B>> {axiom!()}
+/// `K{i}(a)  =>  a`.
+pub fn knows_t<I: Prop, A: Prop>(_: Knows<I, A>) -> A {axiom!()}
+/// `K{i}(a)  =>  K{i}(K{i}(a))`.
+pub fn knows_four<I: Prop, A: Prop>(_: Knows<I, A>) -> Knows<I, Knows<I, A>> {axiom!()}
+/// `¬K{i}(a)  =>  K{i}(¬K{i}(a))`.
+pub fn knows_five<I: Prop, A: Prop>(_: Not<Knows<I, A>>) -> Knows<I, Not<Knows<I, A>>> {
+    axiom!()
+}
+
+/// `[!a]p == (a => p)`.
+pub fn announce_atom<A: Prop, P: Atom>() -> Eq<Announce<A, P>, Imply<A, P>> {axiom!()}
+/// `[!a]¬b == (a => ¬[!a]b)`.
+pub fn announce_not<A: Prop, B: Prop>() ->
+    Eq<Announce<A, Not<B>>, Imply<A, Not<Announce<A, B>>>> {axiom!()}
+/// `[!a](b ⋀ c) == ([!a]b ⋀ [!a]c)`.
+pub fn announce_and<A: Prop, B: Prop, C: Prop>() ->
+    Eq<Announce<A, And<B, C>>, And<Announce<A, B>, Announce<A, C>>> {axiom!()}
+/// `[!a]K{i}(b) == (a => K{i}([!a]b))`.
+pub fn announce_knows<I: Prop, A: Prop, B: Prop>() ->
+    Eq<Announce<A, Knows<I, B>>, Imply<A, Knows<I, Announce<A, B>>>> {axiom!()}
+/// `[!a][!b]c == [!(a ⋀ [!a]b)]c`.
+pub fn announce_comp<A: Prop, B: Prop, C: Prop>() ->
+    Eq<Announce<A, Announce<B, C>>, Announce<And<A, Announce<A, B>>, C>> {axiom!()}
+
+/// `K{i}(a) ⋀ K{i}(a => b)  =>  K{i}(b)`.
+pub fn knows_modus_ponens<I: Prop, A: Prop, B: Prop>(
+    x: Knows<I, A>,
+    y: Knows<I, Imply<A, B>>
+) -> Knows<I, B> {knows_distrib(y)(x)}
+/// `K{i}(a ⋀ b)  =>  K{i}(a)`.
+pub fn knows_fst<I: Prop, A: Prop, B: Prop>(x: Knows<I, And<A, B>>) -> Knows<I, A> {
+    knows_modus_ponens(x, knows_nec(tauto!(Rc::new(and::fst))))
+}
+/// `K{i}(a ⋀ b)  =>  K{i}(b)`.
+pub fn knows_snd<I: Prop, A: Prop, B: Prop>(x: Knows<I, And<A, B>>) -> Knows<I, B> {
+    knows_modus_ponens(x, knows_nec(tauto!(Rc::new(and::snd))))
+}
+
+/// `[!p]p` for atomic `p`.
+pub fn announce_refl<P: Atom>() -> Announce<P, P> {announce_atom().1(imply::id())}
+/// `[!a]p ⋀ a  =>  p` for atomic `p`.
+pub fn announce_atom_mp<A: Prop, P: Atom>(x: Announce<A, P>, a: A) -> P {announce_atom().0(x)(a)}
+/// `(a => p)  =>  [!a]p` for atomic `p`.
+pub fn announce_atom_intro<A: Prop, P: Atom>(x: Imply<A, P>) -> Announce<A, P> {
+    announce_atom().1(x)
+}
+/// `[!p]K{i}(p)` for atomic `p`.
+///
+/// After a truthful announcement of an atomic fact, every agent knows it.
+pub fn announce_knows_atom<I: Prop, P: Atom>() -> Announce<P, Knows<I, P>> {
+    let k: Knows<I, Announce<P, P>> = knows_nec(tauto!(announce_refl()));
+    announce_knows().1(k.map_any())
+}
+/// `[!a]b ⋀ [!a]c  =>  [!a](b ⋀ c)`.
+pub fn announce_and_intro<A: Prop, B: Prop, C: Prop>(
+    x: Announce<A, B>,
+    y: Announce<A, C>
+) -> Announce<A, And<B, C>> {announce_and().1((x, y))}
+/// `[!a]K{i}(b) ⋀ a  =>  K{i}([!a]b)`.
+pub fn announce_knows_mp<I: Prop, A: Prop, B: Prop>(
+    x: Announce<A, Knows<I, B>>,
+    a: A
+) -> Knows<I, Announce<A, B>> {announce_knows().0(x)(a)}
@@ -1,12 +1,31 @@
 //! Tactics for Logical EQ.
+//!
+//! ### Representation
+//!
+//! `Eq<A, B>` is a type alias for a pair of `Imply` closures.
+//! Every use of [transitivity] wraps the previous closures in a new one,
+//! so long chains of equalities create deeply nested `Rc` closures.
+//!
+//! A struct with erased function pointers plus captured data was considered,
+//! but the pair representation is part of the public API:
+//! Proofs destructure `(ab, ba): Eq<A, B>` and call `x.0(a)` directly,
+//! both in this library and downstream.
+//! Changing it would break these proofs without changing what can be proved.
+//! Instead, deep chains should be flattened where they are built, using [EqChain],
+//! as in [trans3], [trans4] and [trans5].
 
 #![allow(unreachable_code)]
 
 use crate::*;
+use std::any::Any;
+use std::marker::PhantomData;
 
 /// `(a == b) ∧ (b == c) => (a == c)`.
 pub fn transitivity<A: Prop, B: Prop, C: Prop>((f0, f1): Eq<A, B>, (g0, g1): Eq<B, C>) -> Eq<A, C> {
-    (Rc::new(move |x| g0(f0(x))), Rc::new(move |x| f1(g1(x))))
+    (
+        Rc::new(move |x| {profile!("eq::transitivity"); g0(f0(x))}),
+        Rc::new(move |x| {profile!("eq::transitivity"); f1(g1(x))})
+    )
 }
 
 /// `(a == b) ∧ (b == c) ∧ (c == d)  =>  (a == d)`.
@@ -14,7 +33,7 @@ pub fn trans3<A: Prop, B: Prop, C: Prop, D: Prop>(
     ab: Eq<A, B>,
     bc: Eq<B, C>,
     cd: Eq<C, D>
-) -> Eq<A, D> {transitivity(ab, transitivity(bc, cd))}
+) -> Eq<A, D> {EqChain::start(ab).then(bc).then(cd).build()}
 
 /// `(a == b) ∧ (b == c) ∧ (c == d) ∧ (d == e)  =>  (a == e)`.
 pub fn trans4<A: Prop, B: Prop, C: Prop, D: Prop, E: Prop>(
@@ -22,7 +41,7 @@ pub fn trans4<A: Prop, B: Prop, C: Prop, D: Prop, E: Prop>(
     bc: Eq<B, C>,
     cd: Eq<C, D>,
     de: Eq<D, E>
-) -> Eq<A, E> {transitivity(trans3(ab, bc, cd), de)}
+) -> Eq<A, E> {EqChain::start(ab).then(bc).then(cd).then(de).build()}
 
 /// `(a == b) ∧ (b == c) ∧ (c == d) ∧ (d == e) ∧ (e == f)  =>  (a == f)`.
 pub fn trans5<A: Prop, B: Prop, C: Prop, D: Prop, E: Prop, F: Prop>(
@@ -31,7 +50,78 @@ pub fn trans5<A: Prop, B: Prop, C: Prop, D: Prop, E: Prop, F: Prop>(
     cd: Eq<C, D>,
     de: Eq<D, E>,
     ef: Eq<E, F>,
-) -> Eq<A, F> {transitivity(trans4(ab, bc, cd, de), ef)}
+) -> Eq<A, F> {
+    EqChain::start(ab).then(bc).then(cd).then(de).then(ef).build()
+}
+
+type Step = Rc<dyn Fn(Box<dyn Any>) -> Box<dyn Any>>;
+
+fn step<A: Prop, B: Prop>(f: Imply<A, B>) -> Step {
+    Rc::new(move |x| Box::new(f(cast(x))))
+}
+
+fn cast<A: Prop>(x: Box<dyn Any>) -> A {
+    match x.downcast::<A>() {
+        Ok(x) => *x,
+        Err(_) => unreachable!("EqChain steps are type checked"),
+    }
+}
+
+/// Builds `a == b` from a chain of equalities.
+///
+/// Unlike [transitivity], the resulting closures do not nest:
+/// The steps are stored in a list and applied one after another,
+/// so the stack depth does not grow with the length of the chain.
+///
+/// A chain of 100 000 steps, which overflows a small stack when built with [transitivity]:
+///
+/// ```rust
+/// use prop::*;
+/// use prop::eq::EqChain;
+///
+/// std::thread::Builder::new().stack_size(1 << 18).spawn(|| {
+///     let mut x = EqChain::<True, True>::refl();
+///     for _ in 0..100_000 {x = x.then(eq::refl())}
+///     let (f, g) = x.build();
+///     let _: True = f(True);
+///     let _: True = g(True);
+/// }).unwrap().join().unwrap();
+/// ```
+pub struct EqChain<A, B> {
+    fwd: Vec<Step>,
+    bwd: Vec<Step>,
+    _marker: PhantomData<fn(A) -> B>,
+}
+
+impl<A: Prop> EqChain<A, A> {
+    /// `a == a`.
+    pub fn refl() -> EqChain<A, A> {EqChain {fwd: vec![], bwd: vec![], _marker: PhantomData}}
+}
+
+impl<A: Prop, B: Prop> EqChain<A, B> {
+    /// Starts a chain with `a == b`.
+    pub fn start(ab: Eq<A, B>) -> EqChain<A, B> {EqChain::refl().then(ab)}
+    /// `(a == b) ∧ (b == c)  =>  (a == c)`.
+    pub fn then<C: Prop>(mut self, (bc, cb): Eq<B, C>) -> EqChain<A, C> {
+        self.fwd.push(step(bc));
+        self.bwd.push(step(cb));
+        EqChain {fwd: self.fwd, bwd: self.bwd, _marker: PhantomData}
+    }
+    /// Composes the chain into `a == b`.
+    pub fn build(self) -> Eq<A, B> {
+        let fwd: Rc<[Step]> = self.fwd.into();
+        let bwd: Rc<[Step]> = self.bwd.into();
+        (Rc::new(move |a: A| {
+            let mut x: Box<dyn Any> = Box::new(a);
+            for f in fwd.iter() {x = f(x)}
+            cast(x)
+        }), Rc::new(move |b: B| {
+            let mut x: Box<dyn Any> = Box::new(b);
+            for f in bwd.iter().rev() {x = f(x)}
+            cast(x)
+        }))
+    }
+}
 
 /// `a => (a == ¬¬a)`.
 pub fn double_neg<A: Prop>(a: A) -> Eq<A, Not<Not<A>>> {
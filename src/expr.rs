@@ -0,0 +1,275 @@
+//! # Reflected Expressions
+//!
+//! Propositions at the type level can not be inspected at runtime.
+//! This module provides a reflected representation [Expr] of the propositional fragment,
+//! which is used by runtime tooling such as the counterexample checker (see [check](crate::check)).
+//!
+//! The notation used when printing follows the rest of the library,
+//! e.g. `a ⋀ b`, `a ⋁ b`, `¬a`, `a => b` and `a == b`.
+//!
+//! The [expr!](crate::expr) macro builds an expression from type-level syntax,
+//! such that reflected statements are kept in sync with the types they describe:
+//!
+//! ```rust
+//! use prop::expr;
+//! use prop::expr::Expr;
+//!
+//! assert_eq!(expr!(And<A, Or<B, Not<C>>>),
+//!     Expr::and(Expr::atom("A"), Expr::or(Expr::atom("B"), !Expr::atom("C"))));
+//! ```
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// Reflected proposition.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Expr {
+    /// `true`.
+    True,
+    /// `false`.
+    False,
+    /// An atomic proposition.
+    Atom(String),
+    /// `¬a`.
+    Not(Box<Expr>),
+    /// `a ⋀ b`.
+    And(Box<Expr>, Box<Expr>),
+    /// `a ⋁ b`.
+    Or(Box<Expr>, Box<Expr>),
+    /// `a => b`.
+    Imply(Box<Expr>, Box<Expr>),
+    /// `a == b`.
+    Eq(Box<Expr>, Box<Expr>),
+}
+
+/// Builds a reflected expression from type-level syntax (see [Expr::from_type]).
+#[macro_export]
+macro_rules! expr(($t:ty) => {
+    $crate::expr::Expr::from_type(stringify!($t)).expect("type syntax")
+});
+
+/// An assignment of truth values to atoms.
+pub type Assignment = BTreeMap<String, bool>;
+
+impl Expr {
+    /// Creates an atom.
+    pub fn atom(name: &str) -> Expr {Expr::Atom(name.into())}
+    /// Creates `a ⋀ b`.
+    pub fn and(a: Expr, b: Expr) -> Expr {Expr::And(Box::new(a), Box::new(b))}
+    /// Creates `a ⋁ b`.
+    pub fn or(a: Expr, b: Expr) -> Expr {Expr::Or(Box::new(a), Box::new(b))}
+    /// Creates `a => b`.
+    pub fn imply(a: Expr, b: Expr) -> Expr {Expr::Imply(Box::new(a), Box::new(b))}
+    /// Creates `a == b`.
+    pub fn eq(a: Expr, b: Expr) -> Expr {Expr::Eq(Box::new(a), Box::new(b))}
+
+    /// Converts type-level syntax, e.g. `And<A, Or<B, C>>`, into an expression.
+    ///
+    /// Supports `True`, `False`, `Not`, `And`, `Or`, `Imply`, `Eq`, `Iff`, `ExcM`, `Dneg`,
+    /// `Either` and pairs `(A, B)`, optionally with module paths.
+    /// Other types are opaque atoms named by their syntax, e.g. `Pow<B, A>`.
+    ///
+    /// Returns `None` if the syntax is malformed.
+    pub fn from_type(s: &str) -> Option<Expr> {parse(s).map(|x| x.to_expr())}
+
+    /// Returns the atoms in sorted order, without duplicates.
+    pub fn atoms(&self) -> Vec<String> {
+        let mut res = vec![];
+        self.collect_atoms(&mut res);
+        res.sort();
+        res.dedup();
+        res
+    }
+
+    fn collect_atoms(&self, res: &mut Vec<String>) {
+        use Expr::*;
+
+        match self {
+            True | False => {}
+            Atom(x) => res.push(x.clone()),
+            Not(a) => a.collect_atoms(res),
+            And(a, b) | Or(a, b) | Imply(a, b) | Eq(a, b) => {
+                a.collect_atoms(res);
+                b.collect_atoms(res);
+            }
+        }
+    }
+
+    /// Evaluates the expression under an assignment.
+    ///
+    /// Returns `None` if some atom is not assigned.
+    pub fn eval(&self, assignment: &Assignment) -> Option<bool> {
+        use Expr::*;
+
+        Some(match self {
+            True => true,
+            False => false,
+            Atom(x) => *assignment.get(x)?,
+            Not(a) => !a.eval(assignment)?,
+            And(a, b) => a.eval(assignment)? && b.eval(assignment)?,
+            Or(a, b) => a.eval(assignment)? || b.eval(assignment)?,
+            Imply(a, b) => !a.eval(assignment)? || b.eval(assignment)?,
+            Eq(a, b) => a.eval(assignment)? == b.eval(assignment)?,
+        })
+    }
+}
+
+/// A type in generic syntax.
+pub(crate) enum Ty {
+    /// A path with generic arguments, e.g. `a::B<C, D>`.
+    Path(String, Vec<Ty>),
+    /// A tuple, e.g. `(A, B)`.
+    Tuple(Vec<Ty>),
+    /// Several items that do not form a path or tuple, e.g. `fn(A) -> B`.
+    Seq(Vec<Ty>),
+}
+
+impl Ty {
+    fn to_expr(&self) -> Expr {
+        use Ty::*;
+
+        let bin = |f: fn(Expr, Expr) -> Expr, args: &[Ty]|
+            f(args[0].to_expr(), args[1].to_expr());
+        match self {
+            Path(name, args) => match (name.rsplit("::").next().unwrap(), args.len()) {
+                ("True", 0) => Expr::True,
+                ("False", 0) => Expr::False,
+                ("Not", 1) => !args[0].to_expr(),
+                ("And", 2) => bin(Expr::and, args),
+                ("Or", 2) | ("Either", 2) => bin(Expr::or, args),
+                ("Imply", 2) => bin(Expr::imply, args),
+                ("Eq", 2) | ("Iff", 2) => bin(Expr::eq, args),
+                ("ExcM", 1) => {let a = args[0].to_expr(); Expr::or(a.clone(), !a)}
+                ("Dneg", 1) => {let a = args[0].to_expr(); Expr::imply(!!a.clone(), a)}
+                _ => Expr::Atom(self.to_string()),
+            },
+            Tuple(args) if args.len() == 1 => args[0].to_expr(),
+            Tuple(args) if args.len() == 2 => bin(Expr::and, args),
+            _ => Expr::Atom(self.to_string()),
+        }
+    }
+}
+
+impl fmt::Display for Ty {
+    fn fmt(&self, w: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fn list(w: &mut fmt::Formatter<'_>, args: &[Ty]) -> fmt::Result {
+            for (i, a) in args.iter().enumerate() {
+                if i > 0 {write!(w, ", ")?}
+                write!(w, "{}", a)?;
+            }
+            Ok(())
+        }
+
+        match self {
+            Ty::Path(name, args) if args.is_empty() => write!(w, "{}", name),
+            Ty::Path(name, args) => {write!(w, "{}<", name)?; list(w, args)?; write!(w, ">")}
+            Ty::Tuple(args) => {write!(w, "(")?; list(w, args)?; write!(w, ")")}
+            Ty::Seq(items) => {
+                for (i, a) in items.iter().enumerate() {
+                    if i > 0 {write!(w, " ")?}
+                    write!(w, "{}", a)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Parses type-level syntax.
+///
+/// Returns `None` if the syntax is malformed.
+pub(crate) fn parse(s: &str) -> Option<Ty> {
+    let tokens = tokenize(s);
+    let mut pos = 0;
+    let res = parse_type(&tokens, &mut pos)?;
+    if pos == tokens.len() {Some(res)} else {None}
+}
+
+fn tokenize(s: &str) -> Vec<String> {
+    let mut res: Vec<String> = vec![];
+    let mut word = String::new();
+    let mut chars = s.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c == '-' && chars.peek() == Some(&'>') {
+            chars.next();
+            if !word.is_empty() {res.push(std::mem::take(&mut word))}
+            res.push("->".into());
+        } else if "<>,()".contains(c) || c.is_whitespace() {
+            if !word.is_empty() {res.push(std::mem::take(&mut word))}
+            if !c.is_whitespace() {res.push(c.to_string())}
+        } else {
+            word.push(c);
+        }
+    }
+    if !word.is_empty() {res.push(word)}
+    res
+}
+
+fn parse_list(tokens: &[String], pos: &mut usize, end: &str) -> Option<Vec<Ty>> {
+    let mut res = vec![];
+    loop {
+        if tokens.get(*pos)? == end {*pos += 1; return Some(res)}
+        res.push(parse_type(tokens, pos)?);
+        match tokens.get(*pos)?.as_str() {
+            "," => *pos += 1,
+            x if x == end => {}
+            _ => return None,
+        }
+    }
+}
+
+fn parse_type(tokens: &[String], pos: &mut usize) -> Option<Ty> {
+    let mut items = vec![];
+    while let Some(t) = tokens.get(*pos) {
+        match t.as_str() {
+            "," | ">" | ")" => break,
+            "<" => return None,
+            "(" => {
+                *pos += 1;
+                items.push(Ty::Tuple(parse_list(tokens, pos, ")")?));
+            }
+            _ => {
+                *pos += 1;
+                let args = if tokens.get(*pos).map(|x| x == "<") == Some(true) {
+                    *pos += 1;
+                    parse_list(tokens, pos, ">")?
+                } else {vec![]};
+                items.push(Ty::Path(t.clone(), args));
+            }
+        }
+    }
+    match items.len() {
+        0 => None,
+        1 => items.pop(),
+        _ => Some(Ty::Seq(items)),
+    }
+}
+
+impl std::ops::Not for Expr {
+    type Output = Expr;
+    fn not(self) -> Expr {Expr::Not(Box::new(self))}
+}
+
+impl fmt::Display for Expr {
+    fn fmt(&self, w: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use Expr::*;
+
+        fn arg(w: &mut fmt::Formatter<'_>, a: &Expr) -> fmt::Result {
+            match a {
+                True | False | Atom(_) | Not(_) => write!(w, "{}", a),
+                _ => write!(w, "({})", a),
+            }
+        }
+
+        match self {
+            True => write!(w, "true"),
+            False => write!(w, "false"),
+            Atom(x) => write!(w, "{}", x),
+            Not(a) => {write!(w, "¬")?; arg(w, a)}
+            And(a, b) => {arg(w, a)?; write!(w, " ⋀ ")?; arg(w, b)}
+            Or(a, b) => {arg(w, a)?; write!(w, " ⋁ ")?; arg(w, b)}
+            Imply(a, b) => {arg(w, a)?; write!(w, " => ")?; arg(w, b)}
+            Eq(a, b) => {arg(w, a)?; write!(w, " == ")?; arg(w, b)}
+        }
+    }
+}
@@ -0,0 +1,57 @@
+//! # Extraction
+//!
+//! Proofs are Rust values, so some proof terms can be run as programs.
+//! This module extracts executable functions from proofs,
+//! where the correctness of the function is guaranteed by the proof it was extracted from.
+//!
+//! - A decidable proposition `a` extracts to a boolean ([decide]) or a witness ([witness])
+//! - A proof `a => (b ⋁ ¬b)` extracts to a decider for `b` ([decider])
+//! - A proof `(b ⋁ ¬b)^a` extracts to a decider for `b` ([pow_decider])
+//! - A proof `a => b` with decidable `a` extracts to a partial witness of `b` ([run])
+//! - A chain `a => b => c` with decidable `a` extracts to a partial witness of `c` ([chain])
+//!
+//! An extracted boolean is `true` exactly when the underlying proof is the left case,
+//! so it can not disagree with the proof it came from.
+
+use crate::*;
+use hooo::Pow;
+
+/// Returns `true` for the left case of `a ⋁ b`.
+pub const fn to_bool<A: Prop, B: Prop>(x: &Or<A, B>) -> bool {matches!(x, Left(_))}
+
+/// Returns `true` if decidable `a` is true.
+pub fn decide<A: DProp>() -> bool {to_bool(&A::decide())}
+
+/// Returns a proof of `a` if decidable `a` is true.
+pub fn witness<A: DProp>() -> Option<A> {
+    match A::decide() {
+        Left(a) => Some(a),
+        Right(_) => None,
+    }
+}
+
+/// Returns a proof of `¬a` if decidable `a` is false.
+pub fn refute<A: DProp>() -> Option<Not<A>> {
+    match A::decide() {
+        Left(_) => None,
+        Right(na) => Some(na),
+    }
+}
+
+/// Extracts a decider for `b` from `a => (b ⋁ ¬b)`.
+pub fn decider<A: Prop, B: Prop>(f: Imply<A, ExcM<B>>) -> impl Fn(A) -> bool {
+    move |a| to_bool(&f(a))
+}
+
+/// Extracts a decider for `b` from `(b ⋁ ¬b)^a`.
+pub fn pow_decider<A: Prop, B: Prop>(f: Pow<ExcM<B>, A>) -> impl Fn(A) -> bool {
+    move |a| to_bool(&f(a))
+}
+
+/// Runs `a => b` when decidable `a` is true.
+pub fn run<A: DProp, B: Prop>(f: Imply<A, B>) -> Option<B> {witness::<A>().map(|a| f(a))}
+
+/// Runs `a => b => c` when decidable `a` is true.
+pub fn chain<A: DProp, B: Prop, C: Prop>(f: Imply<A, B>, g: Imply<B, C>) -> Option<C> {
+    run(imply::transitivity(f, g))
+}
@@ -0,0 +1,103 @@
+//! # Fixed Points of Monotone Operators
+//!
+//! Knaster–Tarski style least and greatest fixed points of operators on propositions.
+//! Operators are represented as symbols applied with [fun::App].
+//!
+//! - Least fixed point `lfp(f)` ([Lfp]) is the strongest proposition `a` such that `f(a) => a`
+//! - Greatest fixed point `gfp(f)` ([Gfp]) is the weakest proposition `a` such that `a => f(a)`
+//!
+//! The least fixed point has an induction principle ([lfp_induction]),
+//! while the greatest fixed point has a coinduction principle ([gfp_coinduction]).
+//! This allows inductive and coinductive definitions without new language features.
+//!
+//! Monotonicity is used as a premise at the level of exponential propositions ([Mono]).
+//! With monotonicity, one can prove that `lfp(f)` and `gfp(f)` are fixed points
+//! ([lfp_fix] and [gfp_fix]).
+
+use crate::*;
+
+use fun::App;
+use hooo::{Pow, PowEq, Tauto};
+use hooo::pow::PowExt;
+
+/// Monotonicity `(f(b)^f(a))^(b^a)`.
+pub type Mono<F, A, B> = Pow<Pow<App<F, B>, App<F, A>>, Pow<B, A>>;
+
+/// Least fixed point `lfp(f)`.
+#[derive(Copy, Clone)]
+pub struct Lfp<F>(F);
+
+/// Greatest fixed point `gfp(f)`.
+#[derive(Copy, Clone)]
+pub struct Gfp<F>(F);
+
+/// `lfp(f)^f(lfp(f))`.
+pub fn lfp_fold<F: Prop>(_: App<F, Lfp<F>>) -> Lfp<F> {axiom!()}
+
+/// `a^f(a)  =>  a^lfp(f)`.
+///
+/// Induction principle of least fixed point.
+pub fn lfp_induction<F: Prop, A: Prop>(_: Pow<A, App<F, A>>) -> Pow<A, Lfp<F>> {axiom!()}
+
+/// `f(gfp(f))^gfp(f)`.
+pub fn gfp_unfold<F: Prop>(_: Gfp<F>) -> App<F, Gfp<F>> {axiom!()}
+
+/// `f(a)^a  =>  gfp(f)^a`.
+///
+/// Coinduction principle of greatest fixed point.
+pub fn gfp_coinduction<F: Prop, A: Prop>(_: Pow<App<F, A>, A>) -> Pow<Gfp<F>, A> {axiom!()}
+
+/// `mono(f, f(lfp(f)), lfp(f))  =>  f(lfp(f))^lfp(f)`.
+pub fn lfp_unfold<F: Prop>(
+    mono: Mono<F, App<F, Lfp<F>>, Lfp<F>>
+) -> Pow<App<F, Lfp<F>>, Lfp<F>> {
+    lfp_induction(mono(lfp_fold))
+}
+
+/// `mono(f, gfp(f), f(gfp(f)))  =>  gfp(f)^f(gfp(f))`.
+pub fn gfp_fold<F: Prop>(
+    mono: Mono<F, Gfp<F>, App<F, Gfp<F>>>
+) -> Pow<Gfp<F>, App<F, Gfp<F>>> {
+    gfp_coinduction(mono(gfp_unfold))
+}
+
+/// `mono(f, f(lfp(f)), lfp(f))  =>  (lfp(f) =^= f(lfp(f)))`.
+pub fn lfp_fix<F: Prop>(
+    mono: Mono<F, App<F, Lfp<F>>, Lfp<F>>
+) -> PowEq<Lfp<F>, App<F, Lfp<F>>> {
+    (lfp_unfold(mono), lfp_fold)
+}
+
+/// `mono(f, gfp(f), f(gfp(f)))  =>  (gfp(f) =^= f(gfp(f)))`.
+pub fn gfp_fix<F: Prop>(
+    mono: Mono<F, Gfp<F>, App<F, Gfp<F>>>
+) -> PowEq<Gfp<F>, App<F, Gfp<F>>> {
+    (gfp_unfold, gfp_fold(mono))
+}
+
+/// `mono(f, f(lfp(f)), lfp(f))  =>  gfp(f)^lfp(f)`.
+pub fn lfp_to_gfp<F: Prop>(mono: Mono<F, App<F, Lfp<F>>, Lfp<F>>) -> Pow<Gfp<F>, Lfp<F>> {
+    gfp_coinduction(lfp_unfold(mono))
+}
+
+/// `(f(a) => a)^true  =>  a^lfp(f)`.
+pub fn lfp_induction_tauto<F: Prop, A: Prop>(
+    x: Tauto<Imply<App<F, A>, A>>
+) -> Pow<A, Lfp<F>> {
+    lfp_induction(hooo::tauto_imply_to_pow(x))
+}
+
+/// `(a => f(a))^true  =>  gfp(f)^a`.
+pub fn gfp_coinduction_tauto<F: Prop, A: Prop>(
+    x: Tauto<Imply<A, App<F, A>>>
+) -> Pow<Gfp<F>, A> {
+    gfp_coinduction(hooo::tauto_imply_to_pow(x))
+}
+
+/// `a^f(a) ⋀ b^a  =>  b^lfp(f)`.
+pub fn lfp_induction_trans<F: Prop, A: Prop, B: Prop>(
+    x: Pow<A, App<F, A>>,
+    y: Pow<B, A>
+) -> Pow<B, Lfp<F>> {
+    lfp_induction(x).trans(y)
+}
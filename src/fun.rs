@@ -74,6 +74,16 @@
 //! ### Qubit Truths
 //!
 //! For information about qubit truths, see the [fun::id] module.
+//!
+//! ### Function Symbols
+//!
+//! For information about registering new function symbols, see the [fun::sym] module.
+//!
+//! ### Modules
+//!
+//! Lemmas are grouped by constructs, e.g. composition ([fun::comp]),
+//! imaginary inverse ([fun::inv]), lambdas ([fun::lam](mod@fun::lam)), substitution ([fun::subst])
+//! and tuples ([fun::tup]). Their contents are also re-exported from this module.
 
 use crate::*;
 use path_semantics::{ty, POrdProof, Ty};
@@ -98,25 +108,59 @@ pub use tup::*;
 pub use typ::*;
 
 mod app;
-mod comp;
 mod dup;
 mod is_const;
-mod lam;
 mod norm;
-mod subst;
-mod tup;
 mod typ;
 
+pub mod adjoint;
 pub mod bool_alg;
+pub mod card;
+pub mod choice;
+pub mod cell;
+pub mod comp;
+pub mod ctx;
 pub mod dep;
+pub mod dialectica;
+pub mod def_eq;
+pub mod obs_eq;
 pub mod eqx;
+pub mod exp;
 pub mod feq;
 pub mod fin;
+pub mod fsm;
 pub mod natc;
 pub mod natp;
+pub mod ord;
+pub mod partial;
 pub mod fun_ext;
+pub mod graph;
 pub mod id;
+pub mod inductive;
 pub mod inv;
+pub mod iso;
+pub mod kleisli;
+pub mod lam;
 pub mod list;
+pub mod lts;
+pub mod mlaws;
+pub mod monoidal;
 pub mod phott;
+pub mod pullback;
+pub mod quote;
 pub mod real;
+pub mod record;
+pub mod refine;
+pub mod regex;
+pub mod rel;
+pub mod retract;
+pub mod session;
+pub mod setoid;
+pub mod squash;
+pub mod sub;
+pub mod subst;
+pub mod sym;
+pub mod tup;
+pub mod type_omega;
+pub mod wf;
+pub mod wiring;
@@ -0,0 +1,77 @@
+//! # Adjoint Equivalences
+//!
+//! An adjoint equivalence `f ≃ g` between `f : x -> y` and `g : y -> x`
+//! is given by a unit and counit:
+//!
+//! - Unit: `id{x} == (g . f)` ([Unit])
+//! - Counit: `(f . g) == id{y}` ([Counit])
+//!
+//! The unit and counit are equalities, not directional 2-morphisms `id{x} => (g . f)`
+//! and `(f . g) => id{y}`, so [AdjointEquiv] is an equivalence and not a general adjunction.
+//! A general adjunction can not be stated in this model, since there are no morphisms
+//! between functions other than equality (see [fun]).
+//!
+//! The triangle identities follow directly from the unit and counit
+//! ([triangle_left], [triangle_right]),
+//! and inverses are unique ([adjoint_unique], [adjoint_unique_q]).
+
+use super::*;
+
+/// Unit `id{x} == (g . f)`.
+pub type Unit<F, G, X> = Eq<Id<X>, Comp<G, F>>;
+/// Counit `(f . g) == id{y}`.
+pub type Counit<F, G, Y> = Eq<Comp<F, G>, Id<Y>>;
+/// Adjoint equivalence `f ≃ g` where `f : x -> y` and `g : y -> x`.
+pub type AdjointEquiv<F, G, X, Y> = And<Unit<F, G, X>, Counit<F, G, Y>>;
+
+/// `(f ≃ g)  =>  (g ≃ f)`.
+pub fn adjoint_symmetry<F: Prop, G: Prop, X: Prop, Y: Prop>(
+    (unit, counit): AdjointEquiv<F, G, X, Y>
+) -> AdjointEquiv<G, F, Y, X> {(eq::symmetry(counit), eq::symmetry(unit))}
+/// `(x : type(n))  =>  (id{x} ≃ id{x})`.
+pub fn adjoint_id<X: Prop, N: Nat>(ty_x: Ty<X, Type<N>>) -> AdjointEquiv<Id<X>, Id<X>, X, X> {
+    let eq = comp_id_left(id_ty(ty_x));
+    (eq::symmetry(eq.clone()), eq)
+}
+/// `(f : x -> y) ⋀ (f ≃ g)  =>  (f . (g . f)) == f`.
+///
+/// Left triangle identity `ε f . f η == 1{f}`.
+pub fn triangle_left<F: Prop, G: Prop, X: Prop, Y: Prop>(
+    ty_f: Ty<F, Pow<Y, X>>,
+    (_, counit): AdjointEquiv<F, G, X, Y>
+) -> Eq<Comp<F, Comp<G, F>>, F> {
+    let x = eq::transitivity(comp_assoc(), comp_eq_left(counit));
+    eq::transitivity(x, comp_id_left(ty_f))
+}
+/// `(g : y -> x) ⋀ (f ≃ g)  =>  (g . (f . g)) == g`.
+///
+/// Right triangle identity `g ε . η g == 1{g}`.
+pub fn triangle_right<F: Prop, G: Prop, X: Prop, Y: Prop>(
+    ty_g: Ty<G, Pow<X, Y>>,
+    (unit, _): AdjointEquiv<F, G, X, Y>
+) -> Eq<Comp<G, Comp<F, G>>, G> {
+    let x = eq::transitivity(comp_assoc(), comp_eq_left(eq::symmetry(unit)));
+    eq::transitivity(x, comp_id_left(ty_g))
+}
+/// `(g : y -> x) ⋀ (h : y -> x) ⋀ (f ≃ g) ⋀ (f ≃ h)  =>  (g == h)`.
+pub fn adjoint_unique<F: Prop, G: Prop, H: Prop, X: Prop, Y: Prop>(
+    ty_g: Ty<G, Pow<X, Y>>,
+    ty_h: Ty<H, Pow<X, Y>>,
+    (unit_fg, _): AdjointEquiv<F, G, X, Y>,
+    (_, counit_fh): AdjointEquiv<F, H, X, Y>,
+) -> Eq<G, H> {
+    let x: Eq<G, Comp<G, Id<Y>>> = eq::symmetry(comp_id_right(ty_g));
+    let x = eq::transitivity(x, comp_eq_right(eq::symmetry(counit_fh)));
+    let x = eq::transitivity(x, comp_assoc());
+    let x = eq::transitivity(x, comp_eq_left(eq::symmetry(unit_fg)));
+    eq::transitivity(x, comp_id_left(ty_h))
+}
+/// `(g : y -> x) ⋀ (h : y -> x) ⋀ (f ≃ g) ⋀ (f ≃ h) ⋀ ~g ⋀ ~h  =>  (g ~~ h)`.
+pub fn adjoint_unique_q<F: Prop, G: Prop, H: Prop, X: Prop, Y: Prop>(
+    ty_g: Ty<G, Pow<X, Y>>,
+    ty_h: Ty<H, Pow<X, Y>>,
+    adj_fg: AdjointEquiv<F, G, X, Y>,
+    adj_fh: AdjointEquiv<F, H, X, Y>,
+    qu_g: Qu<G>,
+    qu_h: Qu<H>,
+) -> Q<G, H> {(adjoint_unique(ty_g, ty_h, adj_fg, adj_fh), (qu_g, qu_h))}
@@ -14,53 +14,53 @@ pub struct App<F, X>(F, X);
 
 /// `is_const(f) ⋀ is_const(x)  =>  is_const(f(x))`.
 pub fn app_is_const<F: Prop, X: Prop>(_f: IsConst<F>, _x: IsConst<X>) -> IsConst<App<F, X>> {
-    unimplemented!()
+    axiom!()
 }
 /// `(x == y)  =>  (f(x) == f(y))`.
 ///
 /// Indiscernibility of identicals (Leibniz's law).
 pub fn app_eq<F: Prop, X: Prop, Y: Prop>(
     _eq_xy: Eq<X, Y>
-) -> Eq<App<F, X>, App<F, Y>> {unimplemented!()}
+) -> Eq<App<F, X>, App<F, Y>> {axiom!()}
 /// `(f == g)  =>  (f(x) == g(y))`.
 ///
 /// Lift equality of maps to application.
 pub fn app_map_eq<F: Prop, G: Prop, X: Prop>(
     _eq_fg: Eq<F, G>
-) -> Eq<App<F, X>, App<G, X>> {unimplemented!()}
+) -> Eq<App<F, X>, App<G, X>> {axiom!()}
 /// `(f(a) : y)^(a : x)  =>  (f : (x -> y))`.
 pub fn app_rev_fun_ty<F: Prop, X: Prop, Y: Prop, A: Prop>(
     _: Pow<Ty<App<F, A>, Y>, Ty<A, X>>
-) -> Ty<F, Pow<Y, X>> {unimplemented!()}
+) -> Ty<F, Pow<Y, X>> {axiom!()}
 /// `(f : (x -> y)) ⋀ (a : x)  =>  (f(a) : y)`.
 ///
 /// Get type of applied function.
 pub fn app_fun_ty<F: Prop, X: Prop, Y: Prop, A: Prop>(
     _ty_f: Ty<F, Pow<Y, X>>,
     _ty_a: Ty<A, X>,
-) -> Ty<App<F, A>, Y> {unimplemented!()}
+) -> Ty<App<F, A>, Y> {axiom!()}
 /// `(f : (x => y)) ⋀ (a : x)  =>  (f(a) : y)`.
 ///
 /// Get type of applied lambda.
 pub fn app_lam_ty<F: Prop, X: Prop, Y: Prop, A: Prop>(
     _ty_f: Ty<F, Imply<X, Y>>,
     _ty_a: Ty<A, X>,
-) -> Ty<App<F, A>, Y> {unimplemented!()}
+) -> Ty<App<F, A>, Y> {axiom!()}
 /// `(a : x) ⋀ (f(a) : y)  =>  (f : (x => y))`.
 pub fn app_rev_lam_ty<F: Prop, X: Prop, Y: Prop, A: Prop>(
     _ty_a: Ty<A, X>,
     _ty_fa: Imply<Ty<A, X>, Ty<App<F, A>, Y>>
-) -> Ty<F, Imply<Y, X>> {unimplemented!()}
+) -> Ty<F, Imply<Y, X>> {axiom!()}
 /// `(f : (x -> y)) ⋀ (g : (x -> y)) ⋀ (f(a) == g(a))^(a : x)  =>  ∃ a : x { f == g }`.
 pub fn app_fun_ext<F: Prop, G: Prop, X: Prop, Y: Prop, A: Prop>(
     _ty_f: Ty<F, Pow<Y, X>>,
     _ty_g: Ty<G, Pow<Y, X>>,
     _pow_eq_fa_ga_ty_a: Pow<Eq<App<F, A>, App<G, A>>, Ty<A, X>>
-) -> Exists<Ty<A, X>, Eq<F, G>> {unimplemented!()}
+) -> Exists<Ty<A, X>, Eq<F, G>> {axiom!()}
 /// `theory(f(x))`.
 ///
 /// This prevents polluting functional values with meta-level truths.
-pub fn app_theory<F: Prop, X: Prop>() -> Theory<App<F, X>> {unimplemented!()}
+pub fn app_theory<F: Prop, X: Prop>() -> Theory<App<F, X>> {axiom!()}
 
 /// `(f : x -> y -> z) ⋀ (a : x) ⋀ (b : y)  =>  f(a)(b) : z`.
 ///
@@ -13,28 +13,28 @@ pub struct Tr(());
 pub struct Fa(());
 
 /// `bool : type(0)`.
-pub fn bool_ty() -> Ty<Bool, Type<Z>> {unimplemented!()}
+pub fn bool_ty() -> Ty<Bool, Type<Z>> {axiom!()}
 /// `is_const(bool)`.
-pub fn bool_is_const() -> IsConst<Bool> {unimplemented!()}
+pub fn bool_is_const() -> IsConst<Bool> {axiom!()}
 /// True type.
-pub fn tr_ty() -> Ty<Tr, Bool> {unimplemented!()}
+pub fn tr_ty() -> Ty<Tr, Bool> {axiom!()}
 /// `is_const(true)`.
-pub fn tr_is_const() -> IsConst<Tr> {unimplemented!()}
+pub fn tr_is_const() -> IsConst<Tr> {axiom!()}
 /// False type.
-pub fn fa_ty() -> Ty<Fa, Bool> {unimplemented!()}
+pub fn fa_ty() -> Ty<Fa, Bool> {axiom!()}
 /// `is_const(false)`.
-pub fn fa_is_const() -> IsConst<Fa> {unimplemented!()}
+pub fn fa_is_const() -> IsConst<Fa> {axiom!()}
 /// Boolean values.
-pub fn bool_values<A: Prop>(_ty_a: Ty<A, Bool>) -> Or<Eq<A, Tr>, Eq<A, Fa>> {unimplemented!()}
+pub fn bool_values<A: Prop>(_ty_a: Ty<A, Bool>) -> Or<Eq<A, Tr>, Eq<A, Fa>> {axiom!()}
 /// `false^(tr == fa)`.
 ///
 /// True and false are exclusive.
-pub fn para_eq_tr_fa(_: Eq<Tr, Fa>) -> False {unimplemented!()}
+pub fn para_eq_tr_fa(_: Eq<Tr, Fa>) -> False {axiom!()}
 /// `∃ tr : bool { x } ⋀ ∃ fa : bool { x }  =>  x`.
 pub fn bool1_exists<X: Prop>(
     _case_fa: Exists<Ty<Fa, Bool>, X>,
     _case_tr: Exists<Ty<Tr, Bool>, X>,
-) -> X {unimplemented!()}
+) -> X {axiom!()}
 /// `∃ (fa, fa) : (bool, bool) { x } ⋀
 ///  ∃ (fa, tr) : (bool, bool) { x } ⋀
 ///  ∃ (tr, fa) : (bool, bool) { x } ⋀
@@ -44,7 +44,7 @@ pub fn bool2_exists<X: Prop>(
     _case_fa_tr: Exists<Ty<Tup<Fa, Tr>, Tup<Bool, Bool>>, X>,
     _case_tr_fa: Exists<Ty<Tup<Tr, Fa>, Tup<Bool, Bool>>, X>,
     _case_tr_tr: Exists<Ty<Tup<Tr, Tr>, Tup<Bool, Bool>>, X>,
-) -> X {unimplemented!()}
+) -> X {axiom!()}
 
 /// `(f : bool -> bool) ⋀ (g : bool -> bool) ⋀
 //  (f(tr) == g(tr))^true ⋀ (f(fa) == g(fa))^true  =>  (f == g)^true`.
@@ -111,11 +111,11 @@ pub fn bool_exists_to_pow_eq_fa<A: Prop>(
 pub struct FFalse1(());
 
 /// Type of False1.
-pub fn false1_ty() -> Ty<FFalse1, Pow<Bool, Bool>> {unimplemented!()}
+pub fn false1_ty() -> Ty<FFalse1, Pow<Bool, Bool>> {axiom!()}
 /// `is_const(false1)`.
-pub fn false1_is_const() -> IsConst<FFalse1> {unimplemented!()}
+pub fn false1_is_const() -> IsConst<FFalse1> {axiom!()}
 /// False1 definition.
-pub fn false1_def<A: Prop>(_: Ty<A, Bool>) -> Eq<App<FFalse1, A>, Tr> {unimplemented!()}
+pub fn false1_def<A: Prop>(_: Ty<A, Bool>) -> Eq<App<FFalse1, A>, Tr> {axiom!()}
 
 /// `(inv(false1) ~~ f) => false`.
 pub fn para_inv_false1<F: Prop>(x: Q<Inv<FFalse1>, F>) -> False {
@@ -160,15 +160,15 @@ pub fn idb_def<A: Prop>(ty_a: Ty<A, Bool>) -> Eq<Idb<A>, A> {
 pub struct FNot(());
 
 /// Type of Not.
-pub fn not_ty() -> Ty<FNot, Pow<Bool, Bool>> {unimplemented!()}
+pub fn not_ty() -> Ty<FNot, Pow<Bool, Bool>> {axiom!()}
 /// `is_const(not)`.
-pub fn not_is_const() -> IsConst<FNot> {unimplemented!()}
+pub fn not_is_const() -> IsConst<FNot> {axiom!()}
 /// `not(false) = true`.
-pub fn not_fa() -> Eq<App<FNot, Fa>, Tr> {unimplemented!()}
+pub fn not_fa() -> Eq<App<FNot, Fa>, Tr> {axiom!()}
 /// `not(true) = false`.
-pub fn not_tr() -> Eq<App<FNot, Tr>, Fa> {unimplemented!()}
+pub fn not_tr() -> Eq<App<FNot, Tr>, Fa> {axiom!()}
 /// `inv(not) ~~ not`.
-pub fn not_q() -> Q<Inv<FNot>, FNot> {unimplemented!()}
+pub fn not_q() -> Q<Inv<FNot>, FNot> {axiom!()}
 
 /// `(not . not) == idb`.
 pub fn eq_not_not_idb() -> Eq<Comp<FNot, FNot>, FIdb> {
@@ -193,11 +193,11 @@ pub fn eq_not_not<X: Prop>(ty_x: Ty<X, Bool>) -> Eq<App<FNot, App<FNot, X>>, X>
 pub struct FTrue1(());
 
 /// Type of True1.
-pub fn true1_ty() -> Ty<FTrue1, Pow<Bool, Bool>> {unimplemented!()}
+pub fn true1_ty() -> Ty<FTrue1, Pow<Bool, Bool>> {axiom!()}
 /// `is_const(true1)`.
-pub fn true1_is_const() -> IsConst<FTrue1> {unimplemented!()}
+pub fn true1_is_const() -> IsConst<FTrue1> {axiom!()}
 /// True1 definition.
-pub fn true1_def<A: Prop>(_: Ty<A, Bool>) -> Eq<App<FTrue1, A>, Tr> {unimplemented!()}
+pub fn true1_def<A: Prop>(_: Ty<A, Bool>) -> Eq<App<FTrue1, A>, Tr> {axiom!()}
 
 /// `(inv(true1) ~~ f) => false`.
 pub fn para_inv_true1<F: Prop>(x: Q<Inv<FTrue1>, F>) -> False {
@@ -227,13 +227,13 @@ pub fn eq_norm1_by_true1<F: Prop>(
 pub struct FAnd(());
 
 /// Type of And.
-pub fn and_ty() -> Ty<FAnd, Pow<Bool, Tup<Bool, Bool>>> {unimplemented!()}
+pub fn and_ty() -> Ty<FAnd, Pow<Bool, Tup<Bool, Bool>>> {axiom!()}
 /// `is_const(and)`.
-pub fn and_is_const() -> IsConst<FAnd> {unimplemented!()}
+pub fn and_is_const() -> IsConst<FAnd> {axiom!()}
 /// `and(true, a) = a`.
-pub fn and_tr<A: Prop>(_ty_a: Ty<A, Bool>) -> Eq<App<FAnd, Tup<Tr, A>>, A> {unimplemented!()}
+pub fn and_tr<A: Prop>(_ty_a: Ty<A, Bool>) -> Eq<App<FAnd, Tup<Tr, A>>, A> {axiom!()}
 /// `and(false, a) = false`.
-pub fn and_fa<A: Prop>(_ty_a: Ty<A, Bool>) -> Eq<App<FAnd, Tup<Fa, A>>, Fa> {unimplemented!()}
+pub fn and_fa<A: Prop>(_ty_a: Ty<A, Bool>) -> Eq<App<FAnd, Tup<Fa, A>>, Fa> {axiom!()}
 
 /// `(inv(and) ~~ f) => false`.
 pub fn para_inv_and<F: Prop>(x: Q<Inv<FAnd>, F>) -> False {
@@ -275,13 +275,13 @@ pub fn eq_norm2_and_not_or() -> Eq<SymNorm2<FAnd, FNot>, FOr> {
 pub struct FOr(());
 
 /// Type of Or.
-pub fn or_ty() -> Ty<FOr, Pow<Bool, Tup<Bool, Bool>>> {unimplemented!()}
+pub fn or_ty() -> Ty<FOr, Pow<Bool, Tup<Bool, Bool>>> {axiom!()}
 /// `is_const(or)`.
-pub fn or_is_const() -> IsConst<FOr> {unimplemented!()}
+pub fn or_is_const() -> IsConst<FOr> {axiom!()}
 /// `or(true, a) = true`.
-pub fn or_tr<A: Prop>(_ty_a: Ty<A, Bool>) -> Eq<App<FOr, Tup<Tr, A>>, Tr> {unimplemented!()}
+pub fn or_tr<A: Prop>(_ty_a: Ty<A, Bool>) -> Eq<App<FOr, Tup<Tr, A>>, Tr> {axiom!()}
 /// `or(false, a) = a`.
-pub fn or_fa<A: Prop>(_ty_a: Ty<A, Bool>) -> Eq<App<FOr, Tup<Fa, A>>, A> {unimplemented!()}
+pub fn or_fa<A: Prop>(_ty_a: Ty<A, Bool>) -> Eq<App<FOr, Tup<Fa, A>>, A> {axiom!()}
 
 /// `(inv(or) ~~ f) => false`.
 pub fn para_inv_or<F: Prop>(x: Q<Inv<FOr>, F>) -> False {
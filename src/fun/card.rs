@@ -0,0 +1,192 @@
+//! # Cardinality
+//!
+//! - `injects(x, y)` ([Injects]) means there is an injective function `x -> y`
+//! - `surjects(x, y)` ([Surjects]) means there is a surjective function `x -> y`
+//! - `same_card(x, y)` ([SameCard]) means there is a bijective function `x -> y`
+//!
+//! The functions are referenced by `inj(x, y)` ([InjOf]) and `surj(x, y)` ([SurjOf]).
+//! Surjective functions have a right inverse `surj_inv(x, y)` ([SurjInv]).
+//! Like [natp::Prev], these are not operations, but references to some function
+//! depending on `x` and `y`, such that they are not confused with other functions in scope.
+//!
+//! Cantor-Schröder-Bernstein ([csb]) holds when membership in the image is decidable.
+//!
+//! Cantor's theorem ([para_surjects_pow_bool]) shows that there is no surjection
+//! from `x` to its power construction `x -> bool`, using the diagonal `diag(f)` ([Diag]).
+
+use super::*;
+use bool_alg::{bool_values, para_eq_tr_fa, Bool, FNot};
+
+/// Injects predicate.
+#[derive(Copy, Clone)]
+pub struct FInjects(());
+
+/// `injects(x, y)`.
+pub type Injects<X, Y> = App<FInjects, Tup<X, Y>>;
+
+/// Surjects predicate.
+#[derive(Copy, Clone)]
+pub struct FSurjects(());
+
+/// `surjects(x, y)`.
+pub type Surjects<X, Y> = App<FSurjects, Tup<X, Y>>;
+
+/// Same cardinality predicate.
+#[derive(Copy, Clone)]
+pub struct FSameCard(());
+
+/// `same_card(x, y)`.
+pub type SameCard<X, Y> = App<FSameCard, Tup<X, Y>>;
+
+/// The injective function `inj(x, y)` of `injects(x, y)`.
+#[derive(Clone)]
+pub struct InjOf<X, Y>(X, Y);
+
+/// The surjective function `surj(x, y)` of `surjects(x, y)`.
+#[derive(Clone)]
+pub struct SurjOf<X, Y>(X, Y);
+
+/// The right inverse `surj_inv(x, y)` of `surj(x, y)`.
+#[derive(Clone)]
+pub struct SurjInv<X, Y>(X, Y);
+
+/// `is_const(injects)`.
+pub fn injects_is_const() -> IsConst<FInjects> {axiom!()}
+/// `is_const(surjects)`.
+pub fn surjects_is_const() -> IsConst<FSurjects> {axiom!()}
+/// `is_const(same_card)`.
+pub fn same_card_is_const() -> IsConst<FSameCard> {axiom!()}
+
+/// `(f : x -> y) ⋀ injective(f, a, b)^((a : x) ⋀ (b : x))  =>  injects(x, y)`.
+pub fn injects_intro<F: Prop, X: Prop, Y: Prop, A: VProp, B: VProp>(
+    _ty_f: Ty<F, Pow<Y, X>>,
+    _inj_f: Pow<Injective<F, A, B>, And<Ty<A, X>, Ty<B, X>>>,
+) -> Injects<X, Y> {axiom!()}
+/// `injects(x, y)  =>  inj(x, y) : x -> y`.
+pub fn injects_ty<X: Prop, Y: Prop>(_: Injects<X, Y>) -> Ty<InjOf<X, Y>, Pow<Y, X>> {axiom!()}
+/// `injects(x, y) ⋀ (a : x) ⋀ (b : x)  =>  injective(inj(x, y), a, b)`.
+pub fn injects_inj<X: Prop, Y: Prop, A: Prop, B: Prop>(
+    _: Injects<X, Y>,
+    _ty_a: Ty<A, X>,
+    _ty_b: Ty<B, X>,
+) -> Injective<InjOf<X, Y>, A, B> {axiom!()}
+
+/// `(f : x -> y) ⋀ (g : y -> x) ⋀ (f(g(b)) == b)^(b : y)  =>  surjects(x, y)`.
+pub fn surjects_intro<F: Prop, G: Prop, X: Prop, Y: Prop, B: VProp>(
+    _ty_f: Ty<F, Pow<Y, X>>,
+    _ty_g: Ty<G, Pow<X, Y>>,
+    _right_inv: Pow<Eq<App<F, App<G, B>>, B>, Ty<B, Y>>,
+) -> Surjects<X, Y> {axiom!()}
+/// `surjects(x, y)  =>  surj(x, y) : x -> y`.
+pub fn surjects_ty<X: Prop, Y: Prop>(_: Surjects<X, Y>) -> Ty<SurjOf<X, Y>, Pow<Y, X>> {axiom!()}
+/// `surjects(x, y)  =>  surj_inv(x, y) : y -> x`.
+pub fn surjects_inv_ty<X: Prop, Y: Prop>(_: Surjects<X, Y>) -> Ty<SurjInv<X, Y>, Pow<X, Y>> {
+    axiom!()
+}
+/// `surjects(x, y) ⋀ (b : y)  =>  surj(x, y)(surj_inv(x, y)(b)) == b`.
+pub fn surjects_inv_def<X: Prop, Y: Prop, B: Prop>(
+    _: Surjects<X, Y>,
+    _ty_b: Ty<B, Y>,
+) -> Eq<App<SurjOf<X, Y>, App<SurjInv<X, Y>, B>>, B> {axiom!()}
+
+/// `(f : x -> y) ⋀ (g : y -> x) ⋀ (g(f(a)) == a)^(a : x) ⋀ (f(g(b)) == b)^(b : y)  =>
+/// same_card(x, y)`.
+pub fn same_card_intro<F: Prop, G: Prop, X: Prop, Y: Prop, A: VProp, B: VProp>(
+    _ty_f: Ty<F, Pow<Y, X>>,
+    _ty_g: Ty<G, Pow<X, Y>>,
+    _left_inv: Pow<Eq<App<G, App<F, A>>, A>, Ty<A, X>>,
+    _right_inv: Pow<Eq<App<F, App<G, B>>, B>, Ty<B, Y>>,
+) -> SameCard<X, Y> {axiom!()}
+/// `same_card(x, y)  =>  injects(x, y)`.
+pub fn same_card_to_injects<X: Prop, Y: Prop>(_: SameCard<X, Y>) -> Injects<X, Y> {axiom!()}
+/// `same_card(x, y)  =>  surjects(x, y)`.
+pub fn same_card_to_surjects<X: Prop, Y: Prop>(_: SameCard<X, Y>) -> Surjects<X, Y> {axiom!()}
+/// `same_card(x, x)`.
+pub fn same_card_refl<X: Prop>() -> SameCard<X, X> {axiom!()}
+/// `same_card(x, y)  =>  same_card(y, x)`.
+pub fn same_card_symmetry<X: Prop, Y: Prop>(_: SameCard<X, Y>) -> SameCard<Y, X> {axiom!()}
+/// `same_card(x, y) ⋀ same_card(y, z)  =>  same_card(x, z)`.
+pub fn same_card_transitivity<X: Prop, Y: Prop, Z: Prop>(
+    _: SameCard<X, Y>,
+    _: SameCard<Y, Z>
+) -> SameCard<X, Z> {axiom!()}
+/// Cantor-Schröder-Bernstein under decidability of the image.
+///
+/// ```text
+/// injects(x, y) ⋀ injects(y, x) ⋀
+/// (∃ b : y { inj(y, x)(b) == a } ⋁ ¬∃ b : y { inj(y, x)(b) == a })^(a : x)
+/// -----------------------------------------------------------------------
+/// same_card(x, y)
+/// ```
+pub fn csb<X: Prop, Y: Prop, A: VProp, B: VProp>(
+    _: Injects<X, Y>,
+    _: Injects<Y, X>,
+    _dec: Pow<ExcM<Exists<Ty<B, Y>, Eq<App<InjOf<Y, X>, B>, A>>>, Ty<A, X>>,
+) -> SameCard<X, Y> {axiom!()}
+
+/// `surjects(x, y) ⋀ (a : y) ⋀ (b : y)  =>  injective(surj_inv(x, y), a, b)`.
+///
+/// The right inverse of a surjective function is injective.
+pub fn surjects_inv_injective<X: Prop, Y: Prop, A: Prop, B: Prop>(
+    x: Surjects<X, Y>,
+    ty_a: Ty<A, Y>,
+    ty_b: Ty<B, Y>,
+) -> Injective<SurjInv<X, Y>, A, B> {
+    let eq_a = surjects_inv_def(x.clone(), ty_a);
+    let eq_b = surjects_inv_def(x, ty_b);
+    Rc::new(move |y| {
+        let y = app_eq::<SurjOf<X, Y>, _, _>(y);
+        eq::in_left_arg(eq::in_right_arg(y, eq_b.clone()), eq_a.clone())
+    })
+}
+
+/// Diagonal.
+#[derive(Copy, Clone)]
+pub struct FDiag(());
+
+/// `diag(f)`.
+pub type Diag<F> = App<FDiag, F>;
+
+/// `is_const(diag)`.
+pub fn diag_is_const() -> IsConst<FDiag> {axiom!()}
+/// `(f : x -> (x -> bool))  =>  diag(f) : x -> bool`.
+pub fn diag_ty<F: Prop, X: Prop>(_ty_f: Ty<F, Pow<Pow<Bool, X>, X>>) -> Ty<Diag<F>, Pow<Bool, X>> {
+    axiom!()
+}
+/// `diag(f)(a) == not(f(a)(a))`.
+pub fn diag_def<F: Prop, A: Prop>() -> Eq<App<Diag<F>, A>, App<FNot, App<App<F, A>, A>>> {
+    axiom!()
+}
+
+/// `(b : bool) ⋀ (b == not(b))  =>  false`.
+pub fn para_eq_not<B: Prop>(ty_b: Ty<B, Bool>, x: Eq<B, App<FNot, B>>) -> False {
+    match bool_values(ty_b) {
+        Left(eq_b_tr) => {
+            let y = eq::transitivity(x, app_eq(eq_b_tr.clone()));
+            let y = eq::transitivity(y, bool_alg::not_tr());
+            para_eq_tr_fa(eq::in_left_arg(y, eq_b_tr))
+        }
+        Right(eq_b_fa) => {
+            let y = eq::transitivity(x, app_eq(eq_b_fa.clone()));
+            let y = eq::transitivity(y, bool_alg::not_fa());
+            para_eq_tr_fa(eq::symmetry(eq::in_left_arg(y, eq_b_fa)))
+        }
+    }
+}
+/// `surjects(x, x -> bool)  =>  false`.
+///
+/// Cantor's theorem.
+pub fn para_surjects_pow_bool<X: Prop>(x: Surjects<X, Pow<Bool, X>>) -> False {
+    let ty_f = surjects_ty(x.clone());
+    let ty_d = diag_ty(ty_f.clone());
+    let ty_a = app_fun_ty(surjects_inv_ty(x.clone()), ty_d.clone());
+    // f(a) == diag(f), where `a := surj_inv(x, x -> bool)(diag(f))`.
+    let eq_fa_d = surjects_inv_def(x, ty_d);
+    let ty_faa = app_fun_ty(app_fun_ty(ty_f, ty_a.clone()), ty_a);
+    let y = eq::transitivity(app_map_eq(eq_fa_d), diag_def());
+    para_eq_not(ty_faa, y)
+}
+/// `same_card(x, x -> bool)  =>  false`.
+pub fn para_same_card_pow_bool<X: Prop>(x: SameCard<X, Pow<Bool, X>>) -> False {
+    para_surjects_pow_bool(same_card_to_surjects(x))
+}
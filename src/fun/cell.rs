@@ -0,0 +1,146 @@
+//! # 2-Cells
+//!
+//! A normal path `f[g1 -> g2]` ([Norm1]) is a map that results from normalizing `f`.
+//! The same map can often be normalized in different ways,
+//! so it is useful to have 2-morphisms between maps, called 2-cells.
+//!
+//! A 2-cell `α : p => q` ([Cell]) transforms the map `p` into the map `q`.
+//!
+//! - Identity `id2(p) : p => p` ([Id2], [cell_id])
+//! - Vertical composition `β · α : p => r` of `α : p => q` and `β : q => r` ([VComp], [cell_vcomp])
+//! - Horizontal composition `β ∘ α : (r . p) => (s . q)` of `α : p => q` and `β : r => s`
+//!   ([HComp], [cell_hcomp])
+//!
+//! Vertical and horizontal composition are related by the interchange law ([interchange]):
+//!
+//! `(δ · γ) ∘ (β · α)  ==  (δ ∘ β) · (γ ∘ α)`
+//!
+//! Equal normal paths are related by identity 2-cells ([eq_to_cell]),
+//! e.g. the two normalizations in [norm1_comp] ([norm1_comp_cell]).
+
+use super::*;
+
+/// 2-cell relation.
+#[derive(Copy, Clone)]
+pub struct FCell(());
+
+/// `α : p => q`.
+pub type Cell<Al, P, Q> = App<App<FCell, Al>, Tup<P, Q>>;
+
+/// Identity 2-cell.
+#[derive(Copy, Clone)]
+pub struct FId2(());
+
+/// `id2(p)`.
+pub type Id2<P> = App<FId2, P>;
+
+/// Vertical composition.
+#[derive(Copy, Clone)]
+pub struct FVComp(());
+
+/// `β · α`.
+pub type VComp<Be, Al> = App<FVComp, Tup<Be, Al>>;
+
+/// Horizontal composition.
+#[derive(Copy, Clone)]
+pub struct FHComp(());
+
+/// `β ∘ α`.
+pub type HComp<Be, Al> = App<FHComp, Tup<Be, Al>>;
+
+/// `is_const(cell)`.
+pub fn cell_is_const() -> IsConst<FCell> {axiom!()}
+/// `is_const(id2)`.
+pub fn id2_is_const() -> IsConst<FId2> {axiom!()}
+/// `is_const(vcomp)`.
+pub fn vcomp_is_const() -> IsConst<FVComp> {axiom!()}
+/// `is_const(hcomp)`.
+pub fn hcomp_is_const() -> IsConst<FHComp> {axiom!()}
+/// `id2(p) : p => p`.
+pub fn cell_id<P: Prop>() -> Cell<Id2<P>, P, P> {axiom!()}
+/// `(α : p => q) ⋀ (β : q => r)  =>  (β · α) : p => r`.
+pub fn cell_vcomp<Al: Prop, Be: Prop, P: Prop, Q: Prop, R: Prop>(
+    _: Cell<Al, P, Q>,
+    _: Cell<Be, Q, R>
+) -> Cell<VComp<Be, Al>, P, R> {axiom!()}
+/// `(α : p => q) ⋀ (β : r => s)  =>  (β ∘ α) : (r . p) => (s . q)`.
+pub fn cell_hcomp<Al: Prop, Be: Prop, P: Prop, Q: Prop, R: Prop, S: Prop>(
+    _: Cell<Al, P, Q>,
+    _: Cell<Be, R, S>
+) -> Cell<HComp<Be, Al>, Comp<R, P>, Comp<S, Q>> {axiom!()}
+/// `(α : p => q)  =>  (id2(q) · α) == α`.
+pub fn vcomp_id_left<Al: Prop, P: Prop, Q: Prop>(_: Cell<Al, P, Q>) -> Eq<VComp<Id2<Q>, Al>, Al> {
+    axiom!()
+}
+/// `(α : p => q)  =>  (α · id2(p)) == α`.
+pub fn vcomp_id_right<Al: Prop, P: Prop, Q: Prop>(_: Cell<Al, P, Q>) -> Eq<VComp<Al, Id2<P>>, Al> {
+    axiom!()
+}
+/// `γ · (β · α)  ==  (γ · β) · α`.
+pub fn vcomp_assoc<Al: Prop, Be: Prop, Ga: Prop>() ->
+    Eq<VComp<Ga, VComp<Be, Al>>, VComp<VComp<Ga, Be>, Al>> {axiom!()}
+/// `id2(r) ∘ id2(p)  ==  id2(r . p)`.
+pub fn hcomp_id<P: Prop, R: Prop>() -> Eq<HComp<Id2<R>, Id2<P>>, Id2<Comp<R, P>>> {axiom!()}
+/// `(α : p => q) ⋀ (β : q => r) ⋀ (γ : s => t) ⋀ (δ : t => u)  =>
+///   (δ · γ) ∘ (β · α)  ==  (δ ∘ β) · (γ ∘ α)`.
+///
+/// Interchange law.
+pub fn interchange<Al: Prop, Be: Prop, Ga: Prop, De: Prop,
+    P: Prop, Q: Prop, R: Prop, S: Prop, T: Prop, U: Prop>(
+    _: Cell<Al, P, Q>,
+    _: Cell<Be, Q, R>,
+    _: Cell<Ga, S, T>,
+    _: Cell<De, T, U>,
+) -> Eq<HComp<VComp<De, Ga>, VComp<Be, Al>>, VComp<HComp<De, Be>, HComp<Ga, Al>>> {axiom!()}
+
+/// `(α : p => q) ⋀ (p == r)  =>  α : r => q`.
+pub fn cell_in_left_arg<Al: Prop, P: Prop, Q: Prop, R: Prop>(
+    x: Cell<Al, P, Q>,
+    y: Eq<P, R>
+) -> Cell<Al, R, Q> {app_eq(tup_eq_fst(y)).0(x)}
+/// `(α : p => q) ⋀ (q == r)  =>  α : p => r`.
+pub fn cell_in_right_arg<Al: Prop, P: Prop, Q: Prop, R: Prop>(
+    x: Cell<Al, P, Q>,
+    y: Eq<Q, R>
+) -> Cell<Al, P, R> {app_eq(tup_eq_snd(y)).0(x)}
+/// `(p == q)  =>  id2(p) : p => q`.
+pub fn eq_to_cell<P: Prop, Q: Prop>(x: Eq<P, Q>) -> Cell<Id2<P>, P, Q> {
+    cell_in_right_arg(cell_id(), x)
+}
+/// `(α : p => q)  =>  (id2(r) ∘ α) : (r . p) => (r . q)`.
+///
+/// Left whiskering.
+pub fn whisker_left<Al: Prop, P: Prop, Q: Prop, R: Prop>(
+    x: Cell<Al, P, Q>
+) -> Cell<HComp<Id2<R>, Al>, Comp<R, P>, Comp<R, Q>> {cell_hcomp(x, cell_id())}
+/// `(β : r => s)  =>  (β ∘ id2(p)) : (r . p) => (s . p)`.
+///
+/// Right whiskering.
+pub fn whisker_right<Be: Prop, P: Prop, R: Prop, S: Prop>(
+    x: Cell<Be, R, S>
+) -> Cell<HComp<Be, Id2<P>>, Comp<R, P>, Comp<S, P>> {cell_hcomp(cell_id(), x)}
+/// `(α : p => q) ⋀ (β : r => s)  =>  (β ∘ α) == (β ∘ id2(q)) · (id2(r) ∘ α)`.
+///
+/// Horizontal composition decomposes into whiskerings.
+pub fn hcomp_whisker<Al: Prop, Be: Prop, P: Prop, Q: Prop, R: Prop, S: Prop>(
+    x: Cell<Al, P, Q>,
+    y: Cell<Be, R, S>,
+) -> Eq<HComp<Be, Al>, VComp<HComp<Be, Id2<Q>>, HComp<Id2<R>, Al>>> {
+    let eq_al = eq::symmetry(vcomp_id_left(x.clone()));
+    let eq_be = eq::symmetry(vcomp_id_right(y.clone()));
+    let z: Eq<HComp<Be, Al>, HComp<VComp<Be, Id2<R>>, VComp<Id2<Q>, Al>>> =
+        app_eq(tup_eq(eq_be, eq_al));
+    eq::transitivity(z, interchange(x, cell_id(), cell_id(), y))
+}
+/// `id2(f[g1 -> g2][g3 -> g4]) : f[g1 -> g2][g3 -> g4] => f[(g3 . g1) -> (g4 . g2)]`.
+pub fn norm1_comp_cell<F: Prop, G1: Prop, G2: Prop, G3: Prop, G4: Prop>() ->
+    Cell<Id2<Norm1<Norm1<F, G1, G2>, G3, G4>>,
+         Norm1<Norm1<F, G1, G2>, G3, G4>, Norm1<F, Comp<G3, G1>, Comp<G4, G2>>>
+{eq_to_cell(norm1_comp())}
+/// `(g1 == h1) ⋀ (g2 == h2)  =>  id2(f[g1 -> g2]) : f[g1 -> g2] => f[h1 -> h2]`.
+pub fn norm1_cell<F: Prop, G1: Prop, G2: Prop, H1: Prop, H2: Prop>(
+    x: Eq<G1, H1>,
+    y: Eq<G2, H2>
+) -> Cell<Id2<Norm1<F, G1, G2>>, Norm1<F, G1, G2>, Norm1<F, H1, H2>> {
+    eq_to_cell(eq::transitivity(norm1_eq_in(x), norm1_eq_out(y)))
+}
@@ -0,0 +1,188 @@
+//! # Choice Principles
+//!
+//! A choice principle turns a total relation `r` (see [fun::rel]) into a choice function `f`,
+//! such that `r(a, f(a))` for all `a : x` ([ChoiceFun]).
+//! Choice principles are not provable constructively, so they are enabled by feature gates:
+//!
+//! - Unique choice `unique_choice` (feature `unique_choice`): For functional relations
+//! - Countable choice `countable_choice` (feature `countable_choice`):
+//!   For relations on natural numbers
+//! - Full choice `choice` (feature `choice`): For all relations
+//!
+//! Full choice implies unique choice and countable choice,
+//! which are proved when the `choice` feature is enabled.
+//! Full choice also implies that every surjection splits (`choice_split`).
+//!
+//! Together with extensionality, a choice function implies Excluded Middle
+//! (Diaconescu's theorem, [diaconescu]).
+//! Results that depend on choice should cite the weakest principle they use.
+//!
+//! The axiom audit (see [crate::axioms]) lists exactly the choice axioms that are compiled:
+//!
+//! ```rust
+//! let listed = |name: &str| prop::axioms().iter().any(|ax| ax.name == name);
+//! assert_eq!(listed("fun::choice::choice"), cfg!(feature = "choice"));
+//! assert_eq!(listed("fun::choice::unique_choice"),
+//!     cfg!(all(feature = "unique_choice", not(feature = "choice"))));
+//! assert_eq!(listed("fun::choice::countable_choice"),
+//!     cfg!(all(feature = "countable_choice", not(feature = "choice"))));
+//! ```
+//!
+//! For functional relations, a choice function is unique up to equality
+//! without any choice principle ([choice_fun_eq]).
+//!
+//! ### Diaconescu's Theorem
+//!
+//! Sets of booleans are lambdas `\(b : bool) = q` with membership `(a ∈ s) == s(a)` ([mem_def]).
+//! For a proposition `p`, where `b` is not free in `p`, consider the sets
+//! `u := {b : bool | (b == 0) ⋁ p}` and `v := {b : bool | (b == 1) ⋁ p}` ([DiaSet]).
+//!
+//! - A choice function `f` for membership gives `f(u) ∈ u` and `f(v) ∈ v`,
+//!   which unfold to `(f(u) == 0) ⋁ p` and `(f(v) == 1) ⋁ p` by beta-reduction ([dia_mem])
+//! - Extensionality of lambdas gives `p => (u == v)` ([dia_ext])
+//!
+//! Then `p ⋁ ¬p` ([diaconescu]).
+//!
+//! Existence in this library is `∃ a { b } := ¬(¬b)^a` (see [hooo::Exists]),
+//! from which a choice function can not be extracted constructively.
+//! Therefore, [diaconescu] takes a choice function `r(a, f(a))^(a : x)`
+//! for membership, as asserted by the choice principles, as premise.
+
+use super::*;
+use rel::{Functional, Rel};
+use bool_alg::{fa_is_const, para_eq_tr_fa, tr_is_const, Bool, Fa, Tr};
+#[cfg(any(feature = "unique_choice", feature = "countable_choice", feature = "choice"))]
+use rel::Total;
+#[cfg(any(feature = "countable_choice", feature = "choice"))]
+use natp::Nat;
+
+/// `∃ f : x -> y { r(a, f(a))^(a : x) }`.
+pub type ChoiceFun<R, X, Y, F, A> = Exists<Ty<F, Pow<Y, X>>, Pow<Rel<R, A, App<F, A>>, Ty<A, X>>>;
+
+/// Membership symbol.
+#[derive(Copy, Clone)]
+pub struct FMem(());
+
+/// `a ∈ s`.
+pub type Mem<S, A> = Rel<FMem, S, A>;
+
+/// `{b : bool | (b == c) ⋁ p}`.
+pub type DiaSet<B, C, P> = Lam<Ty<B, Bool>, Or<Eq<B, C>, P>>;
+
+/// `is_const(∈)`.
+pub fn mem_is_const() -> IsConst<FMem> {axiom!()}
+/// `(a ∈ s) == s(a)`.
+pub fn mem_def<S: Prop, A: Prop>() -> Eq<Mem<S, A>, App<S, A>> {axiom!()}
+
+/// `r(a, f(a)) ⋀ r(a, g(a)) ⋀ functional(r, a, f(a), g(a))  =>  f(a) == g(a)`.
+pub fn choice_fun_eq<R: Prop, F: Prop, G: Prop, A: Prop>(
+    x: Rel<R, A, App<F, A>>,
+    y: Rel<R, A, App<G, A>>,
+    func: Functional<R, A, App<F, A>, App<G, A>>
+) -> Eq<App<F, A>, App<G, A>> {func((x, y))}
+/// `¬free(b, p) ⋀ is_const(c) ⋀ (a : bool) ⋀ (a ∈ {b : bool | (b == c) ⋁ p})  =>
+///   (a == c) ⋁ p`.
+pub fn dia_mem<B: Prop, C: Prop, P: Prop, A: Prop>(
+    nf: NotFreeIn<B, P>,
+    c_is_const: IsConst<C>,
+    ty_a: Ty<A, Bool>,
+    x: Mem<DiaSet<B, C, P>, A>
+) -> Or<Eq<A, C>, P> {
+    match subst_or().0(lam(ty_a).0(mem_def().0(x))) {
+        Left(eq) => {
+            let eq = eq::transitivity(eq::symmetry(subst_trivial()), subst_eq_prop().0(eq));
+            Left(eq::transitivity(eq, subst_const(c_is_const)))
+        }
+        Right(p) => Right(not_free_subst_nop(nf).0(p)),
+    }
+}
+/// `(b : bool) ⋀ p  =>  {b : bool | (b == c) ⋁ p} == {b : bool | (b == d) ⋁ p}`.
+pub fn dia_ext<B: Prop, C: Prop, D: Prop, P: Prop>(
+    ty_b: Ty<B, Bool>,
+    p: P
+) -> Eq<DiaSet<B, C, P>, DiaSet<B, D, P>> {
+    let p2 = p.clone();
+    lam_eq_lift(ty_b, (Rc::new(move |_| Right(p.clone())), Rc::new(move |_| Right(p2.clone()))))
+}
+/// `¬free(b, p) ⋀ (b : bool) ⋀ (f : x -> bool) ⋀ (u : x) ⋀ (v : x) ⋀
+///   (f(a) ∈ a)^(a : x)  =>  p ⋁ ¬p`
+/// where `u := {b : bool | (b == 0) ⋁ p}` and `v := {b : bool | (b == 1) ⋁ p}`.
+///
+/// Diaconescu's theorem.
+/// The choice function is given for `u` and `v` separately,
+/// since a premise `(f(a) ∈ a)^(a : x)` can only be used at a single `a`.
+pub fn diaconescu<F: Prop, X: Prop, B: Prop, P: Prop>(
+    nf: NotFreeIn<B, P>,
+    ty_b: Ty<B, Bool>,
+    ty_f: Ty<F, Pow<Bool, X>>,
+    ty_u: Ty<DiaSet<B, Fa, P>, X>,
+    ty_v: Ty<DiaSet<B, Tr, P>, X>,
+    cu: Pow<Mem<DiaSet<B, Fa, P>, App<F, DiaSet<B, Fa, P>>>, Ty<DiaSet<B, Fa, P>, X>>,
+    cv: Pow<Mem<DiaSet<B, Tr, P>, App<F, DiaSet<B, Tr, P>>>, Ty<DiaSet<B, Tr, P>, X>>,
+) -> ExcM<P> {
+    let fu = dia_mem(nf.clone(), fa_is_const(), app_fun_ty(ty_f.clone(), ty_u.clone()), cu(ty_u));
+    let fv = dia_mem(nf, tr_is_const(), app_fun_ty(ty_f, ty_v.clone()), cv(ty_v));
+    match (fu, fv) {
+        (Right(p), _) | (_, Right(p)) => Left(p),
+        (Left(fu), Left(fv)) => Right(Rc::new(move |p| {
+            let fu_fv = app_eq(dia_ext::<B, Fa, Tr, P>(ty_b.clone(), p));
+            let tr_fu = eq::transitivity(eq::symmetry(fv.clone()), eq::symmetry(fu_fv));
+            para_eq_tr_fa(eq::transitivity(tr_fu, fu.clone()))
+        })),
+    }
+}
+
+/// `total(r, x, y, a, b)^true ⋀ functional(r, a, b, c)^true  =>  ∃ f : x -> y { r(a, f(a)) }`.
+///
+/// Unique choice.
+#[cfg(all(feature = "unique_choice", not(feature = "choice")))]
+pub fn unique_choice<R: Prop, X: Prop, Y: Prop, F: VProp, A: VProp, B: VProp, C: VProp>(
+    _: Tauto<Total<R, X, Y, A, B>>,
+    _: Tauto<Functional<R, A, B, C>>
+) -> ChoiceFun<R, X, Y, F, A> {axiom!()}
+/// `total(r, nat, y, a, b)^true  =>  ∃ f : nat -> y { r(a, f(a)) }`.
+///
+/// Countable choice.
+#[cfg(all(feature = "countable_choice", not(feature = "choice")))]
+pub fn countable_choice<R: Prop, Y: Prop, F: VProp, A: VProp, B: VProp>(
+    _: Tauto<Total<R, Nat, Y, A, B>>
+) -> ChoiceFun<R, Nat, Y, F, A> {axiom!()}
+/// `total(r, x, y, a, b)^true  =>  ∃ f : x -> y { r(a, f(a)) }`.
+///
+/// Axiom of choice.
+#[cfg(feature = "choice")]
+pub fn choice<R: Prop, X: Prop, Y: Prop, F: VProp, A: VProp, B: VProp>(
+    _: Tauto<Total<R, X, Y, A, B>>
+) -> ChoiceFun<R, X, Y, F, A> {axiom!()}
+/// `total(r, x, y, a, b)^true ⋀ functional(r, a, b, c)^true  =>  ∃ f : x -> y { r(a, f(a)) }`.
+///
+/// Unique choice follows from the axiom of choice.
+#[cfg(feature = "choice")]
+pub fn unique_choice<R: Prop, X: Prop, Y: Prop, F: VProp, A: VProp, B: VProp, C: VProp>(
+    x: Tauto<Total<R, X, Y, A, B>>,
+    _: Tauto<Functional<R, A, B, C>>
+) -> ChoiceFun<R, X, Y, F, A> {choice(x)}
+/// `total(r, nat, y, a, b)^true  =>  ∃ f : nat -> y { r(a, f(a)) }`.
+///
+/// Countable choice follows from the axiom of choice.
+#[cfg(feature = "choice")]
+pub fn countable_choice<R: Prop, Y: Prop, F: VProp, A: VProp, B: VProp>(
+    x: Tauto<Total<R, Nat, Y, A, B>>
+) -> ChoiceFun<R, Nat, Y, F, A> {choice(x)}
+/// `total(rel(f)°, y, x, b, a)^true  =>  ∃ g : y -> x { (f(g(b)) == b)^(b : y) }`.
+///
+/// Every surjection splits.
+#[cfg(feature = "choice")]
+pub fn choice_split<F: Prop, G: VProp, X: Prop, Y: Prop, A: VProp, B: VProp>(
+    x: Tauto<Total<rel::Converse<rel::RelFun<F>>, Y, X, B, A>>
+) -> Exists<Ty<G, Pow<X, Y>>, Pow<Eq<App<F, App<G, B>>, B>, Ty<B, Y>>> {
+    fn elim<F: Prop, G: Prop, B: Prop>(
+        r: Rel<rel::Converse<rel::RelFun<F>>, B, App<G, B>>
+    ) -> Eq<App<F, App<G, B>>, B> {rel::rel_fun_elim(rel::converse_def().0(r))}
+    let y = choice::<_, Y, X, G, B, A>(x);
+    Rc::new(move |h: Pow<Not<Pow<Eq<App<F, App<G, B>>, B>, Ty<B, Y>>>, Ty<G, Pow<X, Y>>>| {
+        y(h.trans(|nq| Rc::new(move |q: Pow<Rel<_, B, App<G, B>>, Ty<B, Y>>| {
+            nq(q.trans(elim::<F, G, B>))
+        })))
+    })
+}
@@ -1,3 +1,7 @@
+//! # Function Composition
+//!
+//! Composition `g . f` ([Comp]) with identity and associativity laws.
+
 use super::*;
 
 /// Composition.
@@ -5,7 +9,7 @@ use super::*;
 pub struct FComp(());
 
 /// `is_const(comp)`.
-pub fn fcomp_is_const() -> IsConst<FComp> {unimplemented!()}
+pub fn fcomp_is_const() -> IsConst<FComp> {axiom!()}
 
 /// `f . g`.
 pub type Comp<F, G> = App<FComp, Tup<F, G>>;
@@ -16,29 +20,29 @@ pub type Comp<F, G> = App<FComp, Tup<F, G>>;
 pub fn comp_ty<F: Prop, G: Prop, X: Prop, Y: Prop, Z: Prop>(
     _ty_f: Ty<F, Pow<Y, X>>,
     _ty_g: Ty<G, Pow<Z, Y>>
-) -> Ty<Comp<G, F>, Pow<Z, X>> {unimplemented!()}
+) -> Ty<Comp<G, F>, Pow<Z, X>> {axiom!()}
 /// `g(f(x)) => (g . f)(x)`.
 pub fn app_to_comp<F: Prop, G: Prop, X: Prop>(_: App<G, App<F, X>>) -> App<Comp<G, F>, X> {
-    unimplemented!()
+    axiom!()
 }
 /// `(g . f)(x) => g(f(x))`.
 pub fn comp_to_app<F: Prop, G: Prop, X: Prop>(_: App<Comp<G, F>, X>) -> App<G, App<F, X>> {
-    unimplemented!()
+    axiom!()
 }
 /// `h . (g . f)  ==  (h . g) . f`.
 pub fn comp_assoc<F: Prop, G: Prop, H: Prop>() -> Eq<Comp<H, Comp<G, F>>, Comp<Comp<H, G>, F>> {
-    unimplemented!()
+    axiom!()
 }
 /// `(f : a -> b)  =>  (id{b} . f == f)`.
 pub fn comp_id_left<F: Prop, A: Prop, B: Prop>(
     _ty_f: Ty<F, Pow<B, A>>
-) -> Eq<Comp<App<FId, B>, F>, F> {unimplemented!()}
+) -> Eq<Comp<App<FId, B>, F>, F> {axiom!()}
 /// `(f : a -> b)  =>  (f . id{a} == f)`.
 pub fn comp_id_right<F: Prop, A: Prop, B: Prop>(
     _ty_f: Ty<F, Pow<B, A>>
-) -> Eq<Comp<F, App<FId, A>>, F> {unimplemented!()}
+) -> Eq<Comp<F, App<FId, A>>, F> {axiom!()}
 /// `~f ⋀ ~g  =>  ~(g . f)`.
-pub fn comp_qu<F: Prop, G: Prop>(_: Qu<F>, _: Qu<G>) -> Qu<Comp<G, F>> {unimplemented!()}
+pub fn comp_qu<F: Prop, G: Prop>(_: Qu<F>, _: Qu<G>) -> Qu<Comp<G, F>> {axiom!()}
 
 /// `is_const(f) ⋀ is_const(g)  =>  is_const(g . f)`.
 pub fn comp_is_const<F: Prop, G: Prop>(a: IsConst<F>, b: IsConst<G>) -> IsConst<Comp<G, F>> {
@@ -0,0 +1,94 @@
+//! # Typing Contexts
+//!
+//! A context `Γ` is a list of typing assumptions `a : x`,
+//! starting from the empty context ([Empty]) and extended one assumption at a time ([Ext]).
+//!
+//! A judgment `Γ ⊢ a : x` ([Judg]) means that `a : x` follows from the assumptions in `Γ`.
+//! This makes it possible to do sequent-style reasoning about types,
+//! instead of threading tuples of hypotheses through every lemma.
+//!
+//! The structural rules are:
+//!
+//! - Variable ([var])
+//! - Weakening ([weaken])
+//! - Exchange ([exchange])
+//! - Contraction ([contract])
+//! - Cut ([cut])
+//!
+//! Typing rules such as [lam_ty] and [app_fun_ty] are lifted to judgments in the same context.
+
+use super::*;
+
+/// Empty context `∅`.
+pub type Empty = True;
+
+/// Context extension `Γ, a : x`.
+pub type Ext<G, A, X> = And<G, Ty<A, X>>;
+
+/// Judgment `Γ ⊢ a : x`.
+pub type Judg<G, A, X> = Imply<G, Ty<A, X>>;
+
+/// `(a : x)  =>  Γ ⊢ a : x`.
+pub fn lift<G: Prop, A: Prop, X: Prop>(ty_a: Ty<A, X>) -> Judg<G, A, X> {ty_a.map_any()}
+/// `(∅ ⊢ a : x)  =>  (a : x)`.
+pub fn close<A: Prop, X: Prop>(x: Judg<Empty, A, X>) -> Ty<A, X> {x(True)}
+/// `Γ, a : x ⊢ a : x`.
+pub fn var<G: Prop, A: Prop, X: Prop>() -> Judg<Ext<G, A, X>, A, X> {Rc::new(|(_, ty_a)| ty_a)}
+/// `(Γ ⊢ a : x)  =>  Γ, b : y ⊢ a : x`.
+///
+/// Weakening.
+pub fn weaken<G: Prop, A: Prop, X: Prop, B: Prop, Y: Prop>(
+    x: Judg<G, A, X>
+) -> Judg<Ext<G, B, Y>, A, X> {Rc::new(move |(g, _)| x(g))}
+/// `(Γ, a : x, b : y ⊢ t)  =>  (Γ, b : y, a : x ⊢ t)`.
+///
+/// Exchange.
+pub fn exchange<G: Prop, A: Prop, X: Prop, B: Prop, Y: Prop, T: Prop>(
+    x: Imply<Ext<Ext<G, A, X>, B, Y>, T>
+) -> Imply<Ext<Ext<G, B, Y>, A, X>, T> {Rc::new(move |((g, ty_b), ty_a)| x(((g, ty_a), ty_b)))}
+/// `(Γ, a : x, a : x ⊢ t)  =>  (Γ, a : x ⊢ t)`.
+///
+/// Contraction.
+pub fn contract<G: Prop, A: Prop, X: Prop, T: Prop>(
+    x: Imply<Ext<Ext<G, A, X>, A, X>, T>
+) -> Imply<Ext<G, A, X>, T> {Rc::new(move |(g, ty_a)| x(((g, ty_a.clone()), ty_a)))}
+/// `(Γ ⊢ a : x) ⋀ (Γ, a : x ⊢ t)  =>  (Γ ⊢ t)`.
+///
+/// Cut.
+pub fn cut<G: Prop, A: Prop, X: Prop, T: Prop>(
+    x: Judg<G, A, X>,
+    y: Imply<Ext<G, A, X>, T>
+) -> Imply<G, T> {Rc::new(move |g: G| y((g.clone(), x(g))))}
+/// `(Γ ⊢ a : x) ⋀ (a == b)  =>  (Γ ⊢ b : x)`.
+pub fn in_left_arg<G: Prop, A: Prop, B: Prop, X: Prop>(
+    x: Judg<G, A, X>,
+    y: Eq<A, B>
+) -> Judg<G, B, X> {Rc::new(move |g| ty::in_left_arg(x(g), y.clone()))}
+
+/// `(Γ ⊢ a : x) ⋀ (Γ ⊢ b : y)  =>  Γ ⊢ (\(a : x) = b) : (x => y)`.
+pub fn lam_ty<G: Prop, A: Prop, B: Prop, X: Prop, Y: Prop>(
+    x: Judg<G, A, X>,
+    y: Judg<G, B, Y>
+) -> Judg<G, Lam<Ty<A, X>, B>, Imply<X, Y>> {
+    Rc::new(move |g: G| super::lam_ty(x(g.clone()), y(g)))
+}
+/// `(Γ ⊢ f : x -> y) ⋀ (Γ ⊢ a : x)  =>  Γ ⊢ f(a) : y`.
+pub fn app_fun_ty<G: Prop, F: Prop, X: Prop, Y: Prop, A: Prop>(
+    x: Judg<G, F, Pow<Y, X>>,
+    y: Judg<G, A, X>
+) -> Judg<G, App<F, A>, Y> {Rc::new(move |g: G| super::app_fun_ty(x(g.clone()), y(g)))}
+/// `(Γ ⊢ f : x => y) ⋀ (Γ ⊢ a : x)  =>  Γ ⊢ f(a) : y`.
+pub fn app_lam_ty<G: Prop, F: Prop, X: Prop, Y: Prop, A: Prop>(
+    x: Judg<G, F, Imply<X, Y>>,
+    y: Judg<G, A, X>
+) -> Judg<G, App<F, A>, Y> {Rc::new(move |g: G| super::app_lam_ty(x(g.clone()), y(g)))}
+/// `(Γ ⊢ a : x) ⋀ (Γ ⊢ b : y)  =>  Γ ⊢ (a, b) : (x, y)`.
+pub fn tup_ty<G: Prop, A: Prop, B: Prop, X: Prop, Y: Prop>(
+    x: Judg<G, A, X>,
+    y: Judg<G, B, Y>
+) -> Judg<G, Tup<A, B>, Tup<X, Y>> {Rc::new(move |g: G| super::tup_ty(x(g.clone()), y(g)))}
+/// `(Γ ⊢ f : x -> y) ⋀ (Γ ⊢ g : y -> z)  =>  Γ ⊢ (g . f) : x -> z`.
+pub fn comp_ty<G: Prop, F: Prop, H: Prop, X: Prop, Y: Prop, Z: Prop>(
+    x: Judg<G, F, Pow<Y, X>>,
+    y: Judg<G, H, Pow<Z, Y>>
+) -> Judg<G, Comp<H, F>, Pow<Z, X>> {Rc::new(move |g: G| super::comp_ty(x(g.clone()), y(g)))}
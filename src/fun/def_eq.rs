@@ -0,0 +1,147 @@
+//! # Definitional Equality
+//!
+//! Definitional equality `a ≡ b` ([DefEq]) is a judgment that `a` and `b`
+//! are equal by computation.
+//! It is stricter than propositional equality `a == b`:
+//! A definitional equality can only be constructed from computation rules and congruence,
+//! but it can always be mapped into `a == b` ([to_eq]).
+//!
+//! The computation rules are:
+//!
+//! - Beta-reduction `(\(a : x) = b)(c) ≡ b[a := c]` ([beta])
+//! - Eta-conversion `(\(a : x) = f(a)) ≡ f` ([eta], [eta_pow])
+//! - Identity `id{x}(a) ≡ a` ([id_def])
+//! - Composition `g(f(a)) ≡ (g . f)(a)` ([comp])
+//! - Substitution ([subst_trivial], [subst_nop], [subst_tup], [subst_app],
+//!   [subst_or], [subst_eq_prop])
+//!
+//! This makes it possible for normalization tooling to distinguish computation steps
+//! from propositional equality.
+//!
+//! ### Equality reflection
+//!
+//! The default theory is intensional: `a == b` does not imply `a ≡ b`.
+//! With the `eq_reflect` feature, the axiom `eq_reflect` turns every propositional equality
+//! into a definitional one, as in extensional type theory.
+//! This unlocks definitional versions of typing and substitution lemmas,
+//! e.g. `subst_ty_def` and `subst_const_def`, which are otherwise only propositional
+//! (see [subst::subst_ty] and [subst::subst_const]).
+
+use super::*;
+
+/// `a ≡ b`.
+#[derive(Clone)]
+pub struct DefEq<A, B>(Eq<A, B>);
+
+/// `(a ≡ b)  =>  (a == b)`.
+pub fn to_eq<A: Prop, B: Prop>(x: DefEq<A, B>) -> Eq<A, B> {x.0}
+/// `a ≡ a`.
+pub fn refl<A: Prop>() -> DefEq<A, A> {DefEq(eq::refl())}
+/// `(a ≡ b)  =>  (b ≡ a)`.
+pub fn symmetry<A: Prop, B: Prop>(x: DefEq<A, B>) -> DefEq<B, A> {DefEq(eq::symmetry(x.0))}
+/// `(a ≡ b) ⋀ (b ≡ c)  =>  (a ≡ c)`.
+pub fn transitivity<A: Prop, B: Prop, C: Prop>(x: DefEq<A, B>, y: DefEq<B, C>) -> DefEq<A, C> {
+    DefEq(eq::transitivity(x.0, y.0))
+}
+
+/// `(f ≡ g) ⋀ (x ≡ y)  =>  f(x) ≡ g(y)`.
+pub fn cong_app<F: Prop, G: Prop, X: Prop, Y: Prop>(
+    f: DefEq<F, G>,
+    x: DefEq<X, Y>
+) -> DefEq<App<F, X>, App<G, Y>> {DefEq(eq::transitivity(app_map_eq(f.0), app_eq(x.0)))}
+/// `(a ≡ b) ⋀ (c ≡ d)  =>  (a, c) ≡ (b, d)`.
+pub fn cong_tup<A: Prop, B: Prop, C: Prop, D: Prop>(
+    x: DefEq<A, B>,
+    y: DefEq<C, D>
+) -> DefEq<Tup<A, C>, Tup<B, D>> {DefEq(tup_eq(x.0, y.0))}
+/// `(f ≡ h) ⋀ (g ≡ i)  =>  (f . g) ≡ (h . i)`.
+pub fn cong_comp<F: Prop, G: Prop, H: Prop, I: Prop>(
+    x: DefEq<F, H>,
+    y: DefEq<G, I>
+) -> DefEq<Comp<F, G>, Comp<H, I>> {
+    DefEq(eq::transitivity(comp_eq_left(x.0), comp_eq_right(y.0)))
+}
+/// `(a : x) ⋀ (b ≡ c)  =>  (\(a : x) = b) ≡ (\(a : x) = c)`.
+pub fn cong_lam<A: Prop, X: Prop, B: Prop, C: Prop>(
+    ty_a: Ty<A, X>,
+    x: DefEq<B, C>
+) -> DefEq<Lam<Ty<A, X>, B>, Lam<Ty<A, X>, C>> {DefEq(lam_eq_lift(ty_a, x.0))}
+
+/// `(c : x)  =>  (\(a : x) = b)(c) ≡ b[a := c]`.
+///
+/// Beta-reduction.
+pub fn beta<A: Prop, B: Prop, X: Prop, C: Prop>(
+    _ty_c: Ty<C, X>
+) -> DefEq<App<Lam<Ty<A, X>, B>, C>, Subst<B, A, C>> {axiom!()}
+/// `(f : x => y) ⋀ ¬free(a, f)  =>  (\(a : x) = f(a)) ≡ f`.
+///
+/// Eta-conversion.
+pub fn eta<A: Prop, F: Prop, X: Prop, Y: Prop>(
+    _ty_f: Ty<F, Imply<X, Y>>,
+    _nf: NotFreeIn<A, F>,
+) -> DefEq<Lam<Ty<A, X>, App<F, A>>, F> {axiom!()}
+/// `(f : x -> y) ⋀ ¬free(a, f)  =>  (\(a : x) = f(a)) ≡ f`.
+///
+/// Eta-conversion for functions.
+pub fn eta_pow<A: Prop, F: Prop, X: Prop, Y: Prop>(
+    _ty_f: Ty<F, Pow<Y, X>>,
+    _nf: NotFreeIn<A, F>,
+) -> DefEq<Lam<Ty<A, X>, App<F, A>>, F> {axiom!()}
+/// `(x : type(n)) ⋀ (a : x)  =>  id{x}(a) ≡ a`.
+pub fn id_def<A: Prop, X: Prop, N: Nat>(
+    _ty_x: Ty<X, Type<N>>,
+    _ty_a: Ty<A, X>
+) -> DefEq<App<Id<X>, A>, A> {axiom!()}
+/// `a[a := b] ≡ b`.
+pub fn subst_trivial<A: Prop, B: Prop>() -> DefEq<Subst<A, A, B>, B> {axiom!()}
+/// `a[b := b] ≡ a`.
+pub fn subst_nop<A: Prop, B: Prop>() -> DefEq<Subst<A, B, B>, A> {axiom!()}
+/// `(a, b)[c := d] ≡ (a[c := d], b[c := d])`.
+pub fn subst_tup<A: Prop, B: Prop, C: Prop, D: Prop>() ->
+    DefEq<Subst<Tup<A, B>, C, D>, Tup<Subst<A, C, D>, Subst<B, C, D>>> {axiom!()}
+/// `f(a)[b := c] ≡ f[b := c](a[b := c])`.
+pub fn subst_app<F: Prop, A: Prop, B: Prop, C: Prop>() ->
+    DefEq<Subst<App<F, A>, B, C>, App<Subst<F, B, C>, Subst<A, B, C>>> {axiom!()}
+/// `(a ⋁ b)[c := d] ≡ (a[c := d] ⋁ b[c := d])`.
+pub fn subst_or<A: Prop, B: Prop, C: Prop, D: Prop>() ->
+    DefEq<Subst<Or<A, B>, C, D>, Or<Subst<A, C, D>, Subst<B, C, D>>> {axiom!()}
+/// `(a == b)[c := d] ≡ (a[c := d] == b[c := d])`.
+pub fn subst_eq_prop<A: Prop, B: Prop, C: Prop, D: Prop>() ->
+    DefEq<Subst<Eq<A, B>, C, D>, Eq<Subst<A, C, D>, Subst<B, C, D>>> {axiom!()}
+
+/// `g(f(a)) ≡ (g . f)(a)`.
+pub fn comp<F: Prop, G: Prop, X: Prop>() -> DefEq<App<G, App<F, X>>, App<Comp<G, F>, X>> {
+    DefEq(eq_app_comp())
+}
+/// `(a : x)  =>  (\(a : x) = b)(a) ≡ b`.
+pub fn beta_nop<A: Prop, X: Prop, B: Prop>(ty_a: Ty<A, X>) -> DefEq<App<Lam<Ty<A, X>, B>, A>, B> {
+    transitivity(beta(ty_a), subst_nop())
+}
+
+/// `(a == b)  =>  (a ≡ b)`.
+///
+/// Equality reflection.
+#[cfg(feature = "eq_reflect")]
+pub fn eq_reflect<A: Prop, B: Prop>(_: Eq<A, B>) -> DefEq<A, B> {axiom!()}
+/// `(a : b)  =>  (b[c := a] ≡ b)`.
+#[cfg(feature = "eq_reflect")]
+pub fn subst_ty_def<A: Prop, B: Prop, C: Prop>(ty_a: Ty<A, B>) -> DefEq<Subst<B, C, A>, B> {
+    eq_reflect(subst::subst_ty(ty_a))
+}
+/// `is_const(a)  =>  (a[b := c] ≡ a)`.
+#[cfg(feature = "eq_reflect")]
+pub fn subst_const_def<A: Prop, B: Prop, C: Prop>(
+    a_is_const: IsConst<A>
+) -> DefEq<Subst<A, B, C>, A> {eq_reflect(subst::subst_const(a_is_const))}
+/// `(f == g) ⋀ (x == y)  =>  f(x) ≡ g(y)`.
+#[cfg(feature = "eq_reflect")]
+pub fn app_def<F: Prop, G: Prop, X: Prop, Y: Prop>(
+    f: Eq<F, G>,
+    x: Eq<X, Y>
+) -> DefEq<App<F, X>, App<G, Y>> {cong_app(eq_reflect(f), eq_reflect(x))}
+/// `(a : x) ⋀ (b == c)  =>  (\(a : x) = b) ≡ (\(a : x) = c)`.
+#[cfg(feature = "eq_reflect")]
+pub fn lam_def<A: Prop, X: Prop, B: Prop, C: Prop>(
+    ty_a: Ty<A, X>,
+    x: Eq<B, C>
+) -> DefEq<Lam<Ty<A, X>, B>, Lam<Ty<A, X>, C>> {cong_lam(ty_a, eq_reflect(x))}
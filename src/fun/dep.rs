@@ -39,29 +39,29 @@ pub type DepTup<A, X, B, PredP> = Ty<Tup<A, B>, DepTupTy<A, X, PredP>>;
 pub fn dep_fun_pord<A: Prop, B: Prop, X: Prop, Y: Prop>(
     _: POrdProof<A, X>,
     _: Pow<POrdProof<B, Y>, A>
-) -> POrdProof<Pow<B, A>, Pow<Y, X>> {unimplemented!()}
+) -> POrdProof<Pow<B, A>, Pow<Y, X>> {axiom!()}
 /// `(a : x) ⋀ (b : y)^a  =>  b^a : y^x`.
 pub fn dep_fun_ty<A: Prop, B: Prop, X: Prop, Y: Prop>(
     _ty_a: Ty<A, X>,
     _ty_b: Pow<Ty<B, Y>, A>
-) -> Ty<Pow<B, A>, Pow<Y, X>> {unimplemented!()}
+) -> Ty<Pow<B, A>, Pow<Y, X>> {axiom!()}
 /// `(a < x) ⋀ (b < y)^a  =>  (a, b) < (x, y)`.
 pub fn dep_tup_pord<A: Prop, B: Prop, X: Prop, Y: Prop>(
     _: POrdProof<A, X>,
     _: Pow<POrdProof<B, Y>, A>
-) -> POrdProof<Tup<A, B>, Tup<X, Y>> {unimplemented!()}
+) -> POrdProof<Tup<A, B>, Tup<X, Y>> {axiom!()}
 /// `(a : x) ⋀ (b : y)^a  =>  (a, b) : (x, y)`.
 pub fn dep_tup_ty<A: Prop, B: Prop, X: Prop, Y: Prop>(
     _ty_a: Ty<A, X>,
     _ty_b: Pow<Ty<B, Y>, A>
-) -> Ty<Tup<A, B>, Tup<X, Y>> {unimplemented!()}
+) -> Ty<Tup<A, B>, Tup<X, Y>> {axiom!()}
 /// `f(a)^(a : x)  =>  f(b)^(b : x)`.
 pub fn dep_app<F: Prop, X: Prop, A: Prop, B: Prop>(
     _: Pow<App<F, A>, Ty<A, X>>
-) -> Pow<App<F, B>, Ty<B, X>> {unimplemented!()}
+) -> Pow<App<F, B>, Ty<B, X>> {axiom!()}
 /// `(f(a)^a)(b)  =>  f(b)`.
 pub fn dep_fun_app<F: Prop, A: Prop, B: Prop>(_: App<Pow<App<F, A>, A>, B>) -> App<F, B> {
-    unimplemented!()
+    axiom!()
 }
 
 /// `(f : (a : x) -> y(a))  =>  (f : (b : x) -> y(b))`.
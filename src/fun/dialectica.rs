@@ -0,0 +1,102 @@
+//! # Dialectica Interpretation
+//!
+//! *Notice! This module is experimental and in early stages of development.*
+//!
+//! Gödel's Dialectica interpretation assigns to a proposition `a` a relation `a(x, y)`
+//! between witnesses `x` and counters `y` ([Rel]).
+//! A witness `x` is sound when `a(x, y)^true` for every counter `y`.
+//!
+//! The connectives are interpreted as:
+//!
+//! - `(a ⋀ b)((x, u), (y, v)) := a(x, y) ⋀ b(u, v)` ([dand_def])
+//! - `(a => b)((f, g), (x, v)) := a(x, g((x, v))) => b(f(x), v)` ([dimply_def])
+//!
+//! An implication is witnessed by a pair of functions:
+//! `f` maps witnesses of `a` to witnesses of `b`,
+//! while `g` maps counters of `b` back to counters of `a` ([DImplyW], [DImplyC]).
+//!
+//! Soundness of the propositional fragment:
+//!
+//! - Conjunction: [dand_intro], [dand_fst], [dand_snd]
+//! - Implication: [dimply_intro], [dimply_mp]
+//! - Identity `(id, snd)` witnesses `a => a` ([dimply_id])
+
+use super::*;
+
+/// Dialectica conjunction.
+#[derive(Copy, Clone)]
+pub struct FDAnd(());
+/// Dialectica implication.
+#[derive(Copy, Clone)]
+pub struct FDImply(());
+
+/// `a(x, y)`.
+pub type Rel<A, X, Y> = App<A, Tup<X, Y>>;
+/// `a ⋀ b` as a relation.
+pub type DAnd<A, B> = App<FDAnd, Tup<A, B>>;
+/// `a => b` as a relation.
+pub type DImply<A, B> = App<FDImply, Tup<A, B>>;
+
+/// Witness type of `a ⋀ b`.
+pub type DAndW<WA, WB> = Tup<WA, WB>;
+/// Counter type of `a ⋀ b`.
+pub type DAndC<CA, CB> = Tup<CA, CB>;
+/// Witness type of `a => b`.
+pub type DImplyW<WA, CA, WB, CB> = Tup<Pow<WB, WA>, Pow<CA, Tup<WA, CB>>>;
+/// Counter type of `a => b`.
+pub type DImplyC<WA, CB> = Tup<WA, CB>;
+
+/// `is_const(dand)`.
+pub fn dand_is_const() -> IsConst<FDAnd> {axiom!()}
+/// `is_const(dimply)`.
+pub fn dimply_is_const() -> IsConst<FDImply> {axiom!()}
+/// `(a ⋀ b)((x, u), (y, v)) == a(x, y) ⋀ b(u, v)`.
+pub fn dand_def<A: Prop, B: Prop, X: Prop, U: Prop, Y: Prop, V: Prop>() ->
+    Eq<Rel<DAnd<A, B>, Tup<X, U>, Tup<Y, V>>, And<Rel<A, X, Y>, Rel<B, U, V>>> {axiom!()}
+/// `(a => b)((f, g), (x, v)) == (a(x, g((x, v))) => b(f(x), v))`.
+pub fn dimply_def<A: Prop, B: Prop, F: Prop, G: Prop, X: Prop, V: Prop>() -> Eq<
+    Rel<DImply<A, B>, Tup<F, G>, Tup<X, V>>,
+    Imply<Rel<A, X, App<G, Tup<X, V>>>, Rel<B, App<F, X>, V>>
+> {axiom!()}
+
+/// `a(x, y)^true ⋀ b(u, v)^true  =>  (a ⋀ b)((x, u), (y, v))^true`.
+pub fn dand_intro<A: Prop, B: Prop, X: Prop, U: Prop, Y: Prop, V: Prop>(
+    x: Tauto<Rel<A, X, Y>>,
+    y: Tauto<Rel<B, U, V>>
+) -> Tauto<Rel<DAnd<A, B>, Tup<X, U>, Tup<Y, V>>> {
+    hooo::tauto_in_arg(hooo::hooo_rev_and((x, y)), hooo::tauto_eq_symmetry(tauto!(dand_def())))
+}
+/// `(a ⋀ b)((x, u), (y, v))^true  =>  a(x, y)^true`.
+pub fn dand_fst<A: Prop, B: Prop, X: Prop, U: Prop, Y: Prop, V: Prop>(
+    x: Tauto<Rel<DAnd<A, B>, Tup<X, U>, Tup<Y, V>>>
+) -> Tauto<Rel<A, X, Y>> {hooo::hooo_and(hooo::tauto_in_arg(x, tauto!(dand_def()))).0}
+/// `(a ⋀ b)((x, u), (y, v))^true  =>  b(u, v)^true`.
+pub fn dand_snd<A: Prop, B: Prop, X: Prop, U: Prop, Y: Prop, V: Prop>(
+    x: Tauto<Rel<DAnd<A, B>, Tup<X, U>, Tup<Y, V>>>
+) -> Tauto<Rel<B, U, V>> {hooo::hooo_and(hooo::tauto_in_arg(x, tauto!(dand_def()))).1}
+/// `b(f(x), v)^a(x, g((x, v)))  =>  (a => b)((f, g), (x, v))^true`.
+pub fn dimply_intro<A: Prop, B: Prop, F: Prop, G: Prop, X: Prop, V: Prop>(
+    x: Pow<Rel<B, App<F, X>, V>, Rel<A, X, App<G, Tup<X, V>>>>
+) -> Tauto<Rel<DImply<A, B>, Tup<F, G>, Tup<X, V>>> {
+    hooo::tauto_in_arg(hooo::pow_to_imply_lift(x), hooo::tauto_eq_symmetry(tauto!(dimply_def())))
+}
+/// `(a => b)((f, g), (x, v))^true ⋀ a(x, g((x, v)))^true  =>  b(f(x), v)^true`.
+pub fn dimply_mp<A: Prop, B: Prop, F: Prop, G: Prop, X: Prop, V: Prop>(
+    f: Tauto<Rel<DImply<A, B>, Tup<F, G>, Tup<X, V>>>,
+    x: Tauto<Rel<A, X, App<G, Tup<X, V>>>>
+) -> Tauto<Rel<B, App<F, X>, V>> {hooo::hooo_imply(hooo::tauto_in_arg(f, tauto!(dimply_def())))(x)}
+/// `(t : type(n))^true ⋀ (x : t)^true  =>  (a => a)((id{t}, snd), (x, v))^true`.
+pub fn dimply_id<A: Prop, T: Prop, N: Nat, X: Prop, V: Prop>(
+    ty_t: Tauto<Ty<T, Type<N>>>,
+    ty_x: Tauto<Ty<X, T>>
+) -> Tauto<Rel<DImply<A, A>, Tup<Id<T>, Snd>, Tup<X, V>>> {
+    fn f<A: Prop, T: Prop, N: Nat, X: Prop, V: Prop>(
+        (ty_t, ty_x): And<Ty<T, Type<N>>, Ty<X, T>>
+    ) -> Eq<Rel<A, X, V>, Rel<A, App<Id<T>, X>, V>> {
+        app_eq(tup_eq_fst(eq::symmetry(id_def(ty_t, ty_x))))
+    }
+    let x: Tauto<Eq<Rel<A, X, App<Snd, Tup<X, V>>>, Rel<A, X, V>>> =
+        tauto!(app_eq(tup_eq_snd(snd_def())));
+    let x = hooo::tauto_eq_transitivity(x, hooo::hooo_rev_and((ty_t, ty_x)).trans(f));
+    hooo::tauto_in_arg(x.trans(and::fst), hooo::tauto_eq_symmetry(tauto!(dimply_def())))
+}
@@ -1,3 +1,12 @@
+//! Duplicate function.
+//!
+//! Together with `fst` ([Fst]) and `snd` ([Snd]), `dup` forms a comonoid:
+//!
+//! - Counit laws ([fst_dup], [snd_dup])
+//! - Coassociativity ([dup_coassoc])
+//! - Cocommutativity ([swap_dup])
+//! - Naturality ([dup_nat])
+
 use super::*;
 
 /// Duplicate function.
@@ -7,11 +16,60 @@ pub struct Dup(());
 /// `dup : a -> (a, a)`.
 ///
 /// Type of Dup.
-pub fn dup_ty<A: Prop>() -> Ty<Dup, Pow<Tup<A, A>, A>> {unimplemented!()}
+pub fn dup_ty<A: Prop>() -> Ty<Dup, Pow<Tup<A, A>, A>> {axiom!()}
 /// `is_const(dup)`.
-pub fn dup_is_const() -> IsConst<Dup> {unimplemented!()}
+pub fn dup_is_const() -> IsConst<Dup> {axiom!()}
 
 /// `dup(a) = (a, a)`.
 ///
 /// Definition of Dup function.
-pub fn dup_def<A: Prop>() -> Eq<App<Dup, A>, Tup<A, A>> {unimplemented!()}
+pub fn dup_def<A: Prop>() -> Eq<App<Dup, A>, Tup<A, A>> {axiom!()}
+/// `fst . dup == id{a}`.
+///
+/// Left counit law.
+pub fn fst_dup<A: Prop>() -> Eq<Comp<Fst, Dup>, App<FId, A>> {axiom!()}
+/// `snd . dup == id{a}`.
+///
+/// Right counit law.
+pub fn snd_dup<A: Prop>() -> Eq<Comp<Snd, Dup>, App<FId, A>> {axiom!()}
+/// `(id{a} x dup) . dup  ==  assoc_r . ((dup x id{a}) . dup)`.
+///
+/// Coassociativity.
+pub fn dup_coassoc<A: Prop>() -> Eq<Comp<Par<App<FId, A>, Dup>, Dup>,
+    Comp<AssocR, Comp<Par<Dup, App<FId, A>>, Dup>>> {axiom!()}
+/// `swap . dup == dup`.
+///
+/// Cocommutativity.
+pub fn swap_dup() -> Eq<Comp<Swap, Dup>, Dup> {axiom!()}
+/// `(f : a -> b)  =>  (f x f) . dup == dup . f`.
+///
+/// Naturality.
+pub fn dup_nat<F: Prop, A: Prop, B: Prop>(_ty_f: Ty<F, Pow<B, A>>) ->
+    Eq<Comp<Par<F, F>, Dup>, Comp<Dup, F>> {axiom!()}
+
+/// `fst(dup(a)) == a`.
+pub fn fst_dup_def<A: Prop>() -> Eq<App<Fst, App<Dup, A>>, A> {
+    eq::transitivity(app_eq(dup_def()), fst_def())
+}
+/// `snd(dup(a)) == a`.
+pub fn snd_dup_def<A: Prop>() -> Eq<App<Snd, App<Dup, A>>, A> {
+    eq::transitivity(app_eq(dup_def()), snd_def())
+}
+/// `swap(dup(a)) == dup(a)`.
+pub fn swap_dup_def<A: Prop>() -> Eq<App<Swap, App<Dup, A>>, App<Dup, A>> {
+    eq::transitivity(eq::transitivity(app_eq(dup_def()), swap_def()), eq::symmetry(dup_def()))
+}
+/// `(f x f)(dup(a)) == dup(f(a))`.
+pub fn dup_nat_def<F: Prop, A: Prop>() -> Eq<App<Par<F, F>, App<Dup, A>>, App<Dup, App<F, A>>> {
+    let x = eq::transitivity(app_eq(dup_def()), par_tup_def(eq::refl(), eq::refl()));
+    eq::transitivity(x, eq::symmetry(dup_def()))
+}
+/// `(a : type(n))  =>  (dup x id{a}) . dup  ==  assoc_l . ((id{a} x dup) . dup)`.
+pub fn dup_coassoc_l<A: Prop, N: Nat>(ty_a: Ty<A, Type<N>>) ->
+    Eq<Comp<Par<Dup, App<FId, A>>, Dup>, Comp<AssocL, Comp<Par<App<FId, A>, Dup>, Dup>>>
+{
+    let ty_d = comp_ty(dup_ty(), par_tup_fun_ty(dup_ty(), id_ty(ty_a)));
+    let x = eq::transitivity(comp_eq_right(dup_coassoc()), comp_assoc());
+    let x = eq::transitivity(x, comp_eq_left(eq_assoc_l_assoc_r::<A, A, A>()));
+    eq::symmetry(eq::transitivity(x, comp_id_left(ty_d)))
+}
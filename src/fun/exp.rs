@@ -0,0 +1,118 @@
+//! # Exponential Objects
+//!
+//! The function type `x -> y` (`Pow<Y, X>`) is the internal hom of the function-symbol
+//! category, with evaluation `eval : (y^x, x) -> y` ([Eval]) and currying
+//! `curry(f) : z -> y^x` for `f : (z, x) -> y` ([Curry]):
+//!
+//! - Evaluation: `eval((f, a)) == f(a)` ([eval_def])
+//! - Beta: `eval . (curry(f) x id{x}) == f` ([curry_beta])
+//! - Eta: `(eval . (g x id{x}) == f)  =>  g == curry(f)` ([curry_uniq])
+//! - Currying bijection between hom-sets: `((z, x) -> y) ~~ (z -> y^x)` ([curry_q])
+//!
+//! Currying is natural in `z` ([curry_nat_left]) and in `y` ([curry_nat_right]),
+//! where postcomposition `post(k) : y^x -> w^x` ([Post]) is the action of `k : y -> w`
+//! on the exponential.
+//!
+//! At the level of propositions, the same laws hold for implication
+//! ([imply::exportation]) and for exponential propositions in one direction
+//! ([hooo::pow_lower]), since a function pointer can not capture its argument.
+
+use super::*;
+
+/// Evaluation map.
+#[derive(Copy, Clone)]
+pub struct Eval(());
+
+/// Curry function symbol.
+#[derive(Copy, Clone)]
+pub struct FCurry(());
+
+/// `curry(f)`.
+pub type Curry<F> = App<FCurry, F>;
+
+/// Postcomposition function symbol.
+#[derive(Copy, Clone)]
+pub struct FPost(());
+
+/// `post(k)`.
+pub type Post<K> = App<FPost, K>;
+
+/// `eval : (y^x, x) -> y`.
+pub fn eval_ty<X: Prop, Y: Prop>() -> Ty<Eval, Pow<Y, Tup<Pow<Y, X>, X>>> {axiom!()}
+/// `is_const(eval)`.
+pub fn eval_is_const() -> IsConst<Eval> {axiom!()}
+/// `is_const(curry)`.
+pub fn curry_is_const() -> IsConst<FCurry> {axiom!()}
+/// `is_const(post)`.
+pub fn post_is_const() -> IsConst<FPost> {axiom!()}
+/// `eval((f, a)) == f(a)`.
+pub fn eval_def<F: Prop, A: Prop>() -> Eq<App<Eval, Tup<F, A>>, App<F, A>> {axiom!()}
+/// `(f : (z, x) -> y)  =>  curry(f) : z -> y^x`.
+pub fn curry_ty<F: Prop, X: Prop, Y: Prop, Z: Prop>(
+    _ty_f: Ty<F, Pow<Y, Tup<Z, X>>>
+) -> Ty<Curry<F>, Pow<Pow<Y, X>, Z>> {axiom!()}
+/// `(f : (z, x) -> y)  =>  eval . (curry(f) x id{x}) == f`.
+pub fn curry_beta<F: Prop, X: Prop, Y: Prop, Z: Prop>(
+    _ty_f: Ty<F, Pow<Y, Tup<Z, X>>>
+) -> Eq<Comp<Eval, Par<Curry<F>, Id<X>>>, F> {axiom!()}
+/// `(g : z -> y^x) ⋀ (eval . (g x id{x}) == f)  =>  g == curry(f)`.
+pub fn curry_uniq<F: Prop, G: Prop, X: Prop, Y: Prop, Z: Prop>(
+    _ty_g: Ty<G, Pow<Pow<Y, X>, Z>>,
+    _: Eq<Comp<Eval, Par<G, Id<X>>>, F>
+) -> Eq<G, Curry<F>> {axiom!()}
+/// `((z, x) -> y) ~~ (z -> y^x)`.
+pub fn curry_q<X: Prop, Y: Prop, Z: Prop>() -> Q<Pow<Y, Tup<Z, X>>, Pow<Pow<Y, X>, Z>> {axiom!()}
+/// `(k : y -> w)  =>  post(k) : y^x -> w^x`.
+pub fn post_ty<K: Prop, X: Prop, Y: Prop, W: Prop>(
+    _ty_k: Ty<K, Pow<W, Y>>
+) -> Ty<Post<K>, Pow<Pow<W, X>, Pow<Y, X>>> {axiom!()}
+/// `(k : y -> w)  =>  eval . (post(k) x id{x}) == k . eval`.
+pub fn post_eval<K: Prop, X: Prop, Y: Prop, W: Prop>(
+    _ty_k: Ty<K, Pow<W, Y>>
+) -> Eq<Comp<Eval, Par<Post<K>, Id<X>>>, Comp<K, Eval>> {axiom!()}
+
+/// `((z, x) -> y) == (z -> y^x)`.
+pub fn curry_eq<X: Prop, Y: Prop, Z: Prop>() -> Eq<Pow<Y, Tup<Z, X>>, Pow<Pow<Y, X>, Z>> {
+    quality::to_eq(curry_q())
+}
+/// `(x : type(n)) ⋀ (f : (z, x) -> y) ⋀ (a : x)  =>  curry(f)(c)(a) == f((c, a))`.
+pub fn curry_def<F: Prop, X: Prop, Y: Prop, Z: Prop, C: Prop, A: Prop, N: Nat>(
+    ty_x: Ty<X, Type<N>>,
+    ty_f: Ty<F, Pow<Y, Tup<Z, X>>>,
+    ty_a: Ty<A, X>
+) -> Eq<App<App<Curry<F>, C>, A>, App<F, Tup<C, A>>> {
+    let x = app_eq::<Eval, _, _>(par_tup_def(eq::refl(), id_def(ty_x, ty_a)));
+    let x = eq::transitivity(x, eval_def());
+    let y = eq::transitivity(eq_app_comp(), app_map_eq(curry_beta(ty_f)));
+    eq::transitivity(eq::symmetry(x), y)
+}
+/// `(x : type(n)) ⋀ (f : (z, x) -> y) ⋀ (h : w -> z)  =>
+/// curry(f . (h x id{x})) == curry(f) . h`.
+pub fn curry_nat_left<F: Prop, H: Prop, X: Prop, Y: Prop, Z: Prop, W: Prop, N: Nat>(
+    ty_x: Ty<X, Type<N>>,
+    ty_f: Ty<F, Pow<Y, Tup<Z, X>>>,
+    ty_h: Ty<H, Pow<Z, W>>
+) -> Eq<Curry<Comp<F, Par<H, Id<X>>>>, Comp<Curry<F>, H>> {
+    // `(curry(f) x id{x}) . (h x id{x}) == (curry(f) . h) x id{x}`.
+    let p = eq::transitivity(par_tup_comp(), app_eq(tup_eq_snd(comp_id_left(id_ty(ty_x)))));
+    let e = comp_eq_right::<Eval, _, _>(eq::symmetry(p));
+    let e = eq::transitivity(eq::transitivity(e, comp_assoc()),
+        comp_eq_left(curry_beta(ty_f.clone())));
+    eq::symmetry(curry_uniq(comp_ty(ty_h, curry_ty(ty_f)), e))
+}
+/// `(x : type(n)) ⋀ (f : (z, x) -> y) ⋀ (k : y -> w)  =>
+/// curry(k . f) == post(k) . curry(f)`.
+pub fn curry_nat_right<F: Prop, K: Prop, X: Prop, Y: Prop, Z: Prop, W: Prop, N: Nat>(
+    ty_x: Ty<X, Type<N>>,
+    ty_f: Ty<F, Pow<Y, Tup<Z, X>>>,
+    ty_k: Ty<K, Pow<W, Y>>
+) -> Eq<Curry<Comp<K, F>>, Comp<Post<K>, Curry<F>>> {
+    // `(post(k) x id{x}) . (curry(f) x id{x}) == (post(k) . curry(f)) x id{x}`.
+    let p = eq::transitivity(par_tup_comp(), app_eq(tup_eq_snd(comp_id_left(id_ty(ty_x)))));
+    let e = comp_eq_right::<Eval, _, _>(eq::symmetry(p));
+    let e = eq::transitivity(eq::transitivity(e, comp_assoc()),
+        comp_eq_left(post_eval(ty_k.clone())));
+    let e = eq::transitivity(eq::transitivity(e, eq::symmetry(comp_assoc())),
+        comp_eq_right(curry_beta(ty_f.clone())));
+    eq::symmetry(curry_uniq(comp_ty(curry_ty(ty_f), post_ty(ty_k)), e))
+}
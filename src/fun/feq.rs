@@ -14,29 +14,29 @@ pub type Equal<X, A, B> = App<App<FEq, X>, Tup<A, B>>;
 
 /// `t : type(0)  =>  eq{t} : t x t -> bool`.
 pub fn equal_ty<T: Prop>(_ty_t: Ty<T, Type<Z>>) -> Ty<App<FEq, T>, Pow<Bool, Tup<T, T>>> {
-    unimplemented!()
+    axiom!()
 }
 /// `is_const(eq)`.
-pub fn implicit_equal_is_const() -> IsConst<FEq> {unimplemented!()}
+pub fn implicit_equal_is_const() -> IsConst<FEq> {axiom!()}
 /// `is_const(eq{t})`.
-pub fn equal_is_const<T: Prop>(_: IsConst<T>) -> IsConst<App<FEq, T>> {unimplemented!()}
+pub fn equal_is_const<T: Prop>(_: IsConst<T>) -> IsConst<App<FEq, T>> {axiom!()}
 /// `a : x  =>  eq{x}(a, a) = tr`.
-pub fn equal_refl<X: Prop, A: Prop>(_ty_a: Ty<A, X>) -> Eq<Equal<X, A, A>, Tr> {unimplemented!()}
+pub fn equal_refl<X: Prop, A: Prop>(_ty_a: Ty<A, X>) -> Eq<Equal<X, A, A>, Tr> {axiom!()}
 /// `eq{x}(a, b) = tr  =>  eq{x}(b, a) = tr`.
 pub fn equal_symmetry<X: Prop, A: Prop, B: Prop>(_: Equal<X, A, B>) -> Equal<X, B, A> {
-    unimplemented!()
+    axiom!()
 }
 /// `(eq{x}(a, b) = tr) ⋀ (eq{x}(b, c) = tr)  =>  (eq{x}(a, c) = tr)`.
 pub fn equal_transitivity<X: Prop, A: Prop, B: Prop, C: Prop>(
     _: Equal<X, A, B>,
     _: Equal<X, B, C>
-) -> Equal<X, A, C> {unimplemented!()}
+) -> Equal<X, A, C> {axiom!()}
 /// `(a : x) ⋀ (b : x) ⋀ false^(a == b)  =>  eq{x}(a, b) = fa`.
 pub fn equal_from_para_eq<X: Prop, A: Prop, B: Prop>(
     _: Ty<A, X>,
     _: Ty<B, X>,
     _: Para<Eq<A, B>>
-) -> Eq<Equal<X, A, B>, Fa> {unimplemented!()}
+) -> Eq<Equal<X, A, B>, Fa> {axiom!()}
 
 /// `(a : x) ⋀ (a == b)  =>  eq{x}(a, b) = tr`.
 pub fn equal_lift<X: Prop, A: Prop, B: Prop>(
@@ -0,0 +1,79 @@
+//! # Finite State Machines
+//!
+//! A machine is a transition function symbol `t : s -> s` over some state type `s`.
+//! A state `s` is reachable from an initial state `i` when `reach(t, i, s)` ([Reach]).
+//!
+//! Reachability is the least relation closed under [reach_init] and [reach_step],
+//! which gives an induction principle ([reach_ind]).
+//!
+//! The usual way to verify a machine is to find an invariant `p`:
+//!
+//! - `p(i)` holds for the initial state
+//! - `p(t(s))^p(s)` is preserved by every step ([Invariant])
+//!
+//! Then `p` holds for every reachable state ([invariant]).
+//! A safety property `q` follows when `q` is implied by the invariant ([safety]).
+
+use super::*;
+use hooo::{pow_transitivity, Pow};
+
+/// Reachability.
+#[derive(Copy, Clone)]
+pub struct FReach(());
+
+/// `reach(t, i, s)`.
+pub type Reach<T, I, S> = App<App<FReach, T>, Tup<I, S>>;
+
+/// `p(t(s))^p(s)`.
+///
+/// The proposition `p` is preserved by the transition `t`.
+pub type Invariant<T, P, S> = Pow<App<P, App<T, S>>, App<P, S>>;
+
+/// `is_const(reach)`.
+pub fn reach_is_const() -> IsConst<FReach> {axiom!()}
+/// `reach(t, i, i)`.
+pub fn reach_init<T: Prop, I: Prop>() -> Reach<T, I, I> {axiom!()}
+/// `reach(t, i, s)  =>  reach(t, i, t(s))`.
+pub fn reach_step<T: Prop, I: Prop, S: Prop>(_: Reach<T, I, S>) -> Reach<T, I, App<T, S>> {
+    axiom!()
+}
+/// `p(i) ⋀ (p(t(s))^(reach(t, i, s) ⋀ p(s)))  =>  p(u)^reach(t, i, u)`.
+///
+/// Induction principle of reachability.
+pub fn reach_ind<T: Prop, I: Prop, P: Prop, S: VProp, U: Prop>(
+    _init: App<P, I>,
+    _step: Pow<App<P, App<T, S>>, And<Reach<T, I, S>, App<P, S>>>,
+) -> Pow<App<P, U>, Reach<T, I, U>> {axiom!()}
+
+/// `reach(t, i, s) ⋀ (s == u)  =>  reach(t, i, u)`.
+pub fn reach_in_arg<T: Prop, I: Prop, S: Prop, U: Prop>(
+    x: Reach<T, I, S>,
+    y: Eq<S, U>
+) -> Reach<T, I, U> {app_eq(tup_eq_snd(y)).0(x)}
+/// `reach(t, i, t(i))`.
+pub fn reach_one<T: Prop, I: Prop>() -> Reach<T, I, App<T, I>> {reach_step(reach_init())}
+/// `p(i) ⋀ (p(t(s))^p(s))  =>  p(u)^reach(t, i, u)`.
+///
+/// Invariant preservation.
+pub fn invariant<T: Prop, I: Prop, P: Prop, S: VProp, U: Prop>(
+    init: App<P, I>,
+    inv: Invariant<T, P, S>,
+) -> Pow<App<P, U>, Reach<T, I, U>> {
+    reach_ind::<T, I, P, S, U>(init, pow_transitivity(and::snd, inv))
+}
+/// `p(i) ⋀ (p(t(s))^p(s)) ⋀ (q^p(u))  =>  q^reach(t, i, u)`.
+///
+/// Safety theorem.
+pub fn safety<T: Prop, I: Prop, P: Prop, Q: Prop, S: VProp, U: Prop>(
+    init: App<P, I>,
+    inv: Invariant<T, P, S>,
+    safe: Pow<Q, App<P, U>>,
+) -> Pow<Q, Reach<T, I, U>> {
+    pow_transitivity(invariant::<T, I, P, S, U>(init, inv), safe)
+}
+/// `p(i) ⋀ (p(t(s))^p(s)) ⋀ reach(t, i, u)  =>  p(u)`.
+pub fn invariant_at<T: Prop, I: Prop, P: Prop, S: VProp, U: Prop>(
+    init: App<P, I>,
+    inv: Invariant<T, P, S>,
+    reach: Reach<T, I, U>,
+) -> App<P, U> {invariant::<T, I, P, S, U>(init, inv)(reach)}
@@ -26,6 +26,9 @@
 //! tautology transforms with inverses, together with the proof space of inverses.
 //! With other words, it leverages PSI to say that any proof of `x -> y` is identical to having a
 //! proof of `y -> x` when there exists an inverse and a proof `f : x -> y`.
+//!
+//! When `f` and `g` are eta-convertible (`lam_eta_pow`),
+//! function extensionality follows from equality of lambdas (`eta_fun_ext`).
 
 use super::*;
 
@@ -51,9 +54,9 @@ pub type FunExt<F, G> = App<FFunExt, Tup<F, G>>;
 /// Type of function extensionality.
 pub fn fun_ext_ty<F: Prop, G: Prop, X: Prop, Y: Prop, A: Prop>() ->
     Ty<FunExt<F, G>, Pow<FunExtTy<F, G, X, Y, A>, Tauto<Eq<F, G>>>>
-{unimplemented!()}
+{axiom!()}
 /// `~inv(fun_ext(f, g))`.
-pub fn qu_inv_fun_ext<F: Prop, G: Prop>() -> Qu<Inv<FunExt<F, G>>> {unimplemented!()}
+pub fn qu_inv_fun_ext<F: Prop, G: Prop>() -> Qu<Inv<FunExt<F, G>>> {axiom!()}
 
 /// `(a : x) ⋀ (f == g)  =>  ((\(a : x) = (f(a) == g(a))) . (snd . snd))((f, g, a))`.
 pub fn fun_ext_app_eq_from_eq<F: Prop, G: Prop, A: Prop, X: Prop>(
@@ -62,10 +65,14 @@ pub fn fun_ext_app_eq_from_eq<F: Prop, G: Prop, A: Prop, X: Prop>(
 ) -> App<FunExtAppEq<F, G, A, X>, Tup3<F, G, A>> {
     let x = app_map_eq(comp_eq_left(lam_eq_lift(ty_a.clone(),
         (True.map_any(), app_map_eq(eq).map_any()))));
-    let x = eq::transitivity(x, eq::symmetry(eq_app_comp()));
-    let x = eq::transitivity(x, app_eq(eq::symmetry(eq_app_comp())));
-    let x = eq::transitivity(eq::transitivity(x, app_eq(app_eq(snd_def()))), app_eq(snd_def()));
-    eq::transitivity(x, eq::transitivity(lam(ty_a), subst_nop())).1(True)
+    eq::EqChain::start(x)
+        .then(eq::symmetry(eq_app_comp()))
+        .then(app_eq(eq::symmetry(eq_app_comp())))
+        .then(app_eq(app_eq(snd_def())))
+        .then(app_eq(snd_def()))
+        .then(lam(ty_a))
+        .then(subst_nop())
+        .build().1(True)
 }
 /// `(f == g)^true => fun_ext_ty(f, g)`.
 pub fn fun_ext<F: Prop, G: Prop, X: Prop, Y: Prop, A: Prop>(
@@ -110,3 +117,14 @@ pub fn fun_ext_transitivity<F: Prop, G: Prop, H: Prop, X: Prop, Y: Prop, A: Prop
     let gh = fun_rev_ext(fun_ext_gh);
     fun_ext(hooo::tauto_eq_transitivity(fg, gh))
 }
+/// `((\(a : x) = f(a)) == f)^true ⋀ ((\(a : x) = g(a)) == g)^true ⋀
+///   ((\(a : x) = f(a)) == (\(a : x) = g(a)))^true  =>  fun_ext_ty(f, g)`.
+pub fn eta_fun_ext<F: Prop, G: Prop, X: Prop, Y: Prop, A: Prop>(
+    eta_f: Tauto<Eq<Lam<Ty<A, X>, App<F, A>>, F>>,
+    eta_g: Tauto<Eq<Lam<Ty<A, X>, App<G, A>>, G>>,
+    x: Tauto<Eq<Lam<Ty<A, X>, App<F, A>>, Lam<Ty<A, X>, App<G, A>>>>,
+) -> FunExtTy<F, G, X, Y, A> {
+    use hooo::{tauto_eq_symmetry, tauto_eq_transitivity};
+
+    fun_ext(tauto_eq_transitivity(tauto_eq_symmetry(eta_f), tauto_eq_transitivity(x, eta_g)))
+}
@@ -0,0 +1,105 @@
+//! # Graphs
+//!
+//! A graph `g` is a symbol with edges `edge(g, a, b)` ([EdgeOf]) between nodes `a` and `b`.
+//!
+//! A path `path(g, a, b)` ([Path]) is the transitive closure of edges:
+//!
+//! - Every edge is a path ([path_edge])
+//! - Paths compose like functions ([path_comp])
+//!
+//! Since the transitive closure is the least relation closed under these rules,
+//! there is an induction principle ([path_ind]).
+//! A path can also be decomposed into its last edge ([path_decompose]).
+//!
+//! Reachability `reach(g, a, b)` ([Reachable]) is the reflexive transitive closure.
+
+use super::*;
+use hooo::{pow_transitivity, Exists, Pow};
+
+/// Edge relation.
+#[derive(Copy, Clone)]
+pub struct FEdge(());
+
+/// `edge(g, a, b)`.
+pub type EdgeOf<G, A, B> = App<App<FEdge, G>, Tup<A, B>>;
+
+/// Path relation.
+#[derive(Copy, Clone)]
+pub struct FPath(());
+
+/// `path(g, a, b)`.
+pub type Path<G, A, B> = App<App<FPath, G>, Tup<A, B>>;
+
+/// `reach(g, a, b) := (a == b) ⋁ path(g, a, b)`.
+pub type Reachable<G, A, B> = Or<Eq<A, B>, Path<G, A, B>>;
+
+/// `is_const(edge)`.
+pub fn edge_is_const() -> IsConst<FEdge> {axiom!()}
+/// `is_const(path)`.
+pub fn path_is_const() -> IsConst<FPath> {axiom!()}
+/// `edge(g, a, b)  =>  path(g, a, b)`.
+pub fn path_edge<G: Prop, A: Prop, B: Prop>(_: EdgeOf<G, A, B>) -> Path<G, A, B> {axiom!()}
+/// `path(g, a, b) ⋀ path(g, b, c)  =>  path(g, a, c)`.
+pub fn path_comp<G: Prop, A: Prop, B: Prop, C: Prop>(
+    _: Path<G, A, B>,
+    _: Path<G, B, C>
+) -> Path<G, A, C> {axiom!()}
+/// `(p(a, b)^edge(g, a, b)) ⋀ (p(a, c)^(p(a, b) ⋀ p(b, c)))  =>  p(x, y)^path(g, x, y)`.
+///
+/// Induction principle of paths.
+pub fn path_ind<G: Prop, P: Prop, A: VProp, B: VProp, C: VProp, X: Prop, Y: Prop>(
+    _edge: Pow<App<P, Tup<A, B>>, EdgeOf<G, A, B>>,
+    _comp: Pow<App<P, Tup<A, C>>, And<App<P, Tup<A, B>>, App<P, Tup<B, C>>>>,
+) -> Pow<App<P, Tup<X, Y>>, Path<G, X, Y>> {axiom!()}
+/// `path(g, a, c)  =>  edge(g, a, c) ⋁ ∃ b : path(g, a, b) { edge(g, b, c) }`.
+///
+/// Decomposition of a path into its last edge.
+pub fn path_decompose<G: Prop, A: Prop, B: VProp, C: Prop>(
+    _: Path<G, A, C>
+) -> Or<EdgeOf<G, A, C>, Exists<Path<G, A, B>, EdgeOf<G, B, C>>> {axiom!()}
+
+/// `path(g, a, b) ⋀ edge(g, b, c)  =>  path(g, a, c)`.
+pub fn path_snoc<G: Prop, A: Prop, B: Prop, C: Prop>(
+    x: Path<G, A, B>,
+    y: EdgeOf<G, B, C>
+) -> Path<G, A, C> {path_comp(x, path_edge(y))}
+/// `edge(g, a, b) ⋀ path(g, b, c)  =>  path(g, a, c)`.
+pub fn path_cons<G: Prop, A: Prop, B: Prop, C: Prop>(
+    x: EdgeOf<G, A, B>,
+    y: Path<G, B, C>
+) -> Path<G, A, C> {path_comp(path_edge(x), y)}
+/// `(edge(h, a, b)^edge(g, a, b))  =>  path(h, x, y)^path(g, x, y)`.
+///
+/// Paths in a subgraph are paths in the larger graph.
+pub fn path_sub<G: Prop, H: Prop, A: VProp, B: VProp, C: VProp, X: Prop, Y: Prop>(
+    sub: Pow<EdgeOf<H, A, B>, EdgeOf<G, A, B>>
+) -> Pow<Path<H, X, Y>, Path<G, X, Y>> {
+    fn comp<H: Prop, A: Prop, B: Prop, C: Prop>(
+        (ab, bc): And<Path<H, A, B>, Path<H, B, C>>
+    ) -> Path<H, A, C> {path_comp(ab, bc)}
+    path_ind::<G, App<FPath, H>, A, B, C, X, Y>(
+        pow_transitivity(sub, path_edge), comp::<H, A, B, C>)
+}
+
+/// `reach(g, a, a)`.
+pub fn reachable_refl<G: Prop, A: Prop>() -> Reachable<G, A, A> {Left(eq::refl())}
+/// `path(g, a, b)  =>  reach(g, a, b)`.
+pub fn path_to_reachable<G: Prop, A: Prop, B: Prop>(x: Path<G, A, B>) -> Reachable<G, A, B> {
+    Right(x)
+}
+/// `edge(g, a, b)  =>  reach(g, a, b)`.
+pub fn edge_to_reachable<G: Prop, A: Prop, B: Prop>(x: EdgeOf<G, A, B>) -> Reachable<G, A, B> {
+    Right(path_edge(x))
+}
+/// `reach(g, a, b) ⋀ reach(g, b, c)  =>  reach(g, a, c)`.
+pub fn reachable_trans<G: Prop, A: Prop, B: Prop, C: Prop>(
+    x: Reachable<G, A, B>,
+    y: Reachable<G, B, C>
+) -> Reachable<G, A, C> {
+    match (x, y) {
+        (Left(ab), Left(bc)) => Left(eq::transitivity(ab, bc)),
+        (Left(ab), Right(bc)) => Right(app_eq(tup_eq_fst(eq::symmetry(ab))).0(bc)),
+        (Right(ab), Left(bc)) => Right(app_eq(tup_eq_snd(bc)).0(ab)),
+        (Right(ab), Right(bc)) => Right(path_comp(ab, bc)),
+    }
+}
@@ -33,17 +33,17 @@ pub type Id<T> = App<FId, T>;
 ///
 /// Type of Id.
 pub fn id_ty<A: Prop, N: Nat>(_ty_a: Ty<A, Type<N>>) -> Ty<App<FId, A>, Pow<A, A>> {
-    unimplemented!()
+    axiom!()
 }
 /// `is_const(id)`.
-pub fn implicit_id_is_const() -> IsConst<FId> {unimplemented!()}
+pub fn implicit_id_is_const() -> IsConst<FId> {axiom!()}
 /// `(x : type(n)) ⋀ (a : x)  =>  id{x}(a) = a`.
 ///
 /// Definition of identity function.
 pub fn id_def<A: Prop, X: Prop, N: Nat>(
-    _ty_x: Ty<X, Type<N>>,
-    _ty_a: Ty<A, X>
-) -> Eq<App<Id<X>, A>, A> {unimplemented!()}
+    ty_x: Ty<X, Type<N>>,
+    ty_a: Ty<A, X>
+) -> Eq<App<Id<X>, A>, A> {def_eq::to_eq(def_eq::id_def(ty_x, ty_a))}
 
 /// `is_const(a)  =>  is_const(id{a})`.
 pub fn id_is_const<A: Prop>(a_is_const: IsConst<A>) -> IsConst<App<FId, A>> {
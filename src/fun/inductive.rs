@@ -0,0 +1,214 @@
+//! # Inductive Types
+//!
+//! The [define_inductive!](crate::define_inductive) macro declares an inductive type from its constructors.
+//! It generates:
+//!
+//! - The type symbol `t` with `t : type(0)` and `is_const(t)`
+//! - Constructor symbols `c` with typing rules and `is_const(c)`
+//! - An induction principle
+//! - The list of these axioms `T::AXIOMS`, to be added to the audit
+//!   with [axiom::register](crate::axiom::register)
+//!
+//! Recursive arguments of constructors are written `rec`.
+//! Other arguments are written as a single token tree, e.g. `A` or `(Tup<A, B>)`.
+//! The names of arguments are only for documentation,
+//! since the induction principle uses fresh type parameters for them.
+//! There can be at most 32 arguments in total.
+//!
+//! ```rust
+//! use prop::*;
+//! use prop::fun::*;
+//! use prop::path_semantics::Ty;
+//!
+//! define_inductive!{
+//!     /// Binary trees.
+//!     type Tree: tree_ty, tree_is_const;
+//!     /// Induction on binary trees.
+//!     induction tree_ind<X>;
+//!     /// Leaf.
+//!     Leaf(): leaf_ty, leaf_is_const;
+//!     /// Node.
+//!     Node(l: rec, r: rec): node_ty, node_is_const;
+//! }
+//!
+//! fn leaf_node<L: Prop, R: Prop>(
+//!     ty_l: Ty<L, Tree>,
+//!     ty_r: Ty<R, Tree>
+//! ) -> Ty<App<App<Node, L>, R>, Tree> {
+//!     app_fun_ty(app_fun_ty(node_ty(), ty_l), ty_r)
+//! }
+//! ```
+//!
+//! Constructors may use the same argument names:
+//!
+//! ```rust
+//! #![deny(non_camel_case_types)]
+//! use prop::*;
+//! use prop::fun::*;
+//! use prop::hooo::Pow;
+//! use prop::path_semantics::Ty;
+//!
+//! define_inductive!{
+//!     /// Trees with nodes of two or three children.
+//!     type Tree23: tree23_ty, tree23_is_const;
+//!     /// Induction on trees.
+//!     induction tree23_ind<X>;
+//!     /// Leaf.
+//!     Leaf(): leaf_ty, leaf_is_const;
+//!     /// Node with two children.
+//!     Node2(l: rec, r: rec): node2_ty, node2_is_const;
+//!     /// Node with three children.
+//!     Node3(l: rec, m: rec, r: rec): node3_ty, node3_is_const;
+//! }
+//!
+//! fn ind<P: Prop, L: VProp, R: VProp, L3: VProp, M: VProp, R3: VProp, X: Prop>(
+//!     leaf: Pow<App<P, Leaf>, True>,
+//!     node2: Pow<App<P, App<App<Node2, L>, R>>,
+//!         And<And<Ty<L, Tree23>, App<P, L>>, And<And<Ty<R, Tree23>, App<P, R>>, True>>>,
+//!     node3: Pow<App<P, App<App<App<Node3, L3>, M>, R3>>,
+//!         And<And<Ty<L3, Tree23>, App<P, L3>>, And<And<Ty<M, Tree23>, App<P, M>>,
+//!         And<And<Ty<R3, Tree23>, App<P, R3>>, True>>>>,
+//! ) -> Pow<App<P, X>, Ty<X, Tree23>> {
+//!     tree23_ind(leaf, node2, node3)
+//! }
+//! ```
+//!
+//! The induction principle for the first example is:
+//!
+//! ```text
+//! p(leaf)^true ⋀
+//! p(node(l)(r))^((l : tree) ⋀ p(l) ⋀ (r : tree) ⋀ p(r))
+//! -----------------------------------------------------
+//! p(x)^(x : tree)
+//! ```
+
+/// Declares an inductive type with constructors and an induction principle.
+///
+/// See the [inductive](crate::fun::inductive) module for more information.
+#[macro_export]
+macro_rules! define_inductive(
+    (
+        $(#[$attr:meta])*
+        type $name:ident: $name_ty:ident, $name_is_const:ident;
+        $(#[$iattr:meta])*
+        induction $ind:ident<$x:ident>;
+        $(
+            $(#[$cattr:meta])*
+            $ctor:ident($($arg:ident: $arg_ty:tt),*): $ctor_ty:ident, $ctor_is_const:ident;
+        )*
+    ) => {
+        $(#[$attr])*
+        #[derive(Copy, Clone)]
+        pub struct $name(());
+
+        #[doc = concat!("`", stringify!($name), " : type(0)`.")]
+        pub fn $name_ty() ->
+            $crate::path_semantics::Ty<$name, $crate::fun::Type<$crate::nat::Z>> {$crate::axiom!()}
+        #[doc = concat!("`is_const(", stringify!($name), ")`.")]
+        pub fn $name_is_const() -> $crate::fun::IsConst<$name> {$crate::axiom!()}
+
+        $(
+            $(#[$cattr])*
+            #[derive(Copy, Clone)]
+            pub struct $ctor(());
+
+            #[doc = concat!("Type of `", stringify!($ctor), "`.")]
+            pub fn $ctor_ty() -> $crate::path_semantics::Ty<$ctor,
+                $crate::define_inductive!(@ty $name; $($arg_ty),*)> {$crate::axiom!()}
+            #[doc = concat!("`is_const(", stringify!($ctor), ")`.")]
+            pub fn $ctor_is_const() -> $crate::fun::IsConst<$ctor> {$crate::axiom!()}
+        )*
+
+        impl $name {
+            #[doc = concat!("The axioms generated for `", stringify!($name), "`.")]
+            pub const AXIOMS: &'static [$crate::axiom::AxiomInfo] = &[
+                $crate::define_inductive!(@info $name_ty,
+                    concat!("`", stringify!($name), " : type(0)`.")),
+                $crate::define_inductive!(@info $name_is_const,
+                    concat!("`is_const(", stringify!($name), ")`.")),
+                $(
+                    $crate::define_inductive!(@info $ctor_ty,
+                        concat!("Type of `", stringify!($ctor), "`.")),
+                    $crate::define_inductive!(@info $ctor_is_const,
+                        concat!("`is_const(", stringify!($ctor), ")`.")),
+                )*
+                $crate::define_inductive!(@info $ind,
+                    concat!("Induction on `", stringify!($name), "`.")),
+            ];
+        }
+
+        $crate::define_inductive!(@ind {$(#[$iattr])*} $ind, $x, $name;
+            [
+                __A0 __A1 __A2 __A3 __A4 __A5 __A6 __A7 __A8 __A9 __A10 __A11 __A12 __A13
+                __A14 __A15 __A16 __A17 __A18 __A19 __A20 __A21 __A22 __A23 __A24 __A25
+                __A26 __A27 __A28 __A29 __A30 __A31
+            ]; []; $($ctor($($arg_ty),*);)*);
+    };
+    (@ind $h:tt $ind:ident, $x:ident, $name:ident; $pool:tt; $done:tt;
+        $ctor:ident($($arg_ty:tt),*); $($rest:tt)*
+    ) => {
+        $crate::define_inductive!(@args $h $ind, $x, $name; $pool; $done;
+            $ctor; []; [$($arg_ty)*]; $($rest)*);
+    };
+    (@args $h:tt $ind:ident, $x:ident, $name:ident; [$p:ident $($pool:ident)*]; $done:tt;
+        $ctor:ident; [$($acc:tt)*]; [$arg_ty:tt $($tys:tt)*]; $($rest:tt)*
+    ) => {
+        $crate::define_inductive!(@args $h $ind, $x, $name; [$($pool)*]; $done;
+            $ctor; [$($acc)* ($p: $arg_ty)]; [$($tys)*]; $($rest)*);
+    };
+    (@args $h:tt $ind:ident, $x:ident, $name:ident; []; $done:tt;
+        $ctor:ident; $acc:tt; [$arg_ty:tt $($tys:tt)*]; $($rest:tt)*
+    ) => {
+        compile_error!("too many constructor arguments in `define_inductive!`, the limit is 32");
+    };
+    (@args $h:tt $ind:ident, $x:ident, $name:ident; $pool:tt; [$($done:tt)*];
+        $ctor:ident; $acc:tt; []; $($rest:tt)*
+    ) => {
+        $crate::define_inductive!(@ind $h $ind, $x, $name; $pool; [$($done)* ($ctor $acc)];
+            $($rest)*);
+    };
+    (@ind {$($iattr:tt)*} $ind:ident, $x:ident, $name:ident; $pool:tt;
+        [$(($ctor:ident [$(($arg:ident: $arg_ty:tt))*]))*];
+    ) => {
+        $($iattr)*
+        pub fn $ind<P: $crate::Prop, $($($arg: $crate::fun::VProp,)*)* $x: $crate::Prop>(
+            $(_: $crate::hooo::Pow<
+                $crate::fun::App<P, $crate::define_inductive!(@app $ctor; $($arg),*)>,
+                $crate::define_inductive!(@hyp $name, P; $($arg: $arg_ty),*)
+            >,)*
+        ) -> $crate::hooo::Pow<$crate::fun::App<P, $x>, $crate::path_semantics::Ty<$x, $name>> {
+            $crate::axiom!()
+        }
+    };
+    (@info $f:ident, $statement:expr) => {
+        $crate::axiom::AxiomInfo {
+            name: concat!(module_path!(), "::", stringify!($f)),
+            statement: $statement,
+            cfg: None,
+        }
+    };
+    (@ty $name:ident;) => {$name};
+    (@ty $name:ident; rec $(, $rest:tt)*) => {
+        $crate::hooo::Pow<$crate::define_inductive!(@ty $name; $($rest),*), $name>
+    };
+    (@ty $name:ident; $arg_ty:tt $(, $rest:tt)*) => {
+        $crate::hooo::Pow<$crate::define_inductive!(@ty $name; $($rest),*), $arg_ty>
+    };
+    (@app $acc:ty;) => {$acc};
+    (@app $acc:ty; $arg:ident $(, $rest:ident)*) => {
+        $crate::define_inductive!(@app $crate::fun::App<$acc, $arg>; $($rest),*)
+    };
+    (@hyp $name:ident, $p:ident;) => {$crate::True};
+    (@hyp $name:ident, $p:ident; $arg:ident: rec $(, $rest:ident: $rest_ty:tt)*) => {
+        $crate::And<
+            $crate::And<$crate::path_semantics::Ty<$arg, $name>, $crate::fun::App<$p, $arg>>,
+            $crate::define_inductive!(@hyp $name, $p; $($rest: $rest_ty),*)
+        >
+    };
+    (@hyp $name:ident, $p:ident; $arg:ident: $arg_ty:tt $(, $rest:ident: $rest_ty:tt)*) => {
+        $crate::And<
+            $crate::path_semantics::Ty<$arg, $arg_ty>,
+            $crate::define_inductive!(@hyp $name, $p; $($rest: $rest_ty),*)
+        >
+    };
+);
@@ -28,7 +28,8 @@
 //! This solves the problem such that axioms can be added,
 //! only for functions that have inverses.
 //!
-//! If a function `f` has no inverse, then it is useful to prove `false^(inv(f) ~~ g)`.
+//! If a function `f` has no inverse, then it is useful to prove `false^(inv(f) ~~ g)`,
+//! e.g. when `inv(f)` is not defined outside the image of `f` (see [fun::partial]).
 //!
 //! ### Split Epic and Split Monic functions
 //!
@@ -37,6 +38,12 @@
 //!
 //! - `~(f . inv(f))` is the same as saying that `f` is split epic (see [SplitEpic] and [split_epic])
 //! - `~(inv(f) . f)` is the same as saying that `f` is split monic (see [SplitMonic] and [split_monic])
+//!
+//! ### Composition
+//!
+//! The inverse of a composition reverses the order, `inv(g . f) == inv(f) . inv(g)`
+//! ([eq_comp_inv]), and inverses compose, `~inv(f) ⋀ ~inv(g)  =>  ~inv(g . f)` ([comp_inv_qu]).
+//! For longer chains, see [inv_comp3], [inv_comp4], [comp3_inv_qu] and [comp4_inv_qu].
 
 use super::*;
 
@@ -45,7 +52,7 @@ use super::*;
 pub struct FInv(());
 
 /// `is_const(inv)`.
-pub fn finv_is_const() -> IsConst<FInv> {unimplemented!()}
+pub fn finv_is_const() -> IsConst<FInv> {axiom!()}
 
 /// `inv(f)`.
 pub type Inv<F> = App<FInv, F>;
@@ -68,20 +75,20 @@ pub type SplitMonic<F> = Qu<Comp<Inv<F>, F>>;
 /// Inverse type `(f : x -> y) => (inv(f) : y -> x)`.
 pub fn inv_ty<F: Prop, X: Prop, Y: Prop>(
     _ty_f: Ty<F, Pow<Y, X>>
-) -> Ty<Inv<F>, Pow<X, Y>> {unimplemented!()}
+) -> Ty<Inv<F>, Pow<X, Y>> {axiom!()}
 /// `~inv(f) ⋀ (f(a) == b)  =>  (inv(f)(b) == a)`.
 ///
 /// Get inverse application of `f` if there exists a proof `~inv(f)`.
 pub fn inv_val_qu<F: Prop, A: Prop, B: Prop>(
     _: Qu<Inv<F>>,
     _: Eq<App<F, A>, B>
-) -> Eq<App<Inv<F>, B>, A> {unimplemented!()}
+) -> Eq<App<Inv<F>, B>, A> {axiom!()}
 /// `inv(inv(f)) => f`.
-pub fn inv_involve<F: Prop>(_: Inv<Inv<F>>) -> F {unimplemented!()}
+pub fn inv_involve<F: Prop>(_: Inv<Inv<F>>) -> F {axiom!()}
 /// `f => inv(inv(f))`.
-pub fn involve_inv<F: Prop>(_: F) -> Inv<Inv<F>> {unimplemented!()}
+pub fn involve_inv<F: Prop>(_: F) -> Inv<Inv<F>> {axiom!()}
 /// `(inv(f) == f)  =>  (inv(f) ~~ f)`.
-pub fn self_inv_to_q<F: Prop>(_: Eq<Inv<F>, F>) -> Q<Inv<F>, F> {unimplemented!()}
+pub fn self_inv_to_q<F: Prop>(_: Eq<Inv<F>, F>) -> Q<Inv<F>, F> {axiom!()}
 /// `theory(f) ⋀ ~inv(f) ⋀ (f : x -> y) ⋀ (x -> y)  =>  f ⋀ inv(f)`.
 ///
 /// This makes it possible to get inverse map for free.
@@ -90,40 +97,40 @@ pub fn path<F: Prop, X: Prop, Y: Prop>(
     _: Qu<Inv<F>>,
     _: Ty<F, Pow<Y, X>>,
     _: Pow<Y, X>
-) -> And<F, Inv<F>> {unimplemented!()}
+) -> And<F, Inv<F>> {axiom!()}
 /// `inv(id{x}) == id{x}`.
-pub fn id_inv<X: Prop>() -> Eq<Inv<App<FId, X>>, App<FId, X>> {unimplemented!()}
+pub fn id_inv<X: Prop>() -> Eq<Inv<App<FId, X>>, App<FId, X>> {axiom!()}
 /// `~(f . inv(f)) ⋀ (f : a -> b) ⋀ (f . inv(f))  =>  id{b}`.
 pub fn comp_right_inv_to_id<F: Prop, A: Prop, B: Prop>(
     _: SplitEpic<F>,
     _: Ty<F, Pow<B, A>>,
     _: Comp<F, Inv<F>>
-) -> App<FId, B> {unimplemented!()}
+) -> App<FId, B> {axiom!()}
 /// `~(f . inv(f)) ⋀ (f : a -> b) ⋀ id{b}  =>  (f . inv(f))`.
 pub fn id_to_comp_right_inv<F: Prop, A: Prop, B: Prop>(
     _: SplitEpic<F>,
     _: Ty<F, Pow<B, A>>,
     _: App<FId, B>
-) -> Comp<F, Inv<F>> {unimplemented!()}
+) -> Comp<F, Inv<F>> {axiom!()}
 /// `~(inv(f) . f) ⋀ (f : a -> b) ⋀ (inv(f) . f)  =>  id{a}`.
 pub fn comp_left_inv_to_id<F: Prop, A: Prop, B: Prop>(
     _: SplitMonic<F>,
     _: Ty<F, Pow<B, A>>,
     _: Comp<Inv<F>, F>
-) -> App<FId, A> {unimplemented!()}
+) -> App<FId, A> {axiom!()}
 /// `~(inv(f) . f) ⋀ (f : a -> b) ⋀ id{a}  =>  (inv(f). f)`.
 pub fn id_to_comp_left_inv<F: Prop, A: Prop, B: Prop>(
     _: SplitMonic<F>,
     _: Ty<F, Pow<B, A>>,
     _: App<FId, A>
-) -> Comp<Inv<F>, F> {unimplemented!()}
+) -> Comp<Inv<F>, F> {axiom!()}
 /// `inv(g . f) => (inv(f) . inv(g))`.
 pub fn comp_rev_inv<F: Prop, G: Prop>(_: Inv<Comp<G, F>>) -> Comp<Inv<F>, Inv<G>> {
-    unimplemented!()
+    axiom!()
 }
 /// `(inv(f) . inv(g)) => inv(g . f)`.
 pub fn comp_inv<F: Prop, G: Prop>(_: Comp<Inv<F>, Inv<G>>) -> Inv<Comp<G, F>> {
-    unimplemented!()
+    axiom!()
 }
 
 /// `is_const(f) => is_const(inv(f))`.
@@ -295,3 +302,30 @@ pub fn comp_inv_qu<F: Prop, G: Prop>(x: Qu<Inv<F>>, y: Qu<Inv<G>>) -> Qu<Inv<Com
 pub fn eq_comp_inv<F: Prop, G: Prop>() -> Eq<Comp<Inv<F>, Inv<G>>, Inv<Comp<G, F>>> {
     (Rc::new(comp_inv), Rc::new(comp_rev_inv))
 }
+/// `inv(h . (g . f))  ==  (inv(f) . inv(g)) . inv(h)`.
+pub fn inv_comp3<F: Prop, G: Prop, H: Prop>() ->
+    Eq<Inv<Comp<H, Comp<G, F>>>, Comp<Comp<Inv<F>, Inv<G>>, Inv<H>>>
+{
+    let x = eq::symmetry(eq_comp_inv::<Comp<G, F>, H>());
+    eq::transitivity(x, comp_eq_left(eq::symmetry(eq_comp_inv())))
+}
+/// `inv(i . (h . (g . f)))  ==  ((inv(f) . inv(g)) . inv(h)) . inv(i)`.
+pub fn inv_comp4<F: Prop, G: Prop, H: Prop, I: Prop>() ->
+    Eq<Inv<Comp<I, Comp<H, Comp<G, F>>>>, Comp<Comp<Comp<Inv<F>, Inv<G>>, Inv<H>>, Inv<I>>>
+{
+    let x = eq::symmetry(eq_comp_inv::<Comp<H, Comp<G, F>>, I>());
+    eq::transitivity(x, comp_eq_left(inv_comp3()))
+}
+/// `~inv(f) ⋀ ~inv(g) ⋀ ~inv(h)  =>  ~inv(h . (g . f))`.
+pub fn comp3_inv_qu<F: Prop, G: Prop, H: Prop>(
+    x: Qu<Inv<F>>,
+    y: Qu<Inv<G>>,
+    z: Qu<Inv<H>>
+) -> Qu<Inv<Comp<H, Comp<G, F>>>> {comp_inv_qu(comp_inv_qu(x, y), z)}
+/// `~inv(f) ⋀ ~inv(g) ⋀ ~inv(h) ⋀ ~inv(i)  =>  ~inv(i . (h . (g . f)))`.
+pub fn comp4_inv_qu<F: Prop, G: Prop, H: Prop, I: Prop>(
+    x: Qu<Inv<F>>,
+    y: Qu<Inv<G>>,
+    z: Qu<Inv<H>>,
+    w: Qu<Inv<I>>
+) -> Qu<Inv<Comp<I, Comp<H, Comp<G, F>>>>> {comp_inv_qu(comp3_inv_qu(x, y, z), w)}
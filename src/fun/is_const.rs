@@ -12,6 +12,15 @@ pub struct IsConst<A>(A);
 /// e.g. when doing a proof by induction.
 pub type IsVar<A> = Not<IsConst<A>>;
 
+/// Implemented by symbols that are constants by structure.
+///
+/// Symbols built from constants by formers such as application, tuples, lambdas
+/// or substitution are constants, so `is_const(a)` can be derived with [const_derive].
+pub trait Const: Prop {
+    /// Constructs a proof that the symbol is a constant.
+    fn is_const() -> IsConst<Self>;
+}
+
 /// Implemented by variable propositions.
 pub trait VProp: Prop {
     /// Constructs a proof that the proposition is variable.
@@ -24,7 +33,7 @@ pub fn const_tup<A: Prop, B: Prop>(a: IsConst<A>, b: IsConst<B>) -> IsConst<Tup<
 }
 /// `is_const((a, b))  =>  is_const(a) ⋀ is_const(b)`.
 pub fn tup_const<A: Prop, B: Prop>(_x: IsConst<Tup<A, B>>) -> And<IsConst<A>, IsConst<B>> {
-    unimplemented!()
+    axiom!()
 }
 /// `(a == b)  =>  (is_const(a) == is_const(b))`.
 ///
@@ -34,22 +43,22 @@ pub fn const_eq<A: Prop, B: Prop>((ab, ba): Eq<A, B>) -> Eq<IsConst<A>, IsConst<
 }
 /// `is_const(a) ⋀ is_const(b)  =>  is_const(a ⋀ b)`.
 pub fn and_is_const<A: Prop, B: Prop>(_a: IsConst<A>, _b: IsConst<B>) -> IsConst<And<A, B>> {
-    unimplemented!()
+    axiom!()
 }
 /// `is_const(a) ⋀ is_const(b)  =>  is_const(a ⋁ b)`.
 pub fn or_is_const<A: Prop, B: Prop>(_a: IsConst<A>, _b: IsConst<B>) -> IsConst<Or<A, B>> {
-    unimplemented!()
+    axiom!()
 }
 /// `is_const(a) ⋀ is_const(b)  =>  is_const(a => b)`.
 pub fn imply_is_const<A: Prop, B: Prop>(_a: IsConst<A>, _b: IsConst<B>) -> IsConst<Imply<A, B>> {
-    unimplemented!()
+    axiom!()
 }
 /// `is_const(a) ⋀ is_const(b)  =>  is_const(pord(a, b))`.
 pub fn pord_is_const<A: Prop, B: Prop>(
     _a: IsConst<A>,
     _b: IsConst<B>
 ) -> IsConst<POrdProof<A, B>> {
-    unimplemented!()
+    axiom!()
 }
 
 /// `is_const(a) ⋀ is_const(b)  =>  is_const(a : b)`.
@@ -61,3 +70,91 @@ pub fn ty_is_const<A: Prop, B: Prop>(a: IsConst<A>, b: IsConst<B>) -> IsConst<Ty
 pub fn const_in_arg<A: Prop, B: Prop>(a: IsConst<A>, x: Eq<A, B>) -> IsConst<B> {
     const_eq(x).0(a)
 }
+/// `is_const(true)`.
+pub fn true_is_const() -> IsConst<True> {axiom!()}
+/// `is_const(false)`.
+pub fn false_is_const() -> IsConst<False> {axiom!()}
+/// `is_const(a) ⋀ is_const(b)  =>  is_const(b^a)`.
+pub fn pow_is_const<A: Prop, B: Prop>(_a: IsConst<A>, _b: IsConst<B>) -> IsConst<Pow<B, A>> {
+    axiom!()
+}
+/// `is_const(e)  =>  is_const(e[a := b])`.
+pub fn subst_is_const<E: Prop, A: Prop, B: Prop>(e: IsConst<E>) -> IsConst<Subst<E, A, B>> {
+    const_in_arg(e.clone(), eq::symmetry(subst_const(e)))
+}
+
+/// `is_const(a)` for any symbol built from constants.
+///
+/// ```rust
+/// use prop::fun::*;
+///
+/// fn f<N: prop::nat::Nat>() -> IsConst<Comp<Inv<Fst>, Lam<Type<N>, Dup>>> {const_derive()}
+/// ```
+pub fn const_derive<A: Const>() -> IsConst<A> {A::is_const()}
+
+impl Const for True {
+    fn is_const() -> IsConst<Self> {true_is_const()}
+}
+impl Const for False {
+    fn is_const() -> IsConst<Self> {false_is_const()}
+}
+impl<N: Nat> Const for Type<N> {
+    fn is_const() -> IsConst<Self> {type_is_const()}
+}
+impl Const for type_omega::TypeOmega {
+    fn is_const() -> IsConst<Self> {type_omega::type_omega_is_const()}
+}
+impl Const for FComp {
+    fn is_const() -> IsConst<Self> {fcomp_is_const()}
+}
+impl Const for FId {
+    fn is_const() -> IsConst<Self> {implicit_id_is_const()}
+}
+impl Const for FInv {
+    fn is_const() -> IsConst<Self> {finv_is_const()}
+}
+impl Const for ParTup {
+    fn is_const() -> IsConst<Self> {par_tup_is_const()}
+}
+impl Const for Dup {
+    fn is_const() -> IsConst<Self> {dup_is_const()}
+}
+impl Const for Fst {
+    fn is_const() -> IsConst<Self> {fst_is_const()}
+}
+impl Const for Snd {
+    fn is_const() -> IsConst<Self> {snd_is_const()}
+}
+impl Const for Swap {
+    fn is_const() -> IsConst<Self> {swap_is_const()}
+}
+impl Const for AssocR {
+    fn is_const() -> IsConst<Self> {assoc_r_is_const()}
+}
+impl<F: Const, X: Const> Const for App<F, X> {
+    fn is_const() -> IsConst<Self> {app_is_const(F::is_const(), X::is_const())}
+}
+impl<A: Const, B: Const> Const for Tup<A, B> {
+    fn is_const() -> IsConst<Self> {tup_is_const(A::is_const(), B::is_const())}
+}
+impl<A: Const, B: Const> Const for And<A, B> {
+    fn is_const() -> IsConst<Self> {and_is_const(A::is_const(), B::is_const())}
+}
+impl<A: Const, B: Const> Const for Or<A, B> {
+    fn is_const() -> IsConst<Self> {or_is_const(A::is_const(), B::is_const())}
+}
+impl<A: Const, B: Const> Const for Imply<A, B> {
+    fn is_const() -> IsConst<Self> {imply_is_const(A::is_const(), B::is_const())}
+}
+impl<A: Const, B: Const> Const for Pow<B, A> {
+    fn is_const() -> IsConst<Self> {pow_is_const(A::is_const(), B::is_const())}
+}
+impl<A: Const, B: Const> Const for POrdProof<A, B> {
+    fn is_const() -> IsConst<Self> {pord_is_const(A::is_const(), B::is_const())}
+}
+impl<X: Const, Y: Const> Const for Lam<X, Y> {
+    fn is_const() -> IsConst<Self> {lam_is_const(X::is_const(), Y::is_const())}
+}
+impl<E: Const, A: Prop, B: Prop> Const for Subst<E, A, B> {
+    fn is_const() -> IsConst<Self> {subst_is_const(E::is_const())}
+}
@@ -0,0 +1,156 @@
+//! # Isomorphisms
+//!
+//! The [Iso] record packages a function `f : a -> b` with its imaginary inverse
+//! (see [inv]) and both round-trip equations:
+//!
+//! - `(inv(f) . f) == id{a}`
+//! - `(f . inv(f)) == id{b}`
+//!
+//! An isomorphism is constructed from split monic and split epic proofs ([Iso::from_split]),
+//! or from `~f ⋀ ~inv(f)` ([Iso::from_qu]).
+//! Isomorphisms have identities ([Iso::id]), inverses ([Iso::inverse])
+//! and compose ([Iso::comp]).
+//!
+//! Properties are transported across an isomorphism:
+//!
+//! - Types: `(x : a)  =>  f(x) : b` ([Iso::transport_ty])
+//! - Constants: `is_const(f) ⋀ is_const(x)  =>  is_const(f(x))` ([Iso::transport_is_const])
+//! - Predicates: `p(x)  =>  p(inv(f)(f(x)))` ([Iso::transport])
+//!
+//! The last one is a light form of equivalence induction:
+//! A predicate that holds for `x : a` holds for the image of `x` in `b`, seen through `inv(f)`.
+
+use super::*;
+
+/// Isomorphism record of `f : a -> b`.
+#[derive(Clone)]
+pub struct Iso<F, A, B> {
+    /// `f : a -> b`.
+    pub ty: Ty<F, Pow<B, A>>,
+    /// `inv(f) : b -> a`.
+    pub inv_ty: Ty<Inv<F>, Pow<A, B>>,
+    /// `(inv(f) . f) == id{a}`.
+    pub left: Eq<Comp<Inv<F>, F>, Id<A>>,
+    /// `(f . inv(f)) == id{b}`.
+    pub right: Eq<Comp<F, Inv<F>>, Id<B>>,
+}
+
+impl<F: Prop, A: Prop, B: Prop> Iso<F, A, B> {
+    /// `(f : a -> b) ⋀ ~(inv(f) . f) ⋀ ~(f . inv(f))  =>  iso(f, a, b)`.
+    pub fn from_split(ty_f: Ty<F, Pow<B, A>>, monic: SplitMonic<F>, epic: SplitEpic<F>) -> Self {
+        Iso {
+            inv_ty: inv_ty(ty_f.clone()),
+            left: eq_comp_left_inv_id(monic, ty_f.clone()),
+            right: eq_comp_right_inv_id(epic, ty_f.clone()),
+            ty: ty_f,
+        }
+    }
+
+    /// `(f : a -> b) ⋀ ~f ⋀ ~inv(f)  =>  iso(f, a, b)`.
+    pub fn from_qu(ty_f: Ty<F, Pow<B, A>>, qu_f: Qu<F>, qu_inv_f: Qu<Inv<F>>) -> Self {
+        Iso::from_split(ty_f, comp_qu(qu_f.clone(), qu_inv_f.clone()), comp_qu(qu_inv_f, qu_f))
+    }
+
+    /// `iso(f, a, b)  =>  iso(inv(f), b, a)`.
+    pub fn inverse(self) -> Iso<Inv<F>, B, A> {
+        Iso {
+            inv_ty: ty::in_left_arg(self.ty, eq::symmetry(involve_eq())),
+            left: eq::transitivity(comp_eq_left(involve_eq()), self.right),
+            right: eq::transitivity(comp_eq_right(involve_eq()), self.left),
+            ty: self.inv_ty,
+        }
+    }
+
+    /// `iso(f, a, b) ⋀ iso(g, b, c)  =>  iso(g . f, a, c)`.
+    pub fn comp<G: Prop, C: Prop>(self, other: Iso<G, B, C>) -> Iso<Comp<G, F>, A, C> {
+        // `(inv(f) . inv(g)) . (g . f) == inv(f) . f`.
+        let left = eq::transitivity(comp_assoc(), comp_eq_left(eq::transitivity(
+            eq::symmetry(comp_assoc()),
+            eq::transitivity(comp_eq_right(other.left), comp_id_right(self.inv_ty.clone()))
+        )));
+        // `(g . f) . (inv(f) . inv(g)) == g . inv(g)`.
+        let right = eq::transitivity(comp_assoc(), comp_eq_left(eq::transitivity(
+            eq::symmetry(comp_assoc()),
+            eq::transitivity(comp_eq_right(self.right), comp_id_right(other.ty.clone()))
+        )));
+        let ty = comp_ty(self.ty, other.ty);
+        Iso {
+            inv_ty: inv_ty(ty.clone()),
+            left: eq::transitivity(comp_eq_left(eq::symmetry(eq_comp_inv())),
+                eq::transitivity(left, self.left)),
+            right: eq::transitivity(comp_eq_right(eq::symmetry(eq_comp_inv())),
+                eq::transitivity(right, other.right)),
+            ty,
+        }
+    }
+
+    /// `(a : type(n)) ⋀ (x : a)  =>  inv(f)(f(x)) == x`.
+    pub fn left_val<X: Prop, N: Nat>(
+        &self,
+        ty_a: Ty<A, Type<N>>,
+        ty_x: Ty<X, A>
+    ) -> Eq<App<Inv<F>, App<F, X>>, X> {
+        let x = eq::transitivity(eq_app_comp(), app_map_eq(self.left.clone()));
+        eq::transitivity(x, id_def(ty_a, ty_x))
+    }
+
+    /// `(b : type(n)) ⋀ (y : b)  =>  f(inv(f)(y)) == y`.
+    pub fn right_val<Y: Prop, N: Nat>(
+        &self,
+        ty_b: Ty<B, Type<N>>,
+        ty_y: Ty<Y, B>
+    ) -> Eq<App<F, App<Inv<F>, Y>>, Y> {
+        let x = eq::transitivity(eq_app_comp(), app_map_eq(self.right.clone()));
+        eq::transitivity(x, id_def(ty_b, ty_y))
+    }
+
+    /// `(x : a)  =>  f(x) : b`.
+    pub fn transport_ty<X: Prop>(&self, ty_x: Ty<X, A>) -> Ty<App<F, X>, B> {
+        app_fun_ty(self.ty.clone(), ty_x)
+    }
+
+    /// `(y : b)  =>  inv(f)(y) : a`.
+    pub fn transport_inv_ty<Y: Prop>(&self, ty_y: Ty<Y, B>) -> Ty<App<Inv<F>, Y>, A> {
+        app_fun_ty(self.inv_ty.clone(), ty_y)
+    }
+
+    /// `is_const(f) ⋀ is_const(x)  =>  is_const(f(x))`.
+    pub fn transport_is_const<X: Prop>(
+        &self,
+        f_is_const: IsConst<F>,
+        x_is_const: IsConst<X>
+    ) -> IsConst<App<F, X>> {app_is_const(f_is_const, x_is_const)}
+
+    /// `is_const(f) ⋀ is_const(y)  =>  is_const(inv(f)(y))`.
+    pub fn transport_inv_is_const<Y: Prop>(
+        &self,
+        f_is_const: IsConst<F>,
+        y_is_const: IsConst<Y>
+    ) -> IsConst<App<Inv<F>, Y>> {app_is_const(inv_is_const(f_is_const), y_is_const)}
+
+    /// `(a : type(n)) ⋀ (x : a) ⋀ p(x)  =>  p(inv(f)(f(x)))`.
+    pub fn transport<P: Prop, X: Prop, N: Nat>(
+        &self,
+        ty_a: Ty<A, Type<N>>,
+        ty_x: Ty<X, A>,
+        p_x: App<P, X>
+    ) -> App<P, App<Inv<F>, App<F, X>>> {app_eq(eq::symmetry(self.left_val(ty_a, ty_x))).0(p_x)}
+
+    /// `(a : type(n)) ⋀ (x : a) ⋀ p(inv(f)(f(x)))  =>  p(x)`.
+    pub fn transport_back<P: Prop, X: Prop, N: Nat>(
+        &self,
+        ty_a: Ty<A, Type<N>>,
+        ty_x: Ty<X, A>,
+        p: App<P, App<Inv<F>, App<F, X>>>
+    ) -> App<P, X> {app_eq(self.left_val(ty_a, ty_x)).0(p)}
+}
+
+impl<A: Prop> Iso<Id<A>, A, A> {
+    /// `(a : type(n))  =>  iso(id{a}, a, a)`.
+    pub fn id<N: Nat>(ty_a: Ty<A, Type<N>>) -> Self {
+        let ty = id_ty(ty_a);
+        let eq_inv_id = eq::transitivity(comp_eq_left(id_inv()), comp_id_left(ty.clone()));
+        let eq_id_inv = eq::transitivity(comp_eq_right(id_inv()), comp_id_left(ty.clone()));
+        Iso {inv_ty: inv_ty(ty.clone()), left: eq_inv_id, right: eq_id_inv, ty}
+    }
+}
@@ -0,0 +1,74 @@
+//! # Kleisli Category
+//!
+//! For a monad `m` with `unit` and `bind` (see [mlaws::IsMonad]),
+//! the Kleisli composition `h <=< k` ([KComp]) composes `k : a -> m(b)` with `h : b -> m(c)`:
+//!
+//! `(h <=< k)(a) == bind(k(a), h)` ([kcomp_def])
+//!
+//! The monad laws make this a category with identity `unit`:
+//!
+//! - `(unit <=< k)(a) == k(a)` ([kcomp_unit_left])
+//! - `(h <=< unit)(a) == h(a)` ([kcomp_unit_right])
+//! - `(g <=< (h <=< k))(a) == ((g <=< h) <=< k)(a)` ([kcomp_assoc])
+//!
+//! Functions of the base category are lifted by `J(f) := unit . f` ([KLift]).
+//! This is a functor, preserving identity ([klift_id]) and composition ([klift_comp]).
+
+use super::*;
+use mlaws::IsMonad;
+
+/// Kleisli composition.
+#[derive(Copy, Clone)]
+pub struct FKComp(());
+
+/// `h <=< k` using `bind`.
+pub type KComp<Bind, H, K> = App<App<FKComp, Bind>, Tup<H, K>>;
+
+/// `J(f) := unit . f`.
+pub type KLift<Unit, F> = Comp<Unit, F>;
+
+/// `is_const(kcomp)`.
+pub fn kcomp_is_const() -> IsConst<FKComp> {axiom!()}
+/// `(h <=< k)(a) == bind(k(a), h)`.
+pub fn kcomp_def<Bind: Prop, H: Prop, K: Prop, A: Prop>() ->
+    Eq<App<KComp<Bind, H, K>, A>, App2<Bind, App<K, A>, H>> {axiom!()}
+
+/// `(unit <=< k)(a) == k(a)`.
+pub fn kcomp_unit_left<W, M: Prop, Unit: Prop, Bind: Prop, K: Prop, A: Prop>() ->
+    Eq<App<KComp<Bind, Unit, K>, A>, App<K, A>>
+    where W: IsMonad<M, Unit, Bind>
+{eq::transitivity(kcomp_def(), W::bind_unit_right())}
+/// `(h <=< unit)(a) == h(a)`.
+pub fn kcomp_unit_right<W, M: Prop, Unit: Prop, Bind: Prop, H: Prop, A: Prop>() ->
+    Eq<App<KComp<Bind, H, Unit>, A>, App<H, A>>
+    where W: IsMonad<M, Unit, Bind>
+{eq::transitivity(kcomp_def(), W::bind_unit_left())}
+/// `(b : t) ⋀ ((g <=< h) : t -> y) ⋀ ¬free(b, g <=< h)  =>
+///   (g <=< (h <=< k))(a) == ((g <=< h) <=< k)(a)`.
+pub fn kcomp_assoc<W, M: Prop, Unit: Prop, Bind: Prop,
+    G: Prop, H: Prop, K: Prop, A: Prop, B: VProp, T: Prop, Y: Prop>(
+    ty_b: Ty<B, T>,
+    ty_gh: Ty<KComp<Bind, G, H>, Pow<Y, T>>,
+    nf: NotFreeIn<B, KComp<Bind, G, H>>,
+) -> Eq<App<KComp<Bind, G, KComp<Bind, H, K>>, A>, App<KComp<Bind, KComp<Bind, G, H>, K>, A>>
+    where W: IsMonad<M, Unit, Bind>
+{
+    let lam: Eq<Lam<Ty<B, T>, App2<Bind, App<H, B>, G>>, KComp<Bind, G, H>> = eq::transitivity(
+        lam_eq_lift(ty_b, eq::symmetry(kcomp_def())), lam_eta_pow(ty_gh, nf));
+    let x = eq::transitivity(kcomp_def(), W::bind_eq_left(kcomp_def()));
+    let x = eq::transitivity(x, W::bind_assoc::<App<K, A>, H, G, B, T>());
+    eq::transitivity(eq::transitivity(x, W::bind_eq_right(lam)), eq::symmetry(kcomp_def()))
+}
+/// `J(id{a}) == unit`.
+pub fn klift_id<W, M: Prop, Unit: Prop, Bind: Prop, A: Prop>() -> Eq<KLift<Unit, Id<A>>, Unit>
+    where W: IsMonad<M, Unit, Bind>
+{comp_id_right(W::unit_ty::<A>())}
+/// `(J(g) <=< J(f))(a) == J(g . f)(a)`.
+pub fn klift_comp<W, M: Prop, Unit: Prop, Bind: Prop, F: Prop, G: Prop, A: Prop>() ->
+    Eq<App<KComp<Bind, KLift<Unit, G>, KLift<Unit, F>>, A>, App<KLift<Unit, Comp<G, F>>, A>>
+    where W: IsMonad<M, Unit, Bind>
+{
+    let x = eq::transitivity(kcomp_def(), W::bind_eq_left(eq::symmetry(eq_app_comp())));
+    let x = eq::transitivity(eq::transitivity(x, W::bind_unit_left()), eq::symmetry(eq_app_comp()));
+    eq::transitivity(eq::transitivity(x, app_eq(eq_app_comp())), eq_app_comp())
+}
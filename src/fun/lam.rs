@@ -1,26 +1,49 @@
+//! # Lambda Expressions
+//!
+//! Lambdas `\(a : x) = b` ([Lam]) with beta reduction by substitution (see [fun::subst]).
+
 use super::*;
 
 /// Lambda.
 #[derive(Copy, Clone)]
 pub struct Lam<X, Y>(X, Y);
 
+/// `is_const(x) ⋀ is_const(y)  =>  is_const(\(x) = y)`.
+pub fn lam_is_const<X: Prop, Y: Prop>(_x: IsConst<X>, _y: IsConst<Y>) -> IsConst<Lam<X, Y>> {
+    axiom!()
+}
+
 /// `(a : x) ⋀ (b : y)  =>  (\(a : x) = b) : (x => y)`.
 pub fn lam_ty<A: Prop, B: Prop, X: Prop, Y: Prop>(
     _ty_a: Ty<A, X>,
     _ty_b: Ty<B, Y>,
-) -> Ty<Lam<Ty<A, X>, B>, Imply<X, Y>> {unimplemented!()}
+) -> Ty<Lam<Ty<A, X>, B>, Imply<X, Y>> {axiom!()}
 /// `(a : x) ⋀ b  =>  (\(a : x) = b)`.
 pub fn lam_lift<A: Prop, B: Prop, X: Prop>(ty_a: Ty<A, X>, b: B) -> Lam<Ty<A, X>, B> {Lam(ty_a, b)}
 /// `(a : x) ⋀ (b == c)  =>  (\(a : x) = b) == (\(a : x) = c)`.
 pub fn lam_eq_lift<A: Prop, X: Prop, B: Prop, C: Prop>(
     _ty_a: Ty<A, X>,
     _eq: Eq<B, C>
-) -> Eq<Lam<Ty<A, X>, B>, Lam<Ty<A, X>, C>> {unimplemented!()}
+) -> Eq<Lam<Ty<A, X>, B>, Lam<Ty<A, X>, C>> {axiom!()}
 /// `(c : x) => ((\(a : x) = b)(c) == b[a := c])`.
 pub fn lam<A: Prop, B: Prop, X: Prop, C: Prop>(
-    _ty_c: Ty<C, X>
-) -> Eq<App<Lam<Ty<A, X>, B>, C>, Subst<B, A, C>> {unimplemented!()}
+    ty_c: Ty<C, X>
+) -> Eq<App<Lam<Ty<A, X>, B>, C>, Subst<B, A, C>> {def_eq::to_eq(def_eq::beta(ty_c))}
 
+/// `(f : x => y) ⋀ ¬free(a, f)  =>  (\(a : x) = f(a)) == f`.
+///
+/// Eta-conversion.
+pub fn lam_eta<A: Prop, F: Prop, X: Prop, Y: Prop>(
+    ty_f: Ty<F, Imply<X, Y>>,
+    nf: NotFreeIn<A, F>,
+) -> Eq<Lam<Ty<A, X>, App<F, A>>, F> {def_eq::to_eq(def_eq::eta(ty_f, nf))}
+/// `(f : x -> y) ⋀ ¬free(a, f)  =>  (\(a : x) = f(a)) == f`.
+///
+/// Eta-conversion for functions.
+pub fn lam_eta_pow<A: Prop, F: Prop, X: Prop, Y: Prop>(
+    ty_f: Ty<F, Pow<Y, X>>,
+    nf: NotFreeIn<A, F>,
+) -> Eq<Lam<Ty<A, X>, App<F, A>>, F> {def_eq::to_eq(def_eq::eta_pow(ty_f, nf))}
 /// `(a : x) ⋀ (b : y) ⋀ (c : x)  =>  ((\(a : x) = b)(c) : y)`.
 pub fn lam_app_ty<A: Prop, B: Prop, X: Prop, Y: Prop, C: Prop>(
     ty_a: Ty<A, X>,
@@ -54,7 +77,7 @@ pub fn lam_app_nop<A: Prop, B: Prop, X: Prop>(ty_a: Ty<A, X>) -> Eq<App<Lam<Ty<A
 pub type LamId<A, X> = Lam<Ty<A, X>, A>;
 
 /// `(\(a : x) = a) == id{a}`.
-pub fn lam_id_eq<A: Prop, X: Prop>() -> Eq<LamId<A, X>, App<FId, X>> {unimplemented!()}
+pub fn lam_id_eq<A: Prop, X: Prop>() -> Eq<LamId<A, X>, App<FId, X>> {axiom!()}
 
 /// `(\(a : x) = a) ~~ id{x}`.
 pub fn lam_id_q<A: Prop, X: Prop>() -> Q<LamId<A, X>, App<FId, X>> {
@@ -118,3 +141,19 @@ pub fn lam_snd<A: Prop, B: Prop, C: Prop, X: Prop, Y: Prop>(
         subst_eq_lam_body(eq::transitivity(subst_eq(subst_const(b_is_const.clone())),
             subst_const(b_is_const)))))
 }
+
+/// `(f : x -> y) ⋀ (g : x -> y) ⋀ ¬free(a, f) ⋀ ¬free(a, g) ⋀
+///   (a : x) ⋀ (f(a) == g(a))  =>  (f == g)`.
+///
+/// Extensionality by eta-conversion.
+pub fn lam_eta_ext<A: Prop, F: Prop, G: Prop, X: Prop, Y: Prop>(
+    ty_f: Ty<F, Pow<Y, X>>,
+    ty_g: Ty<G, Pow<Y, X>>,
+    nf_f: NotFreeIn<A, F>,
+    nf_g: NotFreeIn<A, G>,
+    ty_a: Ty<A, X>,
+    x: Eq<App<F, A>, App<G, A>>,
+) -> Eq<F, G> {
+    let x = eq::transitivity(lam_eq_lift(ty_a, x), lam_eta_pow(ty_g, nf_g));
+    eq::transitivity(eq::symmetry(lam_eta_pow(ty_f, nf_f)), x)
+}
@@ -0,0 +1,146 @@
+//! # Labelled Transition Systems
+//!
+//! A labelled transition system `l` is a symbol with transitions `p -a-> q` ([Trans]).
+//!
+//! Strong bisimilarity `p ~ q` ([Bisim]) is the largest relation such that
+//! every transition of `p` is matched by a transition of `q` with the same label,
+//! leading to bisimilar states, and vice versa.
+//! Like [fixpoint::Gfp], this is a greatest fixed point with a coinduction principle:
+//! Every bisimulation is contained in bisimilarity ([bisim_coind]).
+//!
+//! To make coinductive proofs smaller, it is sound to check a bisimulation up to bisimilarity
+//! ([bisim_coind_up_to]), where steps may end in already bisimilar states.
+//!
+//! Bisimilarity is a congruence under parallel composition `p | q` ([PPar], [bisim_par]).
+
+use super::*;
+use hooo::{Exists, Pow};
+
+/// Transition relation.
+#[derive(Copy, Clone)]
+pub struct FTrans(());
+
+/// `p -a-> q` in `l`.
+pub type Trans<L, P, A, Q> = App<App<FTrans, L>, Tup<P, Tup<A, Q>>>;
+
+/// Strong bisimilarity.
+#[derive(Copy, Clone)]
+pub struct FBisim(());
+
+/// `p ~ q` in `l`.
+pub type Bisim<L, P, Q> = App<App<FBisim, L>, Tup<P, Q>>;
+
+/// Parallel composition.
+#[derive(Copy, Clone)]
+pub struct FPPar(());
+
+/// `p | q`.
+pub type PPar<P, Q> = App<FPPar, Tup<P, Q>>;
+
+/// `(∃ q' : q -a-> q' { r(p', q') })^(r(p, q) ⋀ p -a-> p')`.
+///
+/// Forward step of a bisimulation `r`.
+pub type BisimFwd<L, R, P, Q, A, P1, Q1> =
+    Pow<Exists<Trans<L, Q, A, Q1>, App<R, Tup<P1, Q1>>>, And<App<R, Tup<P, Q>>, Trans<L, P, A, P1>>>;
+
+/// `(∃ p' : p -a-> p' { r(p', q') })^(r(p, q) ⋀ q -a-> q')`.
+///
+/// Backward step of a bisimulation `r`.
+pub type BisimBwd<L, R, P, Q, A, P1, Q1> =
+    Pow<Exists<Trans<L, P, A, P1>, App<R, Tup<P1, Q1>>>, And<App<R, Tup<P, Q>>, Trans<L, Q, A, Q1>>>;
+
+/// `r(p', q') ⋁ (p' ~ q')`.
+pub type UpToBisim<L, R, P1, Q1> = Or<App<R, Tup<P1, Q1>>, Bisim<L, P1, Q1>>;
+
+/// `is_const(trans)`.
+pub fn trans_is_const() -> IsConst<FTrans> {axiom!()}
+/// `is_const(bisim)`.
+pub fn bisim_is_const() -> IsConst<FBisim> {axiom!()}
+/// `is_const(par)`.
+pub fn ppar_is_const() -> IsConst<FPPar> {axiom!()}
+
+/// `(p ~ q) ⋀ (p -a-> p')  =>  ∃ q' : q -a-> q' { p' ~ q' }`.
+pub fn bisim_fwd<L: Prop, P: Prop, Q: Prop, A: Prop, P1: Prop, Q1: VProp>(
+    _: Bisim<L, P, Q>,
+    _: Trans<L, P, A, P1>
+) -> Exists<Trans<L, Q, A, Q1>, Bisim<L, P1, Q1>> {axiom!()}
+/// `(p ~ q) ⋀ (q -a-> q')  =>  ∃ p' : p -a-> p' { p' ~ q' }`.
+pub fn bisim_bwd<L: Prop, P: Prop, Q: Prop, A: Prop, P1: VProp, Q1: Prop>(
+    _: Bisim<L, P, Q>,
+    _: Trans<L, Q, A, Q1>
+) -> Exists<Trans<L, P, A, P1>, Bisim<L, P1, Q1>> {axiom!()}
+/// `fwd(r) ⋀ bwd(r)  =>  (x ~ y)^r(x, y)`.
+///
+/// Coinduction principle of bisimilarity.
+pub fn bisim_coind<L: Prop, R: Prop, P: VProp, Q: VProp, A: VProp, P1: VProp, Q1: VProp,
+    X: Prop, Y: Prop>(
+    _fwd: BisimFwd<L, R, P, Q, A, P1, Q1>,
+    _bwd: BisimBwd<L, R, P, Q, A, P1, Q1>,
+) -> Pow<Bisim<L, X, Y>, App<R, Tup<X, Y>>> {axiom!()}
+/// `fwd(r ⋁ ~) ⋀ bwd(r ⋁ ~)  =>  (x ~ y)^r(x, y)`.
+///
+/// Bisimulation up to bisimilarity.
+pub fn bisim_coind_up_to<L: Prop, R: Prop, P: VProp, Q: VProp, A: VProp, P1: VProp, Q1: VProp,
+    X: Prop, Y: Prop>(
+    _fwd: Pow<Exists<Trans<L, Q, A, Q1>, UpToBisim<L, R, P1, Q1>>,
+        And<App<R, Tup<P, Q>>, Trans<L, P, A, P1>>>,
+    _bwd: Pow<Exists<Trans<L, P, A, P1>, UpToBisim<L, R, P1, Q1>>,
+        And<App<R, Tup<P, Q>>, Trans<L, Q, A, Q1>>>,
+) -> Pow<Bisim<L, X, Y>, App<R, Tup<X, Y>>> {axiom!()}
+/// `p ~ p`.
+pub fn bisim_refl<L: Prop, P: Prop>() -> Bisim<L, P, P> {axiom!()}
+/// `(p ~ q)  =>  (q ~ p)`.
+pub fn bisim_symmetry<L: Prop, P: Prop, Q: Prop>(_: Bisim<L, P, Q>) -> Bisim<L, Q, P> {
+    axiom!()
+}
+/// `(p ~ q) ⋀ (q ~ r)  =>  (p ~ r)`.
+pub fn bisim_transitivity<L: Prop, P: Prop, Q: Prop, R: Prop>(
+    _: Bisim<L, P, Q>,
+    _: Bisim<L, Q, R>
+) -> Bisim<L, P, R> {axiom!()}
+
+/// `(p -a-> p')  =>  (p | q) -a-> (p' | q)`.
+pub fn par_trans_left<L: Prop, P: Prop, Q: Prop, A: Prop, P1: Prop>(
+    _: Trans<L, P, A, P1>
+) -> Trans<L, PPar<P, Q>, A, PPar<P1, Q>> {axiom!()}
+/// `(q -a-> q')  =>  (p | q) -a-> (p | q')`.
+pub fn par_trans_right<L: Prop, P: Prop, Q: Prop, A: Prop, Q1: Prop>(
+    _: Trans<L, Q, A, Q1>
+) -> Trans<L, PPar<P, Q>, A, PPar<P, Q1>> {axiom!()}
+/// `(p1 ~ p2) ⋀ (q1 ~ q2)  =>  (p1 | q1) ~ (p2 | q2)`.
+///
+/// Bisimilarity is a congruence under parallel composition.
+pub fn bisim_par<L: Prop, P1: Prop, P2: Prop, Q1: Prop, Q2: Prop>(
+    _: Bisim<L, P1, P2>,
+    _: Bisim<L, Q1, Q2>
+) -> Bisim<L, PPar<P1, Q1>, PPar<P2, Q2>> {axiom!()}
+/// `(p | q) ~ (q | p)`.
+pub fn bisim_par_symmetry<L: Prop, P: Prop, Q: Prop>() -> Bisim<L, PPar<P, Q>, PPar<Q, P>> {
+    axiom!()
+}
+
+/// `(p1 ~ p2)  =>  (p1 | q) ~ (p2 | q)`.
+pub fn bisim_par_left<L: Prop, P1: Prop, P2: Prop, Q: Prop>(
+    x: Bisim<L, P1, P2>
+) -> Bisim<L, PPar<P1, Q>, PPar<P2, Q>> {bisim_par(x, bisim_refl())}
+/// `(q1 ~ q2)  =>  (p | q1) ~ (p | q2)`.
+pub fn bisim_par_right<L: Prop, P: Prop, Q1: Prop, Q2: Prop>(
+    x: Bisim<L, Q1, Q2>
+) -> Bisim<L, PPar<P, Q1>, PPar<P, Q2>> {bisim_par(bisim_refl(), x)}
+/// `(p1 ~ p2) ⋀ (q1 ~ q2)  =>  (p1 | q1) ~ (q2 | p2)`.
+pub fn bisim_par_swap<L: Prop, P1: Prop, P2: Prop, Q1: Prop, Q2: Prop>(
+    x: Bisim<L, P1, P2>,
+    y: Bisim<L, Q1, Q2>
+) -> Bisim<L, PPar<P1, Q1>, PPar<Q2, P2>> {
+    bisim_transitivity(bisim_par(x, y), bisim_par_symmetry())
+}
+/// `(p ~ q) ⋀ (p == p')  =>  (p' ~ q)`.
+pub fn bisim_in_left_arg<L: Prop, P: Prop, Q: Prop, P1: Prop>(
+    x: Bisim<L, P, Q>,
+    y: Eq<P, P1>
+) -> Bisim<L, P1, Q> {app_eq(tup_eq_fst(y)).0(x)}
+/// `(p ~ q) ⋀ (q == q')  =>  (p ~ q')`.
+pub fn bisim_in_right_arg<L: Prop, P: Prop, Q: Prop, Q1: Prop>(
+    x: Bisim<L, P, Q>,
+    y: Eq<Q, Q1>
+) -> Bisim<L, P, Q1> {app_eq(tup_eq_snd(y)).0(x)}
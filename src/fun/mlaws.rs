@@ -0,0 +1,86 @@
+//! # Functor and Monad Laws
+//!
+//! The laws of functors and monads are bundled as traits,
+//! such that derived theorems can be reused for every instance.
+//!
+//! A functor `f` with `map : (a -> b) -> (f(a) -> f(b))` ([IsFunctor]) satisfies:
+//!
+//! - `map(id{a}) == id{f(a)}`
+//! - `map(g . h) == map(g) . map(h)`
+//!
+//! A monad `m` with `unit : a -> m(a)` and `bind : m(a) -> (a -> m(b)) -> m(b)` ([IsMonad])
+//! satisfies:
+//!
+//! - Left unit: `bind(unit(a), k) == k(a)`
+//! - Right unit: `bind(x, unit) == x`
+//! - Associativity: `bind(bind(x, k), h) == bind(x, \(a : t) = bind(k(a), h))`
+//!
+//! To instantiate the laws for some symbols, e.g. a list or option type,
+//! implement the trait for a witness type and prove the required methods.
+
+use super::*;
+
+/// Functor laws for `f` with `map`.
+pub trait IsFunctor<F: Prop, Map: Prop> {
+    /// `map(id{a}) == id{f(a)}`.
+    fn map_id<A: Prop>() -> Eq<App<Map, Id<A>>, Id<App<F, A>>>;
+    /// `map(g . h) == map(g) . map(h)`.
+    fn map_comp<G: Prop, H: Prop>() -> Eq<App<Map, Comp<G, H>>, Comp<App<Map, G>, App<Map, H>>>;
+
+    /// `map(id{a})(x) == id{f(a)}(x)`.
+    fn map_id_app<A: Prop, X: Prop>() -> Eq<App<App<Map, Id<A>>, X>, App<Id<App<F, A>>, X>> {
+        app_map_eq(Self::map_id())
+    }
+    /// `map(g . h)(x) == map(g)(map(h)(x))`.
+    fn map_comp_app<G: Prop, H: Prop, X: Prop>() ->
+        Eq<App<App<Map, Comp<G, H>>, X>, App<App<Map, G>, App<App<Map, H>, X>>>
+    {eq::transitivity(app_map_eq(Self::map_comp()), eq::symmetry(eq_app_comp()))}
+    /// `(g == h)  =>  map(g) == map(h)`.
+    fn map_eq<G: Prop, H: Prop>(x: Eq<G, H>) -> Eq<App<Map, G>, App<Map, H>> {app_eq(x)}
+    /// `map(g . (h . i)) == (map(g) . map(h)) . map(i)`.
+    fn map_comp3<G: Prop, H: Prop, I: Prop>() ->
+        Eq<App<Map, Comp<G, Comp<H, I>>>, Comp<Comp<App<Map, G>, App<Map, H>>, App<Map, I>>>
+    {
+        let x = eq::transitivity(Self::map_comp(), comp_eq_right(Self::map_comp()));
+        eq::transitivity(x, comp_assoc())
+    }
+}
+
+/// Monad laws for `m` with `unit` and `bind`.
+pub trait IsMonad<M: Prop, Unit: Prop, Bind: Prop> {
+    /// `unit : a -> m(a)`.
+    fn unit_ty<A: Prop>() -> Ty<Unit, Pow<App<M, A>, A>>;
+    /// `bind(unit(a), k) == k(a)`.
+    fn bind_unit_left<A: Prop, K: Prop>() -> Eq<App2<Bind, App<Unit, A>, K>, App<K, A>>;
+    /// `bind(x, unit) == x`.
+    fn bind_unit_right<X: Prop>() -> Eq<App2<Bind, X, Unit>, X>;
+    /// `bind(bind(x, k), h) == bind(x, \(a : t) = bind(k(a), h))`.
+    fn bind_assoc<X: Prop, K: Prop, H: Prop, A: VProp, T: Prop>() ->
+        Eq<App2<Bind, App2<Bind, X, K>, H>, App2<Bind, X, Lam<Ty<A, T>, App2<Bind, App<K, A>, H>>>>;
+
+    /// `bind(unit(a), unit) == unit(a)`.
+    fn bind_unit_unit<A: Prop>() -> Eq<App2<Bind, App<Unit, A>, Unit>, App<Unit, A>> {
+        Self::bind_unit_left()
+    }
+    /// `(x == y)  =>  bind(x, k) == bind(y, k)`.
+    fn bind_eq_left<X: Prop, Y: Prop, K: Prop>(
+        x: Eq<X, Y>
+    ) -> Eq<App2<Bind, X, K>, App2<Bind, Y, K>> {app_map_eq(app_eq(x))}
+    /// `(k == h)  =>  bind(x, k) == bind(x, h)`.
+    fn bind_eq_right<X: Prop, K: Prop, H: Prop>(
+        x: Eq<K, H>
+    ) -> Eq<App2<Bind, X, K>, App2<Bind, X, H>> {app_eq(x)}
+    /// `bind(bind(unit(a), k), h) == bind(k(a), h)`.
+    fn bind_unit_left_bind<A: Prop, K: Prop, H: Prop>() ->
+        Eq<App2<Bind, App2<Bind, App<Unit, A>, K>, H>, App2<Bind, App<K, A>, H>>
+    {Self::bind_eq_left(Self::bind_unit_left())}
+    /// `bind(bind(x, unit), h) == bind(x, h)`.
+    fn bind_unit_right_bind<X: Prop, H: Prop>() ->
+        Eq<App2<Bind, App2<Bind, X, Unit>, H>, App2<Bind, X, H>>
+    {Self::bind_eq_left(Self::bind_unit_right())}
+    /// `bind(x, unit . id{a}) == x`.
+    fn bind_unit_id<A: Prop, X: Prop>() -> Eq<App2<Bind, X, Comp<Unit, Id<A>>>, X> {
+        let x = comp_id_right(Self::unit_ty::<A>());
+        eq::transitivity(Self::bind_eq_right(x), Self::bind_unit_right())
+    }
+}
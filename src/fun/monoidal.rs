@@ -0,0 +1,246 @@
+//! # Symmetric Monoidal Structure
+//!
+//! Tuples `(a, b)` ([Tup]) together with the parallel tuple `f x g` ([Par])
+//! form a symmetric monoidal structure with unit `true`.
+//!
+//! The structural maps are function symbols with inverse proofs:
+//!
+//! - Associator `assoc : ((a, b), c) -> (a, (b, c))` ([Assoc])
+//! - Left unitor `unit_left : (true, a) -> a` ([UnitLeft])
+//! - Right unitor `unit_right : (a, true) -> a` ([UnitRight])
+//! - Braiding `braid : (a, b) -> (b, a)` ([Braid])
+//!
+//! The braiding is its own inverse ([braid_inv]), such that `braid . braid == id`
+//! ([eq_braid_braid]).
+//!
+//! The coherence conditions are stated as axioms:
+//!
+//! - Pentagon ([pentagon])
+//! - Triangle ([triangle])
+//! - Hexagon ([hexagon])
+//!
+//! ### Trace
+//!
+//! The trace `trace(f) : a -> b` of `f : (a, x) -> (b, x)` ([Trace]) models feedback,
+//! where the output `x` is fed back as input. This is useful when modelling circuits or dataflow.
+//! The trace satisfies the yanking ([trace_yank]), sliding ([trace_slide]),
+//! tightening ([trace_tight]) and vanishing ([trace_vanish]) axioms.
+
+use super::*;
+
+/// Associator.
+#[derive(Copy, Clone)]
+pub struct Assoc(());
+
+/// `assoc : ((a, b), c) -> (a, (b, c))`.
+pub fn assoc_ty<A: Prop, B: Prop, C: Prop>() ->
+    Ty<Assoc, Pow<Tup<A, Tup<B, C>>, Tup<Tup<A, B>, C>>> {axiom!()}
+/// `is_const(assoc)`.
+pub fn assoc_is_const() -> IsConst<Assoc> {axiom!()}
+/// `assoc(((a, b), c)) == (a, (b, c))`.
+pub fn assoc_def<A: Prop, B: Prop, C: Prop>() ->
+    Eq<App<Assoc, Tup<Tup<A, B>, C>>, Tup<A, Tup<B, C>>> {axiom!()}
+/// `~inv(assoc)`.
+pub fn assoc_inv_qu() -> Qu<Inv<Assoc>> {axiom!()}
+/// `~(inv(assoc) . assoc)`.
+pub fn assoc_split_monic() -> SplitMonic<Assoc> {axiom!()}
+/// `~(assoc . inv(assoc))`.
+pub fn assoc_split_epic() -> SplitEpic<Assoc> {axiom!()}
+/// `inv(assoc)((a, (b, c))) == ((a, b), c)`.
+pub fn assoc_inv_def<A: Prop, B: Prop, C: Prop>() ->
+    Eq<App<Inv<Assoc>, Tup<A, Tup<B, C>>>, Tup<Tup<A, B>, C>> {
+    inv_val_qu(assoc_inv_qu(), assoc_def())
+}
+/// `(inv(assoc) . assoc) == id{((a, b), c)}`.
+pub fn eq_assoc_inv_assoc<A: Prop, B: Prop, C: Prop>() ->
+    Eq<Comp<Inv<Assoc>, Assoc>, Id<Tup<Tup<A, B>, C>>> {
+    eq_comp_left_inv_id(assoc_split_monic(), assoc_ty::<A, B, C>())
+}
+/// `(assoc . inv(assoc)) == id{(a, (b, c))}`.
+pub fn eq_assoc_assoc_inv<A: Prop, B: Prop, C: Prop>() ->
+    Eq<Comp<Assoc, Inv<Assoc>>, Id<Tup<A, Tup<B, C>>>> {
+    eq_comp_right_inv_id(assoc_split_epic(), assoc_ty::<A, B, C>())
+}
+
+/// Left unitor.
+#[derive(Copy, Clone)]
+pub struct UnitLeft(());
+
+/// `unit_left : (true, a) -> a`.
+pub fn unit_left_ty<A: Prop>() -> Ty<UnitLeft, Pow<A, Tup<True, A>>> {axiom!()}
+/// `is_const(unit_left)`.
+pub fn unit_left_is_const() -> IsConst<UnitLeft> {axiom!()}
+/// `unit_left((true, a)) == a`.
+pub fn unit_left_def<A: Prop>() -> Eq<App<UnitLeft, Tup<True, A>>, A> {axiom!()}
+/// `~inv(unit_left)`.
+pub fn unit_left_inv_qu() -> Qu<Inv<UnitLeft>> {axiom!()}
+/// `~(inv(unit_left) . unit_left)`.
+pub fn unit_left_split_monic() -> SplitMonic<UnitLeft> {axiom!()}
+/// `~(unit_left . inv(unit_left))`.
+pub fn unit_left_split_epic() -> SplitEpic<UnitLeft> {axiom!()}
+/// `inv(unit_left)(a) == (true, a)`.
+pub fn unit_left_inv_def<A: Prop>() -> Eq<App<Inv<UnitLeft>, A>, Tup<True, A>> {
+    inv_val_qu(unit_left_inv_qu(), unit_left_def())
+}
+/// `(inv(unit_left) . unit_left) == id{(true, a)}`.
+pub fn eq_unit_left_inv_unit_left<A: Prop>() ->
+    Eq<Comp<Inv<UnitLeft>, UnitLeft>, Id<Tup<True, A>>> {
+    eq_comp_left_inv_id(unit_left_split_monic(), unit_left_ty::<A>())
+}
+/// `(unit_left . inv(unit_left)) == id{a}`.
+pub fn eq_unit_left_unit_left_inv<A: Prop>() -> Eq<Comp<UnitLeft, Inv<UnitLeft>>, Id<A>> {
+    eq_comp_right_inv_id(unit_left_split_epic(), unit_left_ty::<A>())
+}
+
+/// Right unitor.
+#[derive(Copy, Clone)]
+pub struct UnitRight(());
+
+/// `unit_right : (a, true) -> a`.
+pub fn unit_right_ty<A: Prop>() -> Ty<UnitRight, Pow<A, Tup<A, True>>> {axiom!()}
+/// `is_const(unit_right)`.
+pub fn unit_right_is_const() -> IsConst<UnitRight> {axiom!()}
+/// `unit_right((a, true)) == a`.
+pub fn unit_right_def<A: Prop>() -> Eq<App<UnitRight, Tup<A, True>>, A> {axiom!()}
+/// `~inv(unit_right)`.
+pub fn unit_right_inv_qu() -> Qu<Inv<UnitRight>> {axiom!()}
+/// `~(inv(unit_right) . unit_right)`.
+pub fn unit_right_split_monic() -> SplitMonic<UnitRight> {axiom!()}
+/// `~(unit_right . inv(unit_right))`.
+pub fn unit_right_split_epic() -> SplitEpic<UnitRight> {axiom!()}
+/// `inv(unit_right)(a) == (a, true)`.
+pub fn unit_right_inv_def<A: Prop>() -> Eq<App<Inv<UnitRight>, A>, Tup<A, True>> {
+    inv_val_qu(unit_right_inv_qu(), unit_right_def())
+}
+/// `(inv(unit_right) . unit_right) == id{(a, true)}`.
+pub fn eq_unit_right_inv_unit_right<A: Prop>() ->
+    Eq<Comp<Inv<UnitRight>, UnitRight>, Id<Tup<A, True>>> {
+    eq_comp_left_inv_id(unit_right_split_monic(), unit_right_ty::<A>())
+}
+/// `(unit_right . inv(unit_right)) == id{a}`.
+pub fn eq_unit_right_unit_right_inv<A: Prop>() -> Eq<Comp<UnitRight, Inv<UnitRight>>, Id<A>> {
+    eq_comp_right_inv_id(unit_right_split_epic(), unit_right_ty::<A>())
+}
+
+/// Braiding.
+#[derive(Copy, Clone)]
+pub struct Braid(());
+
+/// `braid : (a, b) -> (b, a)`.
+pub fn braid_ty<A: Prop, B: Prop>() -> Ty<Braid, Pow<Tup<B, A>, Tup<A, B>>> {axiom!()}
+/// `is_const(braid)`.
+pub fn braid_is_const() -> IsConst<Braid> {axiom!()}
+/// `braid((a, b)) == (b, a)`.
+pub fn braid_def<A: Prop, B: Prop>() -> Eq<App<Braid, Tup<A, B>>, Tup<B, A>> {axiom!()}
+/// `inv(braid) == braid`.
+pub fn braid_inv() -> Eq<Inv<Braid>, Braid> {axiom!()}
+/// `~(inv(braid) . braid)`.
+pub fn braid_split_monic() -> SplitMonic<Braid> {axiom!()}
+/// `inv(braid) ~~ braid`.
+pub fn braid_inv_q() -> Q<Inv<Braid>, Braid> {self_inv_to_q(braid_inv())}
+/// `(braid . braid) == id{(a, b)}`.
+pub fn eq_braid_braid<A: Prop, B: Prop>() -> Eq<Comp<Braid, Braid>, Id<Tup<A, B>>> {
+    let x = eq_comp_left_inv_id(braid_split_monic(), braid_ty::<A, B>());
+    eq::in_left_arg(x, comp_eq_left(braid_inv()))
+}
+
+/// `assoc . ((f x g) x h)  ==  (f x (g x h)) . assoc`.
+///
+/// Naturality of the associator.
+pub fn assoc_nat<F: Prop, G: Prop, H: Prop>() ->
+    Eq<Comp<Assoc, Par<Par<F, G>, H>>, Comp<Par<F, Par<G, H>>, Assoc>> {axiom!()}
+/// `unit_left . (id{true} x f)  ==  f . unit_left`.
+///
+/// Naturality of the left unitor.
+pub fn unit_left_nat<F: Prop>() ->
+    Eq<Comp<UnitLeft, Par<Id<True>, F>>, Comp<F, UnitLeft>> {axiom!()}
+/// `unit_right . (f x id{true})  ==  f . unit_right`.
+///
+/// Naturality of the right unitor.
+pub fn unit_right_nat<F: Prop>() ->
+    Eq<Comp<UnitRight, Par<F, Id<True>>>, Comp<F, UnitRight>> {axiom!()}
+/// `braid . (f x g)  ==  (g x f) . braid`.
+///
+/// Naturality of the braiding.
+pub fn braid_nat<F: Prop, G: Prop>() ->
+    Eq<Comp<Braid, Par<F, G>>, Comp<Par<G, F>, Braid>> {axiom!()}
+
+/// `(id{a} x assoc) . (assoc . (assoc x id{d}))  ==  assoc . assoc`.
+///
+/// Pentagon coherence on `(((a, b), c), d)`.
+pub fn pentagon<A: Prop, D: Prop>() -> Eq<
+    Comp<Par<Id<A>, Assoc>, Comp<Assoc, Par<Assoc, Id<D>>>>,
+    Comp<Assoc, Assoc>
+> {axiom!()}
+/// `(id{a} x unit_left) . assoc  ==  unit_right x id{b}`.
+///
+/// Triangle coherence on `((a, true), b)`.
+pub fn triangle<A: Prop, B: Prop>() ->
+    Eq<Comp<Par<Id<A>, UnitLeft>, Assoc>, Par<UnitRight, Id<B>>> {axiom!()}
+/// `assoc . (braid . assoc)  ==  (id{b} x braid) . (assoc . (braid x id{c}))`.
+///
+/// Hexagon coherence on `((a, b), c)`.
+pub fn hexagon<B: Prop, C: Prop>() -> Eq<
+    Comp<Assoc, Comp<Braid, Assoc>>,
+    Comp<Par<Id<B>, Braid>, Comp<Assoc, Par<Braid, Id<C>>>>
+> {axiom!()}
+
+/// `(f x g)  ==  braid . ((g x f) . braid)`.
+///
+/// Swapping the arguments of a parallel tuple.
+pub fn par_swap<F: Prop, G: Prop, A: Prop, B: Prop, X: Prop, Y: Prop>(
+    ty_f: Ty<F, Pow<X, A>>,
+    ty_g: Ty<G, Pow<Y, B>>,
+) -> Eq<Par<F, G>, Comp<Braid, Comp<Par<G, F>, Braid>>> {
+    let x: Eq<Comp<Braid, Comp<Braid, Par<F, G>>>, _> =
+        comp_eq_right(braid_nat());
+    let x = eq::in_left_arg(x, comp_assoc());
+    let x = eq::in_left_arg(x, comp_eq_left(eq_braid_braid::<X, Y>()));
+    eq::in_left_arg(x, comp_id_left(par_tup_fun_ty(ty_f, ty_g)))
+}
+
+/// Trace (feedback).
+#[derive(Copy, Clone)]
+pub struct FTrace(());
+
+/// `trace(f)`.
+pub type Trace<F> = App<FTrace, F>;
+
+/// `(f : (a, x) -> (b, x))  =>  trace(f) : a -> b`.
+pub fn trace_ty<F: Prop, A: Prop, B: Prop, X: Prop>(
+    _ty_f: Ty<F, Pow<Tup<B, X>, Tup<A, X>>>
+) -> Ty<Trace<F>, Pow<B, A>> {axiom!()}
+/// `is_const(trace)`.
+pub fn trace_is_const() -> IsConst<FTrace> {axiom!()}
+/// `is_const(f)  =>  is_const(trace(f))`.
+pub fn trace_app_is_const<F: Prop>(x: IsConst<F>) -> IsConst<Trace<F>> {
+    app_is_const(trace_is_const(), x)
+}
+/// `trace(braid) == id{x}`.
+///
+/// Yanking, where `braid : (x, x) -> (x, x)`.
+pub fn trace_yank<X: Prop>() -> Eq<Trace<Braid>, Id<X>> {axiom!()}
+/// `(f : (a, x) -> (b, y)) ⋀ (g : y -> x)  =>
+///  trace((id{b} x g) . f) == trace(f . (id{a} x g))`.
+///
+/// Sliding a function along the feedback loop.
+pub fn trace_slide<F: Prop, G: Prop, A: Prop, B: Prop, X: Prop, Y: Prop>(
+    _ty_f: Ty<F, Pow<Tup<B, Y>, Tup<A, X>>>,
+    _ty_g: Ty<G, Pow<X, Y>>,
+) -> Eq<Trace<Comp<Par<Id<B>, G>, F>>, Trace<Comp<F, Par<Id<A>, G>>>> {axiom!()}
+/// `(f : (a, x) -> (b, x))  =>  trace((h x id{x}) . (f . (g x id{x}))) == h . (trace(f) . g)`.
+///
+/// Tightening (naturality in the input and output).
+pub fn trace_tight<F: Prop, G: Prop, H: Prop, A: Prop, B: Prop, X: Prop>(
+    _ty_f: Ty<F, Pow<Tup<B, X>, Tup<A, X>>>
+) -> Eq<Trace<Comp<Par<H, Id<X>>, Comp<F, Par<G, Id<X>>>>>, Comp<H, Comp<Trace<F>, G>>> {
+    axiom!()
+}
+/// `(f : (a, true) -> (b, true))  =>  trace(f) == unit_right . (f . inv(unit_right))`.
+///
+/// Vanishing of the trivial feedback.
+pub fn trace_vanish<F: Prop, A: Prop, B: Prop>(
+    _ty_f: Ty<F, Pow<Tup<B, True>, Tup<A, True>>>
+) -> Eq<Trace<F>, Comp<UnitRight, Comp<F, Inv<UnitRight>>>> {axiom!()}
+/// `(f == g)  =>  trace(f) == trace(g)`.
+pub fn trace_eq<F: Prop, G: Prop>(x: Eq<F, G>) -> Eq<Trace<F>, Trace<G>> {app_eq(x)}
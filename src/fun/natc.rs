@@ -33,19 +33,19 @@ use super::*;
 pub struct Natc(());
 
 /// `nat_c : type(0)`.
-pub fn natc_ty() -> Ty<Natc, Type<Z>> {unimplemented!()}
+pub fn natc_ty() -> Ty<Natc, Type<Z>> {axiom!()}
 
 /// `n : nat_c  =>  (n == 0_c) ⋁ ∃ m : nat_c { s_c(m) == n }`.
 pub fn natc_def<N: Prop, M: Prop>(
     _: Ty<N, Natc>
-) -> Or<Eq<N, Zc>, Exists<Ty<M, Natc>, Eq<Sc<M>, N>>> {unimplemented!()}
+) -> Or<Eq<N, Zc>, Exists<Ty<M, Natc>, Eq<Sc<M>, N>>> {axiom!()}
 
 /// Closed zero.
 #[derive(Copy, Clone)]
 pub struct Zc(());
 
 /// `0_c : nat_c`.
-pub fn zeroc_ty() -> Ty<Zc, Natc> {unimplemented!()}
+pub fn zeroc_ty() -> Ty<Zc, Natc> {axiom!()}
 
 /// Closed successor function.
 #[derive(Copy, Clone)]
@@ -55,13 +55,13 @@ pub struct FSc(());
 pub type Sc<N> = App<FSc, N>;
 
 /// `s_c : nat_c -> nat_c`.
-pub fn sc_ty() -> Ty<FSc, Pow<Natc, Natc>> {unimplemented!()}
+pub fn sc_ty() -> Ty<FSc, Pow<Natc, Natc>> {axiom!()}
 
 /// `n : nat_c  =>  s_c(n) : nat_c`.
-pub fn sc_def<N: Prop>(_ty_n: Ty<N, Natc>) -> Ty<Sc<N>, Natc> {unimplemented!()}
+pub fn sc_def<N: Prop>(_ty_n: Ty<N, Natc>) -> Ty<Sc<N>, Natc> {axiom!()}
 
 /// `(s_c(n) == s_c(m))  =>  (n == m)`.
-pub fn sc_eq_rev<N: Prop, M: Prop>(_: Eq<Sc<N>, Sc<M>>) -> Eq<N, M> {unimplemented!()}
+pub fn sc_eq_rev<N: Prop, M: Prop>(_: Eq<Sc<N>, Sc<M>>) -> Eq<N, M> {axiom!()}
 
 /// Closed addition.
 #[derive(Copy, Clone)]
@@ -71,23 +71,23 @@ pub struct FAddc(());
 pub type Addc<A, B> = App<FAddc, Tup<A, B>>;
 
 /// `add_c : (nat_c, nat_c) -> nat_c`.
-pub fn addc_ty() -> Ty<FAddc, Pow<Natc, Tup<Natc, Natc>>> {unimplemented!()}
+pub fn addc_ty() -> Ty<FAddc, Pow<Natc, Tup<Natc, Natc>>> {axiom!()}
 
 /// `n : nat_c  =>  add_c(n, 0_c) = n`.
-pub fn addc_zeroc<N: Prop>(_ty_n: Ty<N, Natc>) -> Eq<Addc<N, Zc>, N> {unimplemented!()}
+pub fn addc_zeroc<N: Prop>(_ty_n: Ty<N, Natc>) -> Eq<Addc<N, Zc>, N> {axiom!()}
 
 /// `(s_c(n) : nat_c) ⋀ (m : nat_c)  =>  add_c(n, s_c(m)) = s_c(add_c(n, m))`.
 pub fn addc_sc<N: Prop, M: Prop>(
     _ty_sc_n: Ty<Sc<N>, Natc>,
     _ty_m: Ty<M, Natc>
-) -> Eq<Addc<N, Sc<M>>, Sc<Addc<N, M>>> {unimplemented!()}
+) -> Eq<Addc<N, Sc<M>>, Sc<Addc<N, M>>> {axiom!()}
 
 /// `(n : nat_c) ⋀ (m : nat_c) ⋀ (n ~~ add_c(s_c(n), m))  =>  (n == m)`.
 pub fn addc_closed<N: Prop, M: Prop>(
     _ty_n: Ty<N, Natc>,
     _ty_m: Ty<M, Natc>,
     _: Q<N, Addc<Sc<N>, M>>
-) -> Eq<N, M> {unimplemented!()}
+) -> Eq<N, M> {axiom!()}
 
 /// Closed multiplication.
 #[derive(Copy, Clone)]
@@ -97,16 +97,16 @@ pub struct FMulc(());
 pub type Mulc<A, B> = App<FMulc, Tup<A, B>>;
 
 /// `mul_c : (nat_c, nat_c) -> nat_c`.
-pub fn mulc_ty() -> Ty<FMulc, Pow<Natc, Tup<Natc, Natc>>> {unimplemented!()}
+pub fn mulc_ty() -> Ty<FMulc, Pow<Natc, Tup<Natc, Natc>>> {axiom!()}
 
 /// `n : nat_c  =>  mul_c(n, 0_c) = 0_c`.
-pub fn mulc_zc<N: Prop>(_ty_n: Ty<N, Natc>) -> Eq<Mulc<N, Zc>, Zc> {unimplemented!()}
+pub fn mulc_zc<N: Prop>(_ty_n: Ty<N, Natc>) -> Eq<Mulc<N, Zc>, Zc> {axiom!()}
 
 /// `(n : nat_c) ⋀ (m : nat_c) ⋀ mul_c(n, s_c(m)) = add_c(mul_c(n, m), n)`.
 pub fn mulc_sc<N: Prop, M: Prop>(
     _ty_n: Ty<N, Natc>,
     _ty_m: Ty<M, Natc>,
-) -> Eq<Mulc<N, Sc<M>>, Addc<Mulc<N, M>, N>> {unimplemented!()}
+) -> Eq<Mulc<N, Sc<M>>, Addc<Mulc<N, M>, N>> {axiom!()}
 
 /// `(n : nat_c)^true ⋀ (n ~~ s_c(n))  =>  (n == 0_c)`.
 pub fn eq_last_zeroc<N: Prop>(
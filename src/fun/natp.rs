@@ -26,19 +26,19 @@ pub type IsZero<N> = Eq<N, Zero>;
 pub struct Prev<A>(A);
 
 /// `nat : type(0)`.
-pub fn nat_ty() -> Ty<Nat, Type<Z>> {unimplemented!()}
+pub fn nat_ty() -> Ty<Nat, Type<Z>> {axiom!()}
 /// `is_const(nat)`.
-pub fn nat_is_const() -> IsConst<Nat> {unimplemented!()}
+pub fn nat_is_const() -> IsConst<Nat> {axiom!()}
 /// `(x : nat)  =>  (x == 0) ⋁ ((prev(x) : nat) ⋀ (x == succ(prev(x)))`.
 pub fn nat_def<X: Prop>(
     _x_ty: Ty<X, Nat>
-) -> Or<IsZero<X>, And<Ty<Prev<X>, Nat>, Eq<X, Succ<Prev<X>>>>> {unimplemented!()}
+) -> Or<IsZero<X>, And<Ty<Prev<X>, Nat>, Eq<X, Succ<Prev<X>>>>> {axiom!()}
 /// `(n : nat) ⋀ (n == succ(n))  =>  false`.
-pub fn para_eq_succ<N: Prop>(_: And<Ty<N, Nat>, Eq<N, Succ<N>>>) -> False {unimplemented!()}
+pub fn para_eq_succ<N: Prop>(_: And<Ty<N, Nat>, Eq<N, Succ<N>>>) -> False {axiom!()}
 /// `0 == succ(n)  =>  false`.
-pub fn para_pre_zero<N: Prop>(_: IsZero<Succ<N>>) -> False {unimplemented!()}
+pub fn para_pre_zero<N: Prop>(_: IsZero<Succ<N>>) -> False {axiom!()}
 /// `succ(n) == succ(m)  =>  n == m`.
-pub fn succ_eq_rev<N: Prop, M: Prop>(_: Eq<Succ<N>, Succ<M>>) -> Eq<N, M> {unimplemented!()}
+pub fn succ_eq_rev<N: Prop, M: Prop>(_: Eq<Succ<N>, Succ<M>>) -> Eq<N, M> {axiom!()}
 /// Induction on natural numbers.
 ///
 /// ```text
@@ -52,7 +52,7 @@ pub fn induction<N: VProp, P: Prop>(
     _ty_p: Ty<P, Pow<Bool, Nat>>,
     _case_zero: Tauto<Eq<App<P, Zero>, Tr>>,
     _case_n: Pow<Eq<App<P, Succ<N>>, Tr>, Ty<Succ<N>, Nat>>,
-) -> Pow<Eq<App<P, N>, True>, Ty<N, Nat>> {unimplemented!()}
+) -> Pow<Eq<App<P, N>, True>, Ty<N, Nat>> {axiom!()}
 /// Type induction on natural numbers.
 ///
 /// ```text
@@ -66,19 +66,19 @@ pub fn induction_ty<N: VProp, P: Prop, L: nat::Nat>(
     _ty_p: Ty<P, Pow<Type<L>, Nat>>,
     _case_zero: Tauto<App<P, Zero>>,
     _case_n: Pow<App<P, Succ<N>>, Ty<Succ<N>, Nat>>,
-) -> Pow<App<P, N>, Ty<N, Nat>> {unimplemented!()}
+) -> Pow<App<P, N>, Ty<N, Nat>> {axiom!()}
 /// `x^(n : nat)  =>  (x[n := succ(n)])^(succ(n) : nat)`.
 pub fn subst_induction<N: Prop, X: Prop, M: Prop>(
     _: Pow<X, Ty<N, Nat>>
-) -> Pow<Subst<X, N, Succ<N>>, Ty<Succ<N>, Nat>> {unimplemented!()}
+) -> Pow<Subst<X, N, Succ<N>>, Ty<Succ<N>, Nat>> {axiom!()}
 /// `∃ 0 : nat { x } ⋀ ∃ succ(n) : nat { x }  =>  x`.
 pub fn nat_exists<N: VProp, X: Prop>(
     _exists_zero_x: Exists<Ty<Zero, Nat>, X>,
     _exists_succ_n_x: Exists<Ty<Succ<N>, Nat>, X>
-) -> X {unimplemented!()}
+) -> X {axiom!()}
 /// `n : nat  =>  succ(prev(n)) == prev(succ(n))`.
 pub fn previous_symmetry<N: Prop>(_ty_n: Ty<N, Nat>) -> Eq<Succ<Prev<N>>, Prev<Succ<N>>> {
-    unimplemented!()
+    axiom!()
 }
 
 /// `succ(n)[n := a]  ==  succ(a)`.
@@ -126,18 +126,18 @@ pub fn nat_cover<A: Prop, X: Prop>(
 pub struct Zero(());
 
 /// `zero : nat`.
-pub fn zero_ty() -> Ty<Zero, Nat> {unimplemented!()}
+pub fn zero_ty() -> Ty<Zero, Nat> {axiom!()}
 /// `is_const(zero)`.
-pub fn zero_is_const() -> IsConst<Zero> {unimplemented!()}
+pub fn zero_is_const() -> IsConst<Zero> {axiom!()}
 
 /// Successor.
 #[derive(Copy, Clone)]
 pub struct FSucc(());
 
 /// `succ : nat -> nat`.
-pub fn succ_ty() -> Ty<FSucc, Pow<Nat, Nat>> {unimplemented!()}
+pub fn succ_ty() -> Ty<FSucc, Pow<Nat, Nat>> {axiom!()}
 /// `is_const(succ)`.
-pub fn succ_is_const() -> IsConst<FSucc> {unimplemented!()}
+pub fn succ_is_const() -> IsConst<FSucc> {axiom!()}
 
 /// `is_const(n)  =>  is_const(succ(n))`.
 pub fn succ_app_is_const<N: Prop>(n_is_const: IsConst<N>) -> IsConst<Succ<N>> {
@@ -170,21 +170,21 @@ pub struct FAdd(());
 pub type Add<A, B> = App<FAdd, Tup<A, B>>;
 
 /// `add : (nat, nat) -> nat`.
-pub fn add_ty() -> Ty<FAdd, Pow<Nat, Tup<Nat, Nat>>> {unimplemented!()}
+pub fn add_ty() -> Ty<FAdd, Pow<Nat, Tup<Nat, Nat>>> {axiom!()}
 /// `is_const(add)`.
-pub fn add_is_const() -> IsConst<FAdd> {unimplemented!()}
+pub fn add_is_const() -> IsConst<FAdd> {axiom!()}
 /// `(n : nat)  =>  add(0, n) = n`.
-pub fn add_zero<N: Prop>(_n_ty: Ty<N, Nat>) -> Eq<Add<Zero, N>, N> {unimplemented!()}
+pub fn add_zero<N: Prop>(_n_ty: Ty<N, Nat>) -> Eq<Add<Zero, N>, N> {axiom!()}
 /// `(succ(n) : nat) ⋀ (m : nat)  =>  add(succ(n), m) = succ(add(n, m))`.
 pub fn add_succ<N: Prop, M: Prop>(
     _ty_succ_n: Ty<Succ<N>, Nat>,
     _ty_m: Ty<M, Nat>
-) -> Eq<Add<Succ<N>, M>, Succ<Add<N, M>>> {unimplemented!()}
+) -> Eq<Add<Succ<N>, M>, Succ<Add<N, M>>> {axiom!()}
 /// `add(n, m) == add(m, n)`.
-pub fn add_symmetry<N: Prop, M: Prop>() -> Eq<Add<N, M>, Add<M, N>> {unimplemented!()}
+pub fn add_symmetry<N: Prop, M: Prop>() -> Eq<Add<N, M>, Add<M, N>> {axiom!()}
 /// `add(add(a, b), c) == add(a, add(b, c))`.
 pub fn add_assoc<A: Prop, B: Prop, C: Prop>() -> Eq<Add<Add<A, B>, C>, Add<A, Add<B, C>>> {
-    unimplemented!()
+    axiom!()
 }
 /// `(n : nat) ⋀ (m : nat) ⋀ (a : nat) ⋀ (n + a == m + a)  =>  (n == m)`.
 pub fn add_rev_eq_left<N: Prop, M: Prop, A: Prop>(
@@ -192,16 +192,16 @@ pub fn add_rev_eq_left<N: Prop, M: Prop, A: Prop>(
     _m_ty: Ty<M, Nat>,
     _a_ty: Ty<A, Nat>,
     _x: Eq<Add<N, A>, Add<M, A>>
-) -> Eq<N, M> {unimplemented!()}
+) -> Eq<N, M> {axiom!()}
 /// `(n : nat) ⋀ (m : nat) ⋀ (a : nat) ⋀ (a + n == a + m)  =>  (n == m)`.
 pub fn add_rev_eq_right<N: Prop, M: Prop, A: Prop>(
     _n_ty: Ty<N, Nat>,
     _m_ty: Ty<M, Nat>,
     _a_ty: Ty<A, Nat>,
     _x: Eq<Add<A, N>, Add<A, M>>
-) -> Eq<N, M> {unimplemented!()}
+) -> Eq<N, M> {axiom!()}
 /// `add[even] == eqb`.
-pub fn norm2_add_even() -> Eq<SymNorm2<FAdd, FEven>, FEqb> {unimplemented!()}
+pub fn norm2_add_even() -> Eq<SymNorm2<FAdd, FEven>, FEqb> {axiom!()}
 
 /// `1 : nat`.
 pub fn one_ty() -> Ty<One, Nat> {app_fun_ty(succ_ty(), zero_ty())}
@@ -251,25 +251,25 @@ pub struct FMul(());
 pub type Mul<A, B> = App<FMul, Tup<A, B>>;
 
 /// `mul : (nat, nat) -> nat`.
-pub fn mul_ty() -> Ty<FMul, Pow<Nat, Tup<Nat, Nat>>> {unimplemented!()}
+pub fn mul_ty() -> Ty<FMul, Pow<Nat, Tup<Nat, Nat>>> {axiom!()}
 /// `is_const(mul)`.
-pub fn mul_is_const() -> IsConst<FMul> {unimplemented!()}
+pub fn mul_is_const() -> IsConst<FMul> {axiom!()}
 /// `(n : nat)  =>  mul(0, n) = 0`.
-pub fn mul_zero<N: Prop>(_ty_n: Ty<N, Nat>) -> IsZero<Mul<Zero, N>> {unimplemented!()}
+pub fn mul_zero<N: Prop>(_ty_n: Ty<N, Nat>) -> IsZero<Mul<Zero, N>> {axiom!()}
 /// `(n : nat) ⋀ (m : nat)  =>  mul(succ(n), m) == add(m, mul(n, m))`.
 pub fn mul_succ<N: Prop, M: Prop>(
     _ty_n: Ty<N, Nat>,
     _ty_m: Ty<M, Nat>
-) -> Eq<Mul<Succ<N>, M>, Add<M, Mul<N, M>>> {unimplemented!()}
+) -> Eq<Mul<Succ<N>, M>, Add<M, Mul<N, M>>> {axiom!()}
 /// `mul(n, m) == mul(m, n)`.
-pub fn mul_symmetry<N: Prop, M: Prop>() -> Eq<Mul<N, M>, Mul<M, N>> {unimplemented!()}
+pub fn mul_symmetry<N: Prop, M: Prop>() -> Eq<Mul<N, M>, Mul<M, N>> {axiom!()}
 /// `mul(mul(a, b), c) == mul(a, mul(b, c))`.
 pub fn mul_assoc<A: Prop, B: Prop, C: Prop>() -> Eq<Mul<Mul<A, B>, C>, Mul<A, Mul<B, C>>> {
-    unimplemented!()
+    axiom!()
 }
 /// `mul(a, add(b, c)) == add(mul(a, b), mul(a, c))`.
 pub fn mul_distr<A: Prop, B: Prop, C: Prop>() -> Eq<Mul<A, Add<A, B>>, Add<Mul<A, B>, Add<B, C>>> {
-    unimplemented!()
+    axiom!()
 }
 /// `(n : nat) ⋀ (m : nat) ⋀ (a : nat) ⋀ (n * a == m * a) ⋀ ¬(a == 0)  =>  (n == m)`.
 pub fn mul_rev_eq_left<N: Prop, M: Prop, A: Prop>(
@@ -278,7 +278,7 @@ pub fn mul_rev_eq_left<N: Prop, M: Prop, A: Prop>(
     _a_ty: Ty<A, Nat>,
     _x: Eq<Mul<N, A>, Mul<M, A>>,
     _neq_a_zero: Not<IsZero<A>>,
-) -> Eq<N, M> {unimplemented!()}
+) -> Eq<N, M> {axiom!()}
 /// `(n : nat) ⋀ (m : nat) ⋀ (a : nat) ⋀ (a * n == a * m) ⋀ ¬(a == 0)  =>  (n == m)`.
 pub fn mul_rev_eq_right<N: Prop, M: Prop, A: Prop>(
     _n_ty: Ty<N, Nat>,
@@ -286,7 +286,7 @@ pub fn mul_rev_eq_right<N: Prop, M: Prop, A: Prop>(
     _a_ty: Ty<A, Nat>,
     _x: Eq<Mul<A, N>, Mul<A, M>>,
     _neq_a_zero: Not<IsZero<A>>,
-) -> Eq<N, M> {unimplemented!()}
+) -> Eq<N, M> {axiom!()}
 
 /// `(n : nat)  =>  mul(1, n) = n`.
 pub fn mul_one<N: Prop>(ty_n: Ty<N, Nat>) -> Eq<Mul<One, N>, N> {
@@ -310,12 +310,12 @@ pub struct FEven(());
 pub type Even<A> = App<FEven, A>;
 
 /// `even : nat -> bool`.
-pub fn even_ty() -> Ty<FEven, Pow<Bool, Nat>> {unimplemented!()}
+pub fn even_ty() -> Ty<FEven, Pow<Bool, Nat>> {axiom!()}
 /// `even(0) = tr`.
-pub fn even_zero() -> Eq<Even<Zero>, Tr> {unimplemented!()}
+pub fn even_zero() -> Eq<Even<Zero>, Tr> {axiom!()}
 /// `n : nat  =>  even(succ(n)) = not(even(n))`.
 pub fn even_succ<N: Prop>(_ty_n: Ty<N, Nat>) -> Eq<Even<Succ<N>>, App<FNot, Even<N>>> {
-    unimplemented!()
+    axiom!()
 }
 
 /// `n : nat  =>  even(succ(succ(n))) == even(n)`.
@@ -341,7 +341,7 @@ pub fn eq_comp_even_succ_succ_proof<N: VProp>() -> Eq<Comp<FEven, Comp<FSucc, FS
 ///
 /// This is the same as `eq_comp_even_succ_succ_proof` but without variable argument.
 pub fn eq_comp_even_succ_succ() -> Eq<Comp<FEven, Comp<FSucc, FSucc>>, FEven> {
-    unimplemented!()
+    axiom!()
 }
 
 /// Odd.
@@ -0,0 +1,75 @@
+//! # Observational Equality
+//!
+//! Observational equality `a ≐ b` ([ObsEq]) is computed structurally on type formers,
+//! as in observational type theory:
+//!
+//! - Tuples compare componentwise: `((a, c) ≐ (b, d)) == ((a ≐ b) ⋀ (c ≐ d))` ([tup_def])
+//! - Functions compare pointwise: `(f(a) ≐ g(a))^(a : x)  =>  (f ≐ g)` ([fun()])
+//!
+//! Observational equality coincides with propositional equality `a == b`
+//! ([to_eq], [from_eq]), and pointwise equality of functions is connected to
+//! function extensionality ([to_fun_ext], [from_fun_ext]).
+//! Unlike `a == b`, a proof of `a ≐ b` between tuples can be split into its components
+//! without knowing anything about the types of the components ([tup_split]).
+//! This makes it a more computation-friendly equality for normalization tooling,
+//! where definitional equality ([def_eq]) is often too strict.
+
+use super::*;
+use fun_ext::{fun_ext, fun_rev_ext, FunExtTy};
+
+/// `a ≐ b`.
+#[derive(Clone)]
+pub struct ObsEq<A, B>(Eq<A, B>);
+
+/// `(a ≐ b)  =>  (a == b)`.
+pub fn to_eq<A: Prop, B: Prop>(x: ObsEq<A, B>) -> Eq<A, B> {x.0}
+/// `(a == b)  =>  (a ≐ b)`.
+pub fn from_eq<A: Prop, B: Prop>(x: Eq<A, B>) -> ObsEq<A, B> {ObsEq(x)}
+/// `(a ≡ b)  =>  (a ≐ b)`.
+pub fn from_def_eq<A: Prop, B: Prop>(x: def_eq::DefEq<A, B>) -> ObsEq<A, B> {
+    ObsEq(def_eq::to_eq(x))
+}
+/// `a ≐ a`.
+pub fn refl<A: Prop>() -> ObsEq<A, A> {ObsEq(eq::refl())}
+/// `(a ≐ b)  =>  (b ≐ a)`.
+pub fn symmetry<A: Prop, B: Prop>(x: ObsEq<A, B>) -> ObsEq<B, A> {ObsEq(eq::symmetry(x.0))}
+/// `(a ≐ b) ⋀ (b ≐ c)  =>  (a ≐ c)`.
+pub fn transitivity<A: Prop, B: Prop, C: Prop>(x: ObsEq<A, B>, y: ObsEq<B, C>) -> ObsEq<A, C> {
+    ObsEq(eq::transitivity(x.0, y.0))
+}
+
+/// `(a ≐ b) ⋀ (c ≐ d)  =>  (a, c) ≐ (b, d)`.
+pub fn tup<A: Prop, B: Prop, C: Prop, D: Prop>(
+    x: ObsEq<A, B>,
+    y: ObsEq<C, D>
+) -> ObsEq<Tup<A, C>, Tup<B, D>> {ObsEq(tup_eq(x.0, y.0))}
+/// `((a, c) ≐ (b, d))  =>  (a ≐ b) ⋀ (c ≐ d)`.
+pub fn tup_split<A: Prop, B: Prop, C: Prop, D: Prop>(
+    _: ObsEq<Tup<A, C>, Tup<B, D>>
+) -> And<ObsEq<A, B>, ObsEq<C, D>> {axiom!()}
+/// `((a, c) ≐ (b, d)) == ((a ≐ b) ⋀ (c ≐ d))`.
+pub fn tup_def<A: Prop, B: Prop, C: Prop, D: Prop>() ->
+    Eq<ObsEq<Tup<A, C>, Tup<B, D>>, And<ObsEq<A, B>, ObsEq<C, D>>>
+{(Rc::new(tup_split), Rc::new(|(x, y)| tup(x, y)))}
+/// `(f ≐ g)  =>  f(a) ≐ g(a)`.
+pub fn app<F: Prop, G: Prop, A: Prop>(x: ObsEq<F, G>) -> ObsEq<App<F, A>, App<G, A>> {
+    ObsEq(app_map_eq(x.0))
+}
+/// `(f(a) ≐ g(a))^(a : x)  =>  (f ≐ g)`.
+pub fn fun<F: Prop, G: Prop, X: Prop, A: VProp>(
+    _: Pow<ObsEq<App<F, A>, App<G, A>>, Ty<A, X>>
+) -> ObsEq<F, G> {axiom!()}
+/// `(f ≐ g)^true  =>  fun_ext_ty(f, g)`.
+pub fn to_fun_ext<F: Prop, G: Prop, X: Prop, Y: Prop, A: Prop>(
+    x: Tauto<ObsEq<F, G>>
+) -> FunExtTy<F, G, X, Y, A> {fun_ext(x.trans(to_eq))}
+/// `fun_ext_ty(f, g)  =>  (f ≐ g)^true`.
+pub fn from_fun_ext<F: Prop, G: Prop, X: Prop, Y: Prop, A: Prop>(
+    x: FunExtTy<F, G, X, Y, A>
+) -> Tauto<ObsEq<F, G>> {fun_rev_ext(x).trans(from_eq)}
+/// `(f(a) ≐ g(a))^(a : x)  =>  fun_ext_ty(f, g)`.
+pub fn pointwise_fun_ext<F: Prop, G: Prop, X: Prop, Y: Prop, A: VProp, B: Prop>(
+    x: Pow<ObsEq<App<F, A>, App<G, A>>, Ty<A, X>>
+) -> FunExtTy<F, G, X, Y, B> {
+    to_fun_ext(hooo::pow_lift(x).trans(fun))
+}
@@ -0,0 +1,256 @@
+//! # Ordinal Numbers
+//!
+//! Ordinals are generated from zero ([OZero]), successor ([OSucc])
+//! and limits ([Lim]) of sequences `f : nat -> ord`.
+//!
+//! The ordering `b <ₒ a` ([OLt]) is well-founded on ordinals ([ord_wf]),
+//! which gives transfinite induction ([ord_induction_ty]) and recursion ([ord_rec]).
+//! This supports termination arguments that induction on natural numbers can not express,
+//! for example when a measure decreases through a limit.
+//!
+//! ### Notations up to ε₀
+//!
+//! Natural numbers are embedded into ordinals by `emb(n)` ([OrdNat]), such that `b <ₛ a`
+//! implies `emb(b) <ₒ emb(a)` ([lt_s_to_olt]).
+//!
+//! - Omega `ω := lim(emb)` ([Omega])
+//! - Exponentiation `ω^a` ([OmegaPow])
+//! - Tower `tower(0) := 0`, `tower(succ(n)) := ω^tower(n)` ([Tower])
+//! - Epsilon zero `ε₀ := lim(tower)` ([Eps0]),
+//!   the least fixpoint `ω^ε₀ == ε₀` ([eps0_fixpoint])
+
+use super::*;
+use natp::{Nat, Succ, Zero};
+use wf::{acc_in_arg, acc_intro, acc_rec, Acc, LtS, Wf};
+
+/// The type of ordinal numbers.
+#[derive(Copy, Clone)]
+pub struct Ordinal(());
+
+/// `ord : type(0)`.
+pub fn ord_ty() -> Ty<Ordinal, Type<Z>> {axiom!()}
+/// `is_const(ord)`.
+pub fn ord_is_const() -> IsConst<Ordinal> {axiom!()}
+
+/// Ordinal zero.
+#[derive(Copy, Clone)]
+pub struct OZero(());
+
+/// `0 : ord`.
+pub fn ozero_ty() -> Ty<OZero, Ordinal> {axiom!()}
+/// `is_const(0)`.
+pub fn ozero_is_const() -> IsConst<OZero> {axiom!()}
+
+/// Ordinal successor.
+#[derive(Copy, Clone)]
+pub struct FOSucc(());
+
+/// `succ(a)` for ordinals.
+pub type OSucc<A> = App<FOSucc, A>;
+
+/// `succ : ord -> ord`.
+pub fn osucc_ty() -> Ty<FOSucc, Pow<Ordinal, Ordinal>> {axiom!()}
+/// `is_const(succ)`.
+pub fn osucc_is_const() -> IsConst<FOSucc> {axiom!()}
+/// `a : ord  =>  succ(a) : ord`.
+pub fn osucc_app_ty<A: Prop>(ty_a: Ty<A, Ordinal>) -> Ty<OSucc<A>, Ordinal> {
+    app_fun_ty(osucc_ty(), ty_a)
+}
+/// `(a == b)  =>  (succ(a) == succ(b))`.
+pub fn osucc_eq<A: Prop, B: Prop>(x: Eq<A, B>) -> Eq<OSucc<A>, OSucc<B>> {app_eq(x)}
+
+/// Limit of a sequence of ordinals.
+#[derive(Copy, Clone)]
+pub struct FLim(());
+
+/// `lim(f)`.
+pub type Lim<F> = App<FLim, F>;
+
+/// `lim : (nat -> ord) -> ord`.
+pub fn lim_ty() -> Ty<FLim, Pow<Ordinal, Pow<Ordinal, Nat>>> {axiom!()}
+/// `is_const(lim)`.
+pub fn lim_is_const() -> IsConst<FLim> {axiom!()}
+/// `(f : nat -> ord)  =>  lim(f) : ord`.
+pub fn lim_app_ty<F: Prop>(ty_f: Ty<F, Pow<Ordinal, Nat>>) -> Ty<Lim<F>, Ordinal> {
+    app_fun_ty(lim_ty(), ty_f)
+}
+
+/// Ordinal ordering.
+#[derive(Copy, Clone)]
+pub struct FOLt(());
+
+/// `b <ₒ a`.
+pub type OLt<B, A> = App<FOLt, Tup<B, A>>;
+
+/// `is_const(<ₒ)`.
+pub fn olt_is_const() -> IsConst<FOLt> {axiom!()}
+/// `(b <ₒ 0)  =>  false`.
+pub fn para_olt_zero<B: Prop>(_: OLt<B, OZero>) -> False {axiom!()}
+/// `(a <ₒ a)  =>  false`.
+pub fn para_olt_refl<A: Prop>(_: OLt<A, A>) -> False {axiom!()}
+/// `(a <ₒ b) ⋀ (b <ₒ c)  =>  (a <ₒ c)`.
+pub fn olt_transitivity<A: Prop, B: Prop, C: Prop>(_: OLt<A, B>, _: OLt<B, C>) -> OLt<A, C> {
+    axiom!()
+}
+/// `a <ₒ succ(a)`.
+pub fn olt_succ<A: Prop>() -> OLt<A, OSucc<A>> {axiom!()}
+/// `(b <ₒ succ(a))  =>  (b <ₒ a) ⋁ (b == a)`.
+pub fn olt_succ_rev<A: Prop, B: Prop>(_: OLt<B, OSucc<A>>) -> Or<OLt<B, A>, Eq<B, A>> {axiom!()}
+/// `(f : nat -> ord) ⋀ (n : nat)  =>  f(n) <ₒ lim(f)`.
+pub fn olt_lim<F: Prop, N: Prop>(
+    _ty_f: Ty<F, Pow<Ordinal, Nat>>,
+    _ty_n: Ty<N, Nat>
+) -> OLt<App<F, N>, Lim<F>> {axiom!()}
+/// `<ₒ` is well-founded on ordinals.
+pub fn ord_wf<A: VProp>() -> Wf<FOLt, Ordinal, A> {axiom!()}
+/// Transfinite induction on ordinals.
+///
+/// ```text
+/// (p : ord -> type(0)) ⋀
+/// p(0)^true ⋀
+/// (p(a) => p(succ(a)))^(a : ord) ⋀
+/// (p(f(n))^(n : nat) => p(lim(f)))^(f : nat -> ord)
+/// --------------------------------------------------
+/// p(a)^(a : ord)
+/// ```
+pub fn ord_induction_ty<A: VProp, F: VProp, N: VProp, P: Prop, L: nat::Nat>(
+    _ty_p: Ty<P, Pow<Type<L>, Ordinal>>,
+    _case_zero: Tauto<App<P, OZero>>,
+    _case_succ: Pow<Imply<App<P, A>, App<P, OSucc<A>>>, Ty<A, Ordinal>>,
+    _case_lim: Pow<
+        Imply<Pow<App<P, App<F, N>>, Ty<N, Nat>>, App<P, Lim<F>>>,
+        Ty<F, Pow<Ordinal, Nat>>
+    >,
+) -> Pow<App<P, A>, Ty<A, Ordinal>> {axiom!()}
+
+/// `(b <ₒ a) ⋀ (a == c)  =>  (b <ₒ c)`.
+pub fn olt_in_right_arg<A: Prop, B: Prop, C: Prop>(x: OLt<B, A>, y: Eq<A, C>) -> OLt<B, C> {
+    app_eq(tup_eq_snd(y)).0(x)
+}
+/// `(b <ₒ a) ⋀ (b == c)  =>  (c <ₒ a)`.
+pub fn olt_in_left_arg<A: Prop, B: Prop, C: Prop>(x: OLt<B, A>, y: Eq<B, C>) -> OLt<C, A> {
+    app_eq(tup_eq_fst(y)).0(x)
+}
+/// `(a <ₒ b) ⋀ (b <ₒ a)  =>  false`.
+pub fn para_olt_sym<A: Prop, B: Prop>(x: OLt<A, B>, y: OLt<B, A>) -> False {
+    para_olt_refl(olt_transitivity(x, y))
+}
+/// `(b <ₒ a)  =>  (b <ₒ succ(a))`.
+pub fn olt_to_olt_succ<A: Prop, B: Prop>(x: OLt<B, A>) -> OLt<B, OSucc<A>> {
+    olt_transitivity(x, olt_succ())
+}
+/// `acc(<ₒ, 0)`.
+pub fn acc_olt_zero<B: VProp>() -> Acc<FOLt, OZero> {
+    acc_intro::<FOLt, OZero, B>(Rc::new(move |x| imply::absurd()(para_olt_zero(x))))
+}
+/// `acc(<ₒ, a)  =>  acc(<ₒ, succ(a))`.
+pub fn acc_olt_succ<A: Prop, B: VProp>(acc_a: Acc<FOLt, A>) -> Acc<FOLt, OSucc<A>> {
+    acc_intro::<FOLt, OSucc<A>, B>(Rc::new(move |x| match olt_succ_rev(x) {
+        Left(lt) => wf::acc_inv(acc_a.clone(), lt),
+        Right(eq) => acc_in_arg(acc_a.clone(), eq::symmetry(eq)),
+    }))
+}
+/// Transfinite recursion.
+///
+/// ```text
+/// p(a)^(b <ₒ a  =>  p(b))
+/// -----------------------
+/// p(a)^(a : ord)
+/// ```
+pub fn ord_rec<P: Prop, A: VProp, B: VProp>(
+    step: Pow<App<P, A>, Imply<OLt<B, A>, App<P, B>>>
+) -> Pow<App<P, A>, Ty<A, Ordinal>> {ord_wf().trans(acc_rec(step))}
+
+/// Embedding of natural numbers into ordinals.
+#[derive(Copy, Clone)]
+pub struct FOrdNat(());
+
+/// `emb(n)`.
+pub type OrdNat<N> = App<FOrdNat, N>;
+
+/// `emb : nat -> ord`.
+pub fn ord_nat_ty() -> Ty<FOrdNat, Pow<Ordinal, Nat>> {axiom!()}
+/// `is_const(emb)`.
+pub fn ord_nat_is_const() -> IsConst<FOrdNat> {axiom!()}
+/// `emb(0) == 0`.
+pub fn ord_nat_zero() -> Eq<OrdNat<Zero>, OZero> {axiom!()}
+/// `emb(succ(n)) == succ(emb(n))`.
+pub fn ord_nat_succ<N: Prop>() -> Eq<OrdNat<Succ<N>>, OSucc<OrdNat<N>>> {axiom!()}
+/// `(b <ₛ a)  =>  (emb(b) <ₒ emb(a))`.
+pub fn lt_s_to_olt<A: Prop, B: Prop>(x: LtS<B, A>) -> OLt<OrdNat<B>, OrdNat<A>> {
+    let eq_a_succ_b = wf::lt_s_def().0(x);
+    let y = eq::transitivity(app_eq(eq_a_succ_b), ord_nat_succ());
+    olt_in_right_arg(olt_succ(), eq::symmetry(y))
+}
+
+/// Omega `ω := lim(emb)`.
+pub type Omega = Lim<FOrdNat>;
+
+/// `ω : ord`.
+pub fn omega_ty() -> Ty<Omega, Ordinal> {lim_app_ty(ord_nat_ty())}
+/// `(n : nat)  =>  emb(n) <ₒ ω`.
+pub fn olt_ord_nat_omega<N: Prop>(ty_n: Ty<N, Nat>) -> OLt<OrdNat<N>, Omega> {
+    olt_lim(ord_nat_ty(), ty_n)
+}
+
+/// Exponentiation with base omega.
+#[derive(Copy, Clone)]
+pub struct FOmegaPow(());
+
+/// `ω^a`.
+pub type OmegaPow<A> = App<FOmegaPow, A>;
+
+/// `ω^_ : ord -> ord`.
+pub fn omega_pow_ty() -> Ty<FOmegaPow, Pow<Ordinal, Ordinal>> {axiom!()}
+/// `is_const(ω^_)`.
+pub fn omega_pow_is_const() -> IsConst<FOmegaPow> {axiom!()}
+/// `ω^0 == succ(0)`.
+pub fn omega_pow_zero() -> Eq<OmegaPow<OZero>, OSucc<OZero>> {axiom!()}
+/// `(b <ₒ a)  =>  (ω^b <ₒ ω^a)`.
+pub fn omega_pow_olt<A: Prop, B: Prop>(_: OLt<B, A>) -> OLt<OmegaPow<B>, OmegaPow<A>> {axiom!()}
+/// `ω^succ(0) == ω`.
+pub fn omega_pow_one() -> Eq<OmegaPow<OSucc<OZero>>, Omega> {axiom!()}
+
+/// Tower of omega exponentiation.
+#[derive(Copy, Clone)]
+pub struct FTower(());
+
+/// `tower(n)`.
+pub type Tower<N> = App<FTower, N>;
+
+/// `tower : nat -> ord`.
+pub fn tower_ty() -> Ty<FTower, Pow<Ordinal, Nat>> {axiom!()}
+/// `is_const(tower)`.
+pub fn tower_is_const() -> IsConst<FTower> {axiom!()}
+/// `tower(0) == 0`.
+pub fn tower_zero() -> Eq<Tower<Zero>, OZero> {axiom!()}
+/// `tower(succ(n)) == ω^tower(n)`.
+pub fn tower_succ<N: Prop>() -> Eq<Tower<Succ<N>>, OmegaPow<Tower<N>>> {axiom!()}
+
+/// Epsilon zero `ε₀ := lim(tower)`.
+pub type Eps0 = Lim<FTower>;
+
+/// `ε₀ : ord`.
+pub fn eps0_ty() -> Ty<Eps0, Ordinal> {lim_app_ty(tower_ty())}
+/// `ω^ε₀ == ε₀`.
+pub fn eps0_fixpoint() -> Eq<OmegaPow<Eps0>, Eps0> {axiom!()}
+/// `(a == ω^a) ⋀ (a <ₒ ε₀)  =>  false`.
+///
+/// `ε₀` is the least fixpoint of `ω^_`.
+pub fn para_omega_pow_fixpoint_lt_eps0<A: Prop>(_: Eq<A, OmegaPow<A>>, _: OLt<A, Eps0>) -> False {
+    axiom!()
+}
+/// `(n : nat)  =>  tower(n) <ₒ ε₀`.
+pub fn olt_tower_eps0<N: Prop>(ty_n: Ty<N, Nat>) -> OLt<Tower<N>, Eps0> {olt_lim(tower_ty(), ty_n)}
+/// `ω <ₒ ε₀`.
+pub fn olt_omega_eps0() -> OLt<Omega, Eps0> {
+    use natp::{One, Two};
+
+    // tower(2) == ω^tower(1) == ω^ω^tower(0) == ω^ω^0 == ω^succ(0) == ω.
+    let eq_tower_one: Eq<Tower<One>, OSucc<OZero>> =
+        eq::transitivity(eq::transitivity(tower_succ(), app_eq(tower_zero())), omega_pow_zero());
+    let eq_tower_two: Eq<Tower<Two>, Omega> =
+        eq::transitivity(eq::transitivity(tower_succ(), app_eq(eq_tower_one)), omega_pow_one());
+    let ty_two = natp::succ_app_ty(natp::succ_app_ty(natp::zero_ty()));
+    olt_in_left_arg(olt_tower_eps0(ty_two), eq_tower_two)
+}
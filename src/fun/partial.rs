@@ -0,0 +1,114 @@
+//! # Partial Functions
+//!
+//! A partial function `f : x ⇀ y` ([PartialFun]) is only defined for some arguments.
+//! The domain predicate `defined(f, a)` ([Defined]) means that `f(a)` has a value.
+//! Application is only typed under definedness ([partial_app_ty]):
+//!
+//! `(f : x ⇀ y) ⋀ (a : x) ⋀ defined(f, a)  =>  f(a) : y`
+//!
+//! Every function is a partial function ([partial_lift]),
+//! and a partial function that is defined everywhere is a function ([partial_total]).
+//!
+//! ### Imaginary Inverse
+//!
+//! The imaginary inverse `inv(f)` (see [fun::inv]) is a partial function,
+//! which is defined on the image of `f` when `~inv(f)` ([inv_defined]),
+//! and only there ([inv_defined_image]).
+//!
+//! When `f` is not surjective, there is some `b` outside the image of `f`,
+//! such that `inv(f)` is not defined at `b` ([para_inv_defined]).
+//! If some `g` is defined at `b`, then `false^(inv(f) ~~ g)` ([para_inv_q_defined]).
+
+use super::*;
+
+/// Partial function type symbol.
+#[derive(Copy, Clone)]
+pub struct FPartial(());
+
+/// `x ⇀ y`.
+pub type Partial<Y, X> = App<FPartial, Tup<X, Y>>;
+
+/// `f : x ⇀ y`.
+pub type PartialFun<F, X, Y> = Ty<F, Partial<Y, X>>;
+
+/// Definedness symbol.
+#[derive(Copy, Clone)]
+pub struct FDefined(());
+
+/// `defined(f, a)`.
+pub type Defined<F, A> = App<App<FDefined, F>, A>;
+
+/// `is_const(⇀)`.
+pub fn partial_is_const() -> IsConst<FPartial> {axiom!()}
+/// `is_const(defined)`.
+pub fn defined_is_const() -> IsConst<FDefined> {axiom!()}
+/// `(f : x -> y)  =>  (f : x ⇀ y)`.
+pub fn partial_lift<F: Prop, X: Prop, Y: Prop>(
+    _ty_f: Ty<F, Pow<Y, X>>
+) -> PartialFun<F, X, Y> {axiom!()}
+/// `(f : x ⇀ y) ⋀ (a : x) ⋀ defined(f, a)  =>  f(a) : y`.
+pub fn partial_app_ty<F: Prop, X: Prop, Y: Prop, A: Prop>(
+    _ty_f: PartialFun<F, X, Y>,
+    _ty_a: Ty<A, X>,
+    _: Defined<F, A>
+) -> Ty<App<F, A>, Y> {axiom!()}
+/// `(f : x ⇀ y) ⋀ defined(f, a)^(a : x)  =>  (f : x -> y)`.
+pub fn partial_total<F: Prop, X: Prop, Y: Prop, A: VProp>(
+    _ty_f: PartialFun<F, X, Y>,
+    _: Pow<Defined<F, A>, Ty<A, X>>
+) -> Ty<F, Pow<Y, X>> {axiom!()}
+/// `~inv(f) ⋀ (f(a) == b)  =>  defined(inv(f), b)`.
+pub fn inv_defined<F: Prop, A: Prop, B: Prop>(
+    _: Qu<Inv<F>>,
+    _: Eq<App<F, A>, B>
+) -> Defined<Inv<F>, B> {axiom!()}
+/// `(f : x -> y) ⋀ defined(inv(f), b)  =>  ∃ a : x { f(a) == b }`.
+pub fn inv_defined_image<F: Prop, X: Prop, Y: Prop, A: Prop, B: Prop>(
+    _ty_f: Ty<F, Pow<Y, X>>,
+    _: Defined<Inv<F>, B>
+) -> Exists<Ty<A, X>, Eq<App<F, A>, B>> {axiom!()}
+
+/// `defined(f, a) ⋀ (a == b)  =>  defined(f, b)`.
+pub fn defined_in_arg<F: Prop, A: Prop, B: Prop>(x: Defined<F, A>, y: Eq<A, B>) -> Defined<F, B> {
+    app_eq(y).0(x)
+}
+/// `defined(f, a) ⋀ (f == g)  =>  defined(g, a)`.
+pub fn defined_in_fun<F: Prop, G: Prop, A: Prop>(x: Defined<F, A>, y: Eq<F, G>) -> Defined<G, A> {
+    app_map_eq(app_eq(y)).0(x)
+}
+/// `(f : x -> y)  =>  (inv(f) : y ⇀ x)`.
+pub fn inv_partial<F: Prop, X: Prop, Y: Prop>(ty_f: Ty<F, Pow<Y, X>>) -> PartialFun<Inv<F>, Y, X> {
+    partial_lift(inv_ty(ty_f))
+}
+/// `(inv(f) ~~ g) ⋀ (f(a) == b)  =>  defined(inv(f), b)`.
+pub fn q_inv_defined<F: Prop, G: Prop, A: Prop, B: Prop>(
+    x: Q<Inv<F>, G>,
+    y: Eq<App<F, A>, B>
+) -> Defined<Inv<F>, B> {inv_defined(Qu::<Inv<F>>::from_q(quality::left(x)), y)}
+/// `(inv(f) ~~ g) ⋀ defined(g, b)  =>  defined(inv(f), b)`.
+pub fn q_defined<F: Prop, G: Prop, B: Prop>(
+    x: Q<Inv<F>, G>,
+    y: Defined<G, B>
+) -> Defined<Inv<F>, B> {defined_in_fun(y, eq::symmetry(quality::to_eq(x)))}
+/// `(f : x -> y)^true ⋀ false^(∃ a : x { f(a) == b })  =>  false^defined(inv(f), b)`.
+pub fn para_inv_defined<F: Prop, X: Prop, Y: Prop, A: Prop, B: Prop>(
+    ty_f: Tauto<Ty<F, Pow<Y, X>>>,
+    x: Para<Exists<Ty<A, X>, Eq<App<F, A>, B>>>
+) -> Para<Defined<Inv<F>, B>> {
+    fn f<F: Prop, X: Prop, Y: Prop, A: Prop, B: Prop>(
+        y: Defined<Inv<F>, B>
+    ) -> Imply<Ty<F, Pow<Y, X>>, Exists<Ty<A, X>, Eq<App<F, A>, B>>> {
+        Rc::new(move |ty_f| inv_defined_image(ty_f, y.clone()))
+    }
+    hooo::hooo_imply(f)(hooo::tr().trans(ty_f)).trans(x)
+}
+/// `false^defined(inv(f), b) ⋀ defined(g, b)^true  =>  false^(inv(f) ~~ g)`.
+pub fn para_inv_q_defined<F: Prop, G: Prop, B: Prop>(
+    x: Para<Defined<Inv<F>, B>>,
+    y: Tauto<Defined<G, B>>
+) -> Para<Q<Inv<F>, G>> {
+    fn f<F: Prop, G: Prop, B: Prop>(q: Q<Inv<F>, G>) -> Imply<Defined<G, B>, Defined<Inv<F>, B>> {
+        Rc::new(move |y| q_defined(q.clone(), y))
+    }
+    hooo::hooo_imply(f)(hooo::tr().trans(y)).trans(x)
+}
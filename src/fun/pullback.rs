@@ -0,0 +1,244 @@
+//! # Pullbacks and Pushouts
+//!
+//! A pullback of `f : a -> c` and `g : b -> c` is an object `p` with projections
+//! `p1 : p -> a` and `p2 : p -> b`, such that `f . p1 == g . p2` ([pullback_commute]),
+//! which is universal: For every `h : d -> a` and `k : d -> b` with `f . h == g . k`,
+//! there is a mediating map `med(p, h, k) : d -> p` ([PbMed], [pullback_med_ty])
+//! that commutes with the projections and is unique ([pullback_med_uniq]).
+//!
+//! A pushout of `f : c -> a` and `g : c -> b` is the dual construction,
+//! with injections `i1 : a -> p`, `i2 : b -> p` and the mediating map `comed(p, h, k) : p -> d`
+//! ([PoMed]).
+//!
+//! Existence:
+//!
+//! - The pullback `pullback(f, g)` ([Pullback]) is the subtype of tuples `(a, b)`
+//!   where `f(a) == g(b)`, with the projections `fst` and `snd` ([tup_is_pullback])
+//! - The pushout `pushout(f, g)` ([Pushout]) is a quotient of `a ⋁ b` by `q` ([PushQuot]),
+//!   with the injections `q . inl` and `q . inr` ([either_is_pushout])
+//!
+//! Pullbacks and pushouts are unique up to path semantical quality
+//! ([pullback_q], [pushout_q]).
+
+use super::*;
+use sub::Sub;
+
+/// Pullback predicate.
+#[derive(Copy, Clone)]
+pub struct FIsPullback(());
+
+/// `is_pullback(p, p1, p2, f, g)`.
+pub type IsPullback<P, P1, P2, F, G> = App<FIsPullback, Tup3<P, Tup<P1, P2>, Tup<F, G>>>;
+
+/// Pushout predicate.
+#[derive(Copy, Clone)]
+pub struct FIsPushout(());
+
+/// `is_pushout(p, i1, i2, f, g)`.
+pub type IsPushout<P, I1, I2, F, G> = App<FIsPushout, Tup3<P, Tup<I1, I2>, Tup<F, G>>>;
+
+/// The mediating map `med(p, h, k)` of a pullback.
+#[derive(Clone)]
+pub struct PbMed<P, H, K>(P, H, K);
+
+/// The mediating map `comed(p, h, k)` of a pushout.
+#[derive(Clone)]
+pub struct PoMed<P, H, K>(P, H, K);
+
+/// The pullback type `pullback(f, g)`.
+#[derive(Clone)]
+pub struct Pullback<F, G>(F, G);
+
+/// The pushout type `pushout(f, g)`.
+#[derive(Clone)]
+pub struct Pushout<F, G>(F, G);
+
+/// Left injection.
+#[derive(Copy, Clone)]
+pub struct Inl(());
+
+/// Right injection.
+#[derive(Copy, Clone)]
+pub struct Inr(());
+
+/// The quotient map `q(f, g) : (a ⋁ b) -> pushout(f, g)`.
+#[derive(Clone)]
+pub struct PushQuot<F, G>(F, G);
+
+/// `is_const(is_pullback)`.
+pub fn is_pullback_is_const() -> IsConst<FIsPullback> {axiom!()}
+/// `is_const(is_pushout)`.
+pub fn is_pushout_is_const() -> IsConst<FIsPushout> {axiom!()}
+/// `is_const(inl)`.
+pub fn inl_is_const() -> IsConst<Inl> {axiom!()}
+/// `is_const(inr)`.
+pub fn inr_is_const() -> IsConst<Inr> {axiom!()}
+/// `inl : a -> (a ⋁ b)`.
+pub fn inl_ty<A: Prop, B: Prop>() -> Ty<Inl, Pow<Or<A, B>, A>> {axiom!()}
+/// `inr : b -> (a ⋁ b)`.
+pub fn inr_ty<A: Prop, B: Prop>() -> Ty<Inr, Pow<Or<A, B>, B>> {axiom!()}
+
+/// `is_pullback(p, p1, p2, f, g) ⋀ (p1 : p -> a)  =>  (f . p1) == (g . p2)`.
+pub fn pullback_commute<P: Prop, P1: Prop, P2: Prop, F: Prop, G: Prop, A: Prop>(
+    _: IsPullback<P, P1, P2, F, G>,
+    _ty_p1: Ty<P1, Pow<A, P>>
+) -> Eq<Comp<F, P1>, Comp<G, P2>> {axiom!()}
+/// `is_pullback(p, p1, p2, f, g) ⋀ (h : d -> a) ⋀ (k : d -> b) ⋀ ((f . h) == (g . k))  =>
+/// med(p, h, k) : d -> p`.
+pub fn pullback_med_ty<P: Prop, P1: Prop, P2: Prop, F: Prop, G: Prop,
+                       H: Prop, K: Prop, A: Prop, B: Prop, D: Prop>(
+    _: IsPullback<P, P1, P2, F, G>,
+    _ty_h: Ty<H, Pow<A, D>>,
+    _ty_k: Ty<K, Pow<B, D>>,
+    _: Eq<Comp<F, H>, Comp<G, K>>
+) -> Ty<PbMed<P, H, K>, Pow<P, D>> {axiom!()}
+/// `is_pullback(p, p1, p2, f, g) ⋀ (h : d -> a) ⋀ (k : d -> b) ⋀ ((f . h) == (g . k))  =>
+/// (p1 . med(p, h, k)) == h`.
+pub fn pullback_med_fst<P: Prop, P1: Prop, P2: Prop, F: Prop, G: Prop,
+                        H: Prop, K: Prop, A: Prop, B: Prop, D: Prop>(
+    _: IsPullback<P, P1, P2, F, G>,
+    _ty_h: Ty<H, Pow<A, D>>,
+    _ty_k: Ty<K, Pow<B, D>>,
+    _: Eq<Comp<F, H>, Comp<G, K>>
+) -> Eq<Comp<P1, PbMed<P, H, K>>, H> {axiom!()}
+/// `is_pullback(p, p1, p2, f, g) ⋀ (h : d -> a) ⋀ (k : d -> b) ⋀ ((f . h) == (g . k))  =>
+/// (p2 . med(p, h, k)) == k`.
+pub fn pullback_med_snd<P: Prop, P1: Prop, P2: Prop, F: Prop, G: Prop,
+                        H: Prop, K: Prop, A: Prop, B: Prop, D: Prop>(
+    _: IsPullback<P, P1, P2, F, G>,
+    _ty_h: Ty<H, Pow<A, D>>,
+    _ty_k: Ty<K, Pow<B, D>>,
+    _: Eq<Comp<F, H>, Comp<G, K>>
+) -> Eq<Comp<P2, PbMed<P, H, K>>, K> {axiom!()}
+/// `is_pullback(p, p1, p2, f, g) ⋀ (u : d -> p) ⋀ ((p1 . u) == h) ⋀ ((p2 . u) == k)  =>
+/// u == med(p, h, k)`.
+pub fn pullback_med_uniq<P: Prop, P1: Prop, P2: Prop, F: Prop, G: Prop,
+                         H: Prop, K: Prop, U: Prop, D: Prop>(
+    _: IsPullback<P, P1, P2, F, G>,
+    _ty_u: Ty<U, Pow<P, D>>,
+    _: Eq<Comp<P1, U>, H>,
+    _: Eq<Comp<P2, U>, K>
+) -> Eq<U, PbMed<P, H, K>> {axiom!()}
+/// `is_pullback(p, p1, p2, f, g) ⋀ is_pullback(q, q1, q2, f, g)  =>  p ~~ q`.
+pub fn pullback_q<P: Prop, P1: Prop, P2: Prop, Q1: Prop, Q2: Prop, R: Prop, F: Prop, G: Prop>(
+    _: IsPullback<P, P1, P2, F, G>,
+    _: IsPullback<R, Q1, Q2, F, G>
+) -> Q<P, R> {axiom!()}
+/// `pullback(f, g) <: (a, b)`.
+pub fn pullback_sub<F: Prop, G: Prop, A: Prop, B: Prop>() -> Sub<Pullback<F, G>, Tup<A, B>> {
+    axiom!()
+}
+/// `(f : a -> c) ⋀ (g : b -> c) ⋀ (t : (a, b)) ⋀ (f(fst(t)) == g(snd(t)))  =>
+/// t : pullback(f, g)`.
+pub fn pullback_ty<F: Prop, G: Prop, A: Prop, B: Prop, C: Prop, T: Prop>(
+    _ty_f: Ty<F, Pow<C, A>>,
+    _ty_g: Ty<G, Pow<C, B>>,
+    _ty_t: Ty<T, Tup<A, B>>,
+    _: Eq<App<F, App<Fst, T>>, App<G, App<Snd, T>>>
+) -> Ty<T, Pullback<F, G>> {axiom!()}
+/// `(f : a -> c) ⋀ (g : b -> c)  =>  is_pullback(pullback(f, g), fst, snd, f, g)`.
+pub fn tup_is_pullback<F: Prop, G: Prop, A: Prop, B: Prop, C: Prop>(
+    _ty_f: Ty<F, Pow<C, A>>,
+    _ty_g: Ty<G, Pow<C, B>>
+) -> IsPullback<Pullback<F, G>, Fst, Snd, F, G> {axiom!()}
+
+/// `is_pushout(p, i1, i2, f, g) ⋀ (f : c -> a)  =>  (i1 . f) == (i2 . g)`.
+pub fn pushout_commute<P: Prop, I1: Prop, I2: Prop, F: Prop, G: Prop, A: Prop, C: Prop>(
+    _: IsPushout<P, I1, I2, F, G>,
+    _ty_f: Ty<F, Pow<A, C>>
+) -> Eq<Comp<I1, F>, Comp<I2, G>> {axiom!()}
+/// `is_pushout(p, i1, i2, f, g) ⋀ (h : a -> d) ⋀ (k : b -> d) ⋀ ((h . f) == (k . g))  =>
+/// comed(p, h, k) : p -> d`.
+pub fn pushout_med_ty<P: Prop, I1: Prop, I2: Prop, F: Prop, G: Prop,
+                      H: Prop, K: Prop, A: Prop, B: Prop, D: Prop>(
+    _: IsPushout<P, I1, I2, F, G>,
+    _ty_h: Ty<H, Pow<D, A>>,
+    _ty_k: Ty<K, Pow<D, B>>,
+    _: Eq<Comp<H, F>, Comp<K, G>>
+) -> Ty<PoMed<P, H, K>, Pow<D, P>> {axiom!()}
+/// `is_pushout(p, i1, i2, f, g) ⋀ (h : a -> d) ⋀ (k : b -> d) ⋀ ((h . f) == (k . g))  =>
+/// (comed(p, h, k) . i1) == h`.
+pub fn pushout_med_inl<P: Prop, I1: Prop, I2: Prop, F: Prop, G: Prop,
+                       H: Prop, K: Prop, A: Prop, B: Prop, D: Prop>(
+    _: IsPushout<P, I1, I2, F, G>,
+    _ty_h: Ty<H, Pow<D, A>>,
+    _ty_k: Ty<K, Pow<D, B>>,
+    _: Eq<Comp<H, F>, Comp<K, G>>
+) -> Eq<Comp<PoMed<P, H, K>, I1>, H> {axiom!()}
+/// `is_pushout(p, i1, i2, f, g) ⋀ (h : a -> d) ⋀ (k : b -> d) ⋀ ((h . f) == (k . g))  =>
+/// (comed(p, h, k) . i2) == k`.
+pub fn pushout_med_inr<P: Prop, I1: Prop, I2: Prop, F: Prop, G: Prop,
+                       H: Prop, K: Prop, A: Prop, B: Prop, D: Prop>(
+    _: IsPushout<P, I1, I2, F, G>,
+    _ty_h: Ty<H, Pow<D, A>>,
+    _ty_k: Ty<K, Pow<D, B>>,
+    _: Eq<Comp<H, F>, Comp<K, G>>
+) -> Eq<Comp<PoMed<P, H, K>, I2>, K> {axiom!()}
+/// `is_pushout(p, i1, i2, f, g) ⋀ (u : p -> d) ⋀ ((u . i1) == h) ⋀ ((u . i2) == k)  =>
+/// u == comed(p, h, k)`.
+pub fn pushout_med_uniq<P: Prop, I1: Prop, I2: Prop, F: Prop, G: Prop,
+                        H: Prop, K: Prop, U: Prop, D: Prop>(
+    _: IsPushout<P, I1, I2, F, G>,
+    _ty_u: Ty<U, Pow<D, P>>,
+    _: Eq<Comp<U, I1>, H>,
+    _: Eq<Comp<U, I2>, K>
+) -> Eq<U, PoMed<P, H, K>> {axiom!()}
+/// `is_pushout(p, i1, i2, f, g) ⋀ is_pushout(q, j1, j2, f, g)  =>  p ~~ q`.
+pub fn pushout_q<P: Prop, I1: Prop, I2: Prop, J1: Prop, J2: Prop, R: Prop, F: Prop, G: Prop>(
+    _: IsPushout<P, I1, I2, F, G>,
+    _: IsPushout<R, J1, J2, F, G>
+) -> Q<P, R> {axiom!()}
+/// `(f : c -> a) ⋀ (g : c -> b)  =>  q(f, g) : (a ⋁ b) -> pushout(f, g)`.
+pub fn push_quot_ty<F: Prop, G: Prop, A: Prop, B: Prop, C: Prop>(
+    _ty_f: Ty<F, Pow<A, C>>,
+    _ty_g: Ty<G, Pow<B, C>>
+) -> Ty<PushQuot<F, G>, Pow<Pushout<F, G>, Or<A, B>>> {axiom!()}
+/// `(f : c -> a) ⋀ (g : c -> b)  =>
+/// is_pushout(pushout(f, g), q(f, g) . inl, q(f, g) . inr, f, g)`.
+pub fn either_is_pushout<F: Prop, G: Prop, A: Prop, B: Prop, C: Prop>(
+    _ty_f: Ty<F, Pow<A, C>>,
+    _ty_g: Ty<G, Pow<B, C>>
+) -> IsPushout<Pushout<F, G>, Comp<PushQuot<F, G>, Inl>, Comp<PushQuot<F, G>, Inr>, F, G> {
+    axiom!()
+}
+
+/// `is_pullback(p, p1, p2, f, g) ⋀ (u : d -> p) ⋀ (v : d -> p) ⋀
+/// ((p1 . u) == (p1 . v)) ⋀ ((p2 . u) == (p2 . v))  =>  u == v`.
+///
+/// Maps into a pullback are equal when their projections are equal.
+pub fn pullback_ext<P: Prop, P1: Prop, P2: Prop, F: Prop, G: Prop, U: Prop, V: Prop, D: Prop>(
+    x: IsPullback<P, P1, P2, F, G>,
+    ty_u: Ty<U, Pow<P, D>>,
+    ty_v: Ty<V, Pow<P, D>>,
+    eq_fst: Eq<Comp<P1, U>, Comp<P1, V>>,
+    eq_snd: Eq<Comp<P2, U>, Comp<P2, V>>
+) -> Eq<U, V> {
+    let u = pullback_med_uniq(x.clone(), ty_u, eq_fst, eq_snd);
+    let v = pullback_med_uniq(x, ty_v, eq::refl(), eq::refl());
+    eq::transitivity(u, eq::symmetry(v))
+}
+/// `is_pushout(p, i1, i2, f, g) ⋀ (u : p -> d) ⋀ (v : p -> d) ⋀
+/// ((u . i1) == (v . i1)) ⋀ ((u . i2) == (v . i2))  =>  u == v`.
+///
+/// Maps out of a pushout are equal when they agree on the injections.
+pub fn pushout_ext<P: Prop, I1: Prop, I2: Prop, F: Prop, G: Prop, U: Prop, V: Prop, D: Prop>(
+    x: IsPushout<P, I1, I2, F, G>,
+    ty_u: Ty<U, Pow<D, P>>,
+    ty_v: Ty<V, Pow<D, P>>,
+    eq_inl: Eq<Comp<U, I1>, Comp<V, I1>>,
+    eq_inr: Eq<Comp<U, I2>, Comp<V, I2>>
+) -> Eq<U, V> {
+    let u = pushout_med_uniq(x.clone(), ty_u, eq_inl, eq_inr);
+    let v = pushout_med_uniq(x, ty_v, eq::refl(), eq::refl());
+    eq::transitivity(u, eq::symmetry(v))
+}
+/// `is_pullback(p, p1, p2, f, g) ⋀ is_pullback(q, q1, q2, f, g)  =>  p == q`.
+pub fn pullback_eq<P: Prop, P1: Prop, P2: Prop, Q1: Prop, Q2: Prop, R: Prop, F: Prop, G: Prop>(
+    x: IsPullback<P, P1, P2, F, G>,
+    y: IsPullback<R, Q1, Q2, F, G>
+) -> Eq<P, R> {quality::to_eq(pullback_q(x, y))}
+/// `is_pushout(p, i1, i2, f, g) ⋀ is_pushout(q, j1, j2, f, g)  =>  p == q`.
+pub fn pushout_eq<P: Prop, I1: Prop, I2: Prop, J1: Prop, J2: Prop, R: Prop, F: Prop, G: Prop>(
+    x: IsPushout<P, I1, I2, F, G>,
+    y: IsPushout<R, J1, J2, F, G>
+) -> Eq<P, R> {quality::to_eq(pushout_q(x, y))}
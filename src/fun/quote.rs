@@ -0,0 +1,85 @@
+//! # Quoting
+//!
+//! Quoting `quote(a)` ([Quote]) turns an object-level term `a` into meta-level code,
+//! while unquoting `unquote(a)` ([Unquote]) goes in the opposite direction.
+//!
+//! Quoting shifts the level of types by one:
+//!
+//! `quote(type(n)) == type(n+1)` ([quote_type])
+//!
+//! This means that when `x : type(n)`, the code `quote(x) : type(n+1)` ([quote_type_ty]).
+//! A quoted type always lives at a higher level than the type itself,
+//! such that self-referential constructions can not collapse levels.
+//!
+//! Round-trips are given by [unquote_quote] and [quote_unquote].
+//! The latter requires `a` to be code of some quoted type.
+
+use super::*;
+
+/// Quote.
+#[derive(Copy, Clone)]
+pub struct FQuote(());
+
+/// `quote(a)`.
+pub type Quote<A> = App<FQuote, A>;
+
+/// Unquote.
+#[derive(Copy, Clone)]
+pub struct FUnquote(());
+
+/// `unquote(a)`.
+pub type Unquote<A> = App<FUnquote, A>;
+
+/// `is_const(quote)`.
+pub fn quote_is_const() -> IsConst<FQuote> {axiom!()}
+/// `is_const(unquote)`.
+pub fn unquote_is_const() -> IsConst<FUnquote> {axiom!()}
+/// `(a : x)  =>  quote(a) : quote(x)`.
+pub fn quote_ty<A: Prop, X: Prop>(_: Ty<A, X>) -> Ty<Quote<A>, Quote<X>> {axiom!()}
+/// `(a : quote(x))  =>  unquote(a) : x`.
+pub fn unquote_ty<A: Prop, X: Prop>(_: Ty<A, Quote<X>>) -> Ty<Unquote<A>, X> {axiom!()}
+/// `quote(type(n)) == type(n+1)`.
+pub fn quote_type<N: Nat>() -> Eq<Quote<Type<N>>, Type<S<N>>> {axiom!()}
+/// `(x : type(n))  =>  quote(x) : type(n+1)`.
+pub fn quote_type_ty<X: Prop, N: Nat>(_: Ty<X, Type<N>>) -> Ty<Quote<X>, Type<S<N>>> {
+    axiom!()
+}
+/// `unquote(quote(a)) == a`.
+pub fn unquote_quote<A: Prop>() -> Eq<Unquote<Quote<A>>, A> {axiom!()}
+/// `(a : quote(x))  =>  quote(unquote(a)) == a`.
+pub fn quote_unquote<A: Prop, X: Prop>(_: Ty<A, Quote<X>>) -> Eq<Quote<Unquote<A>>, A> {
+    axiom!()
+}
+
+/// `unquote(type(n+1)) == type(n)`.
+pub fn unquote_type<N: Nat>() -> Eq<Unquote<Type<S<N>>>, Type<N>> {
+    eq::in_left_arg(unquote_quote(), app_eq(quote_type()))
+}
+/// `is_const(a)  =>  is_const(quote(a))`.
+pub fn quote_app_is_const<A: Prop>(x: IsConst<A>) -> IsConst<Quote<A>> {
+    app_is_const(quote_is_const(), x)
+}
+/// `is_const(a)  =>  is_const(unquote(a))`.
+pub fn unquote_app_is_const<A: Prop>(x: IsConst<A>) -> IsConst<Unquote<A>> {
+    app_is_const(unquote_is_const(), x)
+}
+/// `(a == b)  =>  (quote(a) == quote(b))`.
+pub fn quote_eq<A: Prop, B: Prop>(x: Eq<A, B>) -> Eq<Quote<A>, Quote<B>> {app_eq(x)}
+/// `(quote(a) == quote(b))  =>  (a == b)`.
+pub fn quote_rev_eq<A: Prop, B: Prop>(x: Eq<Quote<A>, Quote<B>>) -> Eq<A, B> {
+    let x: Eq<Unquote<Quote<A>>, Unquote<Quote<B>>> = app_eq(x);
+    eq::in_right_arg(eq::in_left_arg(x, unquote_quote()), unquote_quote())
+}
+/// `(a : x)  =>  unquote(quote(a)) : x`.
+pub fn unquote_quote_ty<A: Prop, X: Prop>(x: Ty<A, X>) -> Ty<Unquote<Quote<A>>, X> {
+    unquote_ty(quote_ty(x))
+}
+/// `(a : quote(x)) ⋀ (b : quote(x)) ⋀ (unquote(a) == unquote(b))  =>  (a == b)`.
+pub fn unquote_rev_eq<A: Prop, B: Prop, X: Prop>(
+    ty_a: Ty<A, Quote<X>>,
+    ty_b: Ty<B, Quote<X>>,
+    x: Eq<Unquote<A>, Unquote<B>>
+) -> Eq<A, B> {
+    let x: Eq<Quote<Unquote<A>>, Quote<Unquote<B>>> = quote_eq(x);
+    eq::in_right_arg(eq::in_left_arg(x, quote_unquote(ty_a)), quote_unquote(ty_b))
+}
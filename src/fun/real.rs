@@ -12,13 +12,13 @@ pub struct Real(());
 pub struct Zero(());
 
 /// `real : type(0)`.
-pub fn real_ty() -> Ty<Real, Type<Z>> {unimplemented!()}
+pub fn real_ty() -> Ty<Real, Type<Z>> {axiom!()}
 /// `is_const(real)`.
-pub fn real_is_const() -> IsConst<Real> {unimplemented!()}
+pub fn real_is_const() -> IsConst<Real> {axiom!()}
 /// `0 : real`.
-pub fn zero_ty() -> Ty<Zero, Real> {unimplemented!()}
+pub fn zero_ty() -> Ty<Zero, Real> {axiom!()}
 /// `is_const(zero)`.
-pub fn zero_is_const() -> IsConst<Zero> {unimplemented!()}
+pub fn zero_is_const() -> IsConst<Zero> {axiom!()}
 
 /// `real_range(y) = (aleph(0) < y) & (y < aleph(0))`.
 pub type RealRange<Y> = App<FAnd, Tup<App<Lt, Tup<Aleph<Z>, Y>>, App<Lt, Tup<Y, Aleph<Z>>>>>;
@@ -41,7 +41,7 @@ pub type RealDef<X, A, Q, Y, P> = Pow<AddReal<X, A, Q, Y>, Aleph0Real<Y, P>>;
 /// Definition of real.
 pub fn real_def<X: Prop, A: Prop, Q: Prop, Y: Prop, P: Prop>(
     _ty_x: Ty<X, Real>
-) -> RealDef<X, A, Q, Y, P> {unimplemented!()}
+) -> RealDef<X, A, Q, Y, P> {axiom!()}
 
 /// Addition.
 #[derive(Copy, Clone)]
@@ -0,0 +1,86 @@
+//! # Records
+//!
+//! A record with fields of types `x0, x1, ..., xn` is the nested tuple
+//! `(x0, (x1, ... (xn, true)))`.
+//! The projection of field `i` is `fst . snd^i`, which is built up with [ProjNext].
+//!
+//! The [record!] macro declares a record type together with named projection symbols:
+//!
+//! ```rust
+//! use prop::*;
+//! use prop::fun::*;
+//!
+//! record!(
+//!     /// A point.
+//!     Point<X, Y> {
+//!         /// Projection of x-coordinate.
+//!         PointX,
+//!         /// Projection of y-coordinate.
+//!         PointY,
+//!     }
+//! );
+//!
+//! fn point_y<A: Prop, B: Prop>() -> Eq<App<PointY, Tup<A, Tup<B, True>>>, B> {
+//!     record::proj_next(fst_def())
+//! }
+//! ```
+//!
+//! Typing rules and definitions of projections follow from [proj_next_ty] and [proj_next_def].
+//! The eta law of records is built up field by field using [record_eta].
+
+use super::*;
+
+/// `p . snd`.
+///
+/// Projection of the next field.
+pub type ProjNext<P> = Comp<P, Snd>;
+
+/// Declares a record type with named projection symbols.
+///
+/// The record type `Name<X0, ..., Xn>` is `(x0, (x1, ... (xn, true)))`.
+/// The first projection is `fst`, the next is `fst . snd` and so on.
+#[macro_export]
+macro_rules! record(
+    (
+        $(#[$attr:meta])* $name:ident<$($arg:ident),*>
+        {$($(#[$fattr:meta])* $field:ident),* $(,)?}
+    ) => {
+        $(#[$attr])*
+        pub type $name<$($arg),*> = $crate::record!(@ty $($arg),*);
+        $crate::record!(@proj ($crate::fun::Fst) $($(#[$fattr])* $field),*);
+    };
+    (@ty) => {$crate::True};
+    (@ty $head:ident $(, $tail:ident)*) => {
+        $crate::fun::Tup<$head, $crate::record!(@ty $($tail),*)>
+    };
+    (@proj ($p:ty)) => {};
+    (@proj ($p:ty) $(#[$hattr:meta])* $head:ident $(, $(#[$tattr:meta])* $tail:ident)*) => {
+        $(#[$hattr])*
+        pub type $head = $p;
+        $crate::record!(@proj ($crate::fun::record::ProjNext<$p>) $($(#[$tattr])* $tail),*);
+    };
+);
+
+/// `(p : r -> x)  =>  (p . snd) : (a, r) -> x`.
+pub fn proj_next_ty<P: Prop, A: Prop, R: Prop, X: Prop>(
+    ty_p: Ty<P, Pow<X, R>>
+) -> Ty<ProjNext<P>, Pow<X, Tup<A, R>>> {comp_ty(snd_ty(), ty_p)}
+/// `(p . snd)((a, r)) == p(r)`.
+pub fn proj_next_def<P: Prop, A: Prop, R: Prop>() -> Eq<App<ProjNext<P>, Tup<A, R>>, App<P, R>> {
+    eq::transitivity(eq::symmetry(eq_app_comp()), app_eq(snd_def()))
+}
+/// `(p(r) == b)  =>  (p . snd)((a, r)) == b`.
+pub fn proj_next<P: Prop, A: Prop, R: Prop, B: Prop>(
+    x: Eq<App<P, R>, B>
+) -> Eq<App<ProjNext<P>, Tup<A, R>>, B> {eq::transitivity(proj_next_def(), x)}
+/// `(t : (x, r)) ⋀ (e == snd(t))  =>  (fst(t), e) == t`.
+///
+/// Eta law of records, where `e` is the eta-expansion of the remaining fields.
+pub fn record_eta<T: Prop, X: Prop, R: Prop, E: Prop>(
+    ty_t: Ty<T, Tup<X, R>>,
+    x: Eq<E, App<Snd, T>>
+) -> Eq<Tup<App<Fst, T>, E>, T> {eq::transitivity(tup_eq_snd(x), tup_eta(ty_t))}
+/// `(fst . snd)(t) == fst(snd(t))`.
+pub fn eq_proj_next_fst<T: Prop>() -> Eq<App<ProjNext<Fst>, T>, App<Fst, App<Snd, T>>> {
+    eq::symmetry(eq_app_comp())
+}
@@ -0,0 +1,59 @@
+//! # Refinement Types
+//!
+//! A refinement type `{a : x | p(a)}` ([Refine]) contains the members of `x` that satisfy `p`.
+//!
+//! - Introduction: `(a : x) ⋀ p(a)  =>  a : {x | p}` ([refine_intro])
+//! - Elimination: `(a : {x | p})  =>  (a : x) ⋀ p(a)` ([refine_elim])
+//! - Subtyping: `{x | p} <: x` ([refine_sub])
+//!
+//! The predicate `p(a)` is a proposition, using the same convention as the [dep] module.
+
+use super::*;
+use sub::{Sub, sub_ty};
+
+/// Refinement type `{x | p}`.
+#[derive(Copy, Clone)]
+pub struct Refine<X, P>(X, P);
+
+/// `(x : type(n))  =>  {x | p} : type(n)`.
+pub fn refine_ty<X: Prop, P: Prop, N: Nat>(_ty_x: Ty<X, Type<N>>) -> Ty<Refine<X, P>, Type<N>> {
+    axiom!()
+}
+/// `is_const(x) ⋀ is_const(p)  =>  is_const({x | p})`.
+pub fn refine_is_const<X: Prop, P: Prop>(
+    _x: IsConst<X>,
+    _p: IsConst<P>
+) -> IsConst<Refine<X, P>> {axiom!()}
+/// `(a : x) ⋀ p(a)  =>  a : {x | p}`.
+pub fn refine_intro<A: Prop, X: Prop, P: Prop>(
+    _ty_a: Ty<A, X>,
+    _p_a: App<P, A>
+) -> Ty<A, Refine<X, P>> {axiom!()}
+/// `(a : {x | p})  =>  p(a)`.
+pub fn refine_pred<A: Prop, X: Prop, P: Prop>(_ty_a: Ty<A, Refine<X, P>>) -> App<P, A> {axiom!()}
+/// `{x | p} <: x`.
+pub fn refine_sub<X: Prop, P: Prop>() -> Sub<Refine<X, P>, X> {axiom!()}
+
+/// `(a : {x | p})  =>  (a : x)`.
+pub fn refine_elim_ty<A: Prop, X: Prop, P: Prop>(ty_a: Ty<A, Refine<X, P>>) -> Ty<A, X> {
+    sub_ty(ty_a, refine_sub())
+}
+/// `(a : {x | p})  =>  (a : x) ⋀ p(a)`.
+pub fn refine_elim<A: Prop, X: Prop, P: Prop>(
+    ty_a: Ty<A, Refine<X, P>>
+) -> And<Ty<A, X>, App<P, A>> {(refine_elim_ty(ty_a.clone()), refine_pred(ty_a))}
+/// `(a : {x | p}) ⋀ (b : p(a))  =>  (a, b) : (x, p(a))`.
+///
+/// Pairs a member of a refinement type with evidence of the predicate.
+pub fn refine_tup_ty<A: Prop, B: Prop, X: Prop, P: Prop>(
+    ty_a: Ty<A, Refine<X, P>>,
+    ty_b: Ty<B, App<P, A>>
+) -> Ty<Tup<A, B>, Tup<X, App<P, A>>> {tup_ty(refine_elim_ty(ty_a), ty_b)}
+/// `(a : {x | p}) ⋀ (p(a) => q(a))  =>  a : {x | q}`.
+pub fn refine_weaken<A: Prop, X: Prop, P: Prop, Q: Prop>(
+    ty_a: Ty<A, Refine<X, P>>,
+    f: Imply<App<P, A>, App<Q, A>>
+) -> Ty<A, Refine<X, Q>> {
+    let (ty_a, p_a) = refine_elim(ty_a);
+    refine_intro(ty_a, f(p_a))
+}
@@ -0,0 +1,158 @@
+//! # Regular Expressions
+//!
+//! Words over an alphabet `x` are lists `list(x)` from the [list] module,
+//! built from `nil{x}`, `cons{x}(a, w)` and `concat{x}(v, w)`.
+//!
+//! A regular expression `r` matches a word `w` when `match(r, w)` ([Matches]).
+//! The regular expressions are built from:
+//!
+//! - `∅` ([Empty]) matches no word
+//! - `ε` ([Eps]) matches the empty word
+//! - `chr(a)` ([Chr]) matches the word `[a]`
+//! - `r | s` ([Union]) matches words of either `r` or `s`
+//! - `r s` ([Seq]) matches concatenated words
+//! - `r*` ([Star]) matches zero or more repetitions
+//!
+//! Matching of `r*` is the least fixed point of the rules [star_nil] and [star_cons].
+//! Like with [fixpoint::Lfp], this gives an induction principle ([star_ind]),
+//! which is used to derive star unfolding ([star_unfold]).
+
+use super::*;
+use hooo::Pow;
+use list::{concat_nil, nil_ty, Concat, Cons, List, Nil};
+
+/// Regular expression matching.
+#[derive(Copy, Clone)]
+pub struct FMatch(());
+
+/// `match(r, w)`.
+pub type Matches<R, W> = App<App<FMatch, R>, W>;
+
+/// `∅`.
+#[derive(Copy, Clone)]
+pub struct Empty(());
+
+/// `ε`.
+#[derive(Copy, Clone)]
+pub struct Eps(());
+
+/// `chr(a)`.
+#[derive(Copy, Clone)]
+pub struct Chr<A>(A);
+
+/// `r | s`.
+#[derive(Copy, Clone)]
+pub struct Union<R, S>(R, S);
+
+/// `r s`.
+#[derive(Copy, Clone)]
+pub struct Seq<R, S>(R, S);
+
+/// `r*`.
+#[derive(Copy, Clone)]
+pub struct Star<R>(R);
+
+/// `is_const(match)`.
+pub fn match_is_const() -> IsConst<FMatch> {axiom!()}
+/// `match(r, v) ⋀ (v == w)  =>  match(r, w)`.
+pub fn match_in_arg<R: Prop, V: Prop, W: Prop>(x: Matches<R, V>, y: Eq<V, W>) -> Matches<R, W> {
+    app_eq(y).0(x)
+}
+/// `¬match(∅, w)`.
+pub fn empty_no_match<W: Prop>() -> Not<Matches<Empty, W>> {axiom!()}
+/// `match(ε, nil{x})`.
+pub fn eps_match<X: Prop>() -> Matches<Eps, Nil<X>> {axiom!()}
+/// `(w : list(x)) ⋀ match(ε, w)  =>  (w == nil{x})`.
+pub fn eps_inv<X: Prop, W: Prop>(_: Ty<W, List<X>>, _: Matches<Eps, W>) -> Eq<W, Nil<X>> {
+    axiom!()
+}
+/// `match(chr(a), cons{x}(a, nil{x}))`.
+pub fn chr_match<X: Prop, A: Prop>() -> Matches<Chr<A>, Cons<X, A, Nil<X>>> {axiom!()}
+/// `(w : list(x)) ⋀ match(chr(a), w)  =>  (w == cons{x}(a, nil{x}))`.
+pub fn chr_inv<X: Prop, A: Prop, W: Prop>(
+    _: Ty<W, List<X>>,
+    _: Matches<Chr<A>, W>
+) -> Eq<W, Cons<X, A, Nil<X>>> {axiom!()}
+/// `match(r, w)  =>  match(r | s, w)`.
+pub fn union_left<R: Prop, S: Prop, W: Prop>(_: Matches<R, W>) -> Matches<Union<R, S>, W> {
+    axiom!()
+}
+/// `match(s, w)  =>  match(r | s, w)`.
+pub fn union_right<R: Prop, S: Prop, W: Prop>(_: Matches<S, W>) -> Matches<Union<R, S>, W> {
+    axiom!()
+}
+/// `match(r | s, w)  =>  match(r, w) ⋁ match(s, w)`.
+pub fn union_inv<R: Prop, S: Prop, W: Prop>(
+    _: Matches<Union<R, S>, W>
+) -> Or<Matches<R, W>, Matches<S, W>> {axiom!()}
+/// `match(r, v) ⋀ match(s, w)  =>  match(r s, concat{x}(v, w))`.
+pub fn seq_match<X: Prop, R: Prop, S: Prop, V: Prop, W: Prop>(
+    _: Matches<R, V>,
+    _: Matches<S, W>
+) -> Matches<Seq<R, S>, Concat<X, V, W>> {axiom!()}
+/// `match(r*, nil{x})`.
+pub fn star_nil<X: Prop, R: Prop>() -> Matches<Star<R>, Nil<X>> {axiom!()}
+/// `match(r, v) ⋀ match(r*, w)  =>  match(r*, concat{x}(v, w))`.
+pub fn star_cons<X: Prop, R: Prop, V: Prop, W: Prop>(
+    _: Matches<R, V>,
+    _: Matches<Star<R>, W>
+) -> Matches<Star<R>, Concat<X, V, W>> {axiom!()}
+/// `p(nil{x}) ⋀ (p(concat{x}(v, w))^(match(r, v) ⋀ match(r*, w) ⋀ p(w)))  =>
+///  p(u)^match(r*, u)`.
+///
+/// Induction principle of star.
+pub fn star_ind<X: Prop, R: Prop, P: Prop, V: VProp, W: VProp, U: Prop>(
+    _nil: App<P, Nil<X>>,
+    _step: Pow<App<P, Concat<X, V, W>>, And<Matches<R, V>, And<Matches<Star<R>, W>, App<P, W>>>>,
+) -> Pow<App<P, U>, Matches<Star<R>, U>> {axiom!()}
+
+/// `match(r | s, w)  =>  match(s | r, w)`.
+pub fn union_symmetry<R: Prop, S: Prop, W: Prop>(
+    x: Matches<Union<R, S>, W>
+) -> Matches<Union<S, R>, W> {
+    match union_inv(x) {
+        Left(x) => union_right(x),
+        Right(x) => union_left(x),
+    }
+}
+/// `match(∅ | r, w)  =>  match(r, w)`.
+pub fn union_empty<R: Prop, W: Prop>(x: Matches<Union<Empty, R>, W>) -> Matches<R, W> {
+    match union_inv(x) {
+        Left(x) => not::absurd(empty_no_match(), x),
+        Right(x) => x,
+    }
+}
+/// `(x : type(0)) ⋀ (w : list(x)) ⋀ match(s, w)  =>  match(ε s, w)`.
+pub fn seq_eps_left<X: Prop, S: Prop, W: Prop>(
+    ty_x: Ty<X, Type<Z>>,
+    ty_w: Ty<W, List<X>>,
+    x: Matches<S, W>
+) -> Matches<Seq<Eps, S>, W> {
+    let y: Matches<Seq<Eps, S>, Concat<X, Nil<X>, W>> = seq_match(eps_match(), x);
+    match_in_arg(y, concat_nil(nil_ty(ty_x), ty_w))
+}
+/// `match(r, w)  =>  match(r*, concat{x}(w, nil{x}))`.
+pub fn star_one<X: Prop, R: Prop, W: Prop>(
+    x: Matches<R, W>
+) -> Matches<Star<R>, Concat<X, W, Nil<X>>> {star_cons(x, star_nil())}
+/// `match(r*, u)  =>  match(ε | (r r*), u)`.
+///
+/// Star unfolding.
+pub fn star_unfold<X: Prop, R: Prop, V: VProp, W: VProp, U: Prop>(
+    x: Matches<Star<R>, U>
+) -> Matches<Union<Eps, Seq<R, Star<R>>>, U> {
+    fn step<X: Prop, R: Prop, V: Prop, W: Prop>(
+        (rv, (rw, _)): And<Matches<R, V>, And<Matches<Star<R>, W>, Matches<Union<Eps, Seq<R, Star<R>>>, W>>>
+    ) -> Matches<Union<Eps, Seq<R, Star<R>>>, Concat<X, V, W>> {union_right(seq_match(rv, rw))}
+    star_ind::<X, R, App<FMatch, Union<Eps, Seq<R, Star<R>>>>, V, W, U>(
+        union_left(eps_match()), step::<X, R, V, W>)(x)
+}
+/// `match(∅*, u)  =>  match(ε, u)`.
+pub fn star_empty<X: Prop, V: VProp, W: VProp, U: Prop>(
+    x: Matches<Star<Empty>, U>
+) -> Matches<Eps, U> {
+    fn step<X: Prop, V: Prop, W: Prop>(
+        (ev, _): And<Matches<Empty, V>, And<Matches<Star<Empty>, W>, Matches<Eps, W>>>
+    ) -> Matches<Eps, Concat<X, V, W>> {not::absurd(empty_no_match(), ev)}
+    star_ind::<X, Empty, App<FMatch, Eps>, V, W, U>(eps_match(), step::<X, V, W>)(x)
+}
@@ -0,0 +1,147 @@
+//! # Relations
+//!
+//! A relation `r` is written as a symbol applied to a tuple, `r(a, b)` ([Rel]),
+//! like the relations of well-founded recursion (see [fun::wf]).
+//! Relations are multi-valued functions, where `r(a, b)` means that `b` is a value of `r` at `a`.
+//!
+//! Every function `f` is a relation `rel(f)` ([RelFun]),
+//! where `rel(f)(a, b) == (f(a) == b)` ([rel_fun_def]).
+//!
+//! - Composition `(s ∘ r)(a, c)` when `r(a, b) ⋀ s(b, c)` ([RelComp])
+//! - Converse `r°(b, a) == r(a, b)` ([Converse])
+//!
+//! A relation is functional when it has at most one value ([Functional])
+//! and total when it has at least one value ([Total]).
+//! The relation of a function is functional ([rel_fun_functional]) and total ([rel_fun_total]).
+//!
+//! ### Converse and Inverse
+//!
+//! The converse `rel(f)°` always exists, even when `f` has no inverse.
+//! This makes it possible to reason about the inverse of a non-injective function as a relation.
+//! The converse is functional exactly when `f` is injective
+//! ([injective_to_converse_functional] and [converse_functional_to_injective]).
+//! When `~inv(f)`, the converse is the relation of the inverse ([inv_rel_fun]).
+
+use super::*;
+
+/// `r(a, b)`.
+pub type Rel<R, A, B> = App<R, Tup<A, B>>;
+
+/// Relation of function symbol.
+#[derive(Copy, Clone)]
+pub struct FRelFun(());
+
+/// `rel(f)`.
+pub type RelFun<F> = App<FRelFun, F>;
+
+/// Relational composition symbol.
+#[derive(Copy, Clone)]
+pub struct FRelComp(());
+
+/// `s ∘ r`.
+pub type RelComp<S, R> = App<FRelComp, Tup<S, R>>;
+
+/// Converse symbol.
+#[derive(Copy, Clone)]
+pub struct FConverse(());
+
+/// `r°`.
+pub type Converse<R> = App<FConverse, R>;
+
+/// `functional(r, a, b, c) := r(a, b) ⋀ r(a, c)  =>  (b == c)`.
+pub type Functional<R, A, B, C> = Imply<And<Rel<R, A, B>, Rel<R, A, C>>, Eq<B, C>>;
+
+/// `total(r, x, y, a, b) := (a : x)  =>  ∃ b : y { r(a, b) }`.
+pub type Total<R, X, Y, A, B> = Imply<Ty<A, X>, Exists<Ty<B, Y>, Rel<R, A, B>>>;
+
+/// `is_const(rel)`.
+pub fn rel_fun_is_const() -> IsConst<FRelFun> {axiom!()}
+/// `is_const(∘)`.
+pub fn rel_comp_is_const() -> IsConst<FRelComp> {axiom!()}
+/// `is_const(°)`.
+pub fn converse_is_const() -> IsConst<FConverse> {axiom!()}
+/// `rel(f)(a, b) == (f(a) == b)`.
+pub fn rel_fun_def<F: Prop, A: Prop, B: Prop>() -> Eq<Rel<RelFun<F>, A, B>, Eq<App<F, A>, B>> {
+    axiom!()
+}
+/// `r°(b, a) == r(a, b)`.
+pub fn converse_def<R: Prop, A: Prop, B: Prop>() -> Eq<Rel<Converse<R>, B, A>, Rel<R, A, B>> {
+    axiom!()
+}
+/// `r(a, b) ⋀ s(b, c)  =>  (s ∘ r)(a, c)`.
+pub fn rel_comp_intro<R: Prop, S: Prop, A: Prop, B: Prop, C: Prop>(
+    _: Rel<R, A, B>,
+    _: Rel<S, B, C>
+) -> Rel<RelComp<S, R>, A, C> {axiom!()}
+/// `(s ∘ r)(a, c)  =>  ∃ b : r(a, b) { s(b, c) }`.
+pub fn rel_comp_elim<R: Prop, S: Prop, A: Prop, B: VProp, C: Prop>(
+    _: Rel<RelComp<S, R>, A, C>
+) -> Exists<Rel<R, A, B>, Rel<S, B, C>> {axiom!()}
+
+/// `(f(a) == b)  =>  rel(f)(a, b)`.
+pub fn rel_fun_intro<F: Prop, A: Prop, B: Prop>(x: Eq<App<F, A>, B>) -> Rel<RelFun<F>, A, B> {
+    rel_fun_def().1(x)
+}
+/// `rel(f)(a, b)  =>  (f(a) == b)`.
+pub fn rel_fun_elim<F: Prop, A: Prop, B: Prop>(x: Rel<RelFun<F>, A, B>) -> Eq<App<F, A>, B> {
+    rel_fun_def().0(x)
+}
+/// `rel(f)(a, f(a))`.
+pub fn rel_fun_app<F: Prop, A: Prop>() -> Rel<RelFun<F>, A, App<F, A>> {rel_fun_intro(eq::refl())}
+/// `r°°(a, b) == r(a, b)`.
+pub fn converse_converse<R: Prop, A: Prop, B: Prop>() ->
+    Eq<Rel<Converse<Converse<R>>, A, B>, Rel<R, A, B>>
+{eq::transitivity(converse_def(), converse_def())}
+/// `r(a, b) ⋀ s(b, c)  =>  (r° ∘ s°)(c, a)`.
+pub fn converse_comp<R: Prop, S: Prop, A: Prop, B: Prop, C: Prop>(
+    x: Rel<R, A, B>,
+    y: Rel<S, B, C>
+) -> Rel<RelComp<Converse<R>, Converse<S>>, C, A> {
+    rel_comp_intro(converse_def().1(y), converse_def().1(x))
+}
+/// `(rel(g) ∘ rel(f))(a, (g . f)(a))`.
+pub fn rel_fun_comp<F: Prop, G: Prop, A: Prop>() ->
+    Rel<RelComp<RelFun<G>, RelFun<F>>, A, App<Comp<G, F>, A>>
+{rel_comp_intro(rel_fun_app(), rel_fun_intro(eq_app_comp()))}
+/// `functional(rel(f), a, b, c)`.
+pub fn rel_fun_functional<F: Prop, A: Prop, B: Prop, C: Prop>() -> Functional<RelFun<F>, A, B, C> {
+    Rc::new(move |(x, y)| {
+        eq::transitivity(eq::symmetry(rel_fun_elim(x)), rel_fun_elim(y))
+    })
+}
+/// `(f : x -> y)  =>  total(rel(f), x, y, a, f(a))`.
+pub fn rel_fun_total<F: Prop, X: Prop, Y: Prop, A: Prop>(
+    ty_f: Ty<F, Pow<Y, X>>
+) -> Total<RelFun<F>, X, Y, A, App<F, A>> {
+    Rc::new(move |ty_a| {
+        let ty_fa = app_fun_ty(ty_f.clone(), ty_a);
+        Rc::new(move |x| x(ty_fa.clone())(rel_fun_app()))
+    })
+}
+/// `injective(f, a, c)  =>  functional(rel(f)°, b, a, c)`.
+pub fn injective_to_converse_functional<F: Prop, A: Prop, B: Prop, C: Prop>(
+    x: Injective<F, A, C>
+) -> Functional<Converse<RelFun<F>>, B, A, C> {
+    Rc::new(move |(ba, bc)| {
+        let fa = rel_fun_elim(converse_def().0(ba));
+        let fc = rel_fun_elim(converse_def().0(bc));
+        x(eq::transitivity(fa, eq::symmetry(fc)))
+    })
+}
+/// `functional(rel(f)°, f(a), a, c)  =>  injective(f, a, c)`.
+pub fn converse_functional_to_injective<F: Prop, A: Prop, C: Prop>(
+    x: Functional<Converse<RelFun<F>>, App<F, A>, A, C>
+) -> Injective<F, A, C> {
+    Rc::new(move |fa_fc| {
+        let ba = converse_def().1(rel_fun_app());
+        let bc = converse_def().1(rel_fun_intro(eq::symmetry(fa_fc)));
+        x((ba, bc))
+    })
+}
+/// `~inv(f) ⋀ rel(f)°(b, a)  =>  rel(inv(f))(b, a)`.
+pub fn inv_rel_fun<F: Prop, A: Prop, B: Prop>(
+    x: Qu<Inv<F>>,
+    y: Rel<Converse<RelFun<F>>, B, A>
+) -> Rel<RelFun<Inv<F>>, B, A> {
+    rel_fun_intro(inv_val_qu(x, rel_fun_elim(converse_def().0(y))))
+}
@@ -0,0 +1,139 @@
+//! # Sections and Retractions
+//!
+//! A map `s : a -> b` is a section of `r : b -> a` when `r . s == id{a}` ([Section]).
+//! Then `r` is a retraction and `a` is a retract of `b` ([Retract]).
+//! The maps of a retract are referenced by `sec(a, b)` ([SecOf]) and `ret(a, b)` ([RetOf]).
+//!
+//! Unlike an isomorphism (see [iso::Iso]), only one round-trip equation holds,
+//! so this covers embeddings that are not bijective.
+//!
+//! - Sections compose ([section_comp]) and retracts are transitive ([retract_transitivity])
+//! - Every isomorphism gives a retract ([iso_to_retract])
+//! - A retract of a contractible type is contractible ([retract_is_contr])
+
+use super::*;
+use hott::IsContr;
+use iso::Iso;
+
+/// Section predicate.
+#[derive(Copy, Clone)]
+pub struct FSection(());
+
+/// `section(s, r)`.
+pub type Section<S, R> = App<FSection, Tup<S, R>>;
+
+/// Retract predicate.
+#[derive(Copy, Clone)]
+pub struct FRetract(());
+
+/// `retract(a, b)`.
+pub type Retract<A, B> = App<FRetract, Tup<A, B>>;
+
+/// The section `sec(a, b)` of `retract(a, b)`.
+#[derive(Clone)]
+pub struct SecOf<A, B>(A, B);
+
+/// The retraction `ret(a, b)` of `retract(a, b)`.
+#[derive(Clone)]
+pub struct RetOf<A, B>(A, B);
+
+/// `is_const(section)`.
+pub fn section_is_const() -> IsConst<FSection> {axiom!()}
+/// `is_const(retract)`.
+pub fn retract_is_const() -> IsConst<FRetract> {axiom!()}
+
+/// `(s : a -> b) ⋀ (r : b -> a) ⋀ ((r . s) == id{a})  =>  section(s, r)`.
+pub fn section_intro<S: Prop, R: Prop, A: Prop, B: Prop>(
+    _ty_s: Ty<S, Pow<B, A>>,
+    _ty_r: Ty<R, Pow<A, B>>,
+    _: Eq<Comp<R, S>, Id<A>>
+) -> Section<S, R> {axiom!()}
+/// `section(s, r) ⋀ (s : a -> b)  =>  (r . s) == id{a}`.
+pub fn section_eq<S: Prop, R: Prop, A: Prop, B: Prop>(
+    _: Section<S, R>,
+    _ty_s: Ty<S, Pow<B, A>>
+) -> Eq<Comp<R, S>, Id<A>> {axiom!()}
+/// `(s : a -> b) ⋀ (r : b -> a) ⋀ section(s, r)  =>  retract(a, b)`.
+pub fn retract_intro<S: Prop, R: Prop, A: Prop, B: Prop>(
+    _ty_s: Ty<S, Pow<B, A>>,
+    _ty_r: Ty<R, Pow<A, B>>,
+    _: Section<S, R>
+) -> Retract<A, B> {axiom!()}
+/// `retract(a, b)  =>  sec(a, b) : a -> b`.
+pub fn retract_sec_ty<A: Prop, B: Prop>(_: Retract<A, B>) -> Ty<SecOf<A, B>, Pow<B, A>> {
+    axiom!()
+}
+/// `retract(a, b)  =>  ret(a, b) : b -> a`.
+pub fn retract_ret_ty<A: Prop, B: Prop>(_: Retract<A, B>) -> Ty<RetOf<A, B>, Pow<A, B>> {
+    axiom!()
+}
+/// `retract(a, b)  =>  section(sec(a, b), ret(a, b))`.
+pub fn retract_section<A: Prop, B: Prop>(
+    _: Retract<A, B>
+) -> Section<SecOf<A, B>, RetOf<A, B>> {axiom!()}
+/// `retract(a, b) ⋀ is_contr(b)  =>  is_contr(a)`.
+pub fn retract_is_contr<A: Prop, B: Prop>(_: Retract<A, B>, _: IsContr<B>) -> IsContr<A> {
+    axiom!()
+}
+
+/// `(a : type(n)) ⋀ (s : a -> b) ⋀ section(s, r) ⋀ (x : a)  =>  r(s(x)) == x`.
+pub fn section_val<S: Prop, R: Prop, A: Prop, B: Prop, X: Prop, N: Nat>(
+    ty_a: Ty<A, Type<N>>,
+    ty_s: Ty<S, Pow<B, A>>,
+    x: Section<S, R>,
+    ty_x: Ty<X, A>
+) -> Eq<App<R, App<S, X>>, X> {
+    let y = eq::transitivity(eq_app_comp(), app_map_eq(section_eq(x, ty_s)));
+    eq::transitivity(y, id_def(ty_a, ty_x))
+}
+/// `(s : a -> b) ⋀ (r : b -> a) ⋀ (t : b -> c) ⋀ (u : c -> b) ⋀
+/// section(s, r) ⋀ section(t, u)  =>  section(t . s, r . u)`.
+pub fn section_comp<S: Prop, R: Prop, T: Prop, U: Prop, A: Prop, B: Prop, C: Prop>(
+    ty_s: Ty<S, Pow<B, A>>,
+    ty_r: Ty<R, Pow<A, B>>,
+    ty_t: Ty<T, Pow<C, B>>,
+    ty_u: Ty<U, Pow<B, C>>,
+    sr: Section<S, R>,
+    tu: Section<T, U>
+) -> Section<Comp<T, S>, Comp<R, U>> {
+    // `(r . u) . (t . s) == r . s`.
+    let x = eq::transitivity(comp_assoc(), comp_eq_left(eq::transitivity(
+        eq::symmetry(comp_assoc()),
+        eq::transitivity(comp_eq_right(section_eq(tu, ty_t.clone())), comp_id_right(ty_r.clone()))
+    )));
+    let x = eq::transitivity(x, section_eq(sr, ty_s.clone()));
+    section_intro(comp_ty(ty_s, ty_t), comp_ty(ty_u, ty_r), x)
+}
+/// `(a : type(n))  =>  section(id{a}, id{a})`.
+pub fn section_id<A: Prop, N: Nat>(ty_a: Ty<A, Type<N>>) -> Section<Id<A>, Id<A>> {
+    let ty = id_ty(ty_a);
+    section_intro(ty.clone(), ty.clone(), comp_id_left(ty))
+}
+/// `(a : type(n))  =>  retract(a, a)`.
+pub fn retract_refl<A: Prop, N: Nat>(ty_a: Ty<A, Type<N>>) -> Retract<A, A> {
+    let ty = id_ty(ty_a.clone());
+    retract_intro(ty.clone(), ty, section_id(ty_a))
+}
+/// `retract(a, b) ⋀ retract(b, c)  =>  retract(a, c)`.
+pub fn retract_transitivity<A: Prop, B: Prop, C: Prop>(
+    ab: Retract<A, B>,
+    bc: Retract<B, C>
+) -> Retract<A, C> {
+    let (ty_s, ty_r) = (retract_sec_ty(ab.clone()), retract_ret_ty(ab.clone()));
+    let (ty_t, ty_u) = (retract_sec_ty(bc.clone()), retract_ret_ty(bc.clone()));
+    let x = section_comp(ty_s.clone(), ty_r.clone(), ty_t.clone(), ty_u.clone(),
+        retract_section(ab), retract_section(bc));
+    retract_intro(comp_ty(ty_s, ty_t), comp_ty(ty_u, ty_r), x)
+}
+/// `iso(f, a, b)  =>  section(f, inv(f))`.
+pub fn iso_to_section<F: Prop, A: Prop, B: Prop>(x: Iso<F, A, B>) -> Section<F, Inv<F>> {
+    section_intro(x.ty, x.inv_ty, x.left)
+}
+/// `iso(f, a, b)  =>  retract(a, b)`.
+pub fn iso_to_retract<F: Prop, A: Prop, B: Prop>(x: Iso<F, A, B>) -> Retract<A, B> {
+    retract_intro(x.ty.clone(), x.inv_ty.clone(), iso_to_section(x))
+}
+/// `iso(f, a, b) ⋀ is_contr(b)  =>  is_contr(a)`.
+pub fn iso_is_contr<F: Prop, A: Prop, B: Prop>(x: Iso<F, A, B>, y: IsContr<B>) -> IsContr<A> {
+    retract_is_contr(iso_to_retract(x), y)
+}
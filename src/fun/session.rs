@@ -0,0 +1,391 @@
+//! # Session Types
+//!
+//! A binary session type describes the protocol on one end of a channel:
+//!
+//! - `end` ([End]): The session is closed
+//! - `!a.s` ([Output]): Send a value of type `a`, then continue with `s`
+//! - `?a.s` ([Input]): Receive a value of type `a`, then continue with `s`
+//! - `s ⊕ t` ([Select]): Choose between `s` and `t`
+//! - `s & t` ([Offer]): Offer the choice between `s` and `t` to the other end
+//!
+//! The other end of a channel follows the dual protocol `dual(s)` ([Dual]),
+//! where sending and receiving, selecting and offering are swapped.
+//! Duality is an involution ([dual_dual]).
+//!
+//! A process `p` follows a session `s` when `p ⊢ s` ([Has]).
+//! Processes are built from `stop`, `send(x).p`, `recv(f)`, `inl.p`, `inr.p` and `case(p, q)`,
+//! where `f` maps received values to continuations.
+//! Two processes are composed over their channel as `p ‖ q` ([Cut]),
+//! which reduces by `p ‖ q ⟶ p' ‖ q'` ([Step]) when one side sends and the other receives.
+//!
+//! The channel is linear: Every rule consumes the session prefix,
+//! so each process uses the channel exactly as the session prescribes, once.
+//!
+//! ### Deadlock freedom
+//!
+//! When `p ⊢ s` and `q ⊢ dual(s)` ([Typed]), the composition `p ‖ q` is either finished
+//! (`stop ‖ stop`) or reduces to a composition that is again well typed,
+//! for the continuation of the session ([Reduces]).
+//! This is shown for each form of session type
+//! ([deadlock_free_end], [deadlock_free_output], [deadlock_free_input],
+//! [deadlock_free_select], [deadlock_free_offer]).
+//!
+//! The parts of a process that follows a session are referenced by
+//! `val(p)` ([Val]), `cont(p)` ([Cont]), `handler(p)` ([Handler]),
+//! `case_l(p)` ([CaseL]) and `case_r(p)` ([CaseR]).
+//! Like [natp::Prev], these are not operations, but references to some object depending on `p`.
+
+use super::*;
+
+/// Closed session `end`.
+#[derive(Copy, Clone)]
+pub struct End(());
+
+/// Output session.
+#[derive(Copy, Clone)]
+pub struct FOutput(());
+
+/// `!a.s`.
+pub type Output<A, S> = App<FOutput, Tup<A, S>>;
+
+/// Input session.
+#[derive(Copy, Clone)]
+pub struct FInput(());
+
+/// `?a.s`.
+pub type Input<A, S> = App<FInput, Tup<A, S>>;
+
+/// Internal choice.
+#[derive(Copy, Clone)]
+pub struct FSelect(());
+
+/// `s ⊕ t`.
+pub type Select<S, T> = App<FSelect, Tup<S, T>>;
+
+/// External choice.
+#[derive(Copy, Clone)]
+pub struct FOffer(());
+
+/// `s & t`.
+pub type Offer<S, T> = App<FOffer, Tup<S, T>>;
+
+/// Dual session.
+#[derive(Copy, Clone)]
+pub struct FDual(());
+
+/// `dual(s)`.
+pub type Dual<S> = App<FDual, S>;
+
+/// Stopped process `stop`.
+#[derive(Copy, Clone)]
+pub struct Stop(());
+
+/// Sending process.
+#[derive(Copy, Clone)]
+pub struct FPSend(());
+
+/// `send(x).p`.
+pub type PSend<X, P> = App<FPSend, Tup<X, P>>;
+
+/// Receiving process.
+#[derive(Copy, Clone)]
+pub struct FPRecv(());
+
+/// `recv(f)`.
+pub type PRecv<F> = App<FPRecv, F>;
+
+/// Process selecting the left branch.
+#[derive(Copy, Clone)]
+pub struct FPInl(());
+
+/// `inl.p`.
+pub type PInl<P> = App<FPInl, P>;
+
+/// Process selecting the right branch.
+#[derive(Copy, Clone)]
+pub struct FPInr(());
+
+/// `inr.p`.
+pub type PInr<P> = App<FPInr, P>;
+
+/// Process offering two branches.
+#[derive(Copy, Clone)]
+pub struct FPCase(());
+
+/// `case(p, q)`.
+pub type PCase<P, Q> = App<FPCase, Tup<P, Q>>;
+
+/// Composition over a channel.
+#[derive(Copy, Clone)]
+pub struct FCut(());
+
+/// `p ‖ q`.
+pub type Cut<P, Q> = App<FCut, Tup<P, Q>>;
+
+/// Session judgement.
+#[derive(Copy, Clone)]
+pub struct FHas(());
+
+/// `p ⊢ s`.
+pub type Has<P, S> = App<FHas, Tup<P, S>>;
+
+/// Reduction relation.
+#[derive(Copy, Clone)]
+pub struct FStep(());
+
+/// `p ⟶ q`.
+pub type Step<P, Q> = App<FStep, Tup<P, Q>>;
+
+/// The value `val(p)` sent by `p`.
+#[derive(Clone)]
+pub struct Val<P>(P);
+
+/// The continuation `cont(p)` after the first action of `p`.
+#[derive(Clone)]
+pub struct Cont<P>(P);
+
+/// The function `handler(p)` that `p` applies to received values.
+#[derive(Clone)]
+pub struct Handler<P>(P);
+
+/// The left branch `case_l(p)` offered by `p`.
+#[derive(Clone)]
+pub struct CaseL<P>(P);
+
+/// The right branch `case_r(p)` offered by `p`.
+#[derive(Clone)]
+pub struct CaseR<P>(P);
+
+/// `(p ⊢ s) ⋀ (q ⊢ dual(s))`.
+pub type Typed<P, Q, S> = And<Has<P, S>, Has<Q, Dual<S>>>;
+
+/// `(p ‖ q ⟶ p' ‖ q') ⋀ (p' ⊢ s) ⋀ (q' ⊢ dual(s))`.
+pub type Reduces<P, Q, P1, Q1, S> = And<Step<Cut<P, Q>, Cut<P1, Q1>>, Typed<P1, Q1, S>>;
+
+/// `is_const(end)`.
+pub fn end_is_const() -> IsConst<End> {axiom!()}
+/// `is_const(output)`.
+pub fn output_is_const() -> IsConst<FOutput> {axiom!()}
+/// `is_const(input)`.
+pub fn input_is_const() -> IsConst<FInput> {axiom!()}
+/// `is_const(select)`.
+pub fn select_is_const() -> IsConst<FSelect> {axiom!()}
+/// `is_const(offer)`.
+pub fn offer_is_const() -> IsConst<FOffer> {axiom!()}
+/// `is_const(dual)`.
+pub fn dual_is_const() -> IsConst<FDual> {axiom!()}
+/// `is_const(stop)`.
+pub fn stop_is_const() -> IsConst<Stop> {axiom!()}
+/// `is_const(send)`.
+pub fn psend_is_const() -> IsConst<FPSend> {axiom!()}
+/// `is_const(recv)`.
+pub fn precv_is_const() -> IsConst<FPRecv> {axiom!()}
+/// `is_const(inl)`.
+pub fn pinl_is_const() -> IsConst<FPInl> {axiom!()}
+/// `is_const(inr)`.
+pub fn pinr_is_const() -> IsConst<FPInr> {axiom!()}
+/// `is_const(case)`.
+pub fn pcase_is_const() -> IsConst<FPCase> {axiom!()}
+/// `is_const(cut)`.
+pub fn cut_is_const() -> IsConst<FCut> {axiom!()}
+/// `is_const(has)`.
+pub fn has_is_const() -> IsConst<FHas> {axiom!()}
+/// `is_const(step)`.
+pub fn step_is_const() -> IsConst<FStep> {axiom!()}
+
+/// `dual(end) == end`.
+pub fn dual_end() -> Eq<Dual<End>, End> {axiom!()}
+/// `dual(!a.s) == ?a.dual(s)`.
+pub fn dual_output<A: Prop, S: Prop>() -> Eq<Dual<Output<A, S>>, Input<A, Dual<S>>> {axiom!()}
+/// `dual(?a.s) == !a.dual(s)`.
+pub fn dual_input<A: Prop, S: Prop>() -> Eq<Dual<Input<A, S>>, Output<A, Dual<S>>> {axiom!()}
+/// `dual(s ⊕ t) == dual(s) & dual(t)`.
+pub fn dual_select<S: Prop, T: Prop>() -> Eq<Dual<Select<S, T>>, Offer<Dual<S>, Dual<T>>> {
+    axiom!()
+}
+/// `dual(s & t) == dual(s) ⊕ dual(t)`.
+pub fn dual_offer<S: Prop, T: Prop>() -> Eq<Dual<Offer<S, T>>, Select<Dual<S>, Dual<T>>> {
+    axiom!()
+}
+/// `dual(dual(s)) == s`.
+pub fn dual_dual<S: Prop>() -> Eq<Dual<Dual<S>>, S> {axiom!()}
+
+/// `stop ⊢ end`.
+pub fn stop_has() -> Has<Stop, End> {axiom!()}
+/// `(x : a) ⋀ (p ⊢ s)  =>  send(x).p ⊢ !a.s`.
+pub fn send_has<X: Prop, A: Prop, P: Prop, S: Prop>(
+    _ty_x: Ty<X, A>,
+    _: Has<P, S>
+) -> Has<PSend<X, P>, Output<A, S>> {axiom!()}
+/// `(f(x) ⊢ s)^(x : a)  =>  recv(f) ⊢ ?a.s`.
+pub fn recv_has<F: Prop, X: VProp, A: Prop, S: Prop>(
+    _: Pow<Has<App<F, X>, S>, Ty<X, A>>
+) -> Has<PRecv<F>, Input<A, S>> {axiom!()}
+/// `(p ⊢ s)  =>  inl.p ⊢ s ⊕ t`.
+pub fn inl_has<P: Prop, S: Prop, T: Prop>(_: Has<P, S>) -> Has<PInl<P>, Select<S, T>> {axiom!()}
+/// `(p ⊢ t)  =>  inr.p ⊢ s ⊕ t`.
+pub fn inr_has<P: Prop, S: Prop, T: Prop>(_: Has<P, T>) -> Has<PInr<P>, Select<S, T>> {axiom!()}
+/// `(p ⊢ s) ⋀ (q ⊢ t)  =>  case(p, q) ⊢ s & t`.
+pub fn case_has<P: Prop, Q: Prop, S: Prop, T: Prop>(
+    _: Has<P, S>,
+    _: Has<Q, T>
+) -> Has<PCase<P, Q>, Offer<S, T>> {axiom!()}
+
+/// `(p ⊢ end)  =>  p == stop`.
+pub fn end_inv<P: Prop>(_: Has<P, End>) -> Eq<P, Stop> {axiom!()}
+/// `(p ⊢ !a.s)  =>  p == send(val(p)).cont(p)`.
+pub fn output_inv<P: Prop, A: Prop, S: Prop>(
+    _: Has<P, Output<A, S>>
+) -> Eq<P, PSend<Val<P>, Cont<P>>> {axiom!()}
+/// `(p ⊢ !a.s)  =>  val(p) : a`.
+pub fn output_val_ty<P: Prop, A: Prop, S: Prop>(_: Has<P, Output<A, S>>) -> Ty<Val<P>, A> {
+    axiom!()
+}
+/// `(p ⊢ !a.s)  =>  cont(p) ⊢ s`.
+pub fn output_cont_has<P: Prop, A: Prop, S: Prop>(_: Has<P, Output<A, S>>) -> Has<Cont<P>, S> {
+    axiom!()
+}
+/// `(p ⊢ ?a.s)  =>  p == recv(handler(p))`.
+pub fn input_inv<P: Prop, A: Prop, S: Prop>(
+    _: Has<P, Input<A, S>>
+) -> Eq<P, PRecv<Handler<P>>> {axiom!()}
+/// `(p ⊢ ?a.s) ⋀ (x : a)  =>  handler(p)(x) ⊢ s`.
+pub fn input_handler_has<P: Prop, A: Prop, S: Prop, X: Prop>(
+    _: Has<P, Input<A, S>>,
+    _ty_x: Ty<X, A>,
+) -> Has<App<Handler<P>, X>, S> {axiom!()}
+/// `(p ⊢ s ⊕ t)  =>
+///   ((p == inl.cont(p)) ⋀ (cont(p) ⊢ s)) ⋁ ((p == inr.cont(p)) ⋀ (cont(p) ⊢ t))`.
+pub fn select_inv<P: Prop, S: Prop, T: Prop>(
+    _: Has<P, Select<S, T>>
+) -> Or<And<Eq<P, PInl<Cont<P>>>, Has<Cont<P>, S>>, And<Eq<P, PInr<Cont<P>>>, Has<Cont<P>, T>>> {
+    axiom!()
+}
+/// `(p ⊢ s & t)  =>  p == case(case_l(p), case_r(p))`.
+pub fn offer_inv<P: Prop, S: Prop, T: Prop>(
+    _: Has<P, Offer<S, T>>
+) -> Eq<P, PCase<CaseL<P>, CaseR<P>>> {axiom!()}
+/// `(p ⊢ s & t)  =>  case_l(p) ⊢ s`.
+pub fn offer_left_has<P: Prop, S: Prop, T: Prop>(_: Has<P, Offer<S, T>>) -> Has<CaseL<P>, S> {
+    axiom!()
+}
+/// `(p ⊢ s & t)  =>  case_r(p) ⊢ t`.
+pub fn offer_right_has<P: Prop, S: Prop, T: Prop>(_: Has<P, Offer<S, T>>) -> Has<CaseR<P>, T> {
+    axiom!()
+}
+
+/// `send(x).p ‖ recv(f)  ⟶  p ‖ f(x)`.
+pub fn step_send_recv<X: Prop, P: Prop, F: Prop>() ->
+    Step<Cut<PSend<X, P>, PRecv<F>>, Cut<P, App<F, X>>> {axiom!()}
+/// `inl.p ‖ case(q1, q2)  ⟶  p ‖ q1`.
+pub fn step_inl_case<P: Prop, Q1: Prop, Q2: Prop>() ->
+    Step<Cut<PInl<P>, PCase<Q1, Q2>>, Cut<P, Q1>> {axiom!()}
+/// `inr.p ‖ case(q1, q2)  ⟶  p ‖ q2`.
+pub fn step_inr_case<P: Prop, Q1: Prop, Q2: Prop>() ->
+    Step<Cut<PInr<P>, PCase<Q1, Q2>>, Cut<P, Q2>> {axiom!()}
+/// `(p ‖ q ⟶ p' ‖ q')  =>  (q ‖ p ⟶ q' ‖ p')`.
+pub fn step_cut_swap<P: Prop, Q: Prop, P1: Prop, Q1: Prop>(
+    _: Step<Cut<P, Q>, Cut<P1, Q1>>
+) -> Step<Cut<Q, P>, Cut<Q1, P1>> {axiom!()}
+
+/// `(p ⊢ s) ⋀ (s == t)  =>  (p ⊢ t)`.
+pub fn has_in_arg<P: Prop, S: Prop, T: Prop>(x: Has<P, S>, y: Eq<S, T>) -> Has<P, T> {
+    app_eq(tup_eq_snd(y)).0(x)
+}
+/// `(p ⊢ s) ⋀ (p == q)  =>  (q ⊢ s)`.
+pub fn has_in_proc_arg<P: Prop, Q: Prop, S: Prop>(x: Has<P, S>, y: Eq<P, Q>) -> Has<Q, S> {
+    app_eq(tup_eq_fst(y)).0(x)
+}
+/// `(p ⟶ q) ⋀ (p == p')  =>  (p' ⟶ q)`.
+pub fn step_in_left_arg<P: Prop, Q: Prop, P1: Prop>(x: Step<P, Q>, y: Eq<P, P1>) -> Step<P1, Q> {
+    app_eq(tup_eq_fst(y)).0(x)
+}
+/// `(p == p') ⋀ (q == q')  =>  (p ‖ q) == (p' ‖ q')`.
+pub fn cut_eq<P: Prop, Q: Prop, P1: Prop, Q1: Prop>(
+    x: Eq<P, P1>,
+    y: Eq<Q, Q1>
+) -> Eq<Cut<P, Q>, Cut<P1, Q1>> {app_eq(tup_eq(x, y))}
+/// `(p ⊢ s) ⋀ (q ⊢ dual(s))  =>  (q ⊢ dual(s)) ⋀ (p ⊢ dual(dual(s)))`.
+///
+/// Composition is symmetric.
+pub fn typed_symmetry<P: Prop, Q: Prop, S: Prop>(
+    (x, y): Typed<P, Q, S>
+) -> Typed<Q, P, Dual<S>> {(y, has_in_arg(x, eq::symmetry(dual_dual())))}
+/// `recv(f) ‖ send(x).q  ⟶  f(x) ‖ q`.
+pub fn step_recv_send<F: Prop, X: Prop, Q: Prop>() ->
+    Step<Cut<PRecv<F>, PSend<X, Q>>, Cut<App<F, X>, Q>> {step_cut_swap(step_send_recv())}
+/// `case(p1, p2) ‖ inl.q  ⟶  p1 ‖ q`.
+pub fn step_case_inl<P1: Prop, P2: Prop, Q: Prop>() ->
+    Step<Cut<PCase<P1, P2>, PInl<Q>>, Cut<P1, Q>> {step_cut_swap(step_inl_case())}
+/// `case(p1, p2) ‖ inr.q  ⟶  p2 ‖ q`.
+pub fn step_case_inr<P1: Prop, P2: Prop, Q: Prop>() ->
+    Step<Cut<PCase<P1, P2>, PInr<Q>>, Cut<P2, Q>> {step_cut_swap(step_inr_case())}
+
+/// `(p ⊢ end) ⋀ (q ⊢ dual(end))  =>  (p ‖ q) == (stop ‖ stop)`.
+///
+/// A closed session is finished.
+pub fn deadlock_free_end<P: Prop, Q: Prop>(
+    (x, y): Typed<P, Q, End>
+) -> Eq<Cut<P, Q>, Cut<Stop, Stop>> {
+    cut_eq(end_inv(x), end_inv(has_in_arg(y, dual_end())))
+}
+/// `(p ⊢ !a.s) ⋀ (q ⊢ dual(!a.s))  =>
+///   (p ‖ q ⟶ cont(p) ‖ handler(q)(val(p))) ⋀
+///   (cont(p) ⊢ s) ⋀ (handler(q)(val(p)) ⊢ dual(s))`.
+pub fn deadlock_free_output<P: Prop, Q: Prop, A: Prop, S: Prop>(
+    (x, y): Typed<P, Q, Output<A, S>>
+) -> Reduces<P, Q, Cont<P>, App<Handler<Q>, Val<P>>, S> {
+    let y = has_in_arg(y, dual_output());
+    let eq = eq::symmetry(cut_eq(output_inv(x.clone()), input_inv(y.clone())));
+    (step_in_left_arg(step_send_recv(), eq),
+     (output_cont_has(x.clone()), input_handler_has(y, output_val_ty(x))))
+}
+/// `(p ⊢ ?a.s) ⋀ (q ⊢ dual(?a.s))  =>
+///   (p ‖ q ⟶ handler(p)(val(q)) ‖ cont(q)) ⋀
+///   (handler(p)(val(q)) ⊢ s) ⋀ (cont(q) ⊢ dual(s))`.
+pub fn deadlock_free_input<P: Prop, Q: Prop, A: Prop, S: Prop>(
+    (x, y): Typed<P, Q, Input<A, S>>
+) -> Reduces<P, Q, App<Handler<P>, Val<Q>>, Cont<Q>, S> {
+    let y = has_in_arg(y, dual_input());
+    let eq = eq::symmetry(cut_eq(input_inv(x.clone()), output_inv(y.clone())));
+    (step_in_left_arg(step_recv_send(), eq),
+     (input_handler_has(x, output_val_ty(y.clone())), output_cont_has(y)))
+}
+/// `(p ⊢ s ⊕ t) ⋀ (q ⊢ dual(s ⊕ t))  =>
+///   ((p ‖ q ⟶ cont(p) ‖ case_l(q)) ⋀ (cont(p) ⊢ s) ⋀ (case_l(q) ⊢ dual(s))) ⋁
+///   ((p ‖ q ⟶ cont(p) ‖ case_r(q)) ⋀ (cont(p) ⊢ t) ⋀ (case_r(q) ⊢ dual(t)))`.
+pub fn deadlock_free_select<P: Prop, Q: Prop, S: Prop, T: Prop>(
+    (x, y): Typed<P, Q, Select<S, T>>
+) -> Or<Reduces<P, Q, Cont<P>, CaseL<Q>, S>, Reduces<P, Q, Cont<P>, CaseR<Q>, T>> {
+    let y = has_in_arg(y, dual_select());
+    let eq_q = offer_inv(y.clone());
+    match select_inv(x) {
+        Left((eq_p, x)) => {
+            let eq = eq::symmetry(cut_eq(eq_p, eq_q));
+            Left((step_in_left_arg(step_inl_case(), eq), (x, offer_left_has(y))))
+        }
+        Right((eq_p, x)) => {
+            let eq = eq::symmetry(cut_eq(eq_p, eq_q));
+            Right((step_in_left_arg(step_inr_case(), eq), (x, offer_right_has(y))))
+        }
+    }
+}
+/// `(p ⊢ s & t) ⋀ (q ⊢ dual(s & t))  =>
+///   ((p ‖ q ⟶ case_l(p) ‖ cont(q)) ⋀ (case_l(p) ⊢ s) ⋀ (cont(q) ⊢ dual(s))) ⋁
+///   ((p ‖ q ⟶ case_r(p) ‖ cont(q)) ⋀ (case_r(p) ⊢ t) ⋀ (cont(q) ⊢ dual(t)))`.
+pub fn deadlock_free_offer<P: Prop, Q: Prop, S: Prop, T: Prop>(
+    (x, y): Typed<P, Q, Offer<S, T>>
+) -> Or<Reduces<P, Q, CaseL<P>, Cont<Q>, S>, Reduces<P, Q, CaseR<P>, Cont<Q>, T>> {
+    let y = has_in_arg(y, dual_offer());
+    let eq_p = offer_inv(x.clone());
+    match select_inv(y) {
+        Left((eq_q, y)) => {
+            let eq = eq::symmetry(cut_eq(eq_p, eq_q));
+            Left((step_in_left_arg(step_case_inl(), eq), (offer_left_has(x), y)))
+        }
+        Right((eq_q, y)) => {
+            let eq = eq::symmetry(cut_eq(eq_p, eq_q));
+            Right((step_in_left_arg(step_case_inr(), eq), (offer_right_has(x), y)))
+        }
+    }
+}
@@ -0,0 +1,193 @@
+//! # Setoids
+//!
+//! A setoid `setoid(x, r)` ([Setoid]) bundles a carrier `x` with an equivalence relation `r`.
+//! A relation is written as a function symbol applied to a tuple, `a r b` ([Rel]),
+//! as in the [wf] module.
+//!
+//! A map `f` between setoids `(x, r)` and `(y, s)` is respectful when it sends related
+//! arguments to related results ([Respectful]). Respectful maps form a setoid
+//! ([fun_setoid]) with the relation `f (r ==> s) g` ([RespRel]):
+//!
+//! `f (r ==> s) g  :=  (a r b  =>  f(a) s g(b))^((a : x) ⋀ (b : x))`
+//!
+//! In this setoid, functions that are pointwise related are related ([setoid_ext]).
+//! This is the setoid version of function extensionality (see [fun_ext]),
+//! but it does not need the imaginary inverse or univalence,
+//! since the relation on functions is defined to be extensional.
+//! Developments that rely heavily on extensionality can work relative to setoids instead.
+//!
+//! Equality `=` is a relation ([FEqRel]) that forms a setoid on every type ([eq_setoid]).
+
+use super::*;
+
+/// `a r b`.
+pub type Rel<R, A, B> = App<R, Tup<A, B>>;
+
+/// Setoid predicate.
+#[derive(Copy, Clone)]
+pub struct FSetoid(());
+
+/// `setoid(x, r)`.
+pub type Setoid<X, R> = App<FSetoid, Tup<X, R>>;
+
+/// Respectful relation on functions.
+#[derive(Copy, Clone)]
+pub struct FRespRel(());
+
+/// `r ==> s` on functions from `x`.
+pub type RespRel<X, R, S> = App<FRespRel, Tup3<X, R, S>>;
+
+/// `f (r ==> s) f`.
+pub type Respectful<F, X, R, S> = Rel<RespRel<X, R, S>, F, F>;
+
+/// The type of respectful maps `(x, r) -> (y, s)`.
+#[derive(Copy, Clone)]
+pub struct RespFun<X, R, Y, S>(X, R, Y, S);
+
+/// Equality relation.
+#[derive(Copy, Clone)]
+pub struct FEqRel(());
+
+/// `is_const(setoid)`.
+pub fn setoid_is_const() -> IsConst<FSetoid> {axiom!()}
+/// `is_const(==>)`.
+pub fn resp_rel_is_const() -> IsConst<FRespRel> {axiom!()}
+/// `is_const(=)`.
+pub fn eq_rel_is_const() -> IsConst<FEqRel> {axiom!()}
+
+/// `(a r a)^(a : x) ⋀ (a r b => b r a)^((a : x) ⋀ (b : x)) ⋀
+/// ((a r b) ⋀ (b r c) => a r c)^((a : x) ⋀ (b : x) ⋀ (c : x))  =>  setoid(x, r)`.
+pub fn setoid_intro<X: Prop, R: Prop, A: VProp, B: VProp, C: VProp>(
+    _refl: Pow<Rel<R, A, A>, Ty<A, X>>,
+    _symmetry: Pow<Imply<Rel<R, A, B>, Rel<R, B, A>>, And<Ty<A, X>, Ty<B, X>>>,
+    _transitivity: Pow<
+        Imply<And<Rel<R, A, B>, Rel<R, B, C>>, Rel<R, A, C>>,
+        And<Ty<A, X>, And<Ty<B, X>, Ty<C, X>>>
+    >
+) -> Setoid<X, R> {axiom!()}
+/// `setoid(x, r) ⋀ (a : x)  =>  a r a`.
+pub fn setoid_refl<X: Prop, R: Prop, A: Prop>(
+    _: Setoid<X, R>,
+    _ty_a: Ty<A, X>
+) -> Rel<R, A, A> {axiom!()}
+/// `setoid(x, r) ⋀ (a : x) ⋀ (b : x) ⋀ (a r b)  =>  b r a`.
+pub fn setoid_symmetry<X: Prop, R: Prop, A: Prop, B: Prop>(
+    _: Setoid<X, R>,
+    _ty_a: Ty<A, X>,
+    _ty_b: Ty<B, X>,
+    _: Rel<R, A, B>
+) -> Rel<R, B, A> {axiom!()}
+/// `setoid(x, r) ⋀ (a : x) ⋀ (b : x) ⋀ (c : x) ⋀ (a r b) ⋀ (b r c)  =>  a r c`.
+pub fn setoid_transitivity<X: Prop, R: Prop, A: Prop, B: Prop, C: Prop>(
+    _: Setoid<X, R>,
+    _ty_a: Ty<A, X>,
+    _ty_b: Ty<B, X>,
+    _ty_c: Ty<C, X>,
+    _: Rel<R, A, B>,
+    _: Rel<R, B, C>
+) -> Rel<R, A, C> {axiom!()}
+
+/// `(a = b) == (a == b)`.
+pub fn eq_rel_def<A: Prop, B: Prop>() -> Eq<Rel<FEqRel, A, B>, Eq<A, B>> {axiom!()}
+/// `setoid(x, =)`.
+pub fn eq_setoid<X: Prop, A: VProp, B: VProp, C: VProp>() -> Setoid<X, FEqRel> {
+    fn refl<A: Prop, X: Prop>(_: Ty<A, X>) -> Rel<FEqRel, A, A> {eq_rel_def().1(eq::refl())}
+    fn symmetry<A: Prop, B: Prop, X: Prop>(
+        _: And<Ty<A, X>, Ty<B, X>>
+    ) -> Imply<Rel<FEqRel, A, B>, Rel<FEqRel, B, A>> {
+        Rc::new(|ab| eq_rel_def().1(eq::symmetry(eq_rel_def().0(ab))))
+    }
+    fn transitivity<A: Prop, B: Prop, C: Prop, X: Prop>(
+        _: And<Ty<A, X>, And<Ty<B, X>, Ty<C, X>>>
+    ) -> Imply<And<Rel<FEqRel, A, B>, Rel<FEqRel, B, C>>, Rel<FEqRel, A, C>> {
+        Rc::new(|(ab, bc)| {
+            eq_rel_def().1(eq::transitivity(eq_rel_def().0(ab), eq_rel_def().0(bc)))
+        })
+    }
+    setoid_intro::<X, FEqRel, A, B, C>(refl, symmetry, transitivity)
+}
+
+/// `(a r b  =>  f(a) s g(b))^((a : x) ⋀ (b : x))  =>  f (r ==> s) g`.
+pub fn resp_intro<F: Prop, G: Prop, X: Prop, R: Prop, S: Prop, A: VProp, B: VProp>(
+    _: Pow<Imply<Rel<R, A, B>, Rel<S, App<F, A>, App<G, B>>>, And<Ty<A, X>, Ty<B, X>>>
+) -> Rel<RespRel<X, R, S>, F, G> {axiom!()}
+/// `f (r ==> s) g ⋀ (a : x) ⋀ (b : x) ⋀ (a r b)  =>  f(a) s g(b)`.
+pub fn resp_elim<F: Prop, G: Prop, X: Prop, R: Prop, S: Prop, A: Prop, B: Prop>(
+    _: Rel<RespRel<X, R, S>, F, G>,
+    _ty_a: Ty<A, X>,
+    _ty_b: Ty<B, X>,
+    _: Rel<R, A, B>
+) -> Rel<S, App<F, A>, App<G, B>> {axiom!()}
+/// `(f : x -> y) ⋀ f (r ==> s) f  =>  f : resp_fun(x, r, y, s)`.
+pub fn resp_fun_ty<F: Prop, X: Prop, R: Prop, Y: Prop, S: Prop>(
+    _ty_f: Ty<F, Pow<Y, X>>,
+    _: Respectful<F, X, R, S>
+) -> Ty<F, RespFun<X, R, Y, S>> {axiom!()}
+/// `(f : resp_fun(x, r, y, s))  =>  (f : x -> y)`.
+pub fn resp_fun_to_pow<F: Prop, X: Prop, R: Prop, Y: Prop, S: Prop>(
+    _ty_f: Ty<F, RespFun<X, R, Y, S>>
+) -> Ty<F, Pow<Y, X>> {axiom!()}
+/// `(f : resp_fun(x, r, y, s))  =>  f (r ==> s) f`.
+pub fn resp_fun_respectful<F: Prop, X: Prop, R: Prop, Y: Prop, S: Prop>(
+    _ty_f: Ty<F, RespFun<X, R, Y, S>>
+) -> Respectful<F, X, R, S> {axiom!()}
+/// `setoid(x, r) ⋀ setoid(y, s)  =>  setoid(resp_fun(x, r, y, s), r ==> s)`.
+pub fn fun_setoid<X: Prop, R: Prop, Y: Prop, S: Prop>(
+    _: Setoid<X, R>,
+    _: Setoid<Y, S>
+) -> Setoid<RespFun<X, R, Y, S>, RespRel<X, R, S>> {axiom!()}
+/// `setoid(x, r) ⋀ setoid(y, s) ⋀ (g : resp_fun(x, r, y, s)) ⋀
+/// (f(a) s g(a))^(a : x)  =>  f (r ==> s) g`.
+///
+/// Function extensionality relative to setoids.
+pub fn setoid_ext<F: Prop, G: Prop, X: Prop, R: Prop, Y: Prop, S: Prop, A: VProp>(
+    _: Setoid<X, R>,
+    _: Setoid<Y, S>,
+    _ty_g: Ty<G, RespFun<X, R, Y, S>>,
+    _: Pow<Rel<S, App<F, A>, App<G, A>>, Ty<A, X>>
+) -> Rel<RespRel<X, R, S>, F, G> {axiom!()}
+
+/// `setoid(x, r) ⋀ f (r ==> s) g ⋀ (a : x)  =>  f(a) s g(a)`.
+pub fn resp_pointwise<F: Prop, G: Prop, X: Prop, R: Prop, S: Prop, A: Prop>(
+    setoid_x: Setoid<X, R>,
+    fg: Rel<RespRel<X, R, S>, F, G>,
+    ty_a: Ty<A, X>
+) -> Rel<S, App<F, A>, App<G, A>> {
+    let aa = setoid_refl(setoid_x, ty_a.clone());
+    resp_elim(fg, ty_a.clone(), ty_a, aa)
+}
+/// `(f : resp_fun(x, r, y, s)) ⋀ (a : x) ⋀ (b : x) ⋀ (a r b)  =>  f(a) s f(b)`.
+pub fn resp_fun_app<F: Prop, X: Prop, R: Prop, Y: Prop, S: Prop, A: Prop, B: Prop>(
+    ty_f: Ty<F, RespFun<X, R, Y, S>>,
+    ty_a: Ty<A, X>,
+    ty_b: Ty<B, X>,
+    ab: Rel<R, A, B>
+) -> Rel<S, App<F, A>, App<F, B>> {resp_elim(resp_fun_respectful(ty_f), ty_a, ty_b, ab)}
+/// `(a == b) ⋀ (c r a)  =>  c r b`.
+pub fn rel_in_right_arg<R: Prop, A: Prop, B: Prop, C: Prop>(
+    x: Rel<R, C, A>,
+    eq: Eq<A, B>
+) -> Rel<R, C, B> {app_eq(tup_eq_snd(eq)).0(x)}
+/// `(a == b) ⋀ (a r c)  =>  b r c`.
+pub fn rel_in_left_arg<R: Prop, A: Prop, B: Prop, C: Prop>(
+    x: Rel<R, A, C>,
+    eq: Eq<A, B>
+) -> Rel<R, B, C> {app_eq(tup_eq_fst(eq)).0(x)}
+/// `f (r ==> s) f ⋀ (f == g)  =>  f (r ==> s) g`.
+///
+/// Equal functions are related when one of them is respectful.
+pub fn resp_from_eq<F: Prop, G: Prop, X: Prop, R: Prop, S: Prop>(
+    resp_f: Respectful<F, X, R, S>,
+    eq: Eq<F, G>
+) -> Rel<RespRel<X, R, S>, F, G> {rel_in_right_arg(resp_f, eq)}
+/// `setoid(x, r) ⋀ setoid(y, s) ⋀ (f : resp_fun(x, r, y, s)) ⋀ (g : resp_fun(x, r, y, s)) ⋀
+/// f (r ==> s) g  =>  g (r ==> s) f`.
+pub fn resp_symmetry<F: Prop, G: Prop, X: Prop, R: Prop, Y: Prop, S: Prop>(
+    setoid_x: Setoid<X, R>,
+    setoid_y: Setoid<Y, S>,
+    ty_f: Ty<F, RespFun<X, R, Y, S>>,
+    ty_g: Ty<G, RespFun<X, R, Y, S>>,
+    fg: Rel<RespRel<X, R, S>, F, G>
+) -> Rel<RespRel<X, R, S>, G, F> {
+    setoid_symmetry(fun_setoid(setoid_x, setoid_y), ty_f, ty_g, fg)
+}
@@ -0,0 +1,94 @@
+//! # Squash Types
+//!
+//! A squash type `‖x‖` ([Squash]) forgets which member of `x` was used to construct it.
+//! This separates data-relevant content from proof-only content in dependent constructions,
+//! e.g. `‖x‖` can be used where only the existence of a member of `x` matters.
+//!
+//! - Introduction: `(a : x)  =>  |a| : ‖x‖` ([sq_ty])
+//! - Proof irrelevance: `(a : ‖x‖) ⋀ (b : ‖x‖)  =>  (a == b)` ([squash_eq])
+//! - Elimination into propositions:
+//!   `(f : x -> y) ⋀ is_prop(y)  =>  squash_elim(f) : ‖x‖ -> y` ([squash_elim_ty])
+//! - Computation: `squash_elim(f)(|a|) == f(a)` ([squash_elim_sq])
+//!
+//! The notion of proposition is `is_prop(y) := (~y == y)^true` from the [phott] module,
+//! using the qubit operator `~`. A squash type is a proposition ([squash_is_prop]),
+//! so `~‖x‖ == ‖x‖` ([eq_qu_squash]).
+
+use super::*;
+use phott::{is_prop_to_is_set, IsProp, IsSet};
+
+/// Squash type `‖x‖`.
+#[derive(Copy, Clone)]
+pub struct Squash<X>(X);
+
+/// Squash constructor `|_|`.
+#[derive(Copy, Clone)]
+pub struct FSq(());
+
+/// `|a|`.
+pub type Sq<A> = App<FSq, A>;
+
+/// Squash eliminator.
+#[derive(Copy, Clone)]
+pub struct FSquashElim(());
+
+/// `squash_elim(f)`.
+pub type SquashElim<F> = App<FSquashElim, F>;
+
+/// `(x : type(n))  =>  ‖x‖ : type(n)`.
+pub fn squash_ty<X: Prop, N: Nat>(_ty_x: Ty<X, Type<N>>) -> Ty<Squash<X>, Type<N>> {axiom!()}
+/// `is_const(x)  =>  is_const(‖x‖)`.
+pub fn squash_is_const<X: Prop>(_x: IsConst<X>) -> IsConst<Squash<X>> {axiom!()}
+/// `is_const(|_|)`.
+pub fn sq_is_const() -> IsConst<FSq> {axiom!()}
+/// `is_const(squash_elim)`.
+pub fn squash_elim_is_const() -> IsConst<FSquashElim> {axiom!()}
+/// `(a : x)  =>  |a| : ‖x‖`.
+pub fn sq_ty<A: Prop, X: Prop>(_ty_a: Ty<A, X>) -> Ty<Sq<A>, Squash<X>> {axiom!()}
+/// `(a : ‖x‖) ⋀ (b : ‖x‖)  =>  (a == b)`.
+pub fn squash_eq<A: Prop, B: Prop, X: Prop>(
+    _ty_a: Ty<A, Squash<X>>,
+    _ty_b: Ty<B, Squash<X>>
+) -> Eq<A, B> {axiom!()}
+/// `is_prop(‖x‖)`.
+pub fn squash_is_prop<X: Prop>() -> IsProp<Squash<X>> {axiom!()}
+/// `(f : x -> y) ⋀ is_prop(y)  =>  squash_elim(f) : ‖x‖ -> y`.
+pub fn squash_elim_ty<F: Prop, X: Prop, Y: Prop>(
+    _ty_f: Ty<F, Pow<Y, X>>,
+    _is_prop_y: IsProp<Y>
+) -> Ty<SquashElim<F>, Pow<Y, Squash<X>>> {axiom!()}
+/// `(f : x -> y) ⋀ is_prop(y) ⋀ (a : x)  =>  squash_elim(f)(|a|) == f(a)`.
+pub fn squash_elim_sq<F: Prop, X: Prop, Y: Prop, A: Prop>(
+    _ty_f: Ty<F, Pow<Y, X>>,
+    _is_prop_y: IsProp<Y>,
+    _ty_a: Ty<A, X>
+) -> Eq<App<SquashElim<F>, Sq<A>>, App<F, A>> {axiom!()}
+
+/// `(a : x) ⋀ (b : x)  =>  |a| == |b|`.
+pub fn sq_eq<A: Prop, B: Prop, X: Prop>(ty_a: Ty<A, X>, ty_b: Ty<B, X>) -> Eq<Sq<A>, Sq<B>> {
+    squash_eq(sq_ty(ty_a), sq_ty(ty_b))
+}
+/// `is_set(‖x‖)`.
+pub fn squash_is_set<X: Prop>() -> IsSet<Squash<X>> {is_prop_to_is_set(squash_is_prop())}
+/// `~‖x‖ == ‖x‖`.
+pub fn eq_qu_squash<X: Prop>() -> Eq<Qu<Squash<X>>, Squash<X>> {squash_is_prop()(True)}
+/// `(f : x -> y) ⋀ is_prop(y) ⋀ (a : ‖x‖)  =>  squash_elim(f)(a) : y`.
+pub fn squash_elim_app_ty<F: Prop, X: Prop, Y: Prop, A: Prop>(
+    ty_f: Ty<F, Pow<Y, X>>,
+    is_prop_y: IsProp<Y>,
+    ty_a: Ty<A, Squash<X>>
+) -> Ty<App<SquashElim<F>, A>, Y> {app_fun_ty(squash_elim_ty(ty_f, is_prop_y), ty_a)}
+/// `(f : x -> y) ⋀ is_prop(y) ⋀ (a : x) ⋀ (b : x)  =>  f(a) == f(b)`.
+///
+/// A function into a proposition can not tell members apart.
+pub fn squash_elim_irrelevant<F: Prop, X: Prop, Y: Prop, A: Prop, B: Prop>(
+    ty_f: Ty<F, Pow<Y, X>>,
+    is_prop_y: IsProp<Y>,
+    ty_a: Ty<A, X>,
+    ty_b: Ty<B, X>
+) -> Eq<App<F, A>, App<F, B>> {
+    let eq_ab = app_eq::<SquashElim<F>, _, _>(sq_eq(ty_a.clone(), ty_b.clone()));
+    let a = squash_elim_sq(ty_f.clone(), is_prop_y, ty_a);
+    let b = squash_elim_sq(ty_f, is_prop_y, ty_b);
+    eq::transitivity(eq::transitivity(eq::symmetry(a), eq_ab), b)
+}
@@ -0,0 +1,84 @@
+//! # Subtyping
+//!
+//! A subtype judgment `x <: y` ([Sub]) means that every member of `x` is also a member of `y`.
+//! This is used with typing judgments by subsumption ([sub_ty]):
+//!
+//! `(a : x) ⋀ (x <: y)  =>  (a : y)`
+//!
+//! Subtyping is reflexive ([sub_refl]) and transitive ([sub_transitivity]).
+//! Function types are contravariant in the argument and covariant in the result
+//! ([sub_pow], [sub_imply]), while tuple types are covariant ([sub_tup]).
+//!
+//! A subtype judgment can be coerced into an implication ([sub_to_imply]).
+
+use super::*;
+
+/// `x <: y`.
+#[derive(Copy, Clone)]
+pub struct Sub<X, Y>(X, Y);
+
+/// `x <: x`.
+pub fn sub_refl<X: Prop>() -> Sub<X, X> {axiom!()}
+/// `(x <: y) ⋀ (y <: z)  =>  (x <: z)`.
+pub fn sub_transitivity<X: Prop, Y: Prop, Z: Prop>(_: Sub<X, Y>, _: Sub<Y, Z>) -> Sub<X, Z> {
+    axiom!()
+}
+/// `(a : x) ⋀ (x <: y)  =>  (a : y)`.
+///
+/// Subsumption.
+pub fn sub_ty<A: Prop, X: Prop, Y: Prop>(_ty_a: Ty<A, X>, _: Sub<X, Y>) -> Ty<A, Y> {axiom!()}
+/// `(x <: y)  =>  (x => y)`.
+pub fn sub_to_imply<X: Prop, Y: Prop>(_: Sub<X, Y>) -> Imply<X, Y> {axiom!()}
+/// `(x2 <: x1) ⋀ (y1 <: y2)  =>  ((x1 -> y1) <: (x2 -> y2))`.
+pub fn sub_pow<X1: Prop, X2: Prop, Y1: Prop, Y2: Prop>(
+    _: Sub<X2, X1>,
+    _: Sub<Y1, Y2>
+) -> Sub<Pow<Y1, X1>, Pow<Y2, X2>> {axiom!()}
+/// `(x2 <: x1) ⋀ (y1 <: y2)  =>  ((x1 => y1) <: (x2 => y2))`.
+pub fn sub_imply<X1: Prop, X2: Prop, Y1: Prop, Y2: Prop>(
+    _: Sub<X2, X1>,
+    _: Sub<Y1, Y2>
+) -> Sub<Imply<X1, Y1>, Imply<X2, Y2>> {axiom!()}
+/// `(x1 <: x2) ⋀ (y1 <: y2)  =>  ((x1, y1) <: (x2, y2))`.
+pub fn sub_tup<X1: Prop, X2: Prop, Y1: Prop, Y2: Prop>(
+    _: Sub<X1, X2>,
+    _: Sub<Y1, Y2>
+) -> Sub<Tup<X1, Y1>, Tup<X2, Y2>> {axiom!()}
+
+/// `(x2 <: x1)  =>  ((x1 -> y) <: (x2 -> y))`.
+pub fn sub_pow_dom<X1: Prop, X2: Prop, Y: Prop>(x: Sub<X2, X1>) -> Sub<Pow<Y, X1>, Pow<Y, X2>> {
+    sub_pow(x, sub_refl())
+}
+/// `(y1 <: y2)  =>  ((x -> y1) <: (x -> y2))`.
+pub fn sub_pow_cod<X: Prop, Y1: Prop, Y2: Prop>(y: Sub<Y1, Y2>) -> Sub<Pow<Y1, X>, Pow<Y2, X>> {
+    sub_pow(sub_refl(), y)
+}
+/// `(x2 <: x1)  =>  ((x1 => y) <: (x2 => y))`.
+pub fn sub_imply_dom<X1: Prop, X2: Prop, Y: Prop>(
+    x: Sub<X2, X1>
+) -> Sub<Imply<X1, Y>, Imply<X2, Y>> {sub_imply(x, sub_refl())}
+/// `(y1 <: y2)  =>  ((x => y1) <: (x => y2))`.
+pub fn sub_imply_cod<X: Prop, Y1: Prop, Y2: Prop>(
+    y: Sub<Y1, Y2>
+) -> Sub<Imply<X, Y1>, Imply<X, Y2>> {sub_imply(sub_refl(), y)}
+/// `(x1 <: x2)  =>  ((x1, y) <: (x2, y))`.
+pub fn sub_tup_fst<X1: Prop, X2: Prop, Y: Prop>(x: Sub<X1, X2>) -> Sub<Tup<X1, Y>, Tup<X2, Y>> {
+    sub_tup(x, sub_refl())
+}
+/// `(y1 <: y2)  =>  ((x, y1) <: (x, y2))`.
+pub fn sub_tup_snd<X: Prop, Y1: Prop, Y2: Prop>(y: Sub<Y1, Y2>) -> Sub<Tup<X, Y1>, Tup<X, Y2>> {
+    sub_tup(sub_refl(), y)
+}
+/// `(a : x) ⋀ (x <: y) ⋀ (y <: z)  =>  (a : z)`.
+pub fn sub_ty_transitivity<A: Prop, X: Prop, Y: Prop, Z: Prop>(
+    ty_a: Ty<A, X>,
+    x: Sub<X, Y>,
+    y: Sub<Y, Z>
+) -> Ty<A, Z> {sub_ty(ty_a, sub_transitivity(x, y))}
+/// `(f : x1 -> y1) ⋀ (x2 <: x1) ⋀ (y1 <: y2) ⋀ (a : x2)  =>  f(a) : y2`.
+pub fn sub_app_ty<F: Prop, X1: Prop, X2: Prop, Y1: Prop, Y2: Prop, A: Prop>(
+    ty_f: Ty<F, Pow<Y1, X1>>,
+    x: Sub<X2, X1>,
+    y: Sub<Y1, Y2>,
+    ty_a: Ty<A, X2>
+) -> Ty<App<F, A>, Y2> {app_fun_ty(sub_ty(ty_f, sub_pow(x, y)), ty_a)}
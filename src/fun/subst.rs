@@ -1,3 +1,7 @@
+//! # Substitution
+//!
+//! Substitution `e[a := b]` ([Subst]) of variables in expressions.
+
 use super::*;
 
 /// Substitute in expression `e[a := b]`.
@@ -8,35 +12,173 @@ use super::*;
 pub struct Subst<E, A, B>(E, A, B);
 
 /// `a[a := b] == b`
-pub fn subst_trivial<A: Prop, B: Prop>() -> Eq<Subst<A, A, B>, B> {unimplemented!()}
+pub fn subst_trivial<A: Prop, B: Prop>() -> Eq<Subst<A, A, B>, B> {
+    def_eq::to_eq(def_eq::subst_trivial())
+}
 /// `a[b := b] == a`.
-pub fn subst_nop<A: Prop, B: Prop>() -> Eq<Subst<A, B, B>, A> {unimplemented!()}
+pub fn subst_nop<A: Prop, B: Prop>() -> Eq<Subst<A, B, B>, A> {def_eq::to_eq(def_eq::subst_nop())}
 /// `(a : b) => (b[c := a] == b)`.
 pub fn subst_ty<A: Prop, B: Prop, C: Prop>(_ty_a: Ty<A, B>) -> Eq<Subst<B, C, A>, B> {
-    unimplemented!()
+    axiom!()
 }
 /// `is_const(a) => (a[b := c] == a)`.
 pub fn subst_const<A: Prop, B: Prop, C: Prop>(_a_is_const: IsConst<A>) -> Eq<Subst<A, B, C>, A> {
-    unimplemented!()
+    axiom!()
 }
 /// `(a, b)[c := d] == (a[c := d], b[c := d])`.
 pub fn subst_tup<A: Prop, B: Prop, C: Prop, D: Prop>() ->
-    Eq<Subst<Tup<A, B>, C, D>, Tup<Subst<A, C, D>, Subst<B, C, D>>> {unimplemented!()}
+    Eq<Subst<Tup<A, B>, C, D>, Tup<Subst<A, C, D>, Subst<B, C, D>>> {
+    def_eq::to_eq(def_eq::subst_tup())
+}
+/// `(a ⋁ b)[c := d] == (a[c := d] ⋁ b[c := d])`.
+pub fn subst_or<A: Prop, B: Prop, C: Prop, D: Prop>() ->
+    Eq<Subst<Or<A, B>, C, D>, Or<Subst<A, C, D>, Subst<B, C, D>>> {
+    def_eq::to_eq(def_eq::subst_or())
+}
+/// `(a == b)[c := d] == (a[c := d] == b[c := d])`.
+pub fn subst_eq_prop<A: Prop, B: Prop, C: Prop, D: Prop>() ->
+    Eq<Subst<Eq<A, B>, C, D>, Eq<Subst<A, C, D>, Subst<B, C, D>>> {
+    def_eq::to_eq(def_eq::subst_eq_prop())
+}
 /// `(\(a : x) = b)[a := c] == b[a := c]`.
 pub fn subst_lam<A: Prop, B: Prop, C: Prop, D: Prop, X: Prop>() ->
     Eq<Subst<Lam<Ty<A, X>, B>, C, D>, Lam<Ty<A, Subst<X, C, D>>, Subst<Subst<B, C, D>, A, C>>>
-{unimplemented!()}
+{axiom!()}
 /// `(\(a : x) = b)[a := c] == b[a := c]`.
 pub fn subst_lam_const<A: Prop, B: Prop, C: Prop, D: Prop, X: Prop>(
     _x: Eq<Subst<Lam<Ty<A, X>, B>, C, D>, Lam<Ty<A, Subst<X, C, D>>, Subst<Subst<B, C, D>, A, C>>>
-) -> IsConst<A> {unimplemented!()}
+) -> IsConst<A> {axiom!()}
 /// `a[c := d] == b  =>  a[c := d][e := f] == b[e := f]`.
 pub fn subst_eq<A: Prop, B: Prop, C: Prop, D: Prop, E: Prop, F: Prop>(_x: Eq<Subst<A, C, D>, B>) ->
-    Eq<Subst<Subst<A, C, D>, E, F>, Subst<B, C, D>> {unimplemented!()}
+    Eq<Subst<Subst<A, C, D>, E, F>, Subst<B, C, D>> {axiom!()}
 /// `a[c := d] == b  =>  (\(e) = a[c := d]) == (\(e) = b)`.
 pub fn subst_eq_lam_body<A: Prop, B: Prop, C: Prop, D: Prop, E: Prop>(
     _x: Eq<Subst<A, C, D>, B>
-) -> Eq<Lam<E, Subst<A, C, D>>, Lam<E, B>> {unimplemented!()}
+) -> Eq<Lam<E, Subst<A, C, D>>, Lam<E, B>> {axiom!()}
 /// `f(a)[b := c] == f[b := c](a[b := c])`.
 pub fn subst_app<F: Prop, A: Prop, B: Prop, C: Prop>() ->
-    Eq<Subst<App<F, A>, B, C>, App<Subst<F, B, C>, Subst<A, B, C>>> {unimplemented!()}
+    Eq<Subst<App<F, A>, B, C>, App<Subst<F, B, C>, Subst<A, B, C>>> {
+    def_eq::to_eq(def_eq::subst_app())
+}
+/// `(b == c)  =>  e[a := b] == e[a := c]`.
+pub fn subst_eq_val<E: Prop, A: Prop, B: Prop, C: Prop>(
+    (bc, cb): Eq<B, C>
+) -> Eq<Subst<E, A, B>, Subst<E, A, C>> {
+    (Rc::new(move |x| Subst(x.0, x.1, bc(x.2))), Rc::new(move |x| Subst(x.0, x.1, cb(x.2))))
+}
+
+/// Variable `a` occurs free in expression `e`.
+#[derive(Clone, Copy)]
+pub struct FreeIn<A, E>(A, E);
+
+/// Variable `a` does not occur free in expression `e`.
+///
+/// This is used as occurs-check, e.g. before substituting `a := e`.
+pub type NotFreeIn<A, E> = Not<FreeIn<A, E>>;
+
+/// `is_const(e)  =>  ¬free(a, e)`.
+pub fn not_free_const<A: Prop, E: Prop>(_: IsConst<E>) -> NotFreeIn<A, E> {axiom!()}
+/// `free(a, x)  =>  free(a, (x, y))`.
+pub fn free_in_tup_fst<A: Prop, X: Prop, Y: Prop>(_: FreeIn<A, X>) -> FreeIn<A, Tup<X, Y>> {
+    axiom!()
+}
+/// `free(a, y)  =>  free(a, (x, y))`.
+pub fn free_in_tup_snd<A: Prop, X: Prop, Y: Prop>(_: FreeIn<A, Y>) -> FreeIn<A, Tup<X, Y>> {
+    axiom!()
+}
+/// `free(a, (x, y))  =>  free(a, x) ⋁ free(a, y)`.
+pub fn free_in_tup_inv<A: Prop, X: Prop, Y: Prop>(
+    _: FreeIn<A, Tup<X, Y>>
+) -> Or<FreeIn<A, X>, FreeIn<A, Y>> {axiom!()}
+/// `free(a, f)  =>  free(a, f(x))`.
+pub fn free_in_app_fun<A: Prop, F: Prop, X: Prop>(_: FreeIn<A, F>) -> FreeIn<A, App<F, X>> {
+    axiom!()
+}
+/// `free(a, x)  =>  free(a, f(x))`.
+pub fn free_in_app_arg<A: Prop, F: Prop, X: Prop>(_: FreeIn<A, X>) -> FreeIn<A, App<F, X>> {
+    axiom!()
+}
+/// `free(a, f(x))  =>  free(a, f) ⋁ free(a, x)`.
+pub fn free_in_app_inv<A: Prop, F: Prop, X: Prop>(
+    _: FreeIn<A, App<F, X>>
+) -> Or<FreeIn<A, F>, FreeIn<A, X>> {axiom!()}
+/// `¬free(a, \(a : x) = b)`.
+pub fn not_free_lam_bound<A: Prop, X: Prop, B: Prop>() -> NotFreeIn<A, Lam<Ty<A, X>, B>> {
+    axiom!()
+}
+/// `free(a, \(c : x) = b)  =>  free(a, x) ⋁ free(a, b)`.
+pub fn free_in_lam_inv<A: Prop, C: Prop, X: Prop, B: Prop>(
+    _: FreeIn<A, Lam<Ty<C, X>, B>>
+) -> Or<FreeIn<A, X>, FreeIn<A, B>> {axiom!()}
+/// `¬free(a, e)  =>  e[a := b] == e`.
+pub fn not_free_subst_nop<A: Prop, B: Prop, E: Prop>(_: NotFreeIn<A, E>) -> Eq<Subst<E, A, B>, E> {
+    axiom!()
+}
+/// `¬free(a, b)  =>  ¬free(a, e[a := b])`.
+pub fn not_free_subst<A: Prop, B: Prop, E: Prop>(
+    _: NotFreeIn<A, B>
+) -> NotFreeIn<A, Subst<E, A, B>> {axiom!()}
+/// `¬free(a, c) ⋀ ¬free(a, d)  =>  e[a := b][c := d] == e[c := d][a := b[c := d]]`.
+///
+/// Substitution lemma.
+pub fn subst_lemma<A: Prop, B: Prop, C: Prop, D: Prop, E: Prop>(
+    _: NotFreeIn<A, C>,
+    _: NotFreeIn<A, D>,
+) -> Eq<Subst<Subst<E, A, B>, C, D>, Subst<Subst<E, C, D>, A, Subst<B, C, D>>> {axiom!()}
+
+/// `¬free(a, x) ⋀ ¬free(a, y)  =>  ¬free(a, (x, y))`.
+pub fn not_free_tup<A: Prop, X: Prop, Y: Prop>(
+    x: NotFreeIn<A, X>,
+    y: NotFreeIn<A, Y>
+) -> NotFreeIn<A, Tup<X, Y>> {
+    Rc::new(move |fr| match free_in_tup_inv(fr) {
+        Left(fr_x) => x(fr_x),
+        Right(fr_y) => y(fr_y),
+    })
+}
+/// `¬free(a, (x, y))  =>  ¬free(a, x)`.
+pub fn not_free_tup_fst<A: Prop, X: Prop, Y: Prop>(x: NotFreeIn<A, Tup<X, Y>>) -> NotFreeIn<A, X> {
+    Rc::new(move |fr| x(free_in_tup_fst(fr)))
+}
+/// `¬free(a, (x, y))  =>  ¬free(a, y)`.
+pub fn not_free_tup_snd<A: Prop, X: Prop, Y: Prop>(x: NotFreeIn<A, Tup<X, Y>>) -> NotFreeIn<A, Y> {
+    Rc::new(move |fr| x(free_in_tup_snd(fr)))
+}
+/// `¬free(a, f) ⋀ ¬free(a, x)  =>  ¬free(a, f(x))`.
+pub fn not_free_app<A: Prop, F: Prop, X: Prop>(
+    f: NotFreeIn<A, F>,
+    x: NotFreeIn<A, X>
+) -> NotFreeIn<A, App<F, X>> {
+    Rc::new(move |fr| match free_in_app_inv(fr) {
+        Left(fr_f) => f(fr_f),
+        Right(fr_x) => x(fr_x),
+    })
+}
+/// `¬free(a, f(x))  =>  ¬free(a, f)`.
+pub fn not_free_app_fun<A: Prop, F: Prop, X: Prop>(x: NotFreeIn<A, App<F, X>>) -> NotFreeIn<A, F> {
+    Rc::new(move |fr| x(free_in_app_fun(fr)))
+}
+/// `¬free(a, f(x))  =>  ¬free(a, x)`.
+pub fn not_free_app_arg<A: Prop, F: Prop, X: Prop>(x: NotFreeIn<A, App<F, X>>) -> NotFreeIn<A, X> {
+    Rc::new(move |fr| x(free_in_app_arg(fr)))
+}
+/// `¬free(a, x) ⋀ ¬free(a, b)  =>  ¬free(a, \(c : x) = b)`.
+pub fn not_free_lam<A: Prop, C: Prop, X: Prop, B: Prop>(
+    x: NotFreeIn<A, X>,
+    b: NotFreeIn<A, B>
+) -> NotFreeIn<A, Lam<Ty<C, X>, B>> {
+    Rc::new(move |fr| match free_in_lam_inv(fr) {
+        Left(fr_x) => x(fr_x),
+        Right(fr_b) => b(fr_b),
+    })
+}
+/// `¬free(a, c) ⋀ ¬free(a, d) ⋀ ¬free(c, b)  =>  e[a := b][c := d] == e[c := d][a := b]`.
+///
+/// Independent substitutions commute.
+pub fn subst_comm<A: Prop, B: Prop, C: Prop, D: Prop, E: Prop>(
+    a_c: NotFreeIn<A, C>,
+    a_d: NotFreeIn<A, D>,
+    c_b: NotFreeIn<C, B>,
+) -> Eq<Subst<Subst<E, A, B>, C, D>, Subst<Subst<E, C, D>, A, B>> {
+    eq::transitivity(subst_lemma(a_c, a_d), subst_eq_val(not_free_subst_nop(c_b)))
+}
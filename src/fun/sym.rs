@@ -0,0 +1,155 @@
+//! # Function Symbols
+//!
+//! Function symbols such as `dup` ([Dup]), `fst` ([Fst]) or `id` ([FId]) are introduced
+//! by axioms for constantness, typing and definition.
+//! This module describes these axioms with traits,
+//! such that tactics can be written once for all symbols:
+//!
+//! - Constantness `is_const(f)` ([FunSym] and [Const])
+//! - Typing `f : x -> y` ([FunTy])
+//! - Definition `f(a) == b` ([FunDef])
+//!
+//! Typing and definition might depend on a premise `p`,
+//! e.g. `id{x}(a) == a` requires `(x : type(n)) ⋀ (a : x)`.
+//! Symbols without premises use `true`.
+//!
+//! Downstream crates can register new symbols by implementing these traits,
+//! after which the symbols participate in generic tactics,
+//! e.g. `(a : x)  =>  f(a) : y` ([app_sym_ty]) and `f(a) == b  =>  b : y` ([sym_def_ty]).
+//!
+//! Parallel tuples ([Par]) and composition ([Comp]) of symbols are symbols.
+//! Typing of composition is given by [comp_sym_ty],
+//! since the middle type can not be inferred from the composition.
+
+use super::*;
+
+/// Function symbol `f`.
+///
+/// Function symbols are constants (see [Const]).
+pub trait FunSym: Const {}
+
+/// Function symbol `f : x -> y` under premise `p`.
+pub trait FunTy<X: Prop, Y: Prop, P: Prop = True>: Prop {
+    /// `p  =>  f : x -> y`.
+    fn ty(pre: P) -> Ty<Self, Pow<Y, X>>;
+}
+
+/// Function symbol definition `f(a) == b` under premise `p`.
+pub trait FunDef<A: Prop, P: Prop = True>: Prop {
+    /// The definition `b`.
+    type Out: Prop;
+    /// `p  =>  f(a) == b`.
+    fn def(pre: P) -> Eq<App<Self, A>, Self::Out>;
+}
+
+impl FunSym for Dup {}
+impl<A: Prop> FunTy<A, Tup<A, A>> for Dup {
+    fn ty(_: True) -> Ty<Self, Pow<Tup<A, A>, A>> {dup_ty()}
+}
+impl<A: Prop> FunDef<A> for Dup {
+    type Out = Tup<A, A>;
+    fn def(_: True) -> Eq<App<Self, A>, Tup<A, A>> {dup_def()}
+}
+
+impl FunSym for Fst {}
+impl<A: Prop, B: Prop> FunTy<Tup<A, B>, A> for Fst {
+    fn ty(_: True) -> Ty<Self, Pow<A, Tup<A, B>>> {fst_ty()}
+}
+impl<A: Prop, B: Prop> FunDef<Tup<A, B>> for Fst {
+    type Out = A;
+    fn def(_: True) -> Eq<App<Self, Tup<A, B>>, A> {fst_def()}
+}
+
+impl FunSym for Snd {}
+impl<A: Prop, B: Prop> FunTy<Tup<A, B>, B> for Snd {
+    fn ty(_: True) -> Ty<Self, Pow<B, Tup<A, B>>> {snd_ty()}
+}
+impl<A: Prop, B: Prop> FunDef<Tup<A, B>> for Snd {
+    type Out = B;
+    fn def(_: True) -> Eq<App<Self, Tup<A, B>>, B> {snd_def()}
+}
+
+impl FunSym for Swap {}
+impl<A: Prop, B: Prop> FunTy<Tup<A, B>, Tup<B, A>> for Swap {
+    fn ty(_: True) -> Ty<Self, Pow<Tup<B, A>, Tup<A, B>>> {swap_ty()}
+}
+impl<A: Prop, B: Prop> FunDef<Tup<A, B>> for Swap {
+    type Out = Tup<B, A>;
+    fn def(_: True) -> Eq<App<Self, Tup<A, B>>, Tup<B, A>> {swap_def()}
+}
+
+impl FunSym for AssocR {}
+impl<A: Prop, B: Prop, C: Prop> FunTy<Tup<Tup<A, B>, C>, Tup<A, Tup<B, C>>> for AssocR {
+    fn ty(_: True) -> Ty<Self, Pow<Tup<A, Tup<B, C>>, Tup<Tup<A, B>, C>>> {assoc_r_ty()}
+}
+impl<A: Prop, B: Prop, C: Prop> FunDef<Tup<Tup<A, B>, C>> for AssocR {
+    type Out = Tup<A, Tup<B, C>>;
+    fn def(_: True) -> Eq<App<Self, Tup<Tup<A, B>, C>>, Tup<A, Tup<B, C>>> {assoc_r_def()}
+}
+
+impl FunSym for FId {}
+impl<A: Prop, N: Nat> FunTy<A, A, Ty<A, Type<N>>> for Id<A> {
+    fn ty(ty_a: Ty<A, Type<N>>) -> Ty<Self, Pow<A, A>> {id_ty(ty_a)}
+}
+impl<A: Prop, X: Prop, N: Nat> FunDef<A, And<Ty<X, Type<N>>, Ty<A, X>>> for Id<X> {
+    type Out = A;
+    fn def((ty_x, ty_a): And<Ty<X, Type<N>>, Ty<A, X>>) -> Eq<App<Self, A>, A> {
+        id_def(ty_x, ty_a)
+    }
+}
+
+impl FunSym for ParTup {}
+impl<F: FunSym, G: FunSym> FunSym for Par<F, G> {}
+impl<F, G, X1, X2, Y1, Y2, P1, P2> FunTy<Tup<X1, X2>, Tup<Y1, Y2>, And<P1, P2>> for Par<F, G>
+    where F: FunTy<X1, Y1, P1>, G: FunTy<X2, Y2, P2>,
+          X1: Prop, X2: Prop, Y1: Prop, Y2: Prop, P1: Prop, P2: Prop
+{
+    fn ty((p1, p2): And<P1, P2>) -> Ty<Self, Pow<Tup<Y1, Y2>, Tup<X1, X2>>> {
+        par_tup_fun_ty(F::ty(p1), G::ty(p2))
+    }
+}
+impl<F, G, A1, A2, P1, P2> FunDef<Tup<A1, A2>, And<P1, P2>> for Par<F, G>
+    where F: FunDef<A1, P1>, G: FunDef<A2, P2>, A1: Prop, A2: Prop, P1: Prop, P2: Prop
+{
+    type Out = Tup<F::Out, G::Out>;
+    fn def((p1, p2): And<P1, P2>) -> Eq<App<Self, Tup<A1, A2>>, Self::Out> {
+        par_tup_def(F::def(p1), G::def(p2))
+    }
+}
+
+impl FunSym for FComp {}
+impl<F: FunSym, G: FunSym> FunSym for Comp<G, F> {}
+impl<F, G, A, P1, P2> FunDef<A, And<P1, P2>> for Comp<G, F>
+    where F: FunDef<A, P1>, G: FunDef<F::Out, P2>, A: Prop, P1: Prop, P2: Prop
+{
+    type Out = G::Out;
+    fn def((p1, p2): And<P1, P2>) -> Eq<App<Self, A>, G::Out> {
+        let x = eq::transitivity(app_eq(F::def(p1)), G::def(p2));
+        eq::transitivity(eq::symmetry(eq_app_comp()), x)
+    }
+}
+
+/// `is_const(a)  =>  is_const(f(a))`.
+pub fn app_sym_is_const<F: FunSym, A: Prop>(a: IsConst<A>) -> IsConst<App<F, A>> {
+    app_is_const(F::is_const(), a)
+}
+/// `p ⋀ (a : x)  =>  f(a) : y`.
+pub fn app_sym_ty<F: FunTy<X, Y, P>, X: Prop, Y: Prop, P: Prop, A: Prop>(
+    pre: P,
+    ty_a: Ty<A, X>
+) -> Ty<App<F, A>, Y> {app_fun_ty(F::ty(pre), ty_a)}
+/// `p ⋀ q ⋀ (a : x)  =>  b : y` where `f(a) == b`.
+pub fn sym_def_ty<F, X: Prop, Y: Prop, P: Prop, Q: Prop, A: Prop>(
+    pre_ty: P,
+    pre_def: Q,
+    ty_a: Ty<A, X>
+) -> Ty<<F as FunDef<A, Q>>::Out, Y>
+    where F: FunTy<X, Y, P> + FunDef<A, Q>
+{ty::in_left_arg(app_sym_ty::<F, _, _, _, _>(pre_ty, ty_a), F::def(pre_def))}
+/// `p ⋀ q  =>  (g . f) : x -> z` where `f : x -> y` and `g : y -> z`.
+pub fn comp_sym_ty<F, G, X: Prop, Y: Prop, Z: Prop, P: Prop, Q: Prop>(
+    pre_f: P,
+    pre_g: Q
+) -> Ty<Comp<G, F>, Pow<Z, X>>
+    where F: FunTy<X, Y, P>, G: FunTy<Y, Z, Q>
+{comp_ty(F::ty(pre_f), G::ty(pre_g))}
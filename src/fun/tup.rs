@@ -1,4 +1,8 @@
 //! Tuple.
+//!
+//! Tuples come with the function symbols `fst` ([Fst]), `snd` ([Snd]),
+//! `swap` ([Swap]), `assoc_l` ([AssocL]) and `assoc_r` ([AssocR]).
+//! The latter are the structural maps of the symmetric monoidal structure (see [monoidal]).
 
 use super::*;
 
@@ -8,21 +12,23 @@ pub struct Tup<A, B>(A, B);
 
 /// `(type(n), type(m)) : type(0)`.
 pub fn tup_type_ty<N: Nat, M: Nat>() -> Ty<Tup<Type<N>, Type<M>>, Type<Z>> {
-    unimplemented!()
+    axiom!()
 }
 /// `(a : x) ⋀ (b : y)  =>  (a, b) : (x, y)`.
 pub fn tup_ty<A: Prop, B: Prop, X: Prop, Y: Prop>(
     _ty_a: Ty<A, X>,
     _ty_b: Ty<B, Y>
-) -> Ty<Tup<A, B>, Tup<X, Y>> {unimplemented!()}
+) -> Ty<Tup<A, B>, Tup<X, Y>> {axiom!()}
 /// `is_const(a) ⋀ is_const(b)  =>  is_const((a, b))`.
 pub fn tup_is_const<A: Prop, B: Prop>(_a: IsConst<A>, _b: IsConst<B>) -> IsConst<Tup<A, B>> {
-    unimplemented!()
+    axiom!()
 }
 /// `is_const((a, b))  =>  is_const(a)`.
-pub fn tup_fst_const<A: Prop, B: Prop>(_: IsConst<Tup<A, B>>) -> IsConst<A> {unimplemented!()}
+pub fn tup_fst_const<A: Prop, B: Prop>(_: IsConst<Tup<A, B>>) -> IsConst<A> {axiom!()}
 /// `is_const((a, b))  =>  is_const(b)`.
-pub fn tup_snd_const<A: Prop, B: Prop>(_: IsConst<Tup<A, B>>) -> IsConst<B> {unimplemented!()}
+pub fn tup_snd_const<A: Prop, B: Prop>(_: IsConst<Tup<A, B>>) -> IsConst<B> {axiom!()}
+/// `~a ⋀ ~b  =>  ~(a, b)`.
+pub fn tup_qu<A: Prop, B: Prop>(_: Qu<A>, _: Qu<B>) -> Qu<Tup<A, B>> {axiom!()}
 /// `(a == b)  =>  (a, c) == (b, c)`.
 pub fn tup_eq_fst<A: Prop, B: Prop, C: Prop>((ab, ba): Eq<A, B>) -> Eq<Tup<A, C>, Tup<B, C>> {
     (Rc::new(move |y| Tup(ab(y.0), y.1)), Rc::new(move |y| Tup(ba(y.0), y.1)))
@@ -35,12 +41,12 @@ pub fn tup_eq_snd<A: Prop, B: Prop, C: Prop>((ab, ba): Eq<A, B>) -> Eq<Tup<C, A>
 pub fn tup_rev_eq_fst<A: Prop, B: Prop, C: Prop, D: Prop>(
     _: Ty<C, D>,
     _: Eq<Tup<A, C>, Tup<B, C>>
-) -> Eq<A, B> {unimplemented!()}
+) -> Eq<A, B> {axiom!()}
 /// `(c : d) ⋀ ((c, a) == (c, b))  =>  (a == b)`.
 pub fn tup_rev_eq_snd<A: Prop, B: Prop, C: Prop, D: Prop>(
     _: Ty<C, D>,
     _: Eq<Tup<C, A>, Tup<C, B>>
-) -> Eq<A, B> {unimplemented!()}
+) -> Eq<A, B> {axiom!()}
 
 /// `(a, b) : (x, y)  =>  (a : x)`.
 pub fn tup_fst<A: Prop, B: Prop, X: Prop, Y: Prop>(
@@ -62,6 +68,12 @@ pub fn tup_in_right_arg<A: Prop, B: Prop, C: Prop>(x: Tup<A, B>, y: Eq<B, C>) ->
 pub fn tup_eq_fst_snd<A: Prop, B: Prop>() ->
     Eq<Tup<A, B>, Tup<App<Fst, Tup<A, B>>, App<Snd, Tup<A, B>>>>
 {eq::transitivity(eq::symmetry(tup_eq_fst(fst_def())), tup_eq_snd(eq::symmetry(snd_def())))}
+/// `t : (x, y)  =>  (fst(t), snd(t)) == t`.
+///
+/// Eta law of tuples.
+pub fn tup_eta<T: Prop, X: Prop, Y: Prop>(
+    _: Ty<T, Tup<X, Y>>
+) -> Eq<Tup<App<Fst, T>, App<Snd, T>>, T> {axiom!()}
 /// `(a == b) ⋀ (c == d)  =>  (a, c) == (b, d)`.
 pub fn tup_eq<A: Prop, B: Prop, C: Prop, D: Prop>(
     eq_ab: Eq<A, B>,
@@ -121,15 +133,15 @@ pub struct Fst(());
 /// `fst : (a, b) -> a`.
 ///
 /// Type of Fst.
-pub fn fst_ty<A: Prop, B: Prop>() -> Ty<Fst, Pow<A, Tup<A, B>>> {unimplemented!()}
+pub fn fst_ty<A: Prop, B: Prop>() -> Ty<Fst, Pow<A, Tup<A, B>>> {axiom!()}
 /// `is_const(fst)`.
-pub fn fst_is_const() -> IsConst<Fst> {unimplemented!()}
+pub fn fst_is_const() -> IsConst<Fst> {axiom!()}
 /// `fst((a, b)) = a`.
-pub fn fst_def<A: Prop, B: Prop>() -> Eq<App<Fst, Tup<A, B>>, A> {unimplemented!()}
+pub fn fst_def<A: Prop, B: Prop>() -> Eq<App<Fst, Tup<A, B>>, A> {axiom!()}
 /// `t : (x : a, b)  =>  fst(t) == x`.
 pub fn fst_lower<T: Prop, X: Prop, A: Prop, B: Prop>(
     _: Ty<T, Tup<Ty<X, A>, B>>
-) -> Eq<App<Fst, T>, X> {unimplemented!()}
+) -> Eq<App<Fst, T>, X> {axiom!()}
 
 /// `t : (a, b)  =>  fst(t) : a`.
 pub fn fst<T: Prop, A: Prop, B: Prop>(x: Ty<T, Tup<A, B>>) -> Ty<App<Fst, T>, A> {
@@ -143,15 +155,15 @@ pub struct Snd(());
 /// `snd : (a, b) -> b`.
 ///
 /// Type of Snd.
-pub fn snd_ty<A: Prop, B: Prop>() -> Ty<Snd, Pow<B, Tup<A, B>>> {unimplemented!()}
+pub fn snd_ty<A: Prop, B: Prop>() -> Ty<Snd, Pow<B, Tup<A, B>>> {axiom!()}
 /// `is_const(snd)`.
-pub fn snd_is_const() -> IsConst<Snd> {unimplemented!()}
+pub fn snd_is_const() -> IsConst<Snd> {axiom!()}
 /// `snd((a, b)) = b`.
-pub fn snd_def<A: Prop, B: Prop>() -> Eq<App<Snd, Tup<A, B>>, B> {unimplemented!()}
+pub fn snd_def<A: Prop, B: Prop>() -> Eq<App<Snd, Tup<A, B>>, B> {axiom!()}
 /// `t : (a, x : b)  =>  snd(t) == x`.
 pub fn snd_lower<T: Prop, X: Prop, A: Prop, B: Prop>(
     _: Ty<T, Tup<A, Ty<X, B>>>
-) -> Eq<App<Snd, T>, X> {unimplemented!()}
+) -> Eq<App<Snd, T>, X> {axiom!()}
 
 /// `t : (a, b)  =>  snd(t) : a`.
 pub fn snd<T: Prop, A: Prop, B: Prop>(x: Ty<T, Tup<A, B>>) -> Ty<App<Snd, T>, B> {
@@ -173,36 +185,111 @@ pub fn par_tup_fun_ty<F: Prop, G: Prop, X1: Prop, X2: Prop, Y1: Prop, Y2: Prop>(
     _ty_f: Ty<F, Pow<Y1, X1>>,
     _ty_g: Ty<G, Pow<Y2, X2>>,
 ) -> Ty<Par<F, G>, Pow<Tup<Y1, Y2>, Tup<X1, X2>>> {
-    unimplemented!()
+    axiom!()
 }
 /// `(f : (x1 => y1)) ⋀ (g : (x2 => y2))  =>  (f x g) : ((x1, x2) => (y1, y2))`.
 pub fn par_tup_lam_ty<F: Prop, G: Prop, X1: Prop, X2: Prop, Y1: Prop, Y2: Prop>(
     _ty_f: Ty<F, Imply<X1, Y1>>,
     _ty_g: Ty<G, Imply<X2, Y2>>,
 ) -> Ty<Par<F, G>, Imply<Tup<X1, X2>, Tup<Y1, Y2>>> {
-    unimplemented!()
+    axiom!()
 }
 /// `is_const(par_tup)`.
-pub fn par_tup_is_const() -> IsConst<ParTup> {unimplemented!()}
+pub fn par_tup_is_const() -> IsConst<ParTup> {axiom!()}
 /// `(id{a} x id{b}) == id{(a, b)}`.
 pub fn par_tup_id<A: Prop, B: Prop>() -> Eq<Par<App<FId, A>, App<FId, B>>, App<FId, Tup<A, B>>> {
-    unimplemented!()
+    axiom!()
 }
 /// `(g1 x g2) . (f1 x f2)  ==  ((g1 . f1) x (g2 . f2))`.
 pub fn par_tup_comp<F1: Prop, F2: Prop, G1: Prop, G2: Prop>() ->
     Eq<Comp<Par<G1, G2>, Par<F1, F2>>, Par<Comp<G1, F1>, Comp<G2, F2>>>
-{unimplemented!()}
+{axiom!()}
 /// `inv(f x g)  ==  inv(f) x inv(g)`.
 pub fn par_tup_inv<F: Prop, G: Prop>() -> Eq<Inv<Par<F, G>>, ParInv<F, G>>
-{unimplemented!()}
+{axiom!()}
 /// `(f(i0) == o0) ⋀ (g(i1) == o1)  =>  (f x g)(i0, i1) == (o0, o1)`.
 pub fn par_tup_def<F: Prop, G: Prop, I0: Prop, I1: Prop, O0: Prop, O1: Prop>(
     _eq0: Eq<App<F, I0>, O0>,
     _eq1: Eq<App<G, I1>, O1>,
-) -> Eq<App<Par<F, G>, Tup<I0, I1>>, Tup<O0, O1>> {unimplemented!()}
+) -> Eq<App<Par<F, G>, Tup<I0, I1>>, Tup<O0, O1>> {axiom!()}
 
 /// `is_const(f) ⋀ is_const(g)  =>  is_const(f x g)`.
 pub fn par_tup_app_is_const<F: Prop, G: Prop>(
     f: IsConst<F>,
     g: IsConst<G>
 ) -> IsConst<Par<F, G>> {app_is_const(par_tup_is_const(), tup_is_const(f, g))}
+
+/// Swap.
+///
+/// This is the braiding of the symmetric monoidal structure ([monoidal::Braid]).
+pub type Swap = monoidal::Braid;
+
+/// `swap : (a, b) -> (b, a)`.
+pub fn swap_ty<A: Prop, B: Prop>() -> Ty<Swap, Pow<Tup<B, A>, Tup<A, B>>> {monoidal::braid_ty()}
+/// `is_const(swap)`.
+pub fn swap_is_const() -> IsConst<Swap> {monoidal::braid_is_const()}
+/// `swap((a, b)) == (b, a)`.
+pub fn swap_def<A: Prop, B: Prop>() -> Eq<App<Swap, Tup<A, B>>, Tup<B, A>> {
+    monoidal::braid_def()
+}
+/// `inv(swap) == swap`.
+pub fn swap_inv() -> Eq<Inv<Swap>, Swap> {monoidal::braid_inv()}
+/// `(swap . swap) == id{(a, b)}`.
+pub fn eq_swap_swap<A: Prop, B: Prop>() -> Eq<Comp<Swap, Swap>, App<FId, Tup<A, B>>> {
+    monoidal::eq_braid_braid()
+}
+/// `swap . (f x g)  ==  (g x f) . swap`.
+pub fn swap_nat<F: Prop, G: Prop>() -> Eq<Comp<Swap, Par<F, G>>, Comp<Par<G, F>, Swap>> {
+    monoidal::braid_nat()
+}
+
+/// Associate to the right.
+///
+/// This is the associator of the symmetric monoidal structure ([monoidal::Assoc]).
+pub type AssocR = monoidal::Assoc;
+
+/// Associate to the left.
+pub type AssocL = Inv<AssocR>;
+
+/// `assoc_r : ((a, b), c) -> (a, (b, c))`.
+pub fn assoc_r_ty<A: Prop, B: Prop, C: Prop>() ->
+    Ty<AssocR, Pow<Tup<A, Tup<B, C>>, Tup<Tup<A, B>, C>>> {monoidal::assoc_ty()}
+/// `assoc_l : (a, (b, c)) -> ((a, b), c)`.
+pub fn assoc_l_ty<A: Prop, B: Prop, C: Prop>() ->
+    Ty<AssocL, Pow<Tup<Tup<A, B>, C>, Tup<A, Tup<B, C>>>> {inv_ty(assoc_r_ty())}
+/// `is_const(assoc_r)`.
+pub fn assoc_r_is_const() -> IsConst<AssocR> {monoidal::assoc_is_const()}
+/// `assoc_r(((a, b), c)) == (a, (b, c))`.
+pub fn assoc_r_def<A: Prop, B: Prop, C: Prop>() ->
+    Eq<App<AssocR, Tup<Tup<A, B>, C>>, Tup<A, Tup<B, C>>> {monoidal::assoc_def()}
+/// `assoc_l((a, (b, c))) == ((a, b), c)`.
+pub fn assoc_l_def<A: Prop, B: Prop, C: Prop>() ->
+    Eq<App<AssocL, Tup<A, Tup<B, C>>>, Tup<Tup<A, B>, C>> {monoidal::assoc_inv_def()}
+/// `(assoc_l . assoc_r) == id{((a, b), c)}`.
+pub fn eq_assoc_l_assoc_r<A: Prop, B: Prop, C: Prop>() ->
+    Eq<Comp<AssocL, AssocR>, App<FId, Tup<Tup<A, B>, C>>> {monoidal::eq_assoc_inv_assoc()}
+/// `(assoc_r . assoc_l) == id{(a, (b, c))}`.
+pub fn eq_assoc_r_assoc_l<A: Prop, B: Prop, C: Prop>() ->
+    Eq<Comp<AssocR, AssocL>, App<FId, Tup<A, Tup<B, C>>>> {monoidal::eq_assoc_assoc_inv()}
+/// `assoc_r . ((f x g) x h)  ==  (f x (g x h)) . assoc_r`.
+pub fn assoc_r_nat<F: Prop, G: Prop, H: Prop>() ->
+    Eq<Comp<AssocR, Par<Par<F, G>, H>>, Comp<Par<F, Par<G, H>>, AssocR>> {monoidal::assoc_nat()}
+/// `(f : x1 -> y1) ⋀ (g : x2 -> y2) ⋀ (h : x3 -> y3)  =>
+///   assoc_l . (f x (g x h))  ==  ((f x g) x h) . assoc_l`.
+pub fn assoc_l_nat<F: Prop, G: Prop, H: Prop,
+    X1: Prop, X2: Prop, X3: Prop, Y1: Prop, Y2: Prop, Y3: Prop>(
+    ty_f: Ty<F, Pow<Y1, X1>>,
+    ty_g: Ty<G, Pow<Y2, X2>>,
+    ty_h: Ty<H, Pow<Y3, X3>>,
+) -> Eq<Comp<AssocL, Par<F, Par<G, H>>>, Comp<Par<Par<F, G>, H>, AssocL>> {
+    let ty_p1 = par_tup_fun_ty(par_tup_fun_ty(ty_f.clone(), ty_g.clone()), ty_h.clone());
+    let ty_p2 = par_tup_fun_ty(ty_f, par_tup_fun_ty(ty_g, ty_h));
+    let x = eq::transitivity(eq::symmetry(comp_id_right(ty_p2)),
+        comp_eq_right(eq::symmetry(eq_assoc_r_assoc_l::<X1, X2, X3>())));
+    let x = eq::transitivity(eq::transitivity(x, comp_assoc()),
+        comp_eq_left(eq::symmetry(assoc_r_nat())));
+    let x = eq::transitivity(x, eq::symmetry(comp_assoc()));
+    let x = eq::transitivity(comp_eq_right(x), comp_assoc());
+    let x = eq::transitivity(x, comp_eq_left(eq_assoc_l_assoc_r::<Y1, Y2, Y3>()));
+    eq::transitivity(x, comp_id_left(comp_ty(assoc_l_ty::<X1, X2, X3>(), ty_p1)))
+}
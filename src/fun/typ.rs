@@ -1,4 +1,5 @@
 use super::*;
+use nat::{Le, Lt, MaxNat};
 
 /// Cumulative type hierarchy.
 #[derive(Copy, Clone)]
@@ -12,17 +13,17 @@ impl<N: 'static + Clone> path_semantics::LProp for Type<N> {
 /// `type(n) => type(n+1)`.
 pub fn type_imply<N: Nat>(Type(n): Type<N>) -> Type<S<N>> {Type(S(n))}
 /// `is_const(type(n))`.
-pub fn type_is_const<N: Nat>() -> IsConst<Type<N>> {unimplemented!()}
+pub fn type_is_const<N: Nat>() -> IsConst<Type<N>> {axiom!()}
 /// `(a : x) ⋀ (b : y)  =>  (a -> b) : (x -> y)`.
 pub fn fun_ty<A: Prop, B: Prop, X: Prop, Y: Prop>(
     _: Ty<A, X>,
     _: Ty<B, Y>
-) -> Ty<Pow<B, A>, Pow<Y, X>> {unimplemented!()}
+) -> Ty<Pow<B, A>, Pow<Y, X>> {axiom!()}
 /// `(type(n) -> type(m)) : type(0)`.
-pub fn fun_type_ty<N: Nat, M: Nat>() -> Ty<Pow<Type<M>, Type<N>>, Type<Z>> {unimplemented!()}
+pub fn fun_type_ty<N: Nat, M: Nat>() -> Ty<Pow<Type<M>, Type<N>>, Type<Z>> {axiom!()}
 /// `(b : type(n))  =>  (a : b) : type(n)`.
 pub fn judgement_ty<A: Prop, B: Prop, N: Nat>(_ty_b: Ty<B, Type<N>>) -> Ty<Ty<A, B>, Type<N>> {
-    unimplemented!()
+    axiom!()
 }
 
 /// `type(n) : type(n+1)`.
@@ -34,6 +35,50 @@ pub fn fun_type0<A: Prop, B: Prop, N: Nat, M: Nat>(
     ty_a: Ty<A, Type<N>>,
     ty_b: Ty<B, Type<M>>
 ) -> Ty<Pow<B, A>, Type<Z>> {ty::transitivity(fun_ty(ty_a, ty_b), fun_type_ty())}
+
+/// Maximum level `max(n, m)`.
+pub type MaxLevel<N, M> = <(N, M) as MaxNat>::Out;
+
+/// `type(n) => type(m)` where `n < m`.
+pub fn type_lift<N: Nat + Lt<M>, M: Nat>(_: Type<N>) -> Type<M> {Type(M::default())}
+/// `type(n) : type(m)` where `n < m`.
+pub fn type_lt_ty<N: Nat + Lt<M>, M: Nat>() -> Ty<Type<N>, Type<M>> {
+    (hooo::pow_to_imply(type_lift), POrdProof::new())
+}
+/// `(a : type(n))  =>  a : type(m)` where `n <= m`.
+///
+/// Cumulativity of the type hierarchy.
+pub fn lift_ty<A: Prop, N: Nat + Le<M>, M: Nat>(_ty_a: Ty<A, Type<N>>) -> Ty<A, Type<M>> {
+    axiom!()
+}
+/// `(a : type(n))  =>  a : type(m)` where `n < m`.
+pub fn lift_ty_lt<A: Prop, N: Nat + Lt<M>, M: Nat>(ty_a: Ty<A, Type<N>>) -> Ty<A, Type<M>> {
+    ty::transitivity(ty_a, type_lt_ty())
+}
+/// `(a : type(n))  =>  a : type(n+1)`.
+pub fn lift_ty_level<A: Prop, N: Nat>(ty_a: Ty<A, Type<N>>) -> Ty<A, Type<S<N>>> {
+    ty::transitivity(ty_a, type_ty())
+}
+/// `(a : type(n))  =>  a : type(max(n, m))`.
+pub fn max_ty_left<A: Prop, N: Nat + Le<MaxLevel<N, M>>, M: Nat>(
+    ty_a: Ty<A, Type<N>>
+) -> Ty<A, Type<MaxLevel<N, M>>> where (N, M): MaxNat {lift_ty(ty_a)}
+/// `(b : type(m))  =>  b : type(max(n, m))`.
+pub fn max_ty_right<B: Prop, N: Nat, M: Nat + Le<MaxLevel<N, M>>>(
+    ty_b: Ty<B, Type<M>>
+) -> Ty<B, Type<MaxLevel<N, M>>> where (N, M): MaxNat {lift_ty(ty_b)}
+/// `(a : type(n)) ⋀ (b : type(m))  =>  (a : type(max(n, m))) ⋀ (b : type(max(n, m)))`.
+pub fn max_ty<A: Prop, B: Prop, N: Nat + Le<MaxLevel<N, M>>, M: Nat + Le<MaxLevel<N, M>>>(
+    ty_a: Ty<A, Type<N>>,
+    ty_b: Ty<B, Type<M>>
+) -> And<Ty<A, Type<MaxLevel<N, M>>>, Ty<B, Type<MaxLevel<N, M>>>> where (N, M): MaxNat {
+    (max_ty_left::<A, N, M>(ty_a), max_ty_right::<B, N, M>(ty_b))
+}
+/// `(a : type(n)) ⋀ (b : type(m))  =>  (a -> b) : type(k)`.
+pub fn pow_ty<A: Prop, B: Prop, N: Nat, M: Nat, K: Nat>(
+    ty_a: Ty<A, Type<N>>,
+    ty_b: Ty<B, Type<M>>
+) -> Ty<Pow<B, A>, Type<K>> {lift_ty(fun_type0(ty_a, ty_b))}
 /// `(f : A -> B) ⋀ (inv(f) ~~ g) => ((f ~~ g) : ((A -> B) ~~ (B -> A)))`.
 pub fn q_inv_ty<F: Prop, G: Prop, A: Prop, B: Prop>(
     ty_f: Ty<F, Pow<B, A>>,
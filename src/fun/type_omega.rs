@@ -0,0 +1,64 @@
+//! # Top Level of Types
+//!
+//! The cumulative hierarchy forms a chain of inclusions ([type_imply]):
+//!
+//! `type(0) => type(1) => type(2) => ...`
+//!
+//! Its directed colimit `type(ω)` ([TypeOmega]) is a top level containing every level:
+//!
+//! - Cocone injections `type(n) => type(ω)` ([type_omega_inj])
+//! - Every level is a type at the top level `type(n) : type(ω)` ([type_omega_ty])
+//! - Universal property: A cocone `type(n) => x` for all `n` ([TypeCocone])
+//!   factors through `type(ω) => x` ([type_omega_elim])
+//!
+//! Since propositions form a thin category, the cocone injections commute
+//! with the inclusions and the mediating map is unique, without extra proofs.
+//!
+//! The top level is not a member of any level `type(n)`,
+//! so it can be used to state properties of all types without a universe parameter,
+//! e.g. `(a : type(n)) ⋀ (b : type(m))  =>  (a -> b) : type(ω)` ([fun_type_omega]).
+
+use super::*;
+
+/// Colimit `type(ω)` of the cumulative hierarchy.
+#[derive(Copy, Clone)]
+pub struct TypeOmega(());
+
+/// Cocone `type(n) => x` for all `n`.
+pub trait TypeCocone<X: Prop>: Clone + 'static {
+    /// `type(n) => x`.
+    fn inj<N: Nat>(&self) -> Imply<Type<N>, X>;
+}
+
+/// The colimit cocone `type(n) => type(ω)`.
+#[derive(Copy, Clone)]
+pub struct OmegaCocone;
+
+impl TypeCocone<TypeOmega> for OmegaCocone {
+    fn inj<N: Nat>(&self) -> Imply<Type<N>, TypeOmega> {hooo::pow_to_imply(type_omega_inj)}
+}
+
+/// `is_const(type(ω))`.
+pub fn type_omega_is_const() -> IsConst<TypeOmega> {axiom!()}
+/// `type(n) => type(ω)`.
+pub fn type_omega_inj<N: Nat>(_: Type<N>) -> TypeOmega {axiom!()}
+/// `type(n) : type(ω)`.
+pub fn type_omega_ty<N: Nat>() -> Ty<Type<N>, TypeOmega> {axiom!()}
+/// `(∀ n { type(n) => x })  =>  (type(ω) => x)`.
+pub fn type_omega_elim<X: Prop, C: TypeCocone<X>>(_: C) -> Imply<TypeOmega, X> {axiom!()}
+
+/// `(a : type(n))  =>  a : type(ω)`.
+pub fn ty_omega<A: Prop, N: Nat>(ty_a: Ty<A, Type<N>>) -> Ty<A, TypeOmega> {
+    ty::transitivity(ty_a, type_omega_ty())
+}
+/// `(a : type(n)) ⋀ (b : type(m))  =>  (a -> b) : type(ω)`.
+pub fn fun_type_omega<A: Prop, B: Prop, N: Nat, M: Nat>(
+    ty_a: Ty<A, Type<N>>,
+    ty_b: Ty<B, Type<M>>
+) -> Ty<Pow<B, A>, TypeOmega> {ty_omega(fun_type0(ty_a, ty_b))}
+/// `(∀ n { type(n) => x }) ⋀ (x => type(ω))  =>  (type(ω) == x)`.
+///
+/// A cocone with a map back into the top level is equivalent to the colimit.
+pub fn type_omega_eq<X: Prop, C: TypeCocone<X>>(c: C, x: Imply<X, TypeOmega>) -> Eq<TypeOmega, X> {
+    (type_omega_elim(c), x)
+}
@@ -0,0 +1,101 @@
+//! # Well-founded Relations
+//!
+//! A relation `r` is written as a function symbol applied to a tuple, `r(b, a)` or `b r a`,
+//! meaning `b` is smaller than `a`.
+//!
+//! The accessibility predicate `acc(r, a)` ([Acc]) means that every descending chain
+//! starting at `a` is finite. It is defined inductively ([acc_intro]):
+//!
+//! ```text
+//! (b r a  =>  acc(r, b))
+//! ----------------------
+//! acc(r, a)
+//! ```
+//!
+//! A relation is well-founded on `x` when every `a : x` is accessible ([Wf]).
+//! Functions can be defined by well-founded recursion ([acc_rec], [wf_rec]).
+//!
+//! The successor ordering on natural numbers is well-founded ([AccLtS]).
+
+use super::*;
+use natp::{succ_eq_rev, para_pre_zero, Succ, Zero};
+
+/// Accessibility.
+#[derive(Copy, Clone)]
+pub struct FAcc(());
+
+/// `acc(r, a)`.
+pub type Acc<R, A> = App<App<FAcc, R>, A>;
+
+/// `r` is well-founded on `x`, `acc(r, a)^(a : x)`.
+pub type Wf<R, X, A> = Pow<Acc<R, A>, Ty<A, X>>;
+
+/// `is_const(acc)`.
+pub fn acc_is_const() -> IsConst<FAcc> {axiom!()}
+/// `(b r a  =>  acc(r, b))  =>  acc(r, a)`.
+pub fn acc_intro<R: Prop, A: Prop, B: VProp>(
+    _: Imply<App<R, Tup<B, A>>, Acc<R, B>>
+) -> Acc<R, A> {axiom!()}
+/// `acc(r, a) ⋀ b r a  =>  acc(r, b)`.
+pub fn acc_inv<R: Prop, A: Prop, B: Prop>(
+    _: Acc<R, A>,
+    _: App<R, Tup<B, A>>
+) -> Acc<R, B> {axiom!()}
+/// Well-founded recursion.
+///
+/// ```text
+/// p(a)^(b r a  =>  p(b))
+/// ----------------------
+/// p(a)^acc(r, a)
+/// ```
+pub fn acc_rec<R: Prop, P: Prop, A: VProp, B: VProp>(
+    _step: Pow<App<P, A>, Imply<App<R, Tup<B, A>>, App<P, B>>>
+) -> Pow<App<P, A>, Acc<R, A>> {axiom!()}
+/// `wf(r, x) ⋀ (p(a)^(b r a  =>  p(b)))  =>  p(a)^(a : x)`.
+pub fn wf_rec<R: Prop, X: Prop, P: Prop, A: VProp, B: VProp>(
+    wf: Wf<R, X, A>,
+    step: Pow<App<P, A>, Imply<App<R, Tup<B, A>>, App<P, B>>>
+) -> Pow<App<P, A>, Ty<A, X>> {wf.trans(acc_rec(step))}
+/// `(a == b) ⋀ acc(r, a)  =>  acc(r, b)`.
+pub fn acc_in_arg<R: Prop, A: Prop, B: Prop>(x: Acc<R, A>, y: Eq<A, B>) -> Acc<R, B> {app_eq(y).0(x)}
+
+/// Successor ordering.
+#[derive(Copy, Clone)]
+pub struct FLtS(());
+
+/// `b <ₛ a`.
+pub type LtS<B, A> = App<FLtS, Tup<B, A>>;
+
+/// `is_const(<ₛ)`.
+pub fn lt_s_is_const() -> IsConst<FLtS> {axiom!()}
+/// `(b <ₛ a) == (a == succ(b))`.
+pub fn lt_s_def<A: Prop, B: Prop>() -> Eq<LtS<B, A>, Eq<A, Succ<B>>> {axiom!()}
+/// `acc(<ₛ, 0)`.
+pub fn acc_lt_s_zero<B: VProp>() -> Acc<FLtS, Zero> {
+    acc_intro::<FLtS, Zero, B>(Rc::new(move |x| {
+        imply::absurd()(para_pre_zero(eq::symmetry(lt_s_def().0(x))))
+    }))
+}
+/// `acc(<ₛ, n)  =>  acc(<ₛ, succ(n))`.
+pub fn acc_lt_s_succ<N: Prop, B: VProp>(acc_n: Acc<FLtS, N>) -> Acc<FLtS, Succ<N>> {
+    acc_intro::<FLtS, Succ<N>, B>(Rc::new(move |x| {
+        acc_in_arg(acc_n.clone(), succ_eq_rev(lt_s_def().0(x)))
+    }))
+}
+
+/// Implemented by natural numbers that are accessible by the successor ordering.
+///
+/// This is proved by structural recursion on numerals,
+/// showing that the successor ordering is well-founded.
+pub trait AccLtS: Prop {
+    /// `acc(<ₛ, n)`.
+    fn acc_lt_s<B: VProp>() -> Acc<FLtS, Self>;
+}
+
+impl AccLtS for Zero {
+    fn acc_lt_s<B: VProp>() -> Acc<FLtS, Self> {acc_lt_s_zero::<B>()}
+}
+
+impl<N: AccLtS> AccLtS for Succ<N> {
+    fn acc_lt_s<B: VProp>() -> Acc<FLtS, Self> {acc_lt_s_succ::<N, B>(N::acc_lt_s::<B>())}
+}
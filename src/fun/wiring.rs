@@ -0,0 +1,108 @@
+//! # Wiring Diagrams
+//!
+//! Nested compositions of parallel tuples `f x g` ([Par]) can be thought of as wiring diagrams,
+//! where `f x g` places boxes side by side and `g . f` connects outputs of `f` to inputs of `g`.
+//!
+//! The interchange law ([par_tup_comp]) says that a diagram can be read either
+//! layer by layer or wire by wire:
+//!
+//! `(g1 x g2) . (f1 x f2)  ==  ((g1 . f1) x (g2 . f2))`
+//!
+//! Independent components can be exchanged ([par_exchange]),
+//! and identity wires can be eliminated ([par_id_comp_left], [par_id_comp_right]).
+//!
+//! ### Normal form
+//!
+//! A diagram is in normal form when all parallel tuples are moved outwards,
+//! such that each wire is a single composition.
+//! The [wire!](crate::wire) macro rewrites a diagram into normal form,
+//! by applying the interchange law at chosen positions.
+
+use super::*;
+
+/// Helps rewriting wiring diagrams into normal form.
+///
+/// Produces an equality from the diagram to the rewritten diagram.
+/// Commands are separated by `,` and applied in order.
+///
+/// # Leaf commands
+///
+/// - `ic`: Interchange `(g1 x g2) . (f1 x f2)  =>  ((g1 . f1) x (g2 . f2))`
+/// - `id`: Merge identities `id{a} x id{b}  =>  id{(a, b)}`
+///
+/// # Branch commands
+///
+/// - `l(<cmds>)`: Left component of parallel tuple
+/// - `r(<cmds>)`: Right component of parallel tuple
+/// - `cl(<cmds>)`: Left side of composition
+/// - `cr(<cmds>)`: Right side of composition
+/// - `rev(<cmds>)`: Reverses the rewrite
+#[macro_export]
+macro_rules! wire(
+    (ic) => {$crate::fun::par_tup_comp()};
+    (id) => {$crate::fun::par_tup_id()};
+    (l($($x:tt)+)) => {$crate::fun::wiring::par_eq_left($crate::wire!($($x)+))};
+    (r($($x:tt)+)) => {$crate::fun::wiring::par_eq_right($crate::wire!($($x)+))};
+    (cl($($x:tt)+)) => {$crate::fun::comp_eq_left($crate::wire!($($x)+))};
+    (cr($($x:tt)+)) => {$crate::fun::comp_eq_right($crate::wire!($($x)+))};
+    (rev($($x:tt)+)) => {$crate::eq::symmetry($crate::wire!($($x)+))};
+    ($cmd:ident $(($($x:tt)+))?, $($rest:tt)+) => {
+        $crate::eq::transitivity($crate::wire!($cmd $(($($x)+))?), $crate::wire!($($rest)+))
+    };
+);
+
+/// `(f == h)  =>  (f x g) == (h x g)`.
+pub fn par_eq_left<F: Prop, G: Prop, H: Prop>(x: Eq<F, H>) -> Eq<Par<F, G>, Par<H, G>> {
+    app_eq(tup_eq_fst(x))
+}
+/// `(g == h)  =>  (f x g) == (f x h)`.
+pub fn par_eq_right<F: Prop, G: Prop, H: Prop>(x: Eq<G, H>) -> Eq<Par<F, G>, Par<F, H>> {
+    app_eq(tup_eq_snd(x))
+}
+/// `(f0 == f1) ⋀ (g0 == g1)  =>  (f0 x g0) == (f1 x g1)`.
+pub fn par_eq<F0: Prop, F1: Prop, G0: Prop, G1: Prop>(
+    x: Eq<F0, F1>,
+    y: Eq<G0, G1>
+) -> Eq<Par<F0, G0>, Par<F1, G1>> {eq::transitivity(par_eq_left(x), par_eq_right(y))}
+/// `(h1 x h2) . ((g1 x g2) . (f1 x f2))  ==  ((h1 . (g1 . f1)) x (h2 . (g2 . f2)))`.
+pub fn par_tup_comp3<F1: Prop, F2: Prop, G1: Prop, G2: Prop, H1: Prop, H2: Prop>() -> Eq<
+    Comp<Par<H1, H2>, Comp<Par<G1, G2>, Par<F1, F2>>>,
+    Par<Comp<H1, Comp<G1, F1>>, Comp<H2, Comp<G2, F2>>>
+> {wire!(cr(ic), ic)}
+/// `(f : x1 -> y1) ⋀ (g : x2 -> y2)  =>  (f x g) == (f x id{y2}) . (id{x1} x g)`.
+pub fn par_split_left<F: Prop, G: Prop, X1: Prop, X2: Prop, Y1: Prop, Y2: Prop>(
+    ty_f: Ty<F, Pow<Y1, X1>>,
+    ty_g: Ty<G, Pow<Y2, X2>>,
+) -> Eq<Par<F, G>, Comp<Par<F, Id<Y2>>, Par<Id<X1>, G>>> {
+    eq::symmetry(eq::transitivity(wire!(ic), par_eq(comp_id_right(ty_f), comp_id_left(ty_g))))
+}
+/// `(f : x1 -> y1) ⋀ (g : x2 -> y2)  =>  (f x g) == (id{y1} x g) . (f x id{x2})`.
+pub fn par_split_right<F: Prop, G: Prop, X1: Prop, X2: Prop, Y1: Prop, Y2: Prop>(
+    ty_f: Ty<F, Pow<Y1, X1>>,
+    ty_g: Ty<G, Pow<Y2, X2>>,
+) -> Eq<Par<F, G>, Comp<Par<Id<Y1>, G>, Par<F, Id<X2>>>> {
+    eq::symmetry(eq::transitivity(wire!(ic), par_eq(comp_id_left(ty_f), comp_id_right(ty_g))))
+}
+/// `(f : x1 -> y1) ⋀ (g : x2 -> y2)  =>
+///   (f x id{y2}) . (id{x1} x g)  ==  (id{y1} x g) . (f x id{x2})`.
+///
+/// Independent components can be exchanged.
+pub fn par_exchange<F: Prop, G: Prop, X1: Prop, X2: Prop, Y1: Prop, Y2: Prop>(
+    ty_f: Ty<F, Pow<Y1, X1>>,
+    ty_g: Ty<G, Pow<Y2, X2>>,
+) -> Eq<Comp<Par<F, Id<Y2>>, Par<Id<X1>, G>>, Comp<Par<Id<Y1>, G>, Par<F, Id<X2>>>> {
+    eq::transitivity(eq::symmetry(par_split_left(ty_f.clone(), ty_g.clone())),
+        par_split_right(ty_f, ty_g))
+}
+/// `(f : x -> (a, b))  =>  (id{a} x id{b}) . f == f`.
+pub fn par_id_comp_left<F: Prop, X: Prop, A: Prop, B: Prop>(
+    ty_f: Ty<F, Pow<Tup<A, B>, X>>
+) -> Eq<Comp<Par<Id<A>, Id<B>>, F>, F> {
+    eq::transitivity(wire!(cl(id)), comp_id_left(ty_f))
+}
+/// `(f : (a, b) -> y)  =>  f . (id{a} x id{b}) == f`.
+pub fn par_id_comp_right<F: Prop, Y: Prop, A: Prop, B: Prop>(
+    ty_f: Ty<F, Pow<Y, Tup<A, B>>>
+) -> Eq<Comp<F, Par<Id<A>, Id<B>>>, F> {
+    eq::transitivity(wire!(cr(id)), comp_id_right(ty_f))
+}
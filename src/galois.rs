@@ -0,0 +1,125 @@
+//! # Galois Connections
+//!
+//! A Galois connection between two operators `f` and `g` on propositions is written `f ⊣ g`:
+//!
+//! `(f(a) => b)  ==  (a => g(b))`
+//!
+//! Operators are represented as symbols applied with [fun::App].
+//! Since a Galois connection quantifies over all `a` and `b`,
+//! it is modelled as a trait [Gc] that is implemented by a witness.
+//!
+//! From a Galois connection, one can derive:
+//!
+//! - Unit `a => g(f(a))` and counit `f(g(b)) => b`
+//! - Monotonicity of `f` and `g`
+//! - A closure operator `g . f` (extensive, monotone and idempotent)
+//! - A kernel operator `f . g` (reductive, monotone and idempotent)
+//!
+//! Galois connections compose ([GcComp]).
+//!
+//! In abstract interpretation, `f` is the abstraction and `g` is the concretization.
+
+use crate::*;
+
+use fun::{App, Comp, comp_to_app, app_to_comp, eq_app_comp};
+
+/// Implemented by monotone operators `(a => b)  =>  (f(a) => f(b))`.
+///
+/// Both sides of a Galois connection are monotone (see [Gc::mono_left] and [Gc::mono_right]).
+pub trait Monotone<F: Prop>: Clone + 'static {
+    /// `(a => b)  =>  (f(a) => f(b))`.
+    fn mono<A: Prop, B: Prop>(&self, ab: Imply<A, B>) -> Imply<App<F, A>, App<F, B>>;
+}
+
+/// Galois connection `f ⊣ g`.
+pub trait Gc<F: Prop, G: Prop>: Clone + 'static {
+    /// `(f(a) => b)  ==  (a => g(b))`.
+    fn gc<A: Prop, B: Prop>(&self) -> Eq<Imply<App<F, A>, B>, Imply<A, App<G, B>>>;
+
+    /// Unit `a => g(f(a))`.
+    fn unit<A: Prop>(&self) -> Imply<A, App<G, App<F, A>>> {self.gc().0(imply::id())}
+
+    /// Counit `f(g(b)) => b`.
+    fn counit<B: Prop>(&self) -> Imply<App<F, App<G, B>>, B> {self.gc().1(imply::id())}
+
+    /// `(a => b)  =>  (f(a) => f(b))`.
+    fn mono_left<A: Prop, B: Prop>(&self, ab: Imply<A, B>) -> Imply<App<F, A>, App<F, B>> {
+        self.gc().1(imply::transitivity(ab, self.unit()))
+    }
+
+    /// `(a => b)  =>  (g(a) => g(b))`.
+    fn mono_right<A: Prop, B: Prop>(&self, ab: Imply<A, B>) -> Imply<App<G, A>, App<G, B>> {
+        self.gc().0(imply::transitivity(self.counit(), ab))
+    }
+
+    /// Closure is extensive `a => (g . f)(a)`.
+    fn closure_ext<A: Prop>(&self) -> Imply<A, App<Comp<G, F>, A>> {
+        imply::transitivity(self.unit(), Rc::new(app_to_comp))
+    }
+
+    /// Closure is idempotent `(g . f)((g . f)(a)) => (g . f)(a)`.
+    fn closure_idem<A: Prop>(&self) -> Imply<App<Comp<G, F>, App<Comp<G, F>, A>>, App<Comp<G, F>, A>> {
+        let x: Imply<App<F, App<Comp<G, F>, A>>, App<F, A>> =
+            imply::transitivity(self.mono_left(Rc::new(comp_to_app)), self.counit());
+        let x = imply::transitivity(Rc::new(comp_to_app), self.mono_right(x));
+        imply::transitivity(x, Rc::new(app_to_comp))
+    }
+
+    /// Closure is monotone `(a => b)  =>  ((g . f)(a) => (g . f)(b))`.
+    fn closure_mono<A: Prop, B: Prop>(
+        &self,
+        ab: Imply<A, B>
+    ) -> Imply<App<Comp<G, F>, A>, App<Comp<G, F>, B>> {
+        let x = imply::transitivity(Rc::new(comp_to_app), self.mono_right(self.mono_left(ab)));
+        imply::transitivity(x, Rc::new(app_to_comp))
+    }
+
+    /// Kernel is reductive `(f . g)(b) => b`.
+    fn kernel_red<B: Prop>(&self) -> Imply<App<Comp<F, G>, B>, B> {
+        imply::transitivity(Rc::new(comp_to_app), self.counit())
+    }
+
+    /// Kernel is idempotent `(f . g)(b) => (f . g)((f . g)(b))`.
+    fn kernel_idem<B: Prop>(&self) -> Imply<App<Comp<F, G>, B>, App<Comp<F, G>, App<Comp<F, G>, B>>> {
+        let x: Imply<App<G, B>, App<G, App<Comp<F, G>, B>>> =
+            imply::transitivity(self.unit(), self.mono_right(Rc::new(app_to_comp)));
+        let x = imply::transitivity(Rc::new(comp_to_app), self.mono_left(x));
+        imply::transitivity(x, Rc::new(app_to_comp))
+    }
+
+    /// Kernel is monotone `(a => b)  =>  ((f . g)(a) => (f . g)(b))`.
+    fn kernel_mono<A: Prop, B: Prop>(
+        &self,
+        ab: Imply<A, B>
+    ) -> Imply<App<Comp<F, G>, A>, App<Comp<F, G>, B>> {
+        let x = imply::transitivity(Rc::new(comp_to_app), self.mono_left(self.mono_right(ab)));
+        imply::transitivity(x, Rc::new(app_to_comp))
+    }
+}
+
+/// Composition of Galois connections `(f1 ⊣ g1) ⋀ (f2 ⊣ g2)  =>  (f2 . f1) ⊣ (g1 . g2)`.
+#[derive(Clone)]
+pub struct GcComp<T1, T2>(pub T1, pub T2);
+
+impl<F1: Prop, G1: Prop, F2: Prop, G2: Prop, T1: Gc<F1, G1>, T2: Gc<F2, G2>>
+    Gc<Comp<F2, F1>, Comp<G1, G2>> for GcComp<T1, T2>
+{
+    fn gc<A: Prop, B: Prop>(&self) -> Eq<Imply<App<Comp<F2, F1>, A>, B>, Imply<A, App<Comp<G1, G2>, B>>> {
+        let x = eq::symmetry(imply::eq_left(eq_app_comp::<F1, F2, A>()));
+        let x = eq::transitivity(x, self.1.gc());
+        let x = eq::transitivity(x, self.0.gc());
+        eq::transitivity(x, imply::eq_right(eq_app_comp()))
+    }
+}
+
+/// `(f ⊣ g) ⋀ (f(a) => b)  =>  (a => g(b))`.
+pub fn to_right<F: Prop, G: Prop, A: Prop, B: Prop, T: Gc<F, G>>(
+    t: &T,
+    x: Imply<App<F, A>, B>
+) -> Imply<A, App<G, B>> {t.gc().0(x)}
+
+/// `(f ⊣ g) ⋀ (a => g(b))  =>  (f(a) => b)`.
+pub fn to_left<F: Prop, G: Prop, A: Prop, B: Prop, T: Gc<F, G>>(
+    t: &T,
+    x: Imply<A, App<G, B>>
+) -> Imply<App<F, A>, B> {t.gc().1(x)}
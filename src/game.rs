@@ -0,0 +1,165 @@
+//! # Game Semantics
+//!
+//! This is an experimental module that interprets propositions as games
+//! between two players, Verifier and Falsifier.
+//! A proposition holds when Verifier has a winning strategy,
+//! and it is refuted when Falsifier has a winning strategy.
+//!
+//! Strategies are propositions themselves, given by the [Game] trait:
+//!
+//! - `true`: Verifier wins without moving, Falsifier can not win
+//! - `false`: Falsifier wins without moving, Verifier can not win
+//! - `a ⋀ b`: Falsifier chooses which side to play, so Verifier must win both
+//! - `a ⋁ b`: Verifier chooses which side to play, so Falsifier must win both
+//! - `a => b`: Falsifier attacks by playing `a` as Verifier, which Verifier must
+//!   answer by winning `b` (a dialogue in the style of Lorenzen)
+//!
+//! Atoms are wrapped in [Atom], where the moves are proofs and refutations.
+//!
+//! The semantics agrees with the library:
+//! Winning strategies of Verifier correspond to proofs ([Game::to_prop], [Game::from_prop]),
+//! winning strategies of Falsifier are refutations ([Game::refute]),
+//! and both players can not win the same game ([para_win_lose]).
+//!
+//! Determinacy, that one of the players has a winning strategy,
+//! requires Excluded Middle and is not part of the semantics.
+//!
+//! ```rust
+//! use prop::*;
+//! use prop::game::*;
+//! use std::rc::Rc;
+//! use Either::*;
+//!
+//! // Verifier answers an attack on `a => a ⋁ b` by choosing the left side.
+//! fn win<A: Prop, B: Prop>() -> <Imply<Atom<A>, Or<Atom<A>, Atom<B>>> as Game>::Win {
+//!     Rc::new(Left)
+//! }
+//!
+//! // Falsifier wins `a ⋀ false` by choosing the right side.
+//! fn lose<A: Prop>() -> Not<And<Atom<A>, False>> {
+//!     <And<Atom<A>, False> as Game>::refute(Right(True))
+//! }
+//!
+//! // Falsifier wins `true => false` by playing `true` and winning `false`.
+//! type X = Imply<True, False>;
+//! let _: Not<X> = X::refute((True, True));
+//! let _: <Not<X> as Game>::Win = lose_to_win_not::<X>((True, True));
+//!
+//! // A proof of `a ⋁ false` tells which side Verifier plays.
+//! let win = <Or<Atom<True>, False> as Game>::from_prop(Left(Atom(True)));
+//! assert!(matches!(win, Left(True)));
+//! ```
+
+use crate::*;
+
+/// A proposition interpreted as a game.
+pub trait Game: Prop {
+    /// Winning strategy of Verifier.
+    type Win: Prop;
+    /// Winning strategy of Falsifier.
+    type Lose: Prop;
+    /// Soundness: A winning strategy of Verifier proves the proposition.
+    fn to_prop(win: Self::Win) -> Self;
+    /// Completeness: A proof gives a winning strategy to Verifier.
+    fn from_prop(a: Self) -> Self::Win;
+    /// Soundness: A winning strategy of Falsifier refutes the proposition.
+    fn refute(lose: Self::Lose) -> Not<Self>;
+}
+
+/// An atomic proposition as a game.
+#[derive(Clone)]
+pub struct Atom<A>(pub A);
+
+impl Game for True {
+    type Win = True;
+    type Lose = False;
+    fn to_prop(win: True) -> True {win}
+    fn from_prop(a: True) -> True {a}
+    fn refute(lose: False) -> Not<True> {match lose {}}
+}
+
+impl Game for False {
+    type Win = False;
+    type Lose = True;
+    fn to_prop(win: False) -> False {win}
+    fn from_prop(a: False) -> False {a}
+    fn refute(_: True) -> Not<False> {Rc::new(|x| x)}
+}
+
+impl<A: Prop> Game for Atom<A> {
+    type Win = A;
+    type Lose = Not<A>;
+    fn to_prop(win: A) -> Atom<A> {Atom(win)}
+    fn from_prop(a: Atom<A>) -> A {a.0}
+    fn refute(lose: Not<A>) -> Not<Atom<A>> {Rc::new(move |a| lose(a.0))}
+}
+
+impl<A: Game, B: Game> Game for And<A, B> {
+    type Win = And<A::Win, B::Win>;
+    type Lose = Or<A::Lose, B::Lose>;
+    fn to_prop((a, b): Self::Win) -> Self {(A::to_prop(a), B::to_prop(b))}
+    fn from_prop((a, b): Self) -> Self::Win {(A::from_prop(a), B::from_prop(b))}
+    fn refute(lose: Self::Lose) -> Not<Self> {
+        match lose {
+            Left(x) => {let na = A::refute(x); Rc::new(move |(a, _)| na(a))}
+            Right(x) => {let nb = B::refute(x); Rc::new(move |(_, b)| nb(b))}
+        }
+    }
+}
+
+impl<A: Game, B: Game> Game for Or<A, B> {
+    type Win = Or<A::Win, B::Win>;
+    type Lose = And<A::Lose, B::Lose>;
+    fn to_prop(win: Self::Win) -> Self {
+        match win {
+            Left(a) => Left(A::to_prop(a)),
+            Right(b) => Right(B::to_prop(b)),
+        }
+    }
+    fn from_prop(x: Self) -> Self::Win {
+        match x {
+            Left(a) => Left(A::from_prop(a)),
+            Right(b) => Right(B::from_prop(b)),
+        }
+    }
+    fn refute((x, y): Self::Lose) -> Not<Self> {
+        let (na, nb) = (A::refute(x), B::refute(y));
+        Rc::new(move |ab| match ab {
+            Left(a) => na(a),
+            Right(b) => nb(b),
+        })
+    }
+}
+
+impl<A: Game, B: Game> Game for Imply<A, B> {
+    type Win = Imply<A::Win, B::Win>;
+    type Lose = And<A::Win, B::Lose>;
+    fn to_prop(win: Self::Win) -> Self {Rc::new(move |a| B::to_prop(win(A::from_prop(a))))}
+    fn from_prop(f: Self) -> Self::Win {Rc::new(move |a| B::from_prop(f(A::to_prop(a))))}
+    fn refute((a, y): Self::Lose) -> Not<Self> {
+        let a = A::to_prop(a);
+        let nb = B::refute(y);
+        Rc::new(move |f| nb(f(a.clone())))
+    }
+}
+
+/// `win(a) ⋀ lose(a)  =>  false`.
+///
+/// Both players can not win the same game.
+pub fn para_win_lose<A: Game>(win: A::Win, lose: A::Lose) -> False {
+    A::refute(lose)(A::to_prop(win))
+}
+/// `atom(a) == a`.
+pub fn atom_eq<A: Prop>() -> Eq<Atom<A>, A> {(Rc::new(|a| a.0), Rc::new(Atom))}
+/// `win(a) == a`.
+pub fn eq_win<A: Game>() -> Eq<A::Win, A> {(Rc::new(A::to_prop), Rc::new(A::from_prop))}
+/// `lose(a)  =>  win(¬a)`.
+///
+/// Falsifier's strategy is Verifier's strategy of the negated game.
+pub fn lose_to_win_not<A: Game>(lose: A::Lose) -> <Not<A> as Game>::Win {
+    <Not<A> as Game>::from_prop(A::refute(lose))
+}
+/// `(a == b)  =>  win(a) == win(b)`.
+pub fn eq_win_eq<A: Game, B: Game>(x: Eq<A, B>) -> Eq<A::Win, B::Win> {
+    eq::transitivity(eq_win::<A>(), eq::transitivity(x, eq::symmetry(eq_win::<B>())))
+}
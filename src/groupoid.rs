@@ -0,0 +1,133 @@
+//! # Groupoid of Quality
+//!
+//! Path semantical quality `a ~~ b` (see [quality::Q]) forms a groupoid,
+//! where objects are propositions and morphisms are proofs of quality:
+//!
+//! - Composition: `(a ~~ b) ⋀ (b ~~ c) => (a ~~ c)` ([comp])
+//! - Inverse: `(a ~~ b) => (b ~~ a)` ([inv])
+//! - Identity: `~a => (a ~~ a)` ([id])
+//!
+//! Since quality is a proposition, the coherence laws hold up to `==`,
+//! e.g. `((a ~~ a) ⋀ (a ~~ b)) == (a ~~ b)` ([eq_comp_id_left]).
+//! Laws that compare paths themselves, e.g. that inverting twice gives back the same path,
+//! can not be stated, since there is no equality between proofs of `a ~~ b`.
+//!
+//! The [Groupoid] record packages a path together with its inverse and identities.
+//! It is useful as a starting point for constructions that expect the algebraic structure
+//! of a groupoid, as in the [hott] module.
+
+use crate::*;
+
+use quality::Q;
+use qubit::Qu;
+
+/// Groupoid record of a path `a ~~ b`.
+#[derive(Clone)]
+pub struct Groupoid<A, B> {
+    /// `a ~~ b`.
+    pub path: Q<A, B>,
+    /// `b ~~ a`.
+    pub inv: Q<B, A>,
+    /// `a ~~ a`.
+    pub id_left: Q<A, A>,
+    /// `b ~~ b`.
+    pub id_right: Q<B, B>,
+}
+
+impl<A: Prop, B: Prop> Groupoid<A, B> {
+    /// `(a ~~ b) => groupoid(a, b)`.
+    pub fn from_q(q_ab: Q<A, B>) -> Self {
+        Groupoid {
+            inv: inv(q_ab.clone()),
+            id_left: quality::left(q_ab.clone()),
+            id_right: quality::right(q_ab.clone()),
+            path: q_ab,
+        }
+    }
+
+    /// `groupoid(a, b) => (a ~~ b)`.
+    pub fn to_q(self) -> Q<A, B> {self.path}
+
+    /// `groupoid(a, b) => groupoid(b, a)`.
+    pub fn inverse(self) -> Groupoid<B, A> {
+        Groupoid {
+            path: self.inv,
+            inv: self.path,
+            id_left: self.id_right,
+            id_right: self.id_left,
+        }
+    }
+
+    /// `groupoid(a, b) ⋀ groupoid(b, c) => groupoid(a, c)`.
+    pub fn comp<C: Prop>(self, other: Groupoid<B, C>) -> Groupoid<A, C> {
+        Groupoid {
+            path: comp(self.path, other.path),
+            inv: comp(other.inv, self.inv),
+            id_left: self.id_left,
+            id_right: other.id_right,
+        }
+    }
+}
+
+/// `(a ~~ b) == groupoid(a, b)`.
+pub fn eq_q_groupoid<A: Prop, B: Prop>() -> Eq<Q<A, B>, Groupoid<A, B>> {
+    (Rc::new(Groupoid::from_q), Rc::new(Groupoid::to_q))
+}
+
+/// Composition `(a ~~ b) ⋀ (b ~~ c) => (a ~~ c)`.
+pub fn comp<A: Prop, B: Prop, C: Prop>(q_ab: Q<A, B>, q_bc: Q<B, C>) -> Q<A, C> {
+    quality::transitivity(q_ab, q_bc)
+}
+
+/// Inverse `(a ~~ b) => (b ~~ a)`.
+pub fn inv<A: Prop, B: Prop>(q_ab: Q<A, B>) -> Q<B, A> {quality::symmetry(q_ab)}
+
+/// Identity `~a => (a ~~ a)`.
+pub fn id<A: Prop>(qu_a: Qu<A>) -> Q<A, A> {qu_a.to_q()}
+
+/// `((a ~~ b) ⋀ (b ~~ c)) ⋀ (c ~~ d)  =>  (a ~~ d)`.
+pub fn comp3<A: Prop, B: Prop, C: Prop, D: Prop>(
+    ((q_ab, q_bc), q_cd): And<And<Q<A, B>, Q<B, C>>, Q<C, D>>
+) -> Q<A, D> {
+    comp(comp(q_ab, q_bc), q_cd)
+}
+
+/// Associativity `(((a ~~ b) ⋀ (b ~~ c)) ⋀ (c ~~ d)) == ((a ~~ b) ⋀ ((b ~~ c) ⋀ (c ~~ d)))`.
+pub fn eq_comp_assoc<A: Prop, B: Prop, C: Prop, D: Prop>() -> Eq<
+    And<And<Q<A, B>, Q<B, C>>, Q<C, D>>,
+    And<Q<A, B>, And<Q<B, C>, Q<C, D>>>
+> {
+    (Rc::new(and::assoc), Rc::new(and::rev_assoc))
+}
+
+/// Left unit law `((a ~~ a) ⋀ (a ~~ b)) == (a ~~ b)`.
+pub fn eq_comp_id_left<A: Prop, B: Prop>() -> Eq<And<Q<A, A>, Q<A, B>>, Q<A, B>> {
+    (
+        Rc::new(move |(q_aa, q_ab)| comp(q_aa, q_ab)),
+        Rc::new(move |q_ab: Q<A, B>| (quality::left(q_ab.clone()), q_ab))
+    )
+}
+
+/// Right unit law `((a ~~ b) ⋀ (b ~~ b)) == (a ~~ b)`.
+pub fn eq_comp_id_right<A: Prop, B: Prop>() -> Eq<And<Q<A, B>, Q<B, B>>, Q<A, B>> {
+    (
+        Rc::new(move |(q_ab, q_bb)| comp(q_ab, q_bb)),
+        Rc::new(move |q_ab: Q<A, B>| (q_ab.clone(), quality::right(q_ab)))
+    )
+}
+
+/// Left inverse law `(a ~~ b)  =>  (((b ~~ a) ⋀ (a ~~ b)) == (b ~~ b))`.
+pub fn eq_comp_inv_left<A: Prop, B: Prop>(q_ab: Q<A, B>) -> Eq<And<Q<B, A>, Q<A, B>>, Q<B, B>> {
+    (
+        Rc::new(move |(q_ba, q_ab)| comp(q_ba, q_ab)),
+        Rc::new(move |_| (inv(q_ab.clone()), q_ab.clone()))
+    )
+}
+
+/// Right inverse law `(a ~~ b)  =>  (((a ~~ b) ⋀ (b ~~ a)) == (a ~~ a))`.
+pub fn eq_comp_inv_right<A: Prop, B: Prop>(q_ab: Q<A, B>) -> Eq<And<Q<A, B>, Q<B, A>>, Q<A, A>> {
+    (
+        Rc::new(move |(q_ab, q_ba)| comp(q_ab, q_ba)),
+        Rc::new(move |_| (q_ab.clone(), inv(q_ab.clone())))
+    )
+}
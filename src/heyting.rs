@@ -0,0 +1,274 @@
+//! # Heyting Algebras
+//!
+//! The laws of a Heyting algebra are bundled as a trait ([IsHeyting]),
+//! such that derived theorems can be reused for every instance.
+//!
+//! A Heyting algebra is a bounded lattice with order `a ≤ b`, `⊤`, `⊥`, meet `a ∧ b` and
+//! join `a ∨ b`, together with an implication `a → b` that is right adjoint to meet:
+//!
+//! `(c ∧ a ≤ b)  ==  (c ≤ a → b)`
+//!
+//! Propositions under `=>`, `true`, `false`, `⋀`, `⋁` form a Heyting algebra ([Props]).
+//! The structural tactics of [and], [or], [imply] and [not] are proved directly,
+//! and state the law of [Props] that they correspond to,
+//! e.g. [and::distrib] is distributivity ([IsHeyting::distrib])
+//! and [imply::modus_tollens] is contraposition ([IsHeyting::contra]).
+//! The tactics agree with the laws, both in statement and on values:
+//!
+//! ```rust
+//! use prop::*;
+//! use prop::heyting::{IsHeyting, Props};
+//! use Either::*;
+//!
+//! fn laws<A: Prop, B: Prop, C: Prop>() -> (
+//!     Imply<And<A, B>, And<B, A>>,
+//!     Imply<And<And<A, B>, C>, And<A, And<B, C>>>,
+//!     Imply<And<A, Or<B, C>>, Or<And<A, B>, And<A, C>>>,
+//!     Imply<And<A, Not<A>>, False>,
+//!     Imply<Or<A, B>, Or<B, A>>,
+//!     Imply<Or<Or<A, B>, C>, Or<A, Or<B, C>>>,
+//!     Imply<Imply<A, B>, Imply<Not<B>, Not<A>>>,
+//!     Imply<A, Not<Not<A>>>,
+//! ) {
+//!     (Props::meet_symmetry(), Props::meet_assoc(), Props::distrib(),
+//!      Props::non_contradiction(), Props::join_symmetry(), Props::join_assoc(),
+//!      Props::contra(), Props::double_neg_intro())
+//! }
+//!
+//! fn tactics<A: Prop, B: Prop, C: Prop>() -> (
+//!     fn(And<A, B>) -> And<B, A>,
+//!     fn(And<And<A, B>, C>) -> And<A, And<B, C>>,
+//!     fn(And<A, Or<B, C>>) -> Or<And<A, B>, And<A, C>>,
+//!     fn(And<A, Not<A>>) -> False,
+//!     fn(Or<A, B>) -> Or<B, A>,
+//!     fn(Or<Or<A, B>, C>) -> Or<A, Or<B, C>>,
+//!     fn(Imply<A, B>) -> Imply<Not<B>, Not<A>>,
+//!     fn(A) -> Not<Not<A>>,
+//! ) {
+//!     (and::symmetry, and::assoc, and::distrib, and::paradox,
+//!      or::symmetry, or::assoc, imply::modus_tollens, not::double)
+//! }
+//!
+//! let (meet_symmetry, meet_assoc, distrib, _, join_symmetry, join_assoc, _, _) =
+//!     laws::<True, Or<True, True>, Or<True, True>>();
+//! let (and_symmetry, and_assoc, and_distrib, _, or_symmetry, or_assoc, _, _) =
+//!     tactics::<True, Or<True, True>, Or<True, True>>();
+//!
+//! let x: Or<True, True> = Right(True);
+//! assert!(matches!(meet_symmetry((True, x.clone())), (Right(True), True)));
+//! assert!(matches!(and_symmetry((True, x.clone())), (Right(True), True)));
+//! assert!(matches!(meet_assoc(((True, x.clone()), Left(True))),
+//!     (True, (Right(True), Left(True)))));
+//! assert!(matches!(and_assoc(((True, x.clone()), Left(True))),
+//!     (True, (Right(True), Left(True)))));
+//! assert!(matches!(distrib((True, Right(x.clone()))), Right((True, Right(True)))));
+//! assert!(matches!(and_distrib((True, Right(x.clone()))), Right((True, Right(True)))));
+//! assert!(matches!(join_symmetry(Left(True)), Right(True)));
+//! assert!(matches!(or_symmetry(Left(True)), Right(True)));
+//! assert!(matches!(join_assoc(Left(Right(x.clone()))), Right(Left(Right(True)))));
+//! assert!(matches!(or_assoc(Left(Right(x))), Right(Left(Right(True)))));
+//! ```
+//!
+//! The laws are derived from the axioms of a Heyting algebra, so they are slower than the
+//! tactics: A law of [Props] builds a chain of closures for every use.
+
+use crate::*;
+
+/// `a → ⊥`.
+pub type Neg<H, A> = <H as IsHeyting>::Imp<A, <H as IsHeyting>::Bot>;
+
+/// Heyting algebra laws.
+pub trait IsHeyting: Sized {
+    /// `a ≤ b`.
+    type Le<A: Prop, B: Prop>: Prop;
+    /// `⊤`.
+    type Top: Prop;
+    /// `⊥`.
+    type Bot: Prop;
+    /// `a ∧ b`.
+    type Meet<A: Prop, B: Prop>: Prop;
+    /// `a ∨ b`.
+    type Join<A: Prop, B: Prop>: Prop;
+    /// `a → b`.
+    type Imp<A: Prop, B: Prop>: Prop;
+
+    /// `a ≤ a`.
+    fn le_refl<A: Prop>() -> Self::Le<A, A>;
+    /// `(a ≤ b) ⋀ (b ≤ c)  =>  (a ≤ c)`.
+    fn le_trans<A: Prop, B: Prop, C: Prop>(
+        ab: Self::Le<A, B>,
+        bc: Self::Le<B, C>
+    ) -> Self::Le<A, C>;
+    /// `a ≤ ⊤`.
+    fn top<A: Prop>() -> Self::Le<A, Self::Top>;
+    /// `⊥ ≤ a`.
+    fn bot<A: Prop>() -> Self::Le<Self::Bot, A>;
+    /// `a ∧ b ≤ a`.
+    fn meet_fst<A: Prop, B: Prop>() -> Self::Le<Self::Meet<A, B>, A>;
+    /// `a ∧ b ≤ b`.
+    fn meet_snd<A: Prop, B: Prop>() -> Self::Le<Self::Meet<A, B>, B>;
+    /// `(c ≤ a) ⋀ (c ≤ b)  =>  (c ≤ a ∧ b)`.
+    fn meet_intro<A: Prop, B: Prop, C: Prop>(
+        ca: Self::Le<C, A>,
+        cb: Self::Le<C, B>
+    ) -> Self::Le<C, Self::Meet<A, B>>;
+    /// `a ≤ a ∨ b`.
+    fn join_inl<A: Prop, B: Prop>() -> Self::Le<A, Self::Join<A, B>>;
+    /// `b ≤ a ∨ b`.
+    fn join_inr<A: Prop, B: Prop>() -> Self::Le<B, Self::Join<A, B>>;
+    /// `(a ≤ c) ⋀ (b ≤ c)  =>  (a ∨ b ≤ c)`.
+    fn join_elim<A: Prop, B: Prop, C: Prop>(
+        ac: Self::Le<A, C>,
+        bc: Self::Le<B, C>
+    ) -> Self::Le<Self::Join<A, B>, C>;
+    /// `(c ∧ a ≤ b)  =>  (c ≤ a → b)`.
+    fn curry<A: Prop, B: Prop, C: Prop>(
+        x: Self::Le<Self::Meet<C, A>, B>
+    ) -> Self::Le<C, Self::Imp<A, B>>;
+    /// `(c ≤ a → b)  =>  (c ∧ a ≤ b)`.
+    fn uncurry<A: Prop, B: Prop, C: Prop>(
+        x: Self::Le<C, Self::Imp<A, B>>
+    ) -> Self::Le<Self::Meet<C, A>, B>;
+
+    /// `a ∧ b ≤ b ∧ a`.
+    fn meet_symmetry<A: Prop, B: Prop>() -> Self::Le<Self::Meet<A, B>, Self::Meet<B, A>> {
+        Self::meet_intro(Self::meet_snd(), Self::meet_fst())
+    }
+    /// `a ∨ b ≤ b ∨ a`.
+    fn join_symmetry<A: Prop, B: Prop>() -> Self::Le<Self::Join<A, B>, Self::Join<B, A>> {
+        Self::join_elim(Self::join_inr(), Self::join_inl())
+    }
+    /// `(a ∧ b) ∧ c ≤ a ∧ (b ∧ c)`.
+    fn meet_assoc<A: Prop, B: Prop, C: Prop>() ->
+        Self::Le<Self::Meet<Self::Meet<A, B>, C>, Self::Meet<A, Self::Meet<B, C>>>
+    {
+        let a = Self::le_trans(Self::meet_fst(), Self::meet_fst());
+        let b = Self::le_trans(Self::meet_fst(), Self::meet_snd());
+        Self::meet_intro(a, Self::meet_intro(b, Self::meet_snd()))
+    }
+    /// `(a ∨ b) ∨ c ≤ a ∨ (b ∨ c)`.
+    fn join_assoc<A: Prop, B: Prop, C: Prop>() ->
+        Self::Le<Self::Join<Self::Join<A, B>, C>, Self::Join<A, Self::Join<B, C>>>
+    {
+        let b = Self::le_trans(Self::join_inl(), Self::join_inr());
+        let c = Self::le_trans(Self::join_inr(), Self::join_inr());
+        Self::join_elim(Self::join_elim(Self::join_inl(), b), c)
+    }
+    /// `(a ≤ c) ⋀ (b ≤ d)  =>  (a ∧ b ≤ c ∧ d)`.
+    fn meet_mono<A: Prop, B: Prop, C: Prop, D: Prop>(
+        ac: Self::Le<A, C>,
+        bd: Self::Le<B, D>
+    ) -> Self::Le<Self::Meet<A, B>, Self::Meet<C, D>> {
+        Self::meet_intro(Self::le_trans(Self::meet_fst(), ac), Self::le_trans(Self::meet_snd(), bd))
+    }
+    /// `(a ≤ c) ⋀ (b ≤ d)  =>  (a ∨ b ≤ c ∨ d)`.
+    fn join_mono<A: Prop, B: Prop, C: Prop, D: Prop>(
+        ac: Self::Le<A, C>,
+        bd: Self::Le<B, D>
+    ) -> Self::Le<Self::Join<A, B>, Self::Join<C, D>> {
+        Self::join_elim(Self::le_trans(ac, Self::join_inl()), Self::le_trans(bd, Self::join_inr()))
+    }
+    /// `a ≤ a ∧ ⊤`.
+    fn meet_top<A: Prop>() -> Self::Le<A, Self::Meet<A, Self::Top>> {
+        Self::meet_intro(Self::le_refl(), Self::top())
+    }
+    /// `a ∨ ⊥ ≤ a`.
+    fn join_bot<A: Prop>() -> Self::Le<Self::Join<A, Self::Bot>, A> {
+        Self::join_elim(Self::le_refl(), Self::bot())
+    }
+    /// `(a → b) ∧ a ≤ b`.
+    fn eval<A: Prop, B: Prop>() -> Self::Le<Self::Meet<Self::Imp<A, B>, A>, B> {
+        Self::uncurry(Self::le_refl())
+    }
+    /// `⊤ ≤ a → a`.
+    fn imp_refl<A: Prop>() -> Self::Le<Self::Top, Self::Imp<A, A>> {Self::curry(Self::meet_snd())}
+    /// `a ≤ b → a`.
+    fn imp_weaken<A: Prop, B: Prop>() -> Self::Le<A, Self::Imp<B, A>> {
+        Self::curry(Self::meet_fst())
+    }
+    /// `(a ≤ b)  =>  (⊤ ≤ a → b)`.
+    fn le_to_imp<A: Prop, B: Prop>(x: Self::Le<A, B>) -> Self::Le<Self::Top, Self::Imp<A, B>> {
+        Self::curry(Self::le_trans(Self::meet_snd(), x))
+    }
+    /// `(⊤ ≤ a → b)  =>  (a ≤ b)`.
+    fn imp_to_le<A: Prop, B: Prop>(x: Self::Le<Self::Top, Self::Imp<A, B>>) -> Self::Le<A, B> {
+        Self::le_trans(Self::le_trans(Self::meet_top(), Self::meet_symmetry()), Self::uncurry(x))
+    }
+    /// `(a → b) ∧ (b → c) ≤ a → c`.
+    fn imp_trans<A: Prop, B: Prop, C: Prop>() ->
+        Self::Le<Self::Meet<Self::Imp<A, B>, Self::Imp<B, C>>, Self::Imp<A, C>>
+    {
+        let ab_a = Self::meet_mono(Self::meet_fst(), Self::le_refl());
+        let b = Self::le_trans(ab_a, Self::eval());
+        let bc = Self::le_trans(Self::meet_fst(), Self::meet_snd());
+        Self::curry(Self::le_trans(Self::meet_intro(bc, b), Self::eval()))
+    }
+    /// `a ∧ (b ∨ c) ≤ (a ∧ b) ∨ (a ∧ c)`.
+    ///
+    /// Every Heyting algebra is distributive.
+    fn distrib<A: Prop, B: Prop, C: Prop>() -> Self::Le<
+        Self::Meet<A, Self::Join<B, C>>,
+        Self::Join<Self::Meet<A, B>, Self::Meet<A, C>>
+    > {
+        let b = Self::curry(Self::le_trans(Self::meet_symmetry(), Self::join_inl()));
+        let c = Self::curry(Self::le_trans(Self::meet_symmetry(), Self::join_inr()));
+        Self::le_trans(Self::meet_symmetry(), Self::uncurry(Self::join_elim(b, c)))
+    }
+    /// `a ∧ ¬a ≤ ⊥`.
+    fn non_contradiction<A: Prop>() -> Self::Le<Self::Meet<A, Neg<Self, A>>, Self::Bot> {
+        Self::le_trans(Self::meet_symmetry(), Self::eval())
+    }
+    /// `a ≤ ¬¬a`.
+    fn double_neg_intro<A: Prop>() -> Self::Le<A, Neg<Self, Neg<Self, A>>> {
+        Self::curry(Self::non_contradiction())
+    }
+    /// `a → b ≤ ¬b → ¬a`.
+    fn contra<A: Prop, B: Prop>() ->
+        Self::Le<Self::Imp<A, B>, Self::Imp<Neg<Self, B>, Neg<Self, A>>>
+    {Self::curry(Self::imp_trans())}
+}
+
+/// Propositions as a Heyting algebra.
+pub struct Props;
+
+impl IsHeyting for Props {
+    type Le<A: Prop, B: Prop> = Imply<A, B>;
+    type Top = True;
+    type Bot = False;
+    type Meet<A: Prop, B: Prop> = And<A, B>;
+    type Join<A: Prop, B: Prop> = Or<A, B>;
+    type Imp<A: Prop, B: Prop> = Imply<A, B>;
+
+    fn le_refl<A: Prop>() -> Imply<A, A> {imply::id()}
+    fn le_trans<A: Prop, B: Prop, C: Prop>(ab: Imply<A, B>, bc: Imply<B, C>) -> Imply<A, C> {
+        imply::transitivity(ab, bc)
+    }
+    fn top<A: Prop>() -> Imply<A, True> {True.map_any()}
+    fn bot<A: Prop>() -> Imply<False, A> {Rc::new(|x| match x {})}
+    fn meet_fst<A: Prop, B: Prop>() -> Imply<And<A, B>, A> {Rc::new(|(a, _)| a)}
+    fn meet_snd<A: Prop, B: Prop>() -> Imply<And<A, B>, B> {Rc::new(|(_, b)| b)}
+    fn meet_intro<A: Prop, B: Prop, C: Prop>(
+        ca: Imply<C, A>,
+        cb: Imply<C, B>
+    ) -> Imply<C, And<A, B>> {Rc::new(move |c| (ca(c.clone()), cb(c)))}
+    fn join_inl<A: Prop, B: Prop>() -> Imply<A, Or<A, B>> {Rc::new(Left)}
+    fn join_inr<A: Prop, B: Prop>() -> Imply<B, Or<A, B>> {Rc::new(Right)}
+    fn join_elim<A: Prop, B: Prop, C: Prop>(
+        ac: Imply<A, C>,
+        bc: Imply<B, C>
+    ) -> Imply<Or<A, B>, C> {
+        Rc::new(move |x| match x {
+            Left(a) => ac(a),
+            Right(b) => bc(b),
+        })
+    }
+    fn curry<A: Prop, B: Prop, C: Prop>(x: Imply<And<C, A>, B>) -> Imply<C, Imply<A, B>> {
+        Rc::new(move |c| {
+            let x = x.clone();
+            Rc::new(move |a| x((c.clone(), a)))
+        })
+    }
+    fn uncurry<A: Prop, B: Prop, C: Prop>(x: Imply<C, Imply<A, B>>) -> Imply<And<C, A>, B> {
+        Rc::new(move |(c, a)| x(c)(a))
+    }
+}
@@ -0,0 +1,245 @@
+//! # Holes
+//!
+//! A hole is a deferred proof obligation, written `hole!("label")`.
+//! The hole assumes the expected proposition using the marker type [Hole],
+//! such that a big proof can be sketched top-down and filled in later,
+//! like `admit` in Coq.
+//! When called at runtime, a hole panics with its label and location `file:line`,
+//! which is the location reported by [scan].
+//!
+//! Unlike axioms (see [axiom](mod@crate::axiom)),
+//! holes are not meant to stay in a finished development.
+//! Use [scan] or [scan_dir] to list the outstanding holes:
+//!
+//! ```rust
+//! use prop::hole::scan;
+//!
+//! let src = "fn lemma<A: Prop>() -> A {\n    hole!(\"by induction\")\n}";
+//! let holes = scan(src, "src/lemma.rs");
+//! assert_eq!(holes.len(), 1);
+//! assert_eq!(holes[0].to_string(), "src/lemma.rs:2: lemma: by induction");
+//! ```
+
+use std::fmt;
+use std::fs;
+use std::io;
+use std::marker::PhantomData;
+use std::path::Path;
+
+/// Marker for a deferred proof of `T`.
+pub struct Hole<T>(PhantomData<T>);
+
+impl<T> Hole<T> {
+    /// Defers the proof of `T`.
+    ///
+    /// Panics when called, since there is no value to return.
+    pub fn fill(label: &'static str, location: &'static str) -> T {
+        panic!("hole `{}` has no proof: {}", label, location)
+    }
+    /// Returns the obligation as a Rust type.
+    pub fn statement() -> &'static str {std::any::type_name::<T>()}
+}
+
+/// Introduces a hole with an optional label.
+///
+/// ```rust
+/// use prop::*;
+///
+/// fn lemma<A: Prop, B: Prop>(_a: A) -> B {hole!("by induction")}
+///
+/// // The location is the file and line of the hole.
+/// let err = std::panic::catch_unwind(|| {let _: True = hole!("later");}).unwrap_err();
+/// let msg = err.downcast_ref::<String>().unwrap();
+/// assert!(msg.ends_with(&format!("{}:{}", file!(), line!() - 2)));
+/// ```
+#[macro_export]
+macro_rules! hole(
+    () => {$crate::hole!("")};
+    ($label:expr) => {
+        $crate::hole::Hole::fill($label, concat!(file!(), ":", line!()))
+    };
+);
+
+/// Information about a hole.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HoleInfo {
+    /// The file of the hole.
+    pub file: String,
+    /// The line of the hole, starting at 1.
+    pub line: usize,
+    /// The function containing the hole, if any.
+    pub item: Option<String>,
+    /// The label of the hole.
+    pub label: String,
+}
+
+impl fmt::Display for HoleInfo {
+    fn fmt(&self, w: &mut fmt::Formatter) -> fmt::Result {
+        write!(w, "{}:{}: ", self.file, self.line)?;
+        if let Some(item) = &self.item {write!(w, "{}: ", item)?}
+        write!(w, "{}", self.label)
+    }
+}
+
+fn fn_name(t: &str) -> Option<&str> {
+    let i = t.find("fn ")?;
+    if i > 0 && !t[..i].ends_with(' ') {return None}
+    let t = &t[i + 3..];
+    let end = t.find(|c: char| !(c.is_alphanumeric() || c == '_')).unwrap_or(t.len());
+    if end == 0 {None} else {Some(&t[..end])}
+}
+
+fn label(t: &str) -> String {
+    let t = t.trim_start();
+    match t.strip_prefix('"') {
+        Some(t) => t.find('"').map(|end| t[..end].to_string()).unwrap_or_default(),
+        None => String::new(),
+    }
+}
+
+/// Blanks out comments, string and character literals and the bodies of `macro_rules!`,
+/// keeping lines and the positions of the remaining characters.
+fn code(src: &str) -> Vec<char> {
+    let s: Vec<char> = src.chars().collect();
+    let mut res = s.clone();
+    let blank = |res: &mut Vec<char>, a: usize, b: usize| {
+        for c in &mut res[a..b.min(s.len())] {if *c != '\n' {*c = ' '}}
+    };
+    let is_ident = |c: char| c.is_alphanumeric() || c == '_';
+    let mut i = 0;
+    while i < s.len() {
+        let at = |k: usize| s.get(i + k).copied();
+        if s[i] == '/' && at(1) == Some('/') {
+            let end = s[i..].iter().position(|&c| c == '\n').map(|n| i + n).unwrap_or(s.len());
+            blank(&mut res, i, end);
+            i = end;
+        } else if s[i] == '/' && at(1) == Some('*') {
+            let mut depth = 0;
+            let mut j = i;
+            while j < s.len() {
+                match (s[j], s.get(j + 1)) {
+                    ('/', Some('*')) => {depth += 1; j += 2}
+                    ('*', Some('/')) => {depth -= 1; j += 2; if depth == 0 {break}}
+                    _ => j += 1,
+                }
+            }
+            blank(&mut res, i, j);
+            i = j;
+        } else if s[i] == '"' {
+            let mut j = i + 1;
+            while j < s.len() && s[j] != '"' {j += if s[j] == '\\' {2} else {1}}
+            blank(&mut res, i, j + 1);
+            i = j + 1;
+        } else if s[i] == '\'' {
+            // Character literals, but not lifetimes.
+            let end = if at(1) == Some('\\') {
+                s[i + 2..].iter().skip(1).position(|&c| c == '\'').map(|n| i + n + 4)
+            } else if at(2) == Some('\'') {Some(i + 3)} else {None};
+            if let Some(end) = end {blank(&mut res, i, end)}
+            i = end.unwrap_or(i + 1);
+        } else if is_ident(s[i]) {
+            let start = i;
+            while i < s.len() && is_ident(s[i]) {i += 1}
+            let word: String = s[start..i].iter().collect();
+            if word != "r" && word != "br" {continue}
+            // Raw string literals.
+            let hashes = s[i..].iter().take_while(|&&c| c == '#').count();
+            if s.get(i + hashes) != Some(&'"') {continue}
+            let mut j = i + hashes + 1;
+            while j < s.len() &&
+                !(s[j] == '"' && s[j + 1..].iter().take(hashes).filter(|&&c| c == '#').count()
+                    == hashes) {j += 1}
+            blank(&mut res, start, j + 1 + hashes);
+            i = j + 1 + hashes;
+        } else {i += 1}
+    }
+
+    let text: String = res.iter().collect();
+    let mut offset = 0;
+    while let Some(n) = text[offset..].find("macro_rules!") {
+        let start = offset + n;
+        let from = text[..start].chars().count();
+        let open = res[from..].iter().position(|c| matches!(c, '{' | '(' | '['));
+        let open = match open {Some(x) => from + x, None => break};
+        let close = match res[open] {'{' => '}', '(' => ')', _ => ']'};
+        let mut depth = 0;
+        let mut end = res.len();
+        for (j, &c) in res.iter().enumerate().skip(open) {
+            if c == res[open] {depth += 1}
+            else if c == close {
+                depth -= 1;
+                if depth == 0 {end = j + 1; break}
+            }
+        }
+        offset = start + "macro_rules!".len();
+        blank(&mut res, from, end);
+    }
+    res
+}
+
+/// Lists the holes in source code, where `file` is used for the location.
+///
+/// Comments, string literals and the bodies of `macro_rules!` are skipped:
+///
+/// ```rust
+/// use prop::hole::scan;
+///
+/// let src = r##"
+/// fn a() {
+///     let x = hole!("a"); // hole!("trailing comment")
+///     /* hole!("block comment")
+///        hole!("still in block comment") */
+///     let s = "hole!(\"string literal\")";
+///     let r = r#"hole!("raw string")"#;
+///     let c = '"'; let d = hole!("after a character literal");
+/// }
+/// macro_rules! m(() => {hole!("macro")});
+/// fn b() {hole!()}
+/// "##;
+/// let holes: Vec<String> = scan(src, "lib.rs").iter().map(|h| h.to_string()).collect();
+/// assert_eq!(holes, vec![
+///     "lib.rs:3: a: a",
+///     "lib.rs:8: a: after a character literal",
+///     "lib.rs:11: b: ",
+/// ]);
+/// ```
+pub fn scan(src: &str, file: &str) -> Vec<HoleInfo> {
+    let code: String = code(src).into_iter().collect();
+    let mut holes = vec![];
+    let mut item: Option<String> = None;
+    for (i, (line, orig)) in code.lines().zip(src.lines()).enumerate() {
+        if let Some(name) = fn_name(line.trim()) {item = Some(name.to_string())}
+        for (j, _) in line.match_indices("hole!(") {
+            let rest: String = orig.chars().skip(line[..j].chars().count() + 6).collect();
+            holes.push(HoleInfo {
+                file: file.into(),
+                line: i + 1,
+                item: item.clone(),
+                label: label(&rest),
+            });
+        }
+    }
+    holes
+}
+
+/// Lists the holes in all Rust files of a directory, recursively.
+///
+/// ```rust
+/// use prop::hole::scan_dir;
+///
+/// let holes = scan_dir(concat!(env!("CARGO_MANIFEST_DIR"), "/src")).unwrap();
+/// assert!(holes.is_empty(), "{:?}", holes);
+/// ```
+pub fn scan_dir<P: AsRef<Path>>(dir: P) -> io::Result<Vec<HoleInfo>> {
+    let mut holes = vec![];
+    let mut paths: Vec<_> = fs::read_dir(dir)?.map(|e| e.map(|e| e.path()))
+        .collect::<io::Result<_>>()?;
+    paths.sort();
+    for path in paths {
+        if path.is_dir() {holes.extend(scan_dir(&path)?)}
+        else if path.extension().map(|x| x == "rs").unwrap_or(false) {
+            holes.extend(scan(&fs::read_to_string(&path)?, &path.to_string_lossy()));
+        }
+    }
+    Ok(holes)
+}
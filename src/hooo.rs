@@ -150,7 +150,7 @@ pub type PowEq<A, B> = And<Pow<B, A>, Pow<A, B>>;
 type NEq<A, B> = Not<Eq<A, B>>;
 
 /// `a^b => (a^b)^c`.
-pub fn pow_lift<A: Prop, B: Prop, C: Prop>(_: Pow<A, B>) -> Pow<Pow<A, B>, C> {unimplemented!()}
+pub fn pow_lift<A: Prop, B: Prop, C: Prop>(_: Pow<A, B>) -> Pow<Pow<A, B>, C> {axiom!()}
 
 /// `c^a ⋀ c^b  =>  (c^a ⋀ c^b)^d`.
 pub fn pow_dual_rev_or_lift<A: Prop, B: Prop, C: Prop, D: Prop>(
@@ -437,7 +437,7 @@ pub fn hooo_dual_rev_and<A: Prop, B: Prop, C: Prop>(
 /// `(a ⋁ b)^c => (a^c ⋁ b^c)^true`.
 pub fn tauto_hooo_or<A: Prop, B: Prop, C: Prop>(
     _: Pow<Or<A, B>, C>
-) -> Tauto<Or<Pow<A, C>, Pow<B, C>>> {unimplemented!()}
+) -> Tauto<Or<Pow<A, C>, Pow<B, C>>> {axiom!()}
 
 /// `(a^c ⋁ b^c)^true => (a ⋁ b)^c`.
 pub fn tauto_hooo_rev_or<A: Prop, B: Prop, C: Prop>(
@@ -594,7 +594,7 @@ pub fn hooo_dual_rev_neq<A: DProp, B: DProp, C: DProp>(
 /// `(a => b)^c => (a^c => b^c)^true`.
 pub fn tauto_hooo_imply<A: Prop, B: Prop, C: Prop>(
     _: Pow<Imply<A, B>, C>
-) -> Tauto<Imply<Pow<A, C>, Pow<B, C>>> {unimplemented!()}
+) -> Tauto<Imply<Pow<A, C>, Pow<B, C>>> {axiom!()}
 
 /// `(a => b)^c => (a^c => b^c)`.
 pub fn hooo_imply<A: Prop, B: Prop, C: Prop>(
@@ -680,7 +680,7 @@ pub fn hooo_dual_rev_nrimply<A: DProp, B: DProp, C: DProp>(
 /// `(b : y)^(a : x)  =>  (b^a : y^x)^true`.
 pub fn tauto_hooo_ty<A: Prop, B: Prop, X: Prop, Y: Prop>(
     _: Pow<Ty<B, Y>, Ty<A, X>>
-) -> Tauto<Ty<Pow<B, A>, Pow<Y, X>>> {unimplemented!()}
+) -> Tauto<Ty<Pow<B, A>, Pow<Y, X>>> {axiom!()}
 
 /// `(b : y)^(a : x)  =>  (b^a : y^x)`.
 pub fn hooo_ty<A: Prop, B: Prop, X: Prop, Y: Prop>(
@@ -695,12 +695,12 @@ pub fn tauto_hooo_rev_ty<A: Prop, B: Prop, X: Prop, Y: Prop>(
 /// `(b^a : y^x)  =>  ((a : x) => (b : y))`.
 pub fn hooo_rev_ty<A: Prop, B: Prop, X: Prop, Y: Prop>(
     _: Ty<Pow<B, A>, Pow<Y, X>>
-) -> Imply<Ty<A, X>, Ty<B, Y>> {unimplemented!()}
+) -> Imply<Ty<A, X>, Ty<B, Y>> {axiom!()}
 
 /// `(a < b)^c  =>  (a^c < b^c)^true`
 pub fn tauto_hooo_pord<A: Prop, B: Prop, C: Prop>(
     _: Pow<POrdProof<A, B>, C>
-) -> Tauto<POrdProof<Pow<A, C>, Pow<B, C>>> {unimplemented!()}
+) -> Tauto<POrdProof<Pow<A, C>, Pow<B, C>>> {axiom!()}
 
 /// `(a < b)^c  =>  (a^c < b^c)`
 pub fn hooo_pord<A: Prop, B: Prop, C: Prop>(
@@ -718,18 +718,14 @@ pub fn pow_ty<A: Prop, B: Prop, X: Prop, Y: Prop>(
 /// Lift equality with a theory of equality (implies symbolic distinction) into quality.
 ///
 /// For more information about symbolic distinction, see the [sd] module.
-pub fn lift_q<A: Prop, B: Prop>(_: Eq<A, B>, _: Theory<Eq<A, B>>) -> Q<A, B> {unimplemented!()}
+pub fn lift_q<A: Prop, B: Prop>(_: Eq<A, B>, _: Theory<Eq<A, B>>) -> Q<A, B> {axiom!()}
 
 /// `~a ∧ (a == b)^true  =>  ~b`.
 pub fn qu_in_arg<A: Prop, B: Prop>(x: Qu<A>, y: Tauto<Eq<A, B>>) -> Qu<B> {qubit::in_arg(x, y)}
 
 /// `(a == b)^true  =>  (~a == ~b)^true`.
 pub fn tauto_qu_eq<A: Prop, B: Prop>(x: Tauto<Eq<A, B>>) -> Tauto<Eq<Qu<A>, Qu<B>>> {
-    fn f<A: Prop, B: Prop>(x: Tauto<Eq<A, B>>) -> Eq<Qu<A>, Qu<B>> {
-        let x2 = tauto_eq_symmetry(x);
-        (Rc::new(move |qu_a| qubit::in_arg(qu_a, x)), Rc::new(move |qu_b| qubit::in_arg(qu_b, x2)))
-    }
-    x.lift().trans(f)
+    x.lift().trans(qubit::map_eq)
 }
 
 /// `(a ~~ b) ∧ (a == c)^true  =>  (c ~~ b)`.
@@ -744,6 +740,83 @@ pub fn q_in_right_arg<A: Prop, B: Prop, C: Prop>(
     g: Tauto<Eq<B, C>>
 ) -> Q<A, C> {(eq::in_right_arg(eq_ab, g(True)), (qu_a, qu_in_arg(qu_b, g)))}
 
+/// `~(c^a) ∧ (a == b)^true  =>  ~(c^b)`.
+pub fn qu_pow_right<A: Prop, B: Prop, C: Prop>(x: Qu<Pow<C, A>>, y: Tauto<Eq<A, B>>) -> Qu<Pow<C, B>> {
+    qu_in_arg(x, tauto_pow_eq_right(y))
+}
+
+/// `~(a^c) ∧ (a == b)^true  =>  ~(b^c)`.
+pub fn qu_pow_left<A: Prop, B: Prop, C: Prop>(x: Qu<Pow<A, C>>, y: Tauto<Eq<A, B>>) -> Qu<Pow<B, C>> {
+    qu_in_arg(x, tauto_pow_eq_left(y))
+}
+
+/// `(a ~~ b)^true  =>  (a == b)^true`.
+pub fn tauto_q_to_tauto_eq<A: Prop, B: Prop>(x: Tauto<Q<A, B>>) -> Tauto<Eq<A, B>> {
+    x.trans(and::fst)
+}
+
+/// `(a ~~ b)^true ∧ ~(c^a)  =>  (c^a ~~ c^b)`.
+///
+/// Quality alone does not transport along exponentials,
+/// since `a ~~ b` does not imply `(a == b)^true`.
+/// Instead, the quality must hold tautologically and the qubit of `c^a` must be provided.
+pub fn q_pow<A: Prop, B: Prop, C: Prop>(
+    x: Tauto<Q<A, B>>,
+    qu: Qu<Pow<C, A>>
+) -> Q<Pow<C, A>, Pow<C, B>> {
+    let eq = tauto_q_to_tauto_eq(x);
+    (pow_eq_right(eq), (qu, qu_pow_right(qu, eq)))
+}
+
+/// `(a ~~ b)^true ∧ ~(c^b)  =>  (c^a ~~ c^b)`.
+pub fn q_pow_rev<A: Prop, B: Prop, C: Prop>(
+    x: Tauto<Q<A, B>>,
+    qu: Qu<Pow<C, B>>
+) -> Q<Pow<C, A>, Pow<C, B>> {
+    let eq = tauto_q_to_tauto_eq(x);
+    (pow_eq_right(eq), (qu_pow_right(qu, tauto_eq_symmetry(eq)), qu))
+}
+
+/// `(a ~~ b)^true ∧ ~(a^c)  =>  (a^c ~~ b^c)`.
+pub fn q_pow_left<A: Prop, B: Prop, C: Prop>(
+    x: Tauto<Q<A, B>>,
+    qu: Qu<Pow<A, C>>
+) -> Q<Pow<A, C>, Pow<B, C>> {
+    let eq = tauto_q_to_tauto_eq(x);
+    (pow_eq_left(eq), (qu, qu_pow_left(qu, eq)))
+}
+
+/// `(a ~~ b)^true ∧ ~(b^c)  =>  (a^c ~~ b^c)`.
+pub fn q_pow_left_rev<A: Prop, B: Prop, C: Prop>(
+    x: Tauto<Q<A, B>>,
+    qu: Qu<Pow<B, C>>
+) -> Q<Pow<A, C>, Pow<B, C>> {
+    let eq = tauto_q_to_tauto_eq(x);
+    (pow_eq_left(eq), (qu_pow_left(qu, tauto_eq_symmetry(eq)), qu))
+}
+
+/// `(a ~~ b)^true ∧ ~(c^a)^true  =>  (c^a ~~ c^b)^true`.
+pub fn pow_q_lift<A: Prop, B: Prop, C: Prop>(
+    x: Tauto<Q<A, B>>,
+    qu: Tauto<Qu<Pow<C, A>>>
+) -> Tauto<Q<Pow<C, A>, Pow<C, B>>> {
+    fn f<A: Prop, B: Prop, C: Prop>(
+        (x, qu): And<Tauto<Q<A, B>>, Qu<Pow<C, A>>>
+    ) -> Q<Pow<C, A>, Pow<C, B>> {q_pow(x, qu)}
+    hooo_rev_and((x.lift(), qu)).trans(f)
+}
+
+/// `(a ~~ b)^true ∧ ~(a^c)^true  =>  (a^c ~~ b^c)^true`.
+pub fn pow_q_lift_left<A: Prop, B: Prop, C: Prop>(
+    x: Tauto<Q<A, B>>,
+    qu: Tauto<Qu<Pow<A, C>>>
+) -> Tauto<Q<Pow<A, C>, Pow<B, C>>> {
+    fn f<A: Prop, B: Prop, C: Prop>(
+        (x, qu): And<Tauto<Q<A, B>>, Qu<Pow<A, C>>>
+    ) -> Q<Pow<A, C>, Pow<B, C>> {q_pow_left(x, qu)}
+    hooo_rev_and((x.lift(), qu)).trans(f)
+}
+
 /// `true^a`.
 pub fn tr<A: Prop>() -> Pow<True, A> {
     fn f<A: Prop>(_: A) -> True {True}
@@ -1,5 +1,112 @@
 //! Tautology extensions.
+//!
+//! ### Tautology as modality
+//!
+//! Viewing `a^true` as necessity `□a` (see [modal]), the usual modal rules are:
+//!
+//! - Necessitation `a^true  =>  (a^true)^true` ([tauto_nec]), from the axiom [pow_lift]
+//! - Distribution (K) `(a => b)^true  =>  (a^true => b^true)` ([tauto_k]),
+//!   from the axiom [tauto_hooo_imply]
+//! - Reflexivity (T) `a^true  =>  a` ([tauto_t]), a theorem
+//! - Reflection `a  =>  a^true` for decidable `a` ([tauto_reflect]),
+//!   from [tauto_to_or]
+//!
+//! The same rules hold for `a^c` as a modality indexed by `c`
+//! ([pow_nec], [pow_k], [pow_four]).
+//! Reflection is not sound in general, since `a` might be true in one context but not in another.
 
 /// Maps expression to tautology.
 #[macro_export]
 macro_rules! tauto(($x:expr) => {|_: $crate::True| $x});
+
+use super::*;
+use std::cell::OnceCell;
+
+/// Caches the proof of a tautology.
+///
+/// The tautology is evaluated at most once, at the first call to [TautoCache::get].
+/// Later calls clone the cached proof instead of running the tautology again.
+/// Clones of the cache share the cached proof.
+///
+/// ```rust
+/// use prop::*;
+/// use prop::hooo::tauto::TautoCache;
+/// use std::sync::atomic::{AtomicUsize, Ordering};
+///
+/// static CALLS: AtomicUsize = AtomicUsize::new(0);
+///
+/// fn lemma(_: True) -> True {CALLS.fetch_add(1, Ordering::SeqCst); True}
+///
+/// let cache = TautoCache::new(lemma);
+/// let f = cache.clone().to_imply();
+/// assert_eq!(CALLS.load(Ordering::SeqCst), 0);
+/// let _: True = cache.get();
+/// let _: True = cache.get();
+/// let _: True = f(True);
+/// assert_eq!(CALLS.load(Ordering::SeqCst), 1);
+/// ```
+#[derive(Clone)]
+pub struct TautoCache<A> {
+    tauto: Tauto<A>,
+    cache: Rc<OnceCell<A>>,
+}
+
+impl<A: Prop> TautoCache<A> {
+    /// Creates a new cache for a tautology.
+    pub fn new(tauto: Tauto<A>) -> TautoCache<A> {
+        TautoCache {tauto, cache: Rc::new(OnceCell::new())}
+    }
+    /// Returns the cached proof, evaluating the tautology the first time.
+    pub fn get(&self) -> A {self.cache.get_or_init(|| (self.tauto)(True)).clone()}
+    /// Returns the underlying tautology.
+    pub fn tauto(&self) -> Tauto<A> {self.tauto}
+    /// Returns `true => a` using the cache.
+    pub fn to_imply(self) -> Imply<True, A> {Rc::new(move |_| self.get())}
+}
+
+/// `a^true  =>  cache(a^true)`.
+pub fn tauto_cache<A: Prop>(x: Tauto<A>) -> TautoCache<A> {TautoCache::new(x)}
+
+/// `a^true  =>  (a^true)^true`.
+pub fn tauto_nec<A: Prop>(x: Tauto<A>) -> Tauto<Tauto<A>> {pow_lift(x)}
+/// `(a^true)^true  =>  a^true`.
+pub fn tauto_nec_rev<A: Prop>(x: Tauto<Tauto<A>>) -> Tauto<A> {x(True)}
+/// `a^true == (a^true)^true`.
+pub fn eq_tauto_nec<A: Prop>() -> Eq<Tauto<A>, Tauto<Tauto<A>>> {
+    (Rc::new(tauto_nec), Rc::new(tauto_nec_rev))
+}
+/// `(a => b)^true  =>  (a^true => b^true)`.
+pub fn tauto_k<A: Prop, B: Prop>(x: Tauto<Imply<A, B>>) -> Imply<Tauto<A>, Tauto<B>> {
+    hooo_imply(x)
+}
+/// `a^true  =>  a`.
+pub fn tauto_t<A: Prop>(x: Tauto<A>) -> A {x(True)}
+/// `a  =>  a^true` for decidable `a`.
+pub fn tauto_reflect<A: DProp>(a: A) -> Tauto<A> {
+    match tauto_to_or::<A>() {
+        Left(tauto_a) => tauto_a,
+        Right(tauto_na) => not::absurd(tauto_na(True), a),
+    }
+}
+/// `(a ⋁ ¬a)^true ⋀ a  =>  a^true`.
+pub fn tauto_excm_reflect<A: Prop>(x: Tauto<ExcM<A>>, a: A) -> Tauto<A> {
+    match tauto_excm_to_or(x) {
+        Left(tauto_a) => tauto_a,
+        Right(tauto_na) => not::absurd(tauto_na(True), a),
+    }
+}
+/// `a^true ⋀ b^true  =>  (a ⋀ b)^true`.
+pub fn tauto_and<A: Prop, B: Prop>(a: Tauto<A>, b: Tauto<B>) -> Tauto<And<A, B>> {
+    hooo_rev_and((a, b))
+}
+/// `a^true ⋁ b^true  =>  (a ⋁ b)^true`.
+pub fn tauto_or<A: Prop, B: Prop>(x: Or<Tauto<A>, Tauto<B>>) -> Tauto<Or<A, B>> {hooo_rev_or(x)}
+
+/// `a^b  =>  (a^b)^c`.
+pub fn pow_nec<A: Prop, B: Prop, C: Prop>(x: Pow<A, B>) -> Pow<Pow<A, B>, C> {pow_lift(x)}
+/// `(a => b)^c  =>  (a^c => b^c)`.
+pub fn pow_k<A: Prop, B: Prop, C: Prop>(x: Pow<Imply<A, B>, C>) -> Imply<Pow<A, C>, Pow<B, C>> {
+    hooo_imply(x)
+}
+/// `a^c  =>  (a^c)^c`.
+pub fn pow_four<A: Prop, C: Prop>(x: Pow<A, C>) -> Pow<Pow<A, C>, C> {pow_lift(x)}
@@ -4,9 +4,23 @@
 //!
 //! This is a model of Homotopy Type Theory that attempts to be similar to the version in
 //! the [standard HoTT book](https://homotopytypetheory.org/book/).
+//!
+//! ### Interval
+//!
+//! Inspired by cubical type theory, there is an interval type [I] with endpoints [I0] and [I1].
+//! A function `p : i -> x` gives a path `<i> p(i) : id{x}(p(i0), p(i1))` ([path_lam]).
+//! This is used to derive function extensionality from pointwise paths ([interval_fun_ext]).
+//!
+//! ### Sets
+//!
+//! A type with decidable equality is a set by Hedberg's theorem ([hedberg]).
+//! Decidable equality can be given as identity types, as equality `a == b` ([hedberg_eq])
+//! or by [Decidable::decide] ([hedberg_decide]).
+//! Uniqueness of identity proofs for all types is available with the `uip` feature,
+//! which is not compatible with univalence.
 
 use crate::*;
-use fun::{App, Type};
+use fun::{App, Type, VProp};
 use hooo::Pow;
 use path_semantics::Ty;
 use nat::{Nat, S, Z};
@@ -39,13 +53,28 @@ pub fn id_ty<X: Prop, A: Prop, B: Prop, N: Nat>(
     _ty_x: Ty<X, Type<N>>,
     _ty_a: Ty<A, X>,
     _ty_b: Ty<B, X>
-) -> Ty<Id<X, A, B>, Type<N>> {unimplemented!()}
+) -> Ty<Id<X, A, B>, Type<N>> {axiom!()}
 /// `(a : x)  =>  refl{x}(a) : id{x}(a, a)`.
 pub fn refl<A: Prop, X: Prop, PathP: Prop>(_ty_a: Ty<A, X>) -> Ty<Refl<X, A>, Id<X, A, A>>{
-    unimplemented!()
+    axiom!()
 }
 /// `id{x}(a, b) => (a == b)`.
-pub fn id_to_eq<A: Prop, B: Prop, X: Prop>(_: Id<X, A, B>) -> Eq<A, B> {unimplemented!()}
+pub fn id_to_eq<A: Prop, B: Prop, X: Prop>(_: Id<X, A, B>) -> Eq<A, B> {axiom!()}
+/// `(a : x) ⋀ (b : x) ⋀ (a == b)  =>  id{x}(a, b)`.
+pub fn eq_to_id<A: Prop, B: Prop, X: Prop>(
+    _ty_a: Ty<A, X>,
+    _ty_b: Ty<B, X>,
+    _eq: Eq<A, B>
+) -> Id<X, A, B> {axiom!()}
+/// `(a : x) ⋀ (b : x) ⋀ ((a == b) ⋁ ¬(a == b))  =>  id{x}(a, b) ⋁ ¬id{x}(a, b)`.
+pub fn excm_eq_to_id<A: Prop, B: Prop, X: Prop>(
+    ((ty_a, ty_b), excm): And<And<Ty<A, X>, Ty<B, X>>, ExcM<Eq<A, B>>>
+) -> ExcM<Id<X, A, B>> {
+    match excm {
+        Left(eq) => Left(eq_to_id(ty_a, ty_b, eq)),
+        Right(neq) => Right(imply::modus_tollens(Rc::new(id_to_eq))(neq)),
+    }
+}
 /// `(id{x}(a, b) ⋀ id{x}(a, c)) => id{x}(c, b)`.
 pub fn id_in_left_arg<A: Prop, B: Prop, C: Prop, X: Prop>(
     p: Id<X, A, B>,
@@ -60,12 +89,12 @@ pub fn id_in_right_arg<A: Prop, B: Prop, C: Prop, X: Prop>(
 pub fn ap_fun<F: Prop, X: Prop, Y: Prop, A: Prop, B: Prop>(
     _f: Ty<F, Pow<Y, X>>,
     _p: Id<X, A, B>,
-) -> Id<Y, App<F, A>, App<F, B>> {unimplemented!()}
+) -> Id<Y, App<F, A>, App<F, B>> {axiom!()}
 /// `(f : x => y) ⋀ id{x}(a, b) => id{y}(f(a), f(b))`.
 pub fn ap_lam<F: Prop, X: Prop, Y: Prop, A: Prop, B: Prop>(
     _f: Ty<F, Imply<X, Y>>,
     _p: Id<X, A, B>,
-) -> Id<Y, App<F, A>, App<F, B>> {unimplemented!()}
+) -> Id<Y, App<F, A>, App<F, B>> {axiom!()}
 /// `is_contr(a) => a`.
 pub fn from_is_contr<A: Prop>(x: IsContr<A>) -> A {x.1}
 /// `a => is_contr(a)`.
@@ -75,7 +104,7 @@ pub fn is_htype_to_id<A: Prop, B: Prop, X: Prop, N: Nat>(
     _: IsHType<S<N>, X>,
     _: Ty<A, X>,
     _: Ty<B, X>
-) -> IsHType<N, Id<X, A, B>> {unimplemented!()}
+) -> IsHType<N, Id<X, A, B>> {axiom!()}
 /// `(is_prop(x) ⋀ (a : x) ⋀ (b : x)) => id{x}(a, b)`.
 pub fn is_prop_to_id<A: Prop, B: Prop, X: Prop>(
     is_prop: IsProp<X>,
@@ -133,3 +162,154 @@ pub fn is_groupoid_to_id<
 }
 /// `is_contr(true)`.
 pub fn true_is_contr() -> IsContr<True> {to_is_contr(True)}
+/// `(¬¬id{x}(a, b) => id{x}(a, b))^((a : x) ⋀ (b : x))  =>  is_set(x)`.
+///
+/// A type is a set when its identity types are stable under double negation.
+pub fn stable_id_is_set<X: Prop, A: VProp, B: VProp>(
+    _: Pow<Imply<Not<Not<Id<X, A, B>>>, Id<X, A, B>>, And<Ty<A, X>, Ty<B, X>>>
+) -> IsSet<X> {axiom!()}
+/// `(id{x}(a, b) ⋁ ¬id{x}(a, b))^((a : x) ⋀ (b : x))  =>  is_set(x)`.
+///
+/// Hedberg's theorem: A type with decidable equality is a set.
+/// This does not assume uniqueness of identity proofs and is compatible with univalence.
+pub fn hedberg<X: Prop, A: VProp, B: VProp>(
+    dec: Pow<ExcM<Id<X, A, B>>, And<Ty<A, X>, Ty<B, X>>>
+) -> IsSet<X> {
+    fn stable<A: Prop>(excm: ExcM<A>) -> Imply<Not<Not<A>>, A> {
+        Rc::new(move |nna| not::rev_double_excm(nna, excm.clone()))
+    }
+    stable_id_is_set(hooo::pow_transitivity(dec, stable))
+}
+/// `((a == b) ⋁ ¬(a == b))^((a : x) ⋀ (b : x))  =>  is_set(x)`.
+///
+/// Hedberg's theorem for decidable equality between members.
+pub fn hedberg_eq<X: Prop, A: VProp, B: VProp>(
+    dec: Pow<ExcM<Eq<A, B>>, And<Ty<A, X>, Ty<B, X>>>
+) -> IsSet<X> {
+    let dec = hooo::hooo_rev_and((hooo::pow_refl, dec));
+    hedberg(hooo::pow_transitivity(dec, excm_eq_to_id))
+}
+/// `is_set(x)` when `a == b` is decidable.
+pub fn hedberg_decide<X: Prop, A: VProp, B: VProp>() -> IsSet<X>
+    where Eq<A, B>: DProp
+{
+    fn dec<X: Prop, A: Prop, B: Prop>(_: And<Ty<A, X>, Ty<B, X>>) -> ExcM<Eq<A, B>>
+        where Eq<A, B>: DProp
+    {Eq::<A, B>::decide()}
+    hedberg_eq(dec)
+}
+/// `(id{x}(a, b) ⋁ ¬id{x}(a, b))^((a : x) ⋀ (b : x)) ⋀ (c : x) ⋀ (d : x) ⋀
+/// (p : id{x}(c, d)) ⋀ (q : id{x}(c, d))  =>  id{id{x}(c, d)}(p, q)`.
+pub fn hedberg_id<X: Prop, A: VProp, B: VProp, C: Prop, D: Prop, PathP: Prop, PathQ: Prop>(
+    dec: Pow<ExcM<Id<X, A, B>>, And<Ty<A, X>, Ty<B, X>>>,
+    ty_c: Ty<C, X>,
+    ty_d: Ty<D, X>,
+    path_p: Ty<PathP, Id<X, C, D>>,
+    path_q: Ty<PathQ, Id<X, C, D>>
+) -> Id<Id<X, C, D>, PathP, PathQ> {
+    is_set_to_id(hedberg(dec), ty_c, ty_d, path_p, path_q)
+}
+
+/// Interval type.
+#[derive(Copy, Clone)]
+pub struct I(());
+
+/// Left endpoint of interval.
+#[derive(Copy, Clone)]
+pub struct I0(());
+
+/// Right endpoint of interval.
+#[derive(Copy, Clone)]
+pub struct I1(());
+
+/// Path abstraction `<i> p(i)`.
+#[derive(Copy, Clone)]
+pub struct PathLam<P: Prop>(P);
+
+/// Path application `q @ j`.
+#[derive(Copy, Clone)]
+pub struct PathApp<Q: Prop, J: Prop>(Q, J);
+
+/// Path of functions `\(j : i) = \(a : x) = (h(a) @ j)`.
+#[derive(Copy, Clone)]
+pub struct PathFun<H: Prop>(H);
+
+/// `i : type(0)`.
+pub fn interval_ty() -> Ty<I, Type<Z>> {axiom!()}
+/// `i0 : i`.
+pub fn i0_ty() -> Ty<I0, I> {axiom!()}
+/// `i1 : i`.
+pub fn i1_ty() -> Ty<I1, I> {axiom!()}
+/// `(p : i -> x)  =>  (<i> p(i)) : id{x}(p(i0), p(i1))`.
+pub fn path_lam<P: Prop, X: Prop>(
+    _ty_p: Ty<P, Pow<X, I>>
+) -> Ty<PathLam<P>, Id<X, App<P, I0>, App<P, I1>>> {axiom!()}
+/// `(j : i)  =>  ((<i> p(i)) @ j) == p(j)`.
+pub fn path_beta<P: Prop, J: Prop>(_ty_j: Ty<J, I>) -> Eq<PathApp<PathLam<P>, J>, App<P, J>> {
+    axiom!()
+}
+/// `(q : id{x}(a, b)) ⋀ (j : i)  =>  (q @ j) : x`.
+pub fn path_app_ty<Q: Prop, X: Prop, A: Prop, B: Prop, J: Prop>(
+    _ty_q: Ty<Q, Id<X, A, B>>,
+    _ty_j: Ty<J, I>
+) -> Ty<PathApp<Q, J>, X> {axiom!()}
+/// `(q : id{x}(a, b))  =>  (q @ i0) == a`.
+pub fn path_app_i0<Q: Prop, X: Prop, A: Prop, B: Prop>(
+    _ty_q: Ty<Q, Id<X, A, B>>
+) -> Eq<PathApp<Q, I0>, A> {axiom!()}
+/// `(q : id{x}(a, b))  =>  (q @ i1) == b`.
+pub fn path_app_i1<Q: Prop, X: Prop, A: Prop, B: Prop>(
+    _ty_q: Ty<Q, Id<X, A, B>>
+) -> Eq<PathApp<Q, I1>, B> {axiom!()}
+/// `path_fun(h)(j)(a) == (h(a) @ j)`.
+pub fn path_fun_def<H: Prop, J: Prop, A: Prop>() ->
+    Eq<App<App<PathFun<H>, J>, A>, PathApp<App<H, A>, J>> {axiom!()}
+/// `(h(a) : id{y}(f(a), g(a)))^(a : x)  =>  path_fun(h) : i -> (x -> y)`.
+pub fn path_fun_ty<H: Prop, F: Prop, G: Prop, X: Prop, Y: Prop, A: VProp>(
+    _: Pow<Ty<App<H, A>, Id<Y, App<F, A>, App<G, A>>>, Ty<A, X>>
+) -> Ty<PathFun<H>, Pow<Pow<Y, X>, I>> {axiom!()}
+/// `(h(a) : id{y}(f(a), g(a)))^(a : x)  =>  path_fun(h)(i0) == f`.
+///
+/// In cubical type theory, this holds definitionally by the endpoint computation rule.
+pub fn path_fun_i0<H: Prop, F: Prop, G: Prop, X: Prop, Y: Prop, A: VProp>(
+    _: Pow<Ty<App<H, A>, Id<Y, App<F, A>, App<G, A>>>, Ty<A, X>>
+) -> Eq<App<PathFun<H>, I0>, F> {axiom!()}
+/// `(h(a) : id{y}(f(a), g(a)))^(a : x)  =>  path_fun(h)(i1) == g`.
+///
+/// In cubical type theory, this holds definitionally by the endpoint computation rule.
+pub fn path_fun_i1<H: Prop, F: Prop, G: Prop, X: Prop, Y: Prop, A: VProp>(
+    _: Pow<Ty<App<H, A>, Id<Y, App<F, A>, App<G, A>>>, Ty<A, X>>
+) -> Eq<App<PathFun<H>, I1>, G> {axiom!()}
+
+/// `(a == c) ⋀ (b == d)  =>  id{x}(a, b) == id{x}(c, d)`.
+pub fn id_eq<X: Prop, A: Prop, B: Prop, C: Prop, D: Prop>(
+    (ac, ca): Eq<A, C>,
+    (bd, db): Eq<B, D>
+) -> Eq<Id<X, A, B>, Id<X, C, D>> {
+    (Rc::new(move |Id(x, a, b)| Id(x, ac(a), bd(b))),
+     Rc::new(move |Id(x, c, d)| Id(x, ca(c), db(d))))
+}
+/// `(h(a) : id{y}(f(a), g(a)))^(a : x)  =>  (<i> path_fun(h)(i)) => id{x -> y}(f, g)`.
+///
+/// Function extensionality from the interval.
+/// Compared to the `FunExt` axiom in [fun::fun_ext], which uses the imaginary inverse,
+/// this gives a path between functions from pointwise paths by path abstraction.
+pub fn interval_fun_ext<H: Prop, F: Prop, G: Prop, X: Prop, Y: Prop, A: VProp>(
+    x: Pow<Ty<App<H, A>, Id<Y, App<F, A>, App<G, A>>>, Ty<A, X>>
+) -> Imply<PathLam<PathFun<H>>, Id<Pow<Y, X>, F, G>> {
+    let ty = path_lam(path_fun_ty(x));
+    imply::transitivity(ty.0, id_eq(path_fun_i0(x), path_fun_i1(x)).0)
+}
+/// `(h(a) : id{y}(f(a), g(a)))^(a : x) ⋀ (<i> path_fun(h)(i))  =>  (f == g)`.
+pub fn interval_fun_ext_eq<H: Prop, F: Prop, G: Prop, X: Prop, Y: Prop, A: VProp>(
+    x: Pow<Ty<App<H, A>, Id<Y, App<F, A>, App<G, A>>>, Ty<A, X>>,
+    p: PathLam<PathFun<H>>
+) -> Eq<F, G> {id_to_eq(interval_fun_ext(x)(p))}
+/// `id{x -> y}(f, g)^true  =>  fun_ext_ty(f, g)`.
+///
+/// Connects paths between functions to the `FunExt` axiom block.
+pub fn interval_to_fun_ext<F: Prop, G: Prop, X: Prop, Y: Prop, A: Prop>(
+    x: hooo::Tauto<Id<Pow<Y, X>, F, G>>
+) -> fun::fun_ext::FunExtTy<F, G, X, Y, A> {
+    fun::fun_ext::fun_ext(hooo::pow_transitivity(x, id_to_eq))
+}
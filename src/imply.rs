@@ -7,6 +7,8 @@ use crate::*;
 /// `(a => b)  =>  (¬b => ¬a)`.
 ///
 /// Swap sides of implication by taking their negation.
+///
+/// The law [contra](heyting::IsHeyting::contra) of [Props](heyting::Props).
 pub fn modus_tollens<A: Prop, B: Prop>(f: Imply<A, B>) -> Imply<Not<B>, Not<A>> {
     Rc::new(move |x| {
         let f = f.clone();
@@ -61,7 +63,7 @@ pub fn transitivity<A: Prop, B: Prop, C: Prop>(
     f: Imply<A, B>,
     g: Imply<B, C>,
 ) -> Imply<A, C> {
-    Rc::new(move |x| g(f(x)))
+    Rc::new(move |x| {profile!("imply::transitivity"); g(f(x))})
 }
 
 /// `(a => b) ∧ a  =>  b`
@@ -368,3 +370,46 @@ pub fn total_excm<A: Prop, B: Prop>(excm_a: ExcM<A>) -> Or<Imply<A, B>, Imply<B,
 pub fn reduce<A: Prop, B: Prop>(x: Imply<A, Imply<A, B>>) -> Imply<A, B> {
     Rc::new(move |a| x(a.clone())(a))
 }
+
+/// `((a ∧ b) => c)  ==  (a => (b => c))`.
+///
+/// Exportation (left to right) and importation (right to left).
+pub fn exportation<A: Prop, B: Prop, C: Prop>() -> Eq<Imply<And<A, B>, C>, Imply<A, Imply<B, C>>> {
+    (Rc::new(chain), Rc::new(rev_chain))
+}
+
+/// `(a => (b => c))  ==  (b => (a => c))`.
+pub fn swap_args<A: Prop, B: Prop, C: Prop>() -> Eq<Imply<A, Imply<B, C>>, Imply<B, Imply<A, C>>> {
+    (Rc::new(reorder_args), Rc::new(reorder_args))
+}
+
+/// `(a => b)  =>  (a => (a ∧ b))`.
+pub fn absorb<A: Prop, B: Prop>(f: Imply<A, B>) -> Imply<A, And<A, B>> {
+    Rc::new(move |a| (a.clone(), f(a)))
+}
+
+/// `(a => b)  ==  (a => (a ∧ b))`.
+pub fn eq_absorb<A: Prop, B: Prop>() -> Eq<Imply<A, B>, Imply<A, And<A, B>>> {
+    (Rc::new(absorb), Rc::new(move |f| transitivity(f, Rc::new(and::snd))))
+}
+
+/// `b  =>  (a => b)`.
+pub fn weaken<A: Prop, B: Prop>(b: B) -> Imply<A, B> {b.map_any()}
+
+/// `(a => (b => c)) ∧ (a => b)  =>  (a => c)`.
+pub fn distrib<A: Prop, B: Prop, C: Prop>(
+    f: Imply<A, Imply<B, C>>,
+    g: Imply<A, B>
+) -> Imply<A, C> {
+    Rc::new(move |a| f(a.clone())(g(a)))
+}
+
+/// `b^a  ==  (a => b)^true`.
+pub fn eq_pow_tauto<A: Prop, B: Prop>() -> Eq<hooo::Pow<B, A>, hooo::Tauto<Imply<A, B>>> {
+    (Rc::new(hooo::pow_to_tauto_imply), Rc::new(hooo::tauto_imply_to_pow))
+}
+
+/// `(a => b)^true  =>  (a => b)`.
+pub fn from_tauto<A: Prop, B: Prop>(x: hooo::Tauto<Imply<A, B>>) -> Imply<A, B> {
+    hooo::pow_to_imply(hooo::tauto_imply_to_pow(x))
+}
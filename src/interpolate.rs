@@ -0,0 +1,141 @@
+//! # Craig Interpolation
+//!
+//! When `a => b` holds in classical logic, there is an interpolant `c`
+//! with `a => c` and `c => b`, where `c` uses only the atoms shared by `a` and `b`.
+//! Interpolants are useful for modular verification, since `c` is an interface
+//! between two parts that only talks about what they have in common.
+//!
+//! This module computes an interpolant of reflected propositions (see [expr::Expr])
+//! by eliminating the atoms of `a` that do not occur in `b`:
+//!
+//! `a  =>  a[x := true] ⋁ a[x := false]`
+//!
+//! Since `b` does not depend on `x`, the result still implies `b`.
+//! The implications `a => c` and `c => b` are proved by the resolution prover
+//! (see [resolution::prove]), and can be composed with `imply::transitivity`.
+//!
+//! ```rust
+//! use prop::expr;
+//! use prop::interpolate::interpolate;
+//!
+//! let x = interpolate(&expr!(And<A, B>), &expr!(Or<B, C>)).unwrap();
+//! assert_eq!(x.expr, expr!(B));
+//! assert!(x.check());
+//! ```
+//!
+//! An interpolant that uses atoms which are not shared is rejected,
+//! even when the certificates are valid:
+//!
+//! ```rust
+//! use prop::expr;
+//! use prop::interpolate::interpolate;
+//! use prop::resolution::prove;
+//!
+//! let (a, b) = (expr!(And<A, B>), expr!(Or<B, C>));
+//! let mut x = interpolate(&a, &b).unwrap();
+//! x.expr = a.clone();
+//! x.left = prove(&expr::Expr::imply(a.clone(), a.clone())).unwrap();
+//! x.right = prove(&expr::Expr::imply(a, b)).unwrap();
+//! assert!(x.left.check() && x.right.check());
+//! assert!(!x.check());
+//! ```
+
+use crate::*;
+use expr::Expr;
+use resolution::Certificate;
+use std::fmt;
+
+/// An interpolant with certificates.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Interpolant {
+    /// The interpolant `c`.
+    pub expr: Expr,
+    /// A proof of `a => c`.
+    pub left: Certificate,
+    /// A proof of `c => b`.
+    pub right: Certificate,
+}
+
+impl Interpolant {
+    /// Returns `true` if both certificates are valid, the goals match the interpolant
+    /// and the interpolant uses only atoms shared by both sides.
+    pub fn check(&self) -> bool {
+        let (a, b) = match (self.left.goal(), self.right.goal()) {
+            (Some(Expr::Imply(a, c0)), Some(Expr::Imply(c1, b)))
+                if **c0 == self.expr && **c1 == self.expr => (a.atoms(), b.atoms()),
+            _ => return false,
+        };
+        self.expr.atoms().iter().all(|x| a.contains(x) && b.contains(x)) &&
+        self.left.check() && self.right.check()
+    }
+}
+
+impl fmt::Display for Interpolant {
+    fn fmt(&self, w: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.left.goal(), self.right.goal()) {
+            (Some(Expr::Imply(a, _)), Some(Expr::Imply(_, b))) =>
+                write!(w, "{}  =>  {}  =>  {}", a, self.expr, b),
+            _ => write!(w, "{}", self.expr),
+        }
+    }
+}
+
+/// Computes an interpolant of `a => b`.
+///
+/// Returns `None` if `a => b` is not a classical tautology.
+pub fn interpolate(a: &Expr, b: &Expr) -> Option<Interpolant> {
+    profile!("interpolate::interpolate");
+    let shared = b.atoms();
+    let mut c = a.clone();
+    for x in a.atoms().into_iter().filter(|x| !shared.contains(x)) {
+        c = or(assign(&c, &x, true), assign(&c, &x, false));
+    }
+    let right = resolution::prove(&Expr::imply(c.clone(), b.clone()))?;
+    let left = resolution::prove(&Expr::imply(a.clone(), c.clone()))?;
+    Some(Interpolant {expr: c, left, right})
+}
+
+/// Replaces an atom with a truth value and simplifies.
+pub fn assign(e: &Expr, x: &str, value: bool) -> Expr {
+    use Expr::*;
+
+    match e {
+        Atom(y) if y == x => if value {True} else {False},
+        True | False | Atom(_) => e.clone(),
+        Not(a) => not(assign(a, x, value)),
+        And(a, b) => match (assign(a, x, value), assign(b, x, value)) {
+            (False, _) | (_, False) => False,
+            (True, c) | (c, True) => c,
+            (a, b) => Expr::and(a, b),
+        },
+        Or(a, b) => or(assign(a, x, value), assign(b, x, value)),
+        Imply(a, b) => match (assign(a, x, value), assign(b, x, value)) {
+            (False, _) | (_, True) => True,
+            (True, c) => c,
+            (c, False) => not(c),
+            (a, b) => Expr::imply(a, b),
+        },
+        Eq(a, b) => match (assign(a, x, value), assign(b, x, value)) {
+            (True, c) | (c, True) => c,
+            (False, c) | (c, False) => not(c),
+            (a, b) => Expr::eq(a, b),
+        },
+    }
+}
+
+fn not(e: Expr) -> Expr {
+    match e {
+        Expr::True => Expr::False,
+        Expr::False => Expr::True,
+        e => !e,
+    }
+}
+
+fn or(a: Expr, b: Expr) -> Expr {
+    match (a, b) {
+        (Expr::True, _) | (_, Expr::True) => Expr::True,
+        (Expr::False, c) | (c, Expr::False) => c,
+        (a, b) if a == b => a,
+        (a, b) => Expr::or(a, b),
+    }
+}
@@ -0,0 +1,269 @@
+//! # Kleene's Three-Valued Logic
+//!
+//! Kleene's strong logic of indeterminacy (K3) adds a third truth value `Unknown`
+//! besides true and false. This is useful when modelling partial information.
+//!
+//! A three-valued proposition is represented by two propositions (see [K3]):
+//!
+//! - `T`: The proposition is known to be true
+//! - `F`: The proposition is known to be false
+//!
+//! When neither `T` nor `F` holds, the value is unknown ([Unknown]).
+//!
+//! The connectives are defined as following:
+//!
+//! - `¬a`: `T = a.F`, `F = a.T` ([KNot])
+//! - `a ⋀ b`: `T = a.T ⋀ b.T`, `F = a.F ⋁ b.F` ([KAnd])
+//! - `a ⋁ b`: `T = a.T ⋁ b.T`, `F = a.F ⋀ b.F` ([KOr])
+//! - `a => b`: `T = a.F ⋁ b.T`, `F = a.T ⋀ b.F` ([KImply])
+//!
+//! ### Translation into two-valued logic
+//!
+//! A formula built from atoms ([Known]) translates into the core logic ([Core]).
+//! This translation is sound: `T => out` and `F => ¬out`.
+//!
+//! When atoms are decidable, every formula is either known to be true or known to be false
+//! ([KDecide]), so a theorem of the core logic is also true in K3 ([to_t]).
+//!
+//! ```rust
+//! use prop::*;
+//! use prop::k3::*;
+//! use Either::*;
+//!
+//! // Excluded Middle is not known to be true when the atom is unknown.
+//! type ExcMK3<A> = KOr<A, KNot<A>>;
+//! fn unknown_excm(t: <ExcMK3<Unknown> as K3>::T) -> False {
+//!     match t {
+//!         Left(x) => unknown_not_t()(x),
+//!         Right(x) => unknown_not_f()(x),
+//!     }
+//! }
+//!
+//! // It is known to be true when the atom is decidable.
+//! fn known_excm<A: DProp>() -> <ExcMK3<Known<A>> as K3>::T {
+//!     to_t::<ExcMK3<Known<A>>>(A::decide())
+//! }
+//!
+//! // `true ⋀ ¬false` is known to be true and its negation is known to be false.
+//! type X = KAnd<Known<True>, KNot<Known<False>>>;
+//! let t: <X as K3>::T = to_t::<X>((True, imply::id()));
+//! let _: <KNot<X> as K3>::F = t.clone();
+//! let _: And<True, Not<False>> = X::from_t(t);
+//! ```
+//!
+//! Formulas with unknown parts do not translate into the core logic:
+//!
+//! ```rust,compile_fail
+//! use prop::*;
+//! use prop::k3::*;
+//!
+//! fn unknown_excm() -> <KOr<Unknown, KNot<Unknown>> as K3>::T {
+//!     to_t::<KOr<Unknown, KNot<Unknown>>>(hole!())
+//! }
+//! ```
+
+use crate::*;
+
+/// Implemented by three-valued propositions.
+pub trait K3: Prop {
+    /// Known to be true.
+    type T: Prop;
+    /// Known to be false.
+    type F: Prop;
+}
+
+/// Implemented by three-valued propositions that translate into the core logic.
+pub trait Core: K3 {
+    /// The translated proposition.
+    type Out: Prop;
+    /// `T => out`.
+    fn from_t(t: Self::T) -> Self::Out;
+    /// `F => ¬out`.
+    fn from_f(f: Self::F) -> Not<Self::Out>;
+}
+
+/// Implemented by three-valued propositions that are never unknown.
+pub trait KDecide: K3 {
+    /// `T ⋁ F`.
+    fn decide_k3() -> Or<Self::T, Self::F>;
+}
+
+/// The unknown value.
+#[derive(Copy, Clone)]
+pub struct Unknown;
+
+/// An atom from the core logic.
+#[derive(Clone)]
+pub struct Known<A>(A);
+
+/// Negation `¬a`.
+#[derive(Clone)]
+pub struct KNot<A>(A);
+
+/// Conjunction `a ⋀ b`.
+#[derive(Clone)]
+pub struct KAnd<A, B>(A, B);
+
+/// Disjunction `a ⋁ b`.
+#[derive(Clone)]
+pub struct KOr<A, B>(A, B);
+
+/// Implication `a => b`.
+#[derive(Clone)]
+pub struct KImply<A, B>(A, B);
+
+impl K3 for Unknown {
+    type T = False;
+    type F = False;
+}
+
+impl<A: Prop> K3 for Known<A> {
+    type T = A;
+    type F = Not<A>;
+}
+
+impl<A: K3> K3 for KNot<A> {
+    type T = A::F;
+    type F = A::T;
+}
+
+impl<A: K3, B: K3> K3 for KAnd<A, B> {
+    type T = And<A::T, B::T>;
+    type F = Or<A::F, B::F>;
+}
+
+impl<A: K3, B: K3> K3 for KOr<A, B> {
+    type T = Or<A::T, B::T>;
+    type F = And<A::F, B::F>;
+}
+
+impl<A: K3, B: K3> K3 for KImply<A, B> {
+    type T = Or<A::F, B::T>;
+    type F = And<A::T, B::F>;
+}
+
+impl<A: Prop> Core for Known<A> {
+    type Out = A;
+    fn from_t(t: A) -> A {t}
+    fn from_f(f: Not<A>) -> Not<A> {f}
+}
+
+impl<A: Core> Core for KNot<A> {
+    type Out = Not<A::Out>;
+    fn from_t(t: A::F) -> Not<A::Out> {A::from_f(t)}
+    fn from_f(f: A::T) -> Not<Not<A::Out>> {not::double(A::from_t(f))}
+}
+
+impl<A: Core, B: Core> Core for KAnd<A, B> {
+    type Out = And<A::Out, B::Out>;
+    fn from_t((a, b): And<A::T, B::T>) -> Self::Out {(A::from_t(a), B::from_t(b))}
+    fn from_f(f: Or<A::F, B::F>) -> Not<Self::Out> {
+        match f {
+            Left(a) => {let na = A::from_f(a); Rc::new(move |(x, _)| na(x))}
+            Right(b) => {let nb = B::from_f(b); Rc::new(move |(_, y)| nb(y))}
+        }
+    }
+}
+
+impl<A: Core, B: Core> Core for KOr<A, B> {
+    type Out = Or<A::Out, B::Out>;
+    fn from_t(t: Or<A::T, B::T>) -> Self::Out {
+        match t {
+            Left(a) => Left(A::from_t(a)),
+            Right(b) => Right(B::from_t(b)),
+        }
+    }
+    fn from_f((a, b): And<A::F, B::F>) -> Not<Self::Out> {
+        and::to_de_morgan((A::from_f(a), B::from_f(b)))
+    }
+}
+
+impl<A: Core, B: Core> Core for KImply<A, B> {
+    type Out = Imply<A::Out, B::Out>;
+    fn from_t(t: Or<A::F, B::T>) -> Self::Out {
+        match t {
+            Left(a) => {let na = A::from_f(a); Rc::new(move |x| not::absurd(na.clone(), x))}
+            Right(b) => B::from_t(b).map_any(),
+        }
+    }
+    fn from_f((a, b): And<A::T, B::F>) -> Not<Self::Out> {
+        and::to_imply((A::from_t(a), B::from_f(b)))
+    }
+}
+
+impl<A: DProp> KDecide for Known<A> {
+    fn decide_k3() -> Or<A, Not<A>> {A::decide()}
+}
+
+impl<A: KDecide> KDecide for KNot<A> {
+    fn decide_k3() -> Or<A::F, A::T> {or::symmetry(A::decide_k3())}
+}
+
+impl<A: KDecide, B: KDecide> KDecide for KAnd<A, B> {
+    fn decide_k3() -> Or<Self::T, Self::F> {
+        match (A::decide_k3(), B::decide_k3()) {
+            (Left(a), Left(b)) => Left((a, b)),
+            (Right(a), _) => Right(Left(a)),
+            (_, Right(b)) => Right(Right(b)),
+        }
+    }
+}
+
+impl<A: KDecide, B: KDecide> KDecide for KOr<A, B> {
+    fn decide_k3() -> Or<Self::T, Self::F> {
+        match (A::decide_k3(), B::decide_k3()) {
+            (Left(a), _) => Left(Left(a)),
+            (_, Left(b)) => Left(Right(b)),
+            (Right(a), Right(b)) => Right((a, b)),
+        }
+    }
+}
+
+impl<A: KDecide, B: KDecide> KDecide for KImply<A, B> {
+    fn decide_k3() -> Or<Self::T, Self::F> {
+        match (A::decide_k3(), B::decide_k3()) {
+            (Right(a), _) => Left(Left(a)),
+            (_, Left(b)) => Left(Right(b)),
+            (Left(a), Right(b)) => Right((a, b)),
+        }
+    }
+}
+
+/// `T ⋀ F => false`.
+///
+/// A formula is never both known to be true and known to be false.
+pub fn consistent<A: Core>(t: A::T, f: A::F) -> False {A::from_f(f)(A::from_t(t))}
+
+/// `¬unknown.T`.
+pub fn unknown_not_t() -> Not<<Unknown as K3>::T> {imply::id()}
+
+/// `¬unknown.F`.
+pub fn unknown_not_f() -> Not<<Unknown as K3>::F> {imply::id()}
+
+/// `out => T` when atoms are decidable.
+pub fn to_t<A: Core + KDecide>(out: A::Out) -> A::T {
+    match A::decide_k3() {
+        Left(t) => t,
+        Right(f) => not::absurd(A::from_f(f), out),
+    }
+}
+
+/// `¬out => F` when atoms are decidable.
+pub fn to_f<A: Core + KDecide>(nout: Not<A::Out>) -> A::F {
+    match A::decide_k3() {
+        Left(t) => not::absurd(nout, A::from_t(t)),
+        Right(f) => f,
+    }
+}
+
+/// `T == out` when atoms are decidable.
+pub fn eq_t_out<A: Core + KDecide>() -> Eq<A::T, A::Out> {
+    (Rc::new(A::from_t), Rc::new(to_t::<A>))
+}
+
+/// `(out)^true => T^true` when atoms are decidable.
+///
+/// Translates a tautology of the core logic into a tautology of K3.
+pub fn tauto_to_t<A: Core + KDecide>(x: hooo::Tauto<A::Out>) -> hooo::Tauto<A::T> {
+    hooo::hooo_imply(hooo::pow_to_tauto_imply(to_t::<A>))(x)
+}
@@ -0,0 +1,236 @@
+//! # Kripke Semantics
+//!
+//! The counterexample checker (see [check](crate::check)) uses truth tables,
+//! which can not tell intuitionistic logic (IPL) apart from classical logic.
+//! This module provides Kripke semantics of reflected propositions (see [expr::Expr]),
+//! for which IPL is sound and complete.
+//!
+//! A Kripke frame ([Frame]) is a set of worlds with a preorder `w ≤ v`,
+//! where `v` is a possible future state of knowledge of `w`.
+//! Atoms that are forced in a world stay forced in the future (persistence).
+//! The forcing relation `w ⊩ a` ([Frame::forces]) is defined as:
+//!
+//! - `w ⊩ a ⋀ b` when `w ⊩ a` and `w ⊩ b`
+//! - `w ⊩ a ⋁ b` when `w ⊩ a` or `w ⊩ b`
+//! - `w ⊩ a => b` when `v ⊩ a` implies `v ⊩ b` for all `v ≥ w`
+//! - `w ⊩ ¬a` when there is no `v ≥ w` such that `v ⊩ a`
+//! - `w ⊩ a == b` when `w ⊩ a => b` and `w ⊩ b => a`
+//!
+//! A countermodel ([countermodel]) refutes a goal in IPL, even when it is a classical tautology.
+//!
+//! ### Soundness
+//!
+//! The propositional laws of the library that do not use `DProp` (see [tautos]) are collected
+//! in [laws]. Since these laws are proved in IPL, they must be forced in every world
+//! of every frame. The [soundness] check searches for countermodels of these laws,
+//! which serves as a meta-theoretic check of the library inside the library:
+//!
+//! ```rust
+//! use prop::expr;
+//! use prop::kripke::{countermodel, soundness};
+//!
+//! assert!(soundness(3).is_empty());
+//! // Excluded Middle is not valid in IPL.
+//! assert!(countermodel(&expr!(ExcM<A>), 2).is_some());
+//! ```
+
+use crate::*;
+use expr::Expr;
+use std::collections::BTreeSet;
+use std::fmt;
+
+/// Kripke frame with valuation.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Frame {
+    /// The preorder `le[w][v]` means `w ≤ v`.
+    pub le: Vec<Vec<bool>>,
+    /// The atoms forced in each world.
+    pub val: Vec<BTreeSet<String>>,
+}
+
+impl Frame {
+    /// Creates a frame of unrelated worlds, where no atoms are forced.
+    pub fn new(worlds: usize) -> Frame {
+        Frame {
+            le: (0..worlds).map(|w| (0..worlds).map(|v| w == v).collect()).collect(),
+            val: vec![BTreeSet::new(); worlds],
+        }
+    }
+
+    /// Returns the number of worlds.
+    pub fn worlds(&self) -> usize {self.le.len()}
+
+    /// Adds `w ≤ v` and closes the preorder under transitivity.
+    ///
+    /// Atoms forced in `w` become forced in `v`.
+    pub fn edge(mut self, w: usize, v: usize) -> Frame {
+        let n = self.worlds();
+        self.le[w][v] = true;
+        for k in 0..n {
+            for i in 0..n {
+                for j in 0..n {
+                    if self.le[i][k] && self.le[k][j] {self.le[i][j] = true}
+                }
+            }
+        }
+        for i in 0..n {
+            for j in 0..n {
+                if i != j && self.le[i][j] {
+                    let atoms = self.val[i].clone();
+                    self.val[j].extend(atoms);
+                }
+            }
+        }
+        self
+    }
+
+    /// Forces an atom in `w` and every future world of `w`.
+    pub fn force(mut self, w: usize, atom: &str) -> Frame {
+        for v in 0..self.worlds() {
+            if self.le[w][v] {self.val[v].insert(atom.into());}
+        }
+        self
+    }
+
+    /// Returns `true` if `w ⊩ e`.
+    pub fn forces(&self, w: usize, e: &Expr) -> bool {
+        use Expr::*;
+
+        let future = |w: usize| (0..self.worlds()).filter(move |&v| self.le[w][v]);
+        match e {
+            True => true,
+            False => false,
+            Atom(x) => self.val[w].contains(x),
+            Not(a) => future(w).all(|v| !self.forces(v, a)),
+            And(a, b) => self.forces(w, a) && self.forces(w, b),
+            Or(a, b) => self.forces(w, a) || self.forces(w, b),
+            Imply(a, b) => future(w).all(|v| !self.forces(v, a) || self.forces(v, b)),
+            Eq(a, b) => future(w).all(|v| self.forces(v, a) == self.forces(v, b)),
+        }
+    }
+
+    /// Returns `true` if every world forces `e`.
+    pub fn is_valid(&self, e: &Expr) -> bool {(0..self.worlds()).all(|w| self.forces(w, e))}
+}
+
+impl fmt::Display for Frame {
+    fn fmt(&self, w: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for i in 0..self.worlds() {
+            if i > 0 {write!(w, "; ")?}
+            write!(w, "{} ⊩ {{", i)?;
+            for (k, x) in self.val[i].iter().enumerate() {
+                if k > 0 {write!(w, ", ")?}
+                write!(w, "{}", x)?;
+            }
+            write!(w, "}}")?;
+            let next: Vec<_> = (0..self.worlds()).filter(|&j| i != j && self.le[i][j]).collect();
+            if !next.is_empty() {
+                write!(w, " ≤ ")?;
+                for (k, j) in next.iter().enumerate() {
+                    if k > 0 {write!(w, ", ")?}
+                    write!(w, "{}", j)?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Searches for a frame where the root world `0` does not force `e`.
+///
+/// Frames are trees with up to `max_worlds` worlds.
+/// No countermodel within the bound does not prove `e`,
+/// but a countermodel shows that `e` can not be proved in IPL.
+pub fn countermodel(e: &Expr, max_worlds: usize) -> Option<Frame> {
+    let atoms = e.atoms();
+    for n in 1..=max_worlds {
+        // Trees where the parent of a world is a world with lower index.
+        let mut parents = vec![0; n];
+        loop {
+            let mut tree = Frame::new(n);
+            for (v, &p) in parents.iter().enumerate().skip(1) {tree = tree.edge(p, v)}
+            // Sets of worlds that are closed under the future.
+            let up = |set: &Vec<usize>|
+                set.iter().all(|&w| (0..n).all(|v| !tree.le[w][v] || set.contains(&v)));
+            let ups: Vec<Vec<usize>> = (0..1usize << n)
+                .map(|bits| (0..n).filter(|&w| (bits >> w) & 1 == 1).collect::<Vec<_>>())
+                .filter(up)
+                .collect();
+            let mut choice = vec![0; atoms.len()];
+            loop {
+                let mut frame = tree.clone();
+                for (a, &c) in atoms.iter().zip(choice.iter()) {
+                    for &w in &ups[c] {frame = frame.force(w, a)}
+                }
+                if !frame.forces(0, e) {return Some(frame)}
+                if !next(&mut choice, |_| ups.len()) {break}
+            }
+            if !next(&mut parents[1..], |i| i + 1) {break}
+        }
+    }
+    None
+}
+
+/// Steps to the next combination, where position `i` is bounded by `bound(i)`.
+fn next(xs: &mut [usize], bound: impl Fn(usize) -> usize) -> bool {
+    for (i, x) in xs.iter_mut().enumerate() {
+        *x += 1;
+        if *x < bound(i) {return true}
+        *x = 0;
+    }
+    false
+}
+
+/// The propositional laws of [tautos] that hold in IPL.
+pub fn laws() -> Vec<(&'static str, Expr)> {
+    vec![
+        ("imply_refl", expr!(Imply<A, A>)),
+        ("imply_weaken", expr!(Imply<A, Imply<B, A>>)),
+        ("imply_transitivity", expr!(Imply<And<Imply<A, B>, Imply<B, C>>, Imply<A, C>>)),
+        ("modus_ponens", expr!(Imply<And<Imply<A, B>, A>, B>)),
+        ("modus_tollens", expr!(Imply<And<Imply<A, B>, Not<B>>, Not<A>>)),
+        ("exportation", expr!(Eq<Imply<And<A, B>, C>, Imply<A, Imply<B, C>>>)),
+        ("swap_args", expr!(Eq<Imply<A, Imply<B, C>>, Imply<B, Imply<A, C>>>)),
+        ("imply_absorb", expr!(Eq<Imply<A, B>, Imply<A, And<A, B>>>)),
+        ("and_symmetry", expr!(Eq<And<A, B>, And<B, A>>)),
+        ("or_symmetry", expr!(Eq<Or<A, B>, Or<B, A>>)),
+        ("and_assoc", expr!(Eq<And<And<A, B>, C>, And<A, And<B, C>>>)),
+        ("or_assoc", expr!(Eq<Or<Or<A, B>, C>, Or<A, Or<B, C>>>)),
+        ("and_idem", expr!(Eq<A, And<A, A>>)),
+        ("or_idem", expr!(Eq<A, Or<A, A>>)),
+        ("and_or_absorb", expr!(Eq<And<A, Or<A, B>>, A>)),
+        ("or_and_absorb", expr!(Eq<Or<A, And<A, B>>, A>)),
+        ("and_distrib", expr!(Eq<And<A, Or<B, C>>, Or<And<A, B>, And<A, C>>>)),
+        ("or_distrib", expr!(Eq<Or<A, And<B, C>>, And<Or<A, B>, Or<A, C>>>)),
+        ("and_true", expr!(Eq<And<A, True>, A>)),
+        ("and_false", expr!(Eq<And<A, False>, False>)),
+        ("or_true", expr!(Eq<Or<A, True>, True>)),
+        ("or_false", expr!(Eq<Or<A, False>, A>)),
+        ("double_neg_intro", expr!(Imply<A, Not<Not<A>>>)),
+        ("triple_neg", expr!(Eq<Not<Not<Not<A>>>, Not<A>>)),
+        ("non_contradiction", expr!(Not<And<A, Not<A>>>)),
+        ("nn_excm", expr!(Not<Not<ExcM<A>>>)),
+        ("de_morgan_or", expr!(Eq<Not<Or<A, B>>, And<Not<A>, Not<B>>>)),
+        ("explosion", expr!(Imply<False, A>)),
+        ("or_elim", expr!(Imply<And<Imply<A, C>, Imply<B, C>>, Imply<Or<A, B>, C>>)),
+        ("and_intro_imply", expr!(Imply<And<Imply<A, B>, Imply<A, C>>, Imply<A, And<B, C>>>)),
+        ("eq_refl", expr!(Eq<A, A>)),
+        ("eq_symmetry", expr!(Imply<Eq<A, B>, Eq<B, A>>)),
+        ("eq_transitivity", expr!(Imply<And<Eq<A, B>, Eq<B, C>>, Eq<A, C>>)),
+        ("eq_to_imply", expr!(Imply<Eq<A, B>, Imply<A, B>>)),
+        ("eq_def", expr!(Eq<Eq<A, B>, And<Imply<A, B>, Imply<B, A>>>)),
+        ("imply_and_distrib", expr!(Eq<Imply<A, And<B, C>>, And<Imply<A, B>, Imply<A, C>>>)),
+        ("or_imply_distrib", expr!(Eq<Imply<Or<A, B>, C>, And<Imply<A, C>, Imply<B, C>>>)),
+        ("true_imply", expr!(Eq<Imply<True, A>, A>)),
+        ("imply_true", expr!(Imply<A, True>)),
+    ]
+}
+
+/// Searches for countermodels of the laws with up to `max_worlds` worlds.
+///
+/// Returns the laws that are refuted, which is empty when the library is sound.
+pub fn soundness(max_worlds: usize) -> Vec<(&'static str, Frame)> {
+    laws().into_iter()
+        .filter_map(|(name, e)| countermodel(&e, max_worlds).map(|frame| (name, frame)))
+        .collect()
+}
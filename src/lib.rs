@@ -65,6 +65,8 @@
 //! - A model of Avatar Modal Logic derived from HOOO EP and Theory of Avatar Extensions
 //! - A model of Middle Exponential Logic using EL and HOOO EP
 //! - Tactics organized in modules by constructs (e.g. `and` or `imply`)
+//! - Disambiguated re-exports of the most used tactics (see "prelude" module)
+//! - Deferred proof obligations for sketching proofs top-down (see "hole" module)
 //!
 //! ### Examples
 //!
@@ -108,6 +110,13 @@ use std::rc::Rc;
 
 use Either::*;
 
+/// Profiles the current closure when the `profile` feature is enabled (see [profile]).
+#[cfg(feature = "profile")]
+macro_rules! profile(($name:expr) => {let _scope = $crate::profile::enter($name);});
+/// Profiles the current closure when the `profile` feature is enabled.
+#[cfg(not(feature = "profile"))]
+macro_rules! profile(($name:expr) => {});
+
 pub mod and;
 #[cfg(feature = "avatar_extensions")]
 pub mod avatar_extensions;
@@ -121,6 +130,7 @@ pub mod quality;
 pub mod quality_traits;
 pub mod qubit;
 pub mod queenity;
+pub mod bias;
 pub mod univalence;
 #[cfg(feature = "quantify")]
 pub mod quantify;
@@ -129,6 +139,8 @@ pub mod con_qubit;
 pub mod hooo;
 pub mod hooo_traits;
 pub mod hott;
+#[cfg(feature = "uip")]
+pub mod uip;
 pub mod modal;
 pub mod ava_modal;
 pub mod mid;
@@ -136,6 +148,43 @@ pub mod fun;
 pub mod fun_traits;
 pub mod sd;
 pub mod halt;
+pub mod groupoid;
+pub mod galois;
+pub mod yoneda;
+pub mod fixpoint;
+pub mod k3;
+pub mod heyting;
+pub mod boolean;
+pub mod plogic;
+pub mod expr;
+pub mod diff;
+pub mod rewrite;
+pub mod resolution;
+pub mod interpolate;
+#[cfg(feature = "dpll")]
+pub mod dpll;
+pub mod check;
+pub mod omega;
+pub mod kripke;
+pub mod counter;
+pub mod tautos;
+pub mod prov;
+pub mod sanity;
+pub mod strat;
+pub mod axiom;
+pub mod hole;
+pub mod cont;
+pub mod extract;
+#[cfg(feature = "cf")]
+pub mod cf;
+pub mod epistemic;
+pub mod deontic;
+pub mod game;
+pub mod prelude;
+#[cfg(feature = "profile")]
+pub mod profile;
+
+pub use axiom::axioms;
 
 /// Logical true.
 #[derive(Copy, Clone)]
@@ -189,6 +238,10 @@ pub trait Prop: 'static + Sized + Clone {
 impl<T: 'static + Sized + Clone> Prop for T {}
 
 /// Implemented by decidable types.
+///
+/// Since `¬a` and `a == b` are built from `=>` and `⋀`,
+/// they are decidable when `a` and `b` are decidable.
+/// To split on two decided propositions, use [or::excm_cases] or [or::decide_cases].
 pub trait Decidable: Prop {
     /// Get excluded middle rule.
     fn decide() -> ExcM<Self>;
@@ -201,38 +254,13 @@ impl Decidable for False {
     fn decide() -> ExcM<False> {Right(Rc::new(move |x| x))}
 }
 impl<T, U> Decidable for And<T, U> where T: Decidable, U: Decidable {
-    fn decide() -> ExcM<Self> {
-        match (<T as Decidable>::decide(), <U as Decidable>::decide()) {
-            (Left(a), Left(b)) => Left((a, b)),
-            (_, Right(b)) => Right(Rc::new(move |(_, x)| b.clone()(x))),
-            (Right(a), _) => Right(Rc::new(move |(x, _)| a.clone()(x))),
-        }
-    }
+    fn decide() -> ExcM<Self> {or::excm_and(T::decide(), U::decide())}
 }
 impl<T, U> Decidable for Or<T, U> where T: Decidable, U: Decidable {
-    fn decide() -> ExcM<Self> {
-        match (<T as Decidable>::decide(), <U as Decidable>::decide()) {
-            (Left(a), _) => Left(Left(a)),
-            (_, Left(b)) => Left(Right(b)),
-            (Right(a), Right(b)) => Right(Rc::new(move |f| match f {
-                Left(x) => a.clone()(x),
-                Right(y) => b.clone()(y),
-            }))
-        }
-    }
+    fn decide() -> ExcM<Self> {or::excm_or(T::decide(), U::decide())}
 }
 impl<T, U> Decidable for Imply<T, U> where T: Decidable, U: Decidable {
-    fn decide() -> ExcM<Self> {
-        match (<T as Decidable>::decide(), <U as Decidable>::decide()) {
-            (_, Left(b)) => Left(b.map_any()),
-            (Left(a), Right(b)) =>
-                Right(Rc::new(move |f| b.clone()(f(a.clone())))),
-            (Right(a), _) => {
-                let g: Imply<Not<U>, Not<T>> = a.map_any();
-                Left(imply::rev_modus_tollens(g))
-            }
-        }
-    }
+    fn decide() -> ExcM<Self> {or::excm_imply(T::decide(), U::decide())}
 }
 
 /// Shorthand for decidable proposition.
@@ -11,6 +11,9 @@
 use crate::*;
 use hooo::*;
 
+pub mod hybrid;
+pub mod tableau;
+
 /// `◇p := p^true ⋁ theory(p)`.
 ///
 /// A proposition is theoretical possibly true if it is either a tautology or a theory.
@@ -199,7 +202,7 @@ pub fn to_nnpos<A: Prop>(a: A) -> NNPos<A> {
 ///
 /// This is added as an axiom since Rust can't prove `fn(()) -> A` is the same as `fn() -> A`.
 pub fn n<A: Prop>(_: fn() -> A) -> fn() -> Nec<A> {
-    unimplemented!()
+    axiom!()
 }
 
 /// `□(a => b) => (□a => □b)`.
@@ -0,0 +1,126 @@
+//! # Hybrid Logic
+//!
+//! A nominal `i` ([Nominal]) is a proposition that is true at exactly one world,
+//! such that it can be used to name that world.
+//!
+//! The satisfaction operator `@{i}(a)` ([At]) means that `a` is true at the world named by `i`.
+//!
+//! The axioms are:
+//!
+//! - Necessitation `a^true  =>  @{i}(a)` ([at_nec])
+//! - Distribution (K) `@{i}(a => b)  =>  (@{i}(a) => @{i}(b))` ([at_distrib])
+//! - Self-duality `@{i}(¬a)  ==  ¬@{i}(a)` ([at_not_to_not_at], [not_at_to_at_not])
+//! - Reflexivity `@{i}(i)` ([at_refl])
+//! - Agreement `@{j}(@{i}(a))  =>  @{i}(a)` ([at_agree])
+//! - Introduction `i ⋀ a  =>  @{i}(a)` ([at_intro])
+//! - Back `@{i}(a)  =>  □@{i}(a)` ([at_back])
+//! - Bridge `◇(i ⋀ a)  =>  @{i}(a)` ([pos_to_at])
+//!
+//! Since `@{i}(a)` does not depend on the current world,
+//! it is never a theory ([para_theory_at]) and therefore `◇@{i}(a)  =>  @{i}(a)` ([pos_at_to_at]).
+
+#![allow(unreachable_code)]
+
+use crate::*;
+use hooo::{pow_transitivity, tauto_not_to_para, Tauto, Theory};
+use modal::{Nec, Pos};
+
+/// Implemented by nominals, which are true at exactly one world.
+pub trait Nominal: Prop {}
+
+/// Satisfaction operator `@{i}(a)`.
+#[derive(Copy, Clone)]
+pub struct At<I, A>(I, A);
+
+/// `a^true  =>  @{i}(a)`.
+pub fn at_nec<I: Nominal, A: Prop>(_: Tauto<A>) -> At<I, A> {axiom!()}
+/// `@{i}(a => b)  =>  (@{i}(a) => @{i}(b))`.
+pub fn at_distrib<I: Nominal, A: Prop, B: Prop>(
+    _: At<I, Imply<A, B>>
+) -> Imply<At<I, A>, At<I, B>> {axiom!()}
+/// `@{i}(¬a)  =>  ¬@{i}(a)`.
+pub fn at_not_to_not_at<I: Nominal, A: Prop>(_: At<I, Not<A>>) -> Not<At<I, A>> {axiom!()}
+/// `¬@{i}(a)  =>  @{i}(¬a)`.
+pub fn not_at_to_at_not<I: Nominal, A: Prop>(_: Not<At<I, A>>) -> At<I, Not<A>> {axiom!()}
+/// `@{i}(i)`.
+pub fn at_refl<I: Nominal>() -> At<I, I> {axiom!()}
+/// `@{j}(@{i}(a))  =>  @{i}(a)`.
+pub fn at_agree<I: Nominal, J: Nominal, A: Prop>(_: At<J, At<I, A>>) -> At<I, A> {axiom!()}
+/// `i ⋀ a  =>  @{i}(a)`.
+pub fn at_intro<I: Nominal, A: Prop>(_: I, _: A) -> At<I, A> {axiom!()}
+/// `@{i}(a)  =>  □@{i}(a)`.
+pub fn at_back<I: Nominal, A: Prop>(_: At<I, A>) -> Nec<At<I, A>> {axiom!()}
+/// `◇(i ⋀ a)  =>  @{i}(a)`.
+pub fn pos_to_at<I: Nominal, A: Prop>(_: Pos<And<I, A>>) -> At<I, A> {axiom!()}
+
+/// `@{i}(a)  ==  ¬@{i}(¬a)`.
+pub fn eq_at_not_at_not<I: Nominal, A: DProp>() -> Eq<At<I, A>, Not<At<I, Not<A>>>> {
+    (
+        Rc::new(|x| Rc::new(move |y| at_absurd(x.clone(), y))),
+        Rc::new(|x| at_in_arg(tauto!(Rc::new(not::rev_double)), not_at_to_at_not(x))),
+    )
+}
+/// `@{i}(a => b) ⋀ @{i}(a)  =>  @{i}(b)`.
+pub fn at_modus_ponens<I: Nominal, A: Prop, B: Prop>(
+    x: At<I, Imply<A, B>>,
+    y: At<I, A>
+) -> At<I, B> {at_distrib(x)(y)}
+/// `(a => b)^true ⋀ @{i}(a)  =>  @{i}(b)`.
+pub fn at_in_arg<I: Nominal, A: Prop, B: Prop>(x: Tauto<Imply<A, B>>, y: At<I, A>) -> At<I, B> {
+    at_modus_ponens(at_nec(x), y)
+}
+/// `@{i}(true)`.
+pub fn at_true<I: Nominal>() -> At<I, True> {at_nec(hooo::tr())}
+/// `¬@{i}(false)`.
+pub fn at_consistency<I: Nominal>(x: At<I, False>) -> False {
+    at_not_to_not_at(at_nec(tauto!(imply::id())))(x)
+}
+/// `@{i}(a) ⋀ @{i}(b)  =>  @{i}(a ⋀ b)`.
+pub fn at_and<I: Nominal, A: Prop, B: Prop>(a: At<I, A>, b: At<I, B>) -> At<I, And<A, B>> {
+    fn f<A: Prop, B: Prop>(a: A) -> Imply<B, And<A, B>> {Rc::new(move |b| (a.clone(), b))}
+    at_modus_ponens(at_modus_ponens(at_nec(tauto!(hooo::pow_to_imply(f))), a), b)
+}
+/// `@{i}(a ⋀ b)  =>  @{i}(a)`.
+pub fn at_fst<I: Nominal, A: Prop, B: Prop>(x: At<I, And<A, B>>) -> At<I, A> {
+    at_in_arg(tauto!(Rc::new(and::fst)), x)
+}
+/// `@{i}(a ⋀ b)  =>  @{i}(b)`.
+pub fn at_snd<I: Nominal, A: Prop, B: Prop>(x: At<I, And<A, B>>) -> At<I, B> {
+    at_in_arg(tauto!(Rc::new(and::snd)), x)
+}
+/// `@{i}(a) ⋀ @{i}(¬a)  =>  false`.
+pub fn at_absurd<I: Nominal, A: Prop>(x: At<I, A>, y: At<I, Not<A>>) -> False {
+    at_not_to_not_at(y)(x)
+}
+/// `i ⋀ @{i}(a)  =>  a`.
+pub fn at_elim<I: Nominal, A: DProp>(i: I, x: At<I, A>) -> A {
+    match A::decide() {
+        Left(a) => a,
+        Right(na) => match at_absurd(x, at_intro(i, na)) {},
+    }
+}
+/// `□a  =>  @{i}(a)`.
+pub fn nec_to_at<I: Nominal, A: Prop>(x: Nec<A>) -> At<I, A> {at_nec(x)}
+/// `@{i}(□a)  =>  @{i}(a)`.
+pub fn at_nec_to_at<I: Nominal, A: Prop>(x: At<I, Nec<A>>) -> At<I, A> {
+    at_in_arg(tauto!(Rc::new(modal::t)), x)
+}
+/// `¬@{i}(a)  =>  □¬@{i}(a)`.
+pub fn not_at_to_nec_not_at<I: Nominal, A: Prop>(x: Not<At<I, A>>) -> Nec<Not<At<I, A>>> {
+    pow_transitivity(at_back(not_at_to_at_not(x)), at_not_to_not_at)
+}
+/// `false^(theory(@{i}(a)))`.
+pub fn para_theory_at<I: Nominal, A: Prop>(x: Theory<At<I, A>>) -> False {
+    let y = x.clone();
+    let n: Not<At<I, A>> = Rc::new(move |at| y(Left(at_back(at))));
+    x(Right(tauto_not_to_para(not_at_to_nec_not_at(n))))
+}
+/// `◇@{i}(a)  =>  @{i}(a)`.
+pub fn pos_at_to_at<I: Nominal, A: Prop>(x: Pos<At<I, A>>) -> At<I, A> {
+    match x {
+        Left(tauto_at) => modal::t(tauto_at),
+        Right(theory_at) => match para_theory_at(theory_at) {},
+    }
+}
+/// `@{i}(a)  =>  ◇@{i}(a)`.
+pub fn at_to_pos_at<I: Nominal, A: Prop>(x: At<I, A>) -> Pos<At<I, A>> {Left(at_back(x))}
@@ -0,0 +1,368 @@
+//! # Modal Tableaux
+//!
+//! A labelled tableau prover for reflected modal formulas ([Formula]),
+//! supporting the modal systems K, T and S4 ([System]).
+//!
+//! An entry `w ⊩ a` or `w ⊮ a` ([Entry]) states that `a` is true or false at world `w`.
+//! To prove a goal, the tableau starts with `0 ⊮ goal` and expands entries until every branch
+//! contains a contradiction. The modal rules use an accessibility relation between worlds:
+//!
+//! - `w ⊩ □a` or `w ⊮ ◇a`: the same for `a` at every world accessible from `w`
+//! - `w ⊮ □a` or `w ⊩ ◇a`: the same for `a` at a new world accessible from `w`
+//!
+//! The systems differ by accessibility, which is reflexive in T (`□a => a`, see [modal::t])
+//! and also transitive in S4 (`□a => □□a`, see [modal::four]).
+//! In S4, a world is not expanded with new worlds when an earlier world that can access it
+//! has all of its entries (blocking), which makes the search terminate.
+//!
+//! A closed tableau is a proof object ([Proof]), which [Proof::check] replays
+//! without trusting the prover.
+//! Since the model of [modal] is S5 or stronger for decidable propositions,
+//! goals proved in K, T or S4 also hold there.
+//!
+//! ```rust
+//! use prop::modal::tableau::{prove, Formula, System};
+//!
+//! let (a, b) = (Formula::atom("a"), Formula::atom("b"));
+//! let nec_imply = Formula::nec(Formula::imply(a.clone(), b.clone()));
+//! let k = Formula::imply(nec_imply, Formula::imply(Formula::nec(a.clone()), Formula::nec(b)));
+//! assert!(prove(&k, System::K).unwrap().check());
+//!
+//! let t = Formula::imply(Formula::nec(a.clone()), a.clone());
+//! assert!(prove(&t, System::K).is_none());
+//! assert!(prove(&t, System::T).unwrap().check());
+//!
+//! let four = Formula::imply(Formula::nec(a.clone()), Formula::nec(Formula::nec(a)));
+//! assert!(prove(&four, System::T).is_none());
+//! assert!(prove(&four, System::S4).unwrap().check());
+//! ```
+
+use crate::*;
+use expr::Expr;
+use std::collections::BTreeSet;
+use std::fmt;
+
+/// The maximum number of worlds in a branch.
+pub const MAX_WORLDS: usize = 32;
+
+/// Reflected modal formula.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Formula {
+    /// `true`.
+    True,
+    /// `false`.
+    False,
+    /// An atomic proposition.
+    Atom(String),
+    /// `¬a`.
+    Not(Box<Formula>),
+    /// `a ⋀ b`.
+    And(Box<Formula>, Box<Formula>),
+    /// `a ⋁ b`.
+    Or(Box<Formula>, Box<Formula>),
+    /// `a => b`.
+    Imply(Box<Formula>, Box<Formula>),
+    /// `□a`.
+    Nec(Box<Formula>),
+    /// `◇a`.
+    Pos(Box<Formula>),
+}
+
+impl Formula {
+    /// Creates an atom.
+    pub fn atom(name: &str) -> Formula {Formula::Atom(name.into())}
+    /// Creates `a ⋀ b`.
+    pub fn and(a: Formula, b: Formula) -> Formula {Formula::And(Box::new(a), Box::new(b))}
+    /// Creates `a ⋁ b`.
+    pub fn or(a: Formula, b: Formula) -> Formula {Formula::Or(Box::new(a), Box::new(b))}
+    /// Creates `a => b`.
+    pub fn imply(a: Formula, b: Formula) -> Formula {Formula::Imply(Box::new(a), Box::new(b))}
+    /// Creates `□a`.
+    pub fn nec(a: Formula) -> Formula {Formula::Nec(Box::new(a))}
+    /// Creates `◇a`.
+    pub fn pos(a: Formula) -> Formula {Formula::Pos(Box::new(a))}
+
+    /// Converts from a reflected proposition, where `a == b` becomes `(a => b) ⋀ (b => a)`.
+    pub fn from_expr(e: &Expr) -> Formula {
+        let f = Formula::from_expr;
+        match e {
+            Expr::True => Formula::True,
+            Expr::False => Formula::False,
+            Expr::Atom(x) => Formula::atom(x),
+            Expr::Not(a) => !f(a),
+            Expr::And(a, b) => Formula::and(f(a), f(b)),
+            Expr::Or(a, b) => Formula::or(f(a), f(b)),
+            Expr::Imply(a, b) => Formula::imply(f(a), f(b)),
+            Expr::Eq(a, b) => Formula::and(Formula::imply(f(a), f(b)), Formula::imply(f(b), f(a))),
+        }
+    }
+}
+
+impl std::ops::Not for Formula {
+    type Output = Formula;
+    fn not(self) -> Formula {Formula::Not(Box::new(self))}
+}
+
+impl fmt::Display for Formula {
+    fn fmt(&self, w: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use Formula::*;
+
+        fn arg(w: &mut fmt::Formatter<'_>, a: &Formula) -> fmt::Result {
+            match a {
+                True | False | Atom(_) | Not(_) | Nec(_) | Pos(_) => write!(w, "{}", a),
+                _ => write!(w, "({})", a),
+            }
+        }
+
+        match self {
+            True => write!(w, "true"),
+            False => write!(w, "false"),
+            Atom(x) => write!(w, "{}", x),
+            Not(a) => {write!(w, "¬")?; arg(w, a)}
+            And(a, b) => {arg(w, a)?; write!(w, " ⋀ ")?; arg(w, b)}
+            Or(a, b) => {arg(w, a)?; write!(w, " ⋁ ")?; arg(w, b)}
+            Imply(a, b) => {arg(w, a)?; write!(w, " => ")?; arg(w, b)}
+            Nec(a) => {write!(w, "□")?; arg(w, a)}
+            Pos(a) => {write!(w, "◇")?; arg(w, a)}
+        }
+    }
+}
+
+/// Modal system, given by conditions on accessibility.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum System {
+    /// No conditions.
+    K,
+    /// Reflexive accessibility.
+    T,
+    /// Reflexive and transitive accessibility.
+    S4,
+}
+
+/// A formula that is true or false at a world.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Entry {
+    /// The world.
+    pub world: usize,
+    /// Whether the formula is true.
+    pub sign: bool,
+    /// The formula.
+    pub formula: Formula,
+}
+
+impl fmt::Display for Entry {
+    fn fmt(&self, w: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(w, "{} {} {}", self.world, if self.sign {"⊩"} else {"⊮"}, self.formula)
+    }
+}
+
+/// A closed tableau, where entries are referred to by their index on the branch.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Tableau {
+    /// Closes the branch by two contradicting entries,
+    /// or by the same entry twice when it is `true` false or `false` true.
+    Close(usize, usize),
+    /// Expands a propositional entry, with one tableau for each new branch.
+    Expand(usize, Vec<Tableau>),
+    /// Applies `w ⊩ □a` or `w ⊮ ◇a` at an accessible world.
+    Access(usize, usize, Box<Tableau>),
+    /// Applies `w ⊮ □a` or `w ⊩ ◇a` at a new world.
+    New(usize, Box<Tableau>),
+}
+
+/// A proof of a modal formula.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Proof {
+    /// The modal system.
+    pub system: System,
+    /// The formula that is proved.
+    pub goal: Formula,
+    /// The closed tableau starting with `0 ⊮ goal`.
+    pub tableau: Tableau,
+}
+
+impl Proof {
+    /// Returns `true` if the tableau is closed.
+    pub fn check(&self) -> bool {replay(self.system, Branch::new(&self.goal), &self.tableau)}
+}
+
+/// Searches for a proof of a modal formula.
+///
+/// Returns `None` if the formula is not valid in the system,
+/// or if a branch needs more than [MAX_WORLDS] worlds.
+pub fn prove(goal: &Formula, system: System) -> Option<Proof> {
+    profile!("tableau::prove");
+    let tableau = search(system, Branch::new(goal))?;
+    Some(Proof {system, goal: goal.clone(), tableau})
+}
+
+#[derive(Clone)]
+struct Branch {
+    entries: Vec<Entry>,
+    edges: Vec<(usize, usize)>,
+    worlds: usize,
+    /// Entries that have been applied at a new world.
+    used: BTreeSet<usize>,
+}
+
+impl Branch {
+    fn new(goal: &Formula) -> Branch {
+        Branch {
+            entries: vec![Entry {world: 0, sign: false, formula: goal.clone()}],
+            edges: vec![],
+            worlds: 1,
+            used: BTreeSet::new(),
+        }
+    }
+
+    fn add(mut self, es: Vec<Entry>) -> Branch {
+        for e in es {
+            if !self.entries.contains(&e) {self.entries.push(e)}
+        }
+        self
+    }
+
+    fn access(&self, system: System, w: usize, v: usize) -> bool {
+        if w == v && system != System::K {return true}
+        if system != System::S4 {return self.edges.contains(&(w, v))}
+        let mut stack = vec![w];
+        let mut seen = BTreeSet::new();
+        while let Some(x) = stack.pop() {
+            if !seen.insert(x) {continue}
+            for &(a, b) in &self.edges {
+                if a == x {
+                    if b == v {return true}
+                    stack.push(b);
+                }
+            }
+        }
+        false
+    }
+
+    fn labels(&self, w: usize) -> BTreeSet<(bool, &Formula)> {
+        self.entries.iter().filter(|e| e.world == w).map(|e| (e.sign, &e.formula)).collect()
+    }
+
+    /// Returns `true` if an earlier world that accesses `w` has all entries of `w`.
+    fn blocked(&self, system: System, w: usize) -> bool {
+        if system != System::S4 {return false}
+        let labels = self.labels(w);
+        (0..w).any(|u| self.access(system, u, w) && labels.is_subset(&self.labels(u)))
+    }
+}
+
+/// Returns the branches of a propositional entry.
+fn expand(e: &Entry) -> Option<Vec<Vec<Entry>>> {
+    use Formula::*;
+
+    let at = |sign: bool, a: &Formula| Entry {world: e.world, sign, formula: a.clone()};
+    Some(match (&e.formula, e.sign) {
+        (Not(a), s) => vec![vec![at(!s, a)]],
+        (And(a, b), true) => vec![vec![at(true, a), at(true, b)]],
+        (And(a, b), false) => vec![vec![at(false, a)], vec![at(false, b)]],
+        (Or(a, b), true) => vec![vec![at(true, a)], vec![at(true, b)]],
+        (Or(a, b), false) => vec![vec![at(false, a), at(false, b)]],
+        (Imply(a, b), true) => vec![vec![at(false, a)], vec![at(true, b)]],
+        (Imply(a, b), false) => vec![vec![at(true, a), at(false, b)]],
+        _ => return None,
+    })
+}
+
+/// Returns whether a modal entry applies at every accessible world, and its argument.
+fn modal(e: &Entry) -> Option<(bool, &Formula)> {
+    match (&e.formula, e.sign) {
+        (Formula::Nec(a), true) | (Formula::Pos(a), false) => Some((true, a)),
+        (Formula::Nec(a), false) | (Formula::Pos(a), true) => Some((false, a)),
+        _ => None,
+    }
+}
+
+fn closes(a: &Entry, b: &Entry) -> bool {
+    let constant = matches!((&a.formula, a.sign), (Formula::False, true) | (Formula::True, false));
+    (a == b && constant) || (a.world == b.world && a.formula == b.formula && a.sign != b.sign)
+}
+
+fn replay(system: System, b: Branch, t: &Tableau) -> bool {
+    match t {
+        Tableau::Close(i, j) => match (b.entries.get(*i), b.entries.get(*j)) {
+            (Some(x), Some(y)) => closes(x, y),
+            _ => false,
+        },
+        Tableau::Expand(i, ts) => match b.entries.get(*i).and_then(expand) {
+            Some(bs) if bs.len() == ts.len() =>
+                bs.into_iter().zip(ts).all(|(es, t)| replay(system, b.clone().add(es), t)),
+            _ => false,
+        },
+        Tableau::Access(i, v, t) => match b.entries.get(*i).and_then(|e| Some((e, modal(e)?))) {
+            Some((e, (true, a))) if *v < b.worlds && b.access(system, e.world, *v) => {
+                let e = Entry {world: *v, sign: e.sign, formula: a.clone()};
+                replay(system, b.clone().add(vec![e]), t)
+            }
+            _ => false,
+        },
+        Tableau::New(i, t) => match b.entries.get(*i).and_then(|e| Some((e, modal(e)?))) {
+            Some((e, (false, a))) => {
+                let x = Entry {world: b.worlds, sign: e.sign, formula: a.clone()};
+                let mut b = b.clone();
+                b.edges.push((e.world, b.worlds));
+                b.worlds += 1;
+                replay(system, b.add(vec![x]), t)
+            }
+            _ => false,
+        },
+    }
+}
+
+fn search(system: System, b: Branch) -> Option<Tableau> {
+    let n = b.entries.len();
+    for i in 0..n {
+        for j in i..n {
+            if closes(&b.entries[i], &b.entries[j]) {return Some(Tableau::Close(i, j))}
+        }
+    }
+
+    let open = |es: &Vec<Entry>| es.iter().any(|e| !b.entries.contains(e));
+    // Rules that do not branch first.
+    for (i, e) in b.entries.iter().enumerate() {
+        if let Some(mut bs) = expand(e) {
+            if bs.len() == 1 && open(&bs[0]) {
+                let t = search(system, b.clone().add(bs.pop().unwrap()))?;
+                return Some(Tableau::Expand(i, vec![t]));
+            }
+        }
+    }
+    for (i, e) in b.entries.iter().enumerate() {
+        if let Some((true, a)) = modal(e) {
+            for v in 0..b.worlds {
+                let x = Entry {world: v, sign: e.sign, formula: a.clone()};
+                if b.access(system, e.world, v) && !b.entries.contains(&x) {
+                    let t = search(system, b.clone().add(vec![x]))?;
+                    return Some(Tableau::Access(i, v, Box::new(t)));
+                }
+            }
+        }
+    }
+    for (i, e) in b.entries.iter().enumerate() {
+        if let Some(bs) = expand(e) {
+            if bs.len() > 1 && bs.iter().all(open) {
+                let ts = bs.into_iter().map(|es| search(system, b.clone().add(es)))
+                    .collect::<Option<Vec<_>>>()?;
+                return Some(Tableau::Expand(i, ts));
+            }
+        }
+    }
+    for (i, e) in b.entries.iter().enumerate() {
+        if let Some((false, a)) = modal(e) {
+            if b.used.contains(&i) || b.blocked(system, e.world) {continue}
+            if b.worlds >= MAX_WORLDS {return None}
+            let x = Entry {world: b.worlds, sign: e.sign, formula: a.clone()};
+            let mut b = b.clone();
+            b.used.insert(i);
+            b.edges.push((b.entries[i].world, b.worlds));
+            b.worlds += 1;
+            let t = search(system, b.add(vec![x]))?;
+            return Some(Tableau::New(i, Box::new(t)));
+        }
+    }
+    None
+}
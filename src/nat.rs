@@ -1,6 +1,6 @@
 //! Natural numbers with types.
 
-use crate::{Eq, Prop, Not};
+use crate::{axiom, Eq, Prop, Not};
 
 /// Zero.
 #[derive(Copy, Clone)]
@@ -50,9 +50,16 @@ impl<T: Lt<U>, U> Lt<S<U>> for T {}
 
 /// Provides a proof that the numbers are inequal.
 pub fn lt_neq<T: Lt<U>, U>() -> Not<Eq<T, U>> {
-    unimplemented!()
+    axiom!()
 }
 
+/// Less than or equal comparison.
+#[marker]
+pub trait Le<T> {}
+impl<T> Le<T> for T {}
+impl<T> Le<T> for Z {}
+impl<T: Lt<U>, U> Le<U> for T {}
+
 /// Whether two natural numbers are equal.
 #[marker]
 pub trait EqNat {}
@@ -83,6 +90,36 @@ impl<T, U> Add for (S<T>, U) where (T, U): Add {
     type Out = S<<(T, U) as Add>::Out>;
 }
 
+/// Multiplication.
+pub trait Mul {
+    /// The output type.
+    type Out: Clone;
+}
+impl<U> Mul for (Z, U) {
+    type Out = Z;
+}
+impl<T, U> Mul for (S<T>, U) where (T, U): Mul, (U, <(T, U) as Mul>::Out): Add {
+    type Out = <(U, <(T, U) as Mul>::Out) as Add>::Out;
+}
+
+/// Maximum.
+pub trait MaxNat {
+    /// The output type.
+    type Out: Nat;
+}
+impl MaxNat for (Z, Z) {
+    type Out = Z;
+}
+impl<T: Nat> MaxNat for (Z, S<T>) {
+    type Out = S<T>;
+}
+impl<T: Nat> MaxNat for (S<T>, Z) {
+    type Out = S<T>;
+}
+impl<T, U> MaxNat for (S<T>, S<U>) where (T, U): MaxNat {
+    type Out = S<<(T, U) as MaxNat>::Out>;
+}
+
 /// Decrement.
 pub trait Dec {
     /// The output type.
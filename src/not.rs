@@ -5,6 +5,8 @@
 use crate::*;
 
 /// `a => ¬¬a`.
+///
+/// The law [double_neg_intro](heyting::IsHeyting::double_neg_intro) of [Props](heyting::Props).
 pub fn double<A: Prop>(a: A) -> Not<Not<A>> {
     Rc::new(move |x| x(a.clone()))
 }
@@ -38,3 +40,72 @@ pub fn absurd<A: Prop, B: Prop>(f: Not<A>, g: A) -> B {
 pub fn eq<A: Prop, B: Prop>(x: Eq<A, B>) -> Eq<Not<A>, Not<B>> {
     eq::symmetry(eq::modus_tollens(x))
 }
+
+/// `¬a => ¬¬¬a`.
+pub fn triple<A: Prop>(x: Not<A>) -> Not<Not<Not<A>>> {double(x)}
+
+/// `¬¬¬a == ¬a`.
+pub fn eq_triple<A: Prop>() -> Eq<Not<Not<Not<A>>>, Not<A>> {
+    (Rc::new(rev_triple), Rc::new(triple))
+}
+
+/// `¬¬a ⋀ ¬a => false`.
+pub fn double_absurd<A: Prop>(nna: Not<Not<A>>, na: Not<A>) -> False {nna(na)}
+
+/// `(a => ¬b) == (b => ¬a)`.
+pub fn contrapositive_neg_eq<A: Prop, B: Prop>() -> Eq<Imply<A, Not<B>>, Imply<B, Not<A>>> {
+    (Rc::new(imply::flip_neg_right), Rc::new(imply::flip_neg_right))
+}
+
+/// `(a => b) == (¬b => ¬a)`.
+pub fn contrapositive_eq<A: DProp, B: DProp>() -> Eq<Imply<A, B>, Imply<Not<B>, Not<A>>> {
+    (Rc::new(imply::modus_tollens), Rc::new(imply::rev_modus_tollens))
+}
+
+/// `¬(a ⋁ b) == (¬a ⋀ ¬b)`.
+pub fn eq_or<A: Prop, B: Prop>() -> Eq<Not<Or<A, B>>, And<Not<A>, Not<B>>> {
+    (Rc::new(and::from_de_morgan), Rc::new(and::to_de_morgan))
+}
+
+/// `(¬a ⋁ ¬b) => ¬(a ⋀ b)`.
+///
+/// The reverse direction requires decidability (see [eq_and]).
+pub fn from_or<A: Prop, B: Prop>(x: Or<Not<A>, Not<B>>) -> Not<And<A, B>> {or::to_de_morgan(x)}
+
+/// `¬(a ⋀ b) == (¬a ⋁ ¬b)`.
+pub fn eq_and<A: DProp, B: DProp>() -> Eq<Not<And<A, B>>, Or<Not<A>, Not<B>>> {
+    (Rc::new(or::from_de_morgan), Rc::new(or::to_de_morgan))
+}
+
+/// `¬(a ⋀ b) ⋀ a => ¬b`.
+pub fn and_left<A: Prop, B: Prop>(x: Not<And<A, B>>, a: A) -> Not<B> {
+    Rc::new(move |b| x((a.clone(), b)))
+}
+
+/// `¬(a ⋀ b) ⋀ b => ¬a`.
+pub fn and_right<A: Prop, B: Prop>(x: Not<And<A, B>>, b: B) -> Not<A> {
+    Rc::new(move |a| x((a, b.clone())))
+}
+
+/// `¬(a ⋀ b) == (a => ¬b)`.
+pub fn eq_and_imply<A: Prop, B: Prop>() -> Eq<Not<And<A, B>>, Imply<A, Not<B>>> {
+    (Rc::new(imply::chain), Rc::new(imply::rev_chain))
+}
+
+/// `¬~a => ¬(a ~~ b)`.
+pub fn qu_to_q<A: Prop, B: Prop>(x: Not<qubit::Qu<A>>) -> Not<quality::Q<A, B>> {
+    Rc::new(move |(_, (qu_a, _))| x(qu_a))
+}
+
+/// `¬(a ~~ a) == ¬~a`.
+pub fn eq_q_qu<A: Prop>() -> Eq<Not<quality::Q<A, A>>, Not<qubit::Qu<A>>> {eq(qubit::eq_q_qu())}
+
+/// `¬(a ~~ a) => ~¬a`.
+pub fn q_to_qu_inv<A: Prop>(x: Not<quality::Q<A, A>>) -> qubit::Qu<Not<A>> {
+    qubit::sesh_to_inv(eq_q_qu().0(x))
+}
+
+/// `~¬a => ¬(a ~~ b)`.
+pub fn qu_inv_to_q<A: Prop, B: Prop>(x: qubit::Qu<Not<A>>) -> Not<quality::Q<A, B>> {
+    qu_to_q(qubit::inv_to_sesh(x))
+}
@@ -0,0 +1,1061 @@
+//! # Linear Arithmetic Decision Procedure
+//!
+//! Arithmetic goals on natural numbers are often tedious to prove with the lemmas in
+//! [natp](crate::fun::natp), so it is useful to know in advance whether a goal holds at all.
+//! This module decides quantifier-free linear arithmetic on natural numbers,
+//! similar to the `omega` tactic in other proof assistants.
+//!
+//! A goal [Goal] is built from comparisons `a == b`, `a <= b` and `a < b` of linear terms [Term],
+//! combined with `¬`, `⋀`, `⋁` and `=>`. All variables range over natural numbers.
+//!
+//! The goal is valid when its negation has no solution. The negation is put in disjunctive
+//! normal form, and each conjunction of constraints is checked with Pugh's Omega test,
+//! which is exact for integers. When the goal is not valid, a counterexample is returned.
+//! The arithmetic is checked, such that [Report::Overflow] is returned when numbers get too large.
+//!
+//! ```rust
+//! use prop::omega::*;
+//!
+//! let (x, y) = (Term::var("x"), Term::var("y"));
+//! let c = Term::num;
+//!
+//! // `x < y  =>  succ(x) <= y`.
+//! let goal = Goal::imply(Goal::lt(x.clone(), y.clone()),
+//!                        Goal::le(Term::succ(x.clone()), y.clone()));
+//! assert_eq!(decide(&goal), Report::Valid);
+//!
+//! // `x <= 2 * y` has a counterexample.
+//! let goal = Goal::le(x.clone(), Term::mul(2, y.clone()));
+//! let model = counterexample(&goal).unwrap();
+//! assert_eq!(goal.eval(&model), Some(false));
+//! assert_eq!(decide(&goal).to_string(), "counterexample: x = 1, y = 0");
+//!
+//! // `27 <= 11x + 13y <= 45` and `-10 <= 7x - 9y <= 4` have real solutions, but no integer ones.
+//! // Neither shadow is exact, so this is decided by searching the grey shadow.
+//! let s = || Term::mul(11, x.clone()) + Term::mul(13, y.clone());
+//! let goal = !Goal::and(
+//!     Goal::and(Goal::le(c(27), s()), Goal::le(s(), c(45))),
+//!     Goal::and(Goal::le(Term::mul(7, x.clone()), Term::mul(9, y.clone()) + c(4)),
+//!               Goal::le(Term::mul(9, y.clone()), Term::mul(7, x.clone()) + c(10))));
+//! assert!(is_valid(&goal));
+//!
+//! // Coefficients that do not fit in machine integers.
+//! let big = Term::mul(u64::MAX, Term::mul(u64::MAX, Term::mul(u64::MAX, x.clone())));
+//! assert_eq!(decide(&Goal::le(big.clone(), y.clone())), Report::Overflow);
+//! let mut model = Model::new();
+//! model.insert("x".into(), 1);
+//! assert_eq!(big.eval(&model), None);
+//! ```
+//!
+//! ### Certificates
+//!
+//! Linear terms are reflected into [natp](crate::fun::natp) by [Term::to_natp].
+//! For an equality `a == b` that holds for all natural numbers, [prove_eq] returns a
+//! certificate ([Proof]) that rewrites both sides into the same normal form.
+//! Each step ([Step]) applies a lemma of natp ([Rule]) to a sub-term,
+//! and [Proof::check] replays the steps, so it does not trust the decision procedure.
+//!
+//! Since natp has no order on natural numbers, goals using `<=` and `<` are decided
+//! without certificates.
+//!
+//! ```rust
+//! use prop::omega::*;
+//!
+//! let x = Term::var("x");
+//! let a = Term::mul(2, x.clone()) + Term::num(1);
+//! assert!(prove_eq(&a, &(Term::succ(x.clone()) + x.clone())).unwrap().check());
+//! assert!(prove_eq(&x, &Term::succ(x.clone())).is_none());
+//!
+//! let mut proof = prove_eq(&(x.clone() + Term::num(0)), &(Term::num(0) + x.clone())).unwrap();
+//! assert_eq!(proof.left_steps[0].rule, Rule::AddZeroRight);
+//! assert_eq!(proof.right_steps[0].rule, Rule::AddZero);
+//! proof.right_steps.clear();
+//! assert!(!proof.check());
+//! ```
+//!
+//! The certificate of `x + 0 == 0 + x` corresponds to the following proof:
+//!
+//! ```rust
+//! use prop::*;
+//! use prop::fun::natp::{add_zero, add_zero_right, Add, Nat, Zero};
+//! use prop::path_semantics::Ty;
+//!
+//! fn lemma<X: Prop>(ty_x: Ty<X, Nat>) -> Eq<Add<X, Zero>, Add<Zero, X>> {
+//!     eq::transitivity(add_zero_right(ty_x.clone()), eq::symmetry(add_zero(ty_x)))
+//! }
+//! ```
+
+use std::collections::BTreeMap;
+use std::convert::TryFrom;
+use std::fmt;
+
+/// Reflected linear term of natural numbers.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Term {
+    /// A number.
+    Num(u64),
+    /// A variable.
+    Var(String),
+    /// `a + b`.
+    Add(Box<Term>, Box<Term>),
+    /// `n * a`.
+    Mul(u64, Box<Term>),
+}
+
+/// Reflected linear arithmetic goal.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Goal {
+    /// `true`.
+    True,
+    /// `false`.
+    False,
+    /// `a == b`.
+    Eq(Term, Term),
+    /// `a <= b`.
+    Le(Term, Term),
+    /// `a < b`.
+    Lt(Term, Term),
+    /// `¬a`.
+    Not(Box<Goal>),
+    /// `a ⋀ b`.
+    And(Box<Goal>, Box<Goal>),
+    /// `a ⋁ b`.
+    Or(Box<Goal>, Box<Goal>),
+    /// `a => b`.
+    Imply(Box<Goal>, Box<Goal>),
+}
+
+/// An assignment of natural numbers to variables.
+pub type Model = BTreeMap<String, u64>;
+
+/// The result of deciding a goal.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Report {
+    /// The goal holds for all natural numbers.
+    Valid,
+    /// An assignment that makes the goal false.
+    Counterexample(Model),
+    /// The coefficients or the counterexample do not fit in machine integers.
+    Overflow,
+}
+
+impl Report {
+    /// Returns `true` if the goal is valid.
+    pub fn is_valid(&self) -> bool {matches!(self, Report::Valid)}
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, w: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Report::Valid => write!(w, "valid"),
+            Report::Overflow => write!(w, "overflow"),
+            Report::Counterexample(a) => {
+                write!(w, "counterexample: ")?;
+                for (i, (k, v)) in a.iter().enumerate() {
+                    if i > 0 {write!(w, ", ")?}
+                    write!(w, "{} = {}", k, v)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl Term {
+    /// Creates a number.
+    pub fn num(n: u64) -> Term {Term::Num(n)}
+    /// Creates a variable.
+    pub fn var(name: &str) -> Term {Term::Var(name.into())}
+    /// Creates `n * a`.
+    pub fn mul(n: u64, a: Term) -> Term {Term::Mul(n, Box::new(a))}
+    /// Creates `succ(a) := a + 1`.
+    pub fn succ(a: Term) -> Term {a + Term::Num(1)}
+
+    fn collect_vars(&self, res: &mut Vec<String>) {
+        use Term::*;
+
+        match self {
+            Num(_) => {}
+            Var(x) => res.push(x.clone()),
+            Add(a, b) => {a.collect_vars(res); b.collect_vars(res)}
+            Mul(_, a) => a.collect_vars(res),
+        }
+    }
+
+    /// Evaluates the term under a model.
+    ///
+    /// Returns `None` if some variable is not assigned or the value overflows.
+    pub fn eval(&self, model: &Model) -> Option<u128> {
+        use Term::*;
+
+        match self {
+            Num(n) => Some(*n as u128),
+            Var(x) => Some(*model.get(x)? as u128),
+            Add(a, b) => a.eval(model)?.checked_add(b.eval(model)?),
+            Mul(n, a) => (*n as u128).checked_mul(a.eval(model)?),
+        }
+    }
+}
+
+impl Goal {
+    /// Creates `a == b`.
+    pub fn eq(a: Term, b: Term) -> Goal {Goal::Eq(a, b)}
+    /// Creates `a <= b`.
+    pub fn le(a: Term, b: Term) -> Goal {Goal::Le(a, b)}
+    /// Creates `a < b`.
+    pub fn lt(a: Term, b: Term) -> Goal {Goal::Lt(a, b)}
+    /// Creates `a ⋀ b`.
+    pub fn and(a: Goal, b: Goal) -> Goal {Goal::And(Box::new(a), Box::new(b))}
+    /// Creates `a ⋁ b`.
+    pub fn or(a: Goal, b: Goal) -> Goal {Goal::Or(Box::new(a), Box::new(b))}
+    /// Creates `a => b`.
+    pub fn imply(a: Goal, b: Goal) -> Goal {Goal::Imply(Box::new(a), Box::new(b))}
+
+    /// Returns the variables in sorted order, without duplicates.
+    pub fn vars(&self) -> Vec<String> {
+        let mut res = vec![];
+        self.collect_vars(&mut res);
+        res.sort();
+        res.dedup();
+        res
+    }
+
+    fn collect_vars(&self, res: &mut Vec<String>) {
+        use Goal::*;
+
+        match self {
+            True | False => {}
+            Eq(a, b) | Le(a, b) | Lt(a, b) => {a.collect_vars(res); b.collect_vars(res)}
+            Not(a) => a.collect_vars(res),
+            And(a, b) | Or(a, b) | Imply(a, b) => {a.collect_vars(res); b.collect_vars(res)}
+        }
+    }
+
+    /// Evaluates the goal under a model.
+    ///
+    /// Returns `None` if some variable is not assigned or some term overflows.
+    pub fn eval(&self, model: &Model) -> Option<bool> {
+        use Goal::*;
+
+        Some(match self {
+            True => true,
+            False => false,
+            Eq(a, b) => a.eval(model)? == b.eval(model)?,
+            Le(a, b) => a.eval(model)? <= b.eval(model)?,
+            Lt(a, b) => a.eval(model)? < b.eval(model)?,
+            Not(a) => !a.eval(model)?,
+            And(a, b) => a.eval(model)? && b.eval(model)?,
+            Or(a, b) => a.eval(model)? || b.eval(model)?,
+            Imply(a, b) => !a.eval(model)? || b.eval(model)?,
+        })
+    }
+}
+
+impl std::ops::Add for Term {
+    type Output = Term;
+    fn add(self, other: Term) -> Term {Term::Add(Box::new(self), Box::new(other))}
+}
+
+impl std::ops::Not for Goal {
+    type Output = Goal;
+    fn not(self) -> Goal {Goal::Not(Box::new(self))}
+}
+
+impl fmt::Display for Term {
+    fn fmt(&self, w: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use Term::*;
+
+        match self {
+            Num(n) => write!(w, "{}", n),
+            Var(x) => write!(w, "{}", x),
+            Add(a, b) => write!(w, "{} + {}", a, b),
+            Mul(n, a) => match **a {
+                Add(_, _) => write!(w, "{} * ({})", n, a),
+                _ => write!(w, "{} * {}", n, a),
+            }
+        }
+    }
+}
+
+impl fmt::Display for Goal {
+    fn fmt(&self, w: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use Goal::*;
+
+        fn arg(w: &mut fmt::Formatter<'_>, a: &Goal) -> fmt::Result {
+            match a {
+                True | False | Not(_) => write!(w, "{}", a),
+                _ => write!(w, "({})", a),
+            }
+        }
+
+        match self {
+            True => write!(w, "true"),
+            False => write!(w, "false"),
+            Eq(a, b) => write!(w, "{} == {}", a, b),
+            Le(a, b) => write!(w, "{} <= {}", a, b),
+            Lt(a, b) => write!(w, "{} < {}", a, b),
+            Not(a) => {write!(w, "¬")?; arg(w, a)}
+            And(a, b) => {arg(w, a)?; write!(w, " ⋀ ")?; arg(w, b)}
+            Or(a, b) => {arg(w, a)?; write!(w, " ⋁ ")?; arg(w, b)}
+            Imply(a, b) => {arg(w, a)?; write!(w, " => ")?; arg(w, b)}
+        }
+    }
+}
+
+/// Decides a goal.
+///
+/// Returns [Report::Overflow] when the arithmetic of the decision procedure
+/// does not fit in `i128`, or a counterexample does not fit in `u64`.
+pub fn decide(goal: &Goal) -> Report {
+    profile!("omega::decide");
+    let vars = goal.vars();
+    let index: BTreeMap<&str, usize> = vars.iter().enumerate().map(|(i, x)| (&**x, i)).collect();
+    let search = || {
+        for conj in dnf(goal, true) {
+            let mut p = Problem {eqs: vec![], geqs: vec![], next: vars.len()};
+            for i in 0..vars.len() {p.geqs.push(Lin::var(i))}
+            for lit in &conj {p.add(lit, &index)?}
+            if p.sat()? {return Some(Report::Counterexample(p.model(&vars)?))}
+        }
+        Some(Report::Valid)
+    };
+    search().unwrap_or(Report::Overflow)
+}
+
+/// Returns `true` if a goal is valid.
+pub fn is_valid(goal: &Goal) -> bool {decide(goal).is_valid()}
+
+/// Returns a counterexample of a goal, if any.
+pub fn counterexample(goal: &Goal) -> Option<Model> {
+    match decide(goal) {
+        Report::Counterexample(a) => Some(a),
+        Report::Valid | Report::Overflow => None,
+    }
+}
+
+/// The maximum size of the terms for [prove_eq], counted in unary notation.
+///
+/// Numerals of [natp](crate::fun::natp) are unary, so the certificate grows with the numbers.
+pub const MAX_UNARY: u64 = 4096;
+
+/// Reflected term of [natp](crate::fun::natp).
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum NatTerm {
+    /// `0`, see [natp::Zero](crate::fun::natp::Zero).
+    Zero,
+    /// `succ(a)`, see [natp::Succ](crate::fun::natp::Succ).
+    Succ(Box<NatTerm>),
+    /// A variable of type `nat`.
+    Var(String),
+    /// `a + b`, see [natp::Add](crate::fun::natp::Add).
+    Add(Box<NatTerm>, Box<NatTerm>),
+    /// `a * b`, see [natp::Mul](crate::fun::natp::Mul).
+    Mul(Box<NatTerm>, Box<NatTerm>),
+}
+
+impl NatTerm {
+    /// Creates `succ(...succ(0))` with `n` successors.
+    pub fn numeral(n: u64) -> NatTerm {
+        (0..n).fold(NatTerm::Zero, |a, _| NatTerm::succ(a))
+    }
+    /// Creates a variable.
+    pub fn var(name: &str) -> NatTerm {NatTerm::Var(name.into())}
+    /// Creates `succ(a)`.
+    pub fn succ(a: NatTerm) -> NatTerm {NatTerm::Succ(Box::new(a))}
+
+    /// Returns `n` if the term is a numeral.
+    pub fn to_num(&self) -> Option<u64> {
+        match self {
+            NatTerm::Zero => Some(0),
+            NatTerm::Succ(a) => a.to_num()?.checked_add(1),
+            _ => None,
+        }
+    }
+}
+
+impl std::ops::Add for NatTerm {
+    type Output = NatTerm;
+    fn add(self, other: NatTerm) -> NatTerm {NatTerm::Add(Box::new(self), Box::new(other))}
+}
+
+impl std::ops::Mul for NatTerm {
+    type Output = NatTerm;
+    fn mul(self, other: NatTerm) -> NatTerm {NatTerm::Mul(Box::new(self), Box::new(other))}
+}
+
+impl fmt::Display for NatTerm {
+    fn fmt(&self, w: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use NatTerm::*;
+
+        fn arg(w: &mut fmt::Formatter<'_>, a: &NatTerm) -> fmt::Result {
+            match a {
+                Add(..) | Mul(..) => write!(w, "({})", a),
+                _ => write!(w, "{}", a),
+            }
+        }
+
+        if let Some(n) = self.to_num() {return write!(w, "{}", n)}
+        match self {
+            Zero => write!(w, "0"),
+            Succ(a) => write!(w, "succ({})", a),
+            Var(x) => write!(w, "{}", x),
+            Add(a, b) => {arg(w, a)?; write!(w, " + ")?; arg(w, b)}
+            Mul(a, b) => {arg(w, a)?; write!(w, " * ")?; arg(w, b)}
+        }
+    }
+}
+
+impl Term {
+    /// Converts into a term of [natp](crate::fun::natp).
+    ///
+    /// Numbers become numerals, e.g. `2 * x` becomes `succ(succ(0)) * x`.
+    pub fn to_natp(&self) -> NatTerm {
+        use Term::*;
+
+        match self {
+            Num(n) => NatTerm::numeral(*n),
+            Var(x) => NatTerm::Var(x.clone()),
+            Add(a, b) => a.to_natp() + b.to_natp(),
+            Mul(n, a) => NatTerm::numeral(*n) * a.to_natp(),
+        }
+    }
+
+    /// The size of the term in unary notation.
+    ///
+    /// Returns `None` on overflow.
+    fn unary(&self) -> Option<u64> {
+        use Term::*;
+
+        match self {
+            Num(n) => n.checked_add(1),
+            Var(_) => Some(1),
+            Add(a, b) => a.unary()?.checked_add(b.unary()?),
+            Mul(n, a) => n.checked_add(1)?.checked_mul(a.unary()?),
+        }
+    }
+}
+
+/// A rewrite rule, which is an equality lemma of [natp](crate::fun::natp).
+///
+/// Each rule may be used from left to right, or from right to left.
+/// The variables are assumed to be of type `nat`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Rule {
+    /// `0 + a == a`, see [natp::add_zero](crate::fun::natp::add_zero).
+    AddZero,
+    /// `a + 0 == a`, see [natp::add_zero_right](crate::fun::natp::add_zero_right).
+    AddZeroRight,
+    /// `a + b == b + a`, see [natp::add_symmetry](crate::fun::natp::add_symmetry).
+    AddSymmetry,
+    /// `(a + b) + c == a + (b + c)`, see [natp::add_assoc](crate::fun::natp::add_assoc).
+    AddAssoc,
+    /// `succ(a) == a + 1`, see [natp::add_succ_plus_one](crate::fun::natp::add_succ_plus_one).
+    AddSuccPlusOne,
+    /// `0 * a == 0`, see [natp::mul_zero](crate::fun::natp::mul_zero).
+    MulZero,
+    /// `succ(a) * b == b + a * b`, see [natp::mul_succ](crate::fun::natp::mul_succ).
+    MulSucc,
+}
+
+impl Rule {
+    /// All rules.
+    pub const ALL: &'static [Rule] = {
+        use Rule::*;
+
+        &[AddZero, AddZeroRight, AddSymmetry, AddAssoc, AddSuccPlusOne, MulZero, MulSucc]
+    };
+
+    /// The sides of the equality, using variables `a`, `b` and `c`.
+    pub fn sides(&self) -> (NatTerm, NatTerm) {
+        use NatTerm as N;
+        use Rule::*;
+
+        let (a, b, c) = (N::var("a"), N::var("b"), N::var("c"));
+        match self {
+            AddZero => (N::Zero + a.clone(), a),
+            AddZeroRight => (a.clone() + N::Zero, a),
+            AddSymmetry => (a.clone() + b.clone(), b + a),
+            AddAssoc => ((a.clone() + b.clone()) + c.clone(), a + (b + c)),
+            AddSuccPlusOne => (N::succ(a.clone()), a + N::numeral(1)),
+            MulZero => (N::Zero * a, N::Zero),
+            MulSucc => (N::succ(a.clone()) * b.clone(), b.clone() + a * b),
+        }
+    }
+
+    /// Applies the rule at the root of a term, from right to left when `rev` is `true`.
+    ///
+    /// Returns `None` if the rule does not match,
+    /// or when the other side has a variable that is not bound by the match.
+    pub fn apply(&self, e: &NatTerm, rev: bool) -> Option<NatTerm> {
+        let (lhs, rhs) = self.sides();
+        let (from, to) = if rev {(rhs, lhs)} else {(lhs, rhs)};
+        let mut m = BTreeMap::new();
+        if !unify(&from, e, &mut m) {return None}
+        inst(&to, &m)
+    }
+}
+
+fn unify(pat: &NatTerm, e: &NatTerm, m: &mut BTreeMap<String, NatTerm>) -> bool {
+    use NatTerm::*;
+
+    match (pat, e) {
+        (Var(x), _) => match m.get(x) {
+            Some(y) => y == e,
+            None => {m.insert(x.clone(), e.clone()); true}
+        },
+        (Zero, Zero) => true,
+        (Succ(a), Succ(b)) => unify(a, b, m),
+        (Add(a0, a1), Add(b0, b1)) | (Mul(a0, a1), Mul(b0, b1)) =>
+            unify(a0, b0, m) && unify(a1, b1, m),
+        _ => false,
+    }
+}
+
+fn inst(pat: &NatTerm, m: &BTreeMap<String, NatTerm>) -> Option<NatTerm> {
+    use NatTerm::*;
+
+    Some(match pat {
+        Var(x) => m.get(x)?.clone(),
+        Zero => Zero,
+        Succ(a) => NatTerm::succ(inst(a, m)?),
+        Add(a, b) => inst(a, m)? + inst(b, m)?,
+        Mul(a, b) => inst(a, m)? * inst(b, m)?,
+    })
+}
+
+impl fmt::Display for Rule {
+    fn fmt(&self, w: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (lhs, rhs) = self.sides();
+        write!(w, "{} == {}", lhs, rhs)
+    }
+}
+
+/// A step into a sub-term.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Arg {
+    /// The argument of `succ(a)`.
+    Succ,
+    /// The left argument of `+` or `*`.
+    Left,
+    /// The right argument of `+` or `*`.
+    Right,
+}
+
+/// A rule applied to the sub-term at a path.
+///
+/// At the type level, the sub-term is reached by congruence,
+/// e.g. [natp::add_eq_left](crate::fun::natp::add_eq_left)
+/// or [natp::succ_eq](crate::fun::natp::succ_eq).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Step {
+    /// The path from the root to the sub-term.
+    pub path: Vec<Arg>,
+    /// The rule to apply.
+    pub rule: Rule,
+    /// Whether the rule is used from right to left.
+    pub rev: bool,
+}
+
+/// Replays rewrite steps from a term.
+///
+/// Returns `None` if some step does not apply.
+pub fn replay(e: &NatTerm, steps: &[Step]) -> Option<NatTerm> {
+    let mut e = e.clone();
+    for step in steps {
+        let x = at_mut(&mut e, &step.path)?;
+        *x = step.rule.apply(x, step.rev)?;
+    }
+    Some(e)
+}
+
+fn at_mut<'a>(e: &'a mut NatTerm, path: &[Arg]) -> Option<&'a mut NatTerm> {
+    use NatTerm::*;
+
+    let mut e = e;
+    for arg in path {
+        e = match (arg, e) {
+            (Arg::Succ, Succ(a)) => a,
+            (Arg::Left, Add(a, _) | Mul(a, _)) => a,
+            (Arg::Right, Add(_, b) | Mul(_, b)) => b,
+            _ => return None,
+        };
+    }
+    Some(e)
+}
+
+/// A certificate of `left == right` in [natp](crate::fun::natp).
+///
+/// Both sides are rewritten into the same term,
+/// such that the lemmas of the steps give `left == right` by `eq::transitivity`
+/// and `eq::symmetry`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Proof {
+    /// The left side.
+    pub left: NatTerm,
+    /// The right side.
+    pub right: NatTerm,
+    /// The rewrite steps of the left side.
+    pub left_steps: Vec<Step>,
+    /// The rewrite steps of the right side.
+    pub right_steps: Vec<Step>,
+}
+
+impl Proof {
+    /// Returns `true` if both sides are rewritten into the same term.
+    pub fn check(&self) -> bool {
+        match (replay(&self.left, &self.left_steps), replay(&self.right, &self.right_steps)) {
+            (Some(a), Some(b)) => a == b,
+            _ => false,
+        }
+    }
+}
+
+impl fmt::Display for Proof {
+    fn fmt(&self, w: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fn side(w: &mut fmt::Formatter<'_>, e: &NatTerm, steps: &[Step]) -> fmt::Result {
+            write!(w, "{}", e)?;
+            let mut e = e.clone();
+            for step in steps {
+                if let Some(x) = at_mut(&mut e, &step.path) {
+                    if let Some(y) = step.rule.apply(x, step.rev) {*x = y}
+                }
+                let rev = if step.rev {" rev"} else {""};
+                write!(w, "\n  == {}    ({:?}{} at ", e, step.rule, rev)?;
+                if step.path.is_empty() {write!(w, "root")?}
+                for (i, arg) in step.path.iter().enumerate() {
+                    if i > 0 {write!(w, ".")?}
+                    write!(w, "{:?}", arg)?;
+                }
+                write!(w, ")")?;
+            }
+            Ok(())
+        }
+
+        side(w, &self.left, &self.left_steps)?;
+        writeln!(w)?;
+        side(w, &self.right, &self.right_steps)
+    }
+}
+
+/// Proves `a == b` in [natp](crate::fun::natp), when both sides are equal as linear terms.
+///
+/// Returns `None` if the sides are not equal for all natural numbers,
+/// or if the terms are larger than [MAX_UNARY].
+pub fn prove_eq(a: &Term, b: &Term) -> Option<Proof> {
+    profile!("omega::prove_eq");
+    if a.unary()?.checked_add(b.unary()?)? > MAX_UNARY {return None}
+    let (left, right) = (a.to_natp(), b.to_natp());
+    let (mut ta, mut tb) = (Trace::new(), Trace::new());
+    if ta.norm(left.clone()) != tb.norm(right.clone()) {return None}
+    Some(Proof {left, right, left_steps: ta.steps, right_steps: tb.steps})
+}
+
+/// Records rewrite steps while normalizing.
+///
+/// The normal form is `0` or a sum `x + (y + ...)` of sorted atoms,
+/// where atoms are variables and `1`.
+struct Trace {
+    path: Vec<Arg>,
+    steps: Vec<Step>,
+}
+
+impl Trace {
+    fn new() -> Trace {Trace {path: vec![], steps: vec![]}}
+
+    fn rewrite(&mut self, rule: Rule, rev: bool, e: &NatTerm) -> NatTerm {
+        let res = rule.apply(e, rev).expect("rule applies");
+        self.steps.push(Step {path: self.path.clone(), rule, rev});
+        res
+    }
+
+    fn at(&mut self, arg: Arg, f: impl FnOnce(&mut Trace) -> NatTerm) -> NatTerm {
+        self.path.push(arg);
+        let res = f(self);
+        self.path.pop();
+        res
+    }
+
+    fn norm(&mut self, e: NatTerm) -> NatTerm {
+        use NatTerm::*;
+
+        match e {
+            Zero | Var(_) => e,
+            Succ(ref a) if **a == Zero => e,
+            Succ(_) => {
+                let e = self.rewrite(Rule::AddSuccPlusOne, false, &e);
+                self.norm(e)
+            }
+            Add(a, b) => {
+                let a = self.at(Arg::Left, |t| t.norm(*a));
+                let b = self.at(Arg::Right, |t| t.norm(*b));
+                self.merge(a + b)
+            }
+            Mul(k, a) => {
+                let a = self.at(Arg::Right, |t| t.norm(*a));
+                self.unfold(*k * a)
+            }
+        }
+    }
+
+    /// Normalizes `k * a`, where `a` is normalized.
+    ///
+    /// When `k` is not a numeral, the term is kept as an atom.
+    fn unfold(&mut self, e: NatTerm) -> NatTerm {
+        use NatTerm::*;
+
+        match &e {
+            Mul(k, _) if **k == Zero => self.rewrite(Rule::MulZero, false, &e),
+            Mul(k, _) if k.to_num().is_some() => match self.rewrite(Rule::MulSucc, false, &e) {
+                Add(a, m) => {
+                    let m = self.at(Arg::Right, |t| t.unfold(*m));
+                    self.merge(*a + m)
+                }
+                _ => unreachable!(),
+            },
+            _ => e,
+        }
+    }
+
+    /// Normalizes `x + y`, where `x` and `y` are normalized.
+    fn merge(&mut self, e: NatTerm) -> NatTerm {
+        use NatTerm::*;
+
+        match &e {
+            Add(x, _) if **x == Zero => self.rewrite(Rule::AddZero, false, &e),
+            Add(_, y) if **y == Zero => self.rewrite(Rule::AddZeroRight, false, &e),
+            Add(x, _) if matches!(**x, Add(..)) => match self.rewrite(Rule::AddAssoc, false, &e) {
+                Add(h, m) => {
+                    let m = self.at(Arg::Right, |t| t.merge(*m));
+                    self.insert(*h + m)
+                }
+                _ => unreachable!(),
+            },
+            _ => self.insert(e),
+        }
+    }
+
+    /// Normalizes `h + y`, where `h` is an atom and `y` is normalized and not `0`.
+    fn insert(&mut self, e: NatTerm) -> NatTerm {
+        use NatTerm::*;
+
+        let (h, y) = match &e {Add(h, y) => (h, y), _ => return e};
+        match &**y {
+            Add(g, rest) if h > g => {
+                let rest = (**rest).clone();
+                let x = self.rewrite(Rule::AddAssoc, true, &e);
+                let x = match x {
+                    Add(hg, rest) => {
+                        self.at(Arg::Left, |t| t.rewrite(Rule::AddSymmetry, false, &hg)) + *rest
+                    }
+                    _ => unreachable!(),
+                };
+                let x = self.rewrite(Rule::AddAssoc, false, &x);
+                let h = (**h).clone();
+                match x {
+                    Add(g, _) => *g + self.at(Arg::Right, |t| t.insert(h + rest)),
+                    _ => unreachable!(),
+                }
+            }
+            Add(..) => e,
+            g if **h > *g => self.rewrite(Rule::AddSymmetry, false, &e),
+            _ => e,
+        }
+    }
+}
+
+/// A literal of the disjunctive normal form.
+#[derive(Clone, Copy)]
+enum Lit<'a> {
+    /// `a == b`.
+    Eq(&'a Term, &'a Term),
+    /// `a <= b`.
+    Le(&'a Term, &'a Term),
+    /// `a < b`.
+    Lt(&'a Term, &'a Term),
+}
+
+/// Returns the disjunctive normal form of `a` (when `neg` is `false`) or `¬a`.
+///
+/// The negation is put in the disjunctive normal form, since a goal is valid
+/// when all conjunctions of its negation have no solution.
+fn dnf(goal: &Goal, neg: bool) -> Vec<Vec<Lit<'_>>> {
+    use Goal::*;
+
+    fn and<'a>(a: Vec<Vec<Lit<'a>>>, b: Vec<Vec<Lit<'a>>>) -> Vec<Vec<Lit<'a>>> {
+        let mut res = vec![];
+        for x in &a {
+            for y in &b {
+                res.push(x.iter().chain(y.iter()).cloned().collect());
+            }
+        }
+        res
+    }
+
+    match (goal, neg) {
+        (True, false) | (False, true) => vec![vec![]],
+        (True, true) | (False, false) => vec![],
+        (Eq(a, b), false) => vec![vec![Lit::Eq(a, b)]],
+        (Eq(a, b), true) => vec![vec![Lit::Lt(a, b)], vec![Lit::Lt(b, a)]],
+        (Le(a, b), false) => vec![vec![Lit::Le(a, b)]],
+        (Le(a, b), true) => vec![vec![Lit::Lt(b, a)]],
+        (Lt(a, b), false) => vec![vec![Lit::Lt(a, b)]],
+        (Lt(a, b), true) => vec![vec![Lit::Le(b, a)]],
+        (Not(a), _) => dnf(a, !neg),
+        (And(a, b), false) | (Or(a, b), true) => and(dnf(a, neg), dnf(b, neg)),
+        (Or(a, b), false) | (And(a, b), true) => {
+            let mut res = dnf(a, neg);
+            res.extend(dnf(b, neg));
+            res
+        }
+        (Imply(a, b), false) => {
+            let mut res = dnf(a, true);
+            res.extend(dnf(b, false));
+            res
+        }
+        (Imply(a, b), true) => and(dnf(a, false), dnf(b, true)),
+    }
+}
+
+/// Linear combination `Σ coef[i] * x[i] + c`.
+///
+/// The operations return `None` on overflow.
+#[derive(Clone, Debug)]
+struct Lin {
+    coef: BTreeMap<usize, i128>,
+    c: i128,
+}
+
+impl Lin {
+    fn num(c: i128) -> Lin {Lin {coef: BTreeMap::new(), c}}
+
+    fn var(x: usize) -> Lin {
+        let mut res = Lin::num(0);
+        res.coef.insert(x, 1);
+        res
+    }
+
+    fn from_term(t: &Term, index: &BTreeMap<&str, usize>) -> Option<Lin> {
+        use Term::*;
+
+        match t {
+            Num(n) => Some(Lin::num(*n as i128)),
+            Var(x) => Some(Lin::var(index[x.as_str()])),
+            Add(a, b) => Lin::from_term(a, index)?.add(&Lin::from_term(b, index)?, 1),
+            Mul(n, a) => Lin::from_term(a, index)?.scale(*n as i128),
+        }
+    }
+
+    fn scale(mut self, k: i128) -> Option<Lin> {
+        for v in self.coef.values_mut() {*v = v.checked_mul(k)?}
+        self.c = self.c.checked_mul(k)?;
+        Some(self.retain())
+    }
+
+    /// Returns `self + k * other`.
+    fn add(mut self, other: &Lin, k: i128) -> Option<Lin> {
+        for (&x, &v) in &other.coef {
+            let e = self.coef.entry(x).or_insert(0);
+            *e = e.checked_add(k.checked_mul(v)?)?;
+        }
+        self.c = self.c.checked_add(k.checked_mul(other.c)?)?;
+        Some(self.retain())
+    }
+
+    fn retain(mut self) -> Lin {
+        self.coef.retain(|_, v| *v != 0);
+        self
+    }
+
+    /// Replaces `x` with `e`.
+    fn subst(mut self, x: usize, e: &Lin) -> Option<Lin> {
+        match self.coef.remove(&x) {
+            Some(a) => self.add(e, a),
+            None => Some(self),
+        }
+    }
+
+    fn gcd(&self) -> Option<i128> {
+        self.coef.values().try_fold(0, |g, &v| Some(gcd(g, v.checked_abs()?)))
+    }
+
+    /// Divides by a common divisor `g`, rounding the constant down.
+    fn div(&self, g: i128) -> Lin {
+        Lin {coef: self.coef.iter().map(|(&x, &v)| (x, v / g)).collect(), c: self.c.div_euclid(g)}
+    }
+}
+
+fn gcd(a: i128, b: i128) -> i128 {if b == 0 {a} else {gcd(b, a % b)}}
+
+/// `a mod^ m := a - m * floor(a / m + 1 / 2)`.
+fn mod_hat(a: i128, m: i128) -> Option<i128> {
+    let q = a.checked_mul(2)?.checked_add(m)?.div_euclid(m.checked_mul(2)?);
+    a.checked_sub(m.checked_mul(q)?)
+}
+
+/// Constraints `e == 0` and `e >= 0` on integers.
+///
+/// The methods return `None` on overflow.
+#[derive(Clone, Debug)]
+struct Problem {
+    eqs: Vec<Lin>,
+    geqs: Vec<Lin>,
+    /// The next fresh variable.
+    next: usize,
+}
+
+impl Problem {
+    fn add(&mut self, lit: &Lit<'_>, index: &BTreeMap<&str, usize>) -> Option<()> {
+        let lin = |a: &Term, b: &Term| {
+            Lin::from_term(b, index)?.add(&Lin::from_term(a, index)?, -1)
+        };
+        match *lit {
+            Lit::Eq(a, b) => self.eqs.push(lin(a, b)?),
+            Lit::Le(a, b) => self.geqs.push(lin(a, b)?),
+            Lit::Lt(a, b) => self.geqs.push(lin(a, b)?.add(&Lin::num(1), -1)?),
+        }
+        Some(())
+    }
+
+    /// Returns `true` if the constraints have an integer solution.
+    fn sat(&self) -> Option<bool> {
+        let mut p = self.clone();
+        if !p.solve_eqs()? {return Some(false)}
+        p.sat_geqs()
+    }
+
+    /// Eliminates all equalities.
+    ///
+    /// Returns `false` if some equality has no integer solution.
+    fn solve_eqs(&mut self) -> Option<bool> {
+        while let Some(e) = self.eqs.pop() {
+            if e.coef.is_empty() {
+                if e.c != 0 {return Some(false)}
+                continue;
+            }
+            let g = e.gcd()?;
+            if e.c % g != 0 {return Some(false)}
+            let e = e.div(g);
+            let (x, sol) = match e.coef.iter().find(|(_, v)| v.unsigned_abs() == 1) {
+                Some((&x, &a)) => {
+                    // `a * x + rest == 0` gives `x == -a * rest`.
+                    let mut rest = e.clone();
+                    rest.coef.remove(&x);
+                    (x, rest.scale(-a)?)
+                }
+                None => {
+                    // Pugh's method introduces `σ`, such that `x` gets a unit coefficient.
+                    let (&x, &a) = e.coef.iter().min_by_key(|(_, v)| v.unsigned_abs()).unwrap();
+                    let m = a.checked_abs()?.checked_add(1)?;
+                    let sigma = self.next;
+                    self.next += 1;
+                    let mut rest = Lin::num(mod_hat(e.c, m)?);
+                    for (&y, &v) in &e.coef {
+                        if y != x {rest = rest.add(&Lin::var(y), mod_hat(v, m)?)?}
+                    }
+                    rest = rest.add(&Lin::var(sigma), -m)?;
+                    // `-sign(a) * x + rest == 0` gives `x == sign(a) * rest`.
+                    self.eqs.push(e.clone());
+                    (x, rest.scale(a.signum())?)
+                }
+            };
+            for e in self.eqs.iter_mut().chain(self.geqs.iter_mut()) {
+                *e = std::mem::replace(e, Lin::num(0)).subst(x, &sol)?;
+            }
+        }
+        Some(true)
+    }
+
+    /// Decides inequalities with the Omega test, assuming there are no equalities.
+    fn sat_geqs(&self) -> Option<bool> {
+        let mut geqs = vec![];
+        for e in &self.geqs {
+            if e.coef.is_empty() {
+                if e.c < 0 {return Some(false)}
+                continue;
+            }
+            geqs.push(e.div(e.gcd()?));
+        }
+        let vars: Vec<usize> = {
+            let mut vars: Vec<usize> = geqs.iter().flat_map(|e| e.coef.keys().cloned()).collect();
+            vars.sort();
+            vars.dedup();
+            vars
+        };
+        if vars.is_empty() {return Some(true)}
+
+        // Prefer a variable where the real shadow is exact.
+        let exact = |x: usize| {
+            geqs.iter().all(|e| e.coef.get(&x).map(|v| *v <= 1).unwrap_or(true)) ||
+            geqs.iter().all(|e| e.coef.get(&x).map(|v| *v >= -1).unwrap_or(true))
+        };
+        let x = vars.iter().cloned().find(|&x| exact(x)).unwrap_or(vars[0]);
+        let (mut lower, mut upper, mut rest) = (vec![], vec![], vec![]);
+        for e in &geqs {
+            match e.coef.get(&x) {
+                Some(&v) if v > 0 => lower.push(e),
+                Some(_) => upper.push(e),
+                None => rest.push(e.clone()),
+            }
+        }
+        let problem = |geqs: Vec<Lin>| Problem {eqs: vec![], geqs, next: self.next};
+        if lower.is_empty() || upper.is_empty() {return problem(rest).sat_geqs()}
+
+        let shadow = |dark: bool| {
+            let mut res = rest.clone();
+            for l in &lower {
+                for u in &upper {
+                    let a = l.coef[&x];
+                    let b = -u.coef[&x];
+                    let mut e = (*l).clone().scale(b)?.add(u, a)?;
+                    if dark {e.c = e.c.checked_sub((a - 1).checked_mul(b - 1)?)?}
+                    res.push(e);
+                }
+            }
+            Some(res)
+        };
+        if exact(x) {return problem(shadow(false)?).sat_geqs()}
+        if !problem(shadow(false)?).sat_geqs()? {return Some(false)}
+        if problem(shadow(true)?).sat_geqs()? {return Some(true)}
+
+        // Search the grey shadow, `a * x + l == i` for small `i`.
+        let max_b = upper.iter().map(|u| -u.coef[&x]).max().unwrap();
+        for l in &lower {
+            let a = l.coef[&x];
+            let n = max_b.checked_mul(a)?.checked_sub(max_b)?.checked_sub(a)? / max_b;
+            for i in 0..=n {
+                let mut p = problem(geqs.clone());
+                p.eqs.push((*l).clone().add(&Lin::num(i), -1)?);
+                if p.sat()? {return Some(true)}
+            }
+        }
+        Some(false)
+    }
+
+    /// Returns a solution, assuming that the constraints have one.
+    ///
+    /// Each variable is assigned its least value for which there is a solution.
+    fn model(mut self, vars: &[String]) -> Option<Model> {
+        let mut model = Model::new();
+        for (i, name) in vars.iter().enumerate() {
+            let fits = |p: &Problem, v: i128| {
+                let mut p = p.clone();
+                p.geqs.push(Lin::num(v).add(&Lin::var(i), -1)?);
+                p.sat()
+            };
+            let mut hi: i128 = 1;
+            while !fits(&self, hi)? {hi = hi.checked_mul(2)?}
+            let mut lo = 0;
+            if !fits(&self, lo)? {
+                // Invariant: no solution with `x <= lo`, some solution with `x <= hi`.
+                while hi - lo > 1 {
+                    let mid = lo + (hi - lo) / 2;
+                    if fits(&self, mid)? {hi = mid} else {lo = mid}
+                }
+                lo = hi;
+            }
+            self.eqs.push(Lin::var(i).add(&Lin::num(lo), -1)?);
+            model.insert(name.clone(), u64::try_from(lo).ok()?);
+        }
+        Some(model)
+    }
+}
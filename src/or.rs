@@ -5,6 +5,8 @@
 use crate::*;
 
 /// `a ∨ b => b ∨ a`.
+///
+/// The law [join_symmetry](heyting::IsHeyting::join_symmetry) of [Props](heyting::Props).
 pub fn symmetry<A: Prop, B: Prop>(or: Or<A, B>) -> Or<B, A> {
     use Either::*;
 
@@ -15,6 +17,8 @@ pub fn symmetry<A: Prop, B: Prop>(or: Or<A, B>) -> Or<B, A> {
 }
 
 /// `(a ∨ b) ∨ c  =>  a ∨ (b ∨ c)`
+///
+/// The law [join_assoc](heyting::IsHeyting::join_assoc) of [Props](heyting::Props).
 pub fn assoc<A: Prop, B: Prop, C: Prop>(
     f: Or<Or<A, B>, C>
 ) -> Or<A, Or<B, C>> {
@@ -116,3 +120,74 @@ pub fn both<A: Prop>(x: Or<A, A>) -> A {
         Right(a) => a,
     }
 }
+
+/// `(a ⋀ b) ⋁ (a ⋀ ¬b) ⋁ (¬a ⋀ b) ⋁ (¬a ⋀ ¬b)`.
+///
+/// The four cases of two decided propositions.
+pub type Cases<A, B> = Or<Or<And<A, B>, And<A, Not<B>>>, Or<And<Not<A>, B>, And<Not<A>, Not<B>>>>;
+
+/// `(a ⋁ ¬a) ⋀ (b ⋁ ¬b)  =>  cases(a, b)`.
+pub fn excm_cases<A: Prop, B: Prop>(a: ExcM<A>, b: ExcM<B>) -> Cases<A, B> {
+    match (a, b) {
+        (Left(a), Left(b)) => Left(Left((a, b))),
+        (Left(a), Right(b)) => Left(Right((a, b))),
+        (Right(a), Left(b)) => Right(Left((a, b))),
+        (Right(a), Right(b)) => Right(Right((a, b))),
+    }
+}
+
+/// `cases(a, b)  =>  (a ⋁ ¬a) ⋀ (b ⋁ ¬b)`.
+pub fn cases_excm<A: Prop, B: Prop>(x: Cases<A, B>) -> And<ExcM<A>, ExcM<B>> {
+    match x {
+        Left(Left((a, b))) => (Left(a), Left(b)),
+        Left(Right((a, b))) => (Left(a), Right(b)),
+        Right(Left((a, b))) => (Right(a), Left(b)),
+        Right(Right((a, b))) => (Right(a), Right(b)),
+    }
+}
+
+/// `cases(a, b)` for decidable `a` and `b`.
+pub fn decide_cases<A: DProp, B: DProp>() -> Cases<A, B> {excm_cases(A::decide(), B::decide())}
+
+/// `(a ⋁ ¬a) ⋀ (b ⋁ ¬b)  =>  (a ⋀ b) ⋁ ¬(a ⋀ b)`.
+pub fn excm_and<A: Prop, B: Prop>(a: ExcM<A>, b: ExcM<B>) -> ExcM<And<A, B>> {
+    match (a, b) {
+        (Left(a), Left(b)) => Left((a, b)),
+        (_, Right(b)) => Right(Rc::new(move |(_, x)| b.clone()(x))),
+        (Right(a), _) => Right(Rc::new(move |(x, _)| a.clone()(x))),
+    }
+}
+
+/// `(a ⋁ ¬a) ⋀ (b ⋁ ¬b)  =>  (a ⋁ b) ⋁ ¬(a ⋁ b)`.
+pub fn excm_or<A: Prop, B: Prop>(a: ExcM<A>, b: ExcM<B>) -> ExcM<Or<A, B>> {
+    match (a, b) {
+        (Left(a), _) => Left(Left(a)),
+        (_, Left(b)) => Left(Right(b)),
+        (Right(a), Right(b)) => Right(Rc::new(move |f| match f {
+            Left(x) => a.clone()(x),
+            Right(y) => b.clone()(y),
+        }))
+    }
+}
+
+/// `(a ⋁ ¬a) ⋀ (b ⋁ ¬b)  =>  (a => b) ⋁ ¬(a => b)`.
+pub fn excm_imply<A: Prop, B: Prop>(a: ExcM<A>, b: ExcM<B>) -> ExcM<Imply<A, B>> {
+    match (a, b) {
+        (_, Left(b)) => Left(b.map_any()),
+        (Left(a), Right(b)) => Right(Rc::new(move |f| b.clone()(f(a.clone())))),
+        (Right(a), _) => Left(Rc::new(move |x| match a(x) {})),
+    }
+}
+
+/// `(a ⋁ ¬a)  =>  ¬a ⋁ ¬¬a`.
+pub fn excm_not<A: Prop>(a: ExcM<A>) -> ExcM<Not<A>> {
+    match a {
+        Left(a) => Right(not::double(a)),
+        Right(na) => Left(na),
+    }
+}
+
+/// `(a ⋁ ¬a) ⋀ (b ⋁ ¬b)  =>  (a == b) ⋁ ¬(a == b)`.
+pub fn excm_eq<A: Prop, B: Prop>(a: ExcM<A>, b: ExcM<B>) -> ExcM<Eq<A, B>> {
+    excm_and(excm_imply(a.clone(), b.clone()), excm_imply(b, a))
+}
@@ -414,6 +414,15 @@ pub type PAndSnd<A, B, C, D> = Imply<
     Q<B, D>,
 >;
 
+/// Sends a projection of a product to higher level.
+///
+/// This is the product rule, generalizing `PAndFst` and `PAndSnd`
+/// to any family `a` with a projection `a => b`.
+pub type PAndProj<A, B, C, D> = Imply<
+    And<Q<A, C>, Imply<C, D>>,
+    Q<B, D>,
+>;
+
 /// Sends first argument of Logical OR to higher level.
 pub type POrFst<A, B, C, D> = Imply<
     And<Q<Or<A, B>, C>, Imply<C, D>>,
@@ -436,7 +445,7 @@ pub type PSemNaiveNorm<A, B, C, D> = PSemNaive<
 
 /// Assumes the core axiom safely for propositions.
 pub fn assume<A: Prop, B: Prop, C: Prop, D: Prop>() -> PSem<A, B, C, D> {
-    unimplemented!()
+    axiom!()
 }
 
 /// `(f1 : x1) ⋀ (f2 : x2)  =>  (f1 ~~ f2) : (x1 ~~ x2)`.
@@ -697,6 +706,77 @@ pub fn to_pand_snd<A: Prop, B: Prop, C: Prop, D: Prop>(
     Rc::new(move |(f, g)| p.clone()((f, (y.clone(), g))))
 }
 
+/// Converts core axiom to `PAndProj` using a projection `a => b`.
+pub fn to_pand_proj<A: Prop, B: Prop, C: Prop, D: Prop>(
+    p: PSemNaive<A, C, B, D>,
+    proj: Imply<A, B>,
+) -> PAndProj<A, B, C, D> {
+    Rc::new(move |(f, g)| p.clone()((f, (proj.clone(), g))))
+}
+
+/// `(a => b) ⋀ (b => e)  =>  (a => e)` for projections of products.
+///
+/// Composes projections of nested products, e.g. `(a ⋀ b) ⋀ c => a ⋀ b => a`.
+pub fn pand_proj_comp<A: Prop, B: Prop, E: Prop, C: Prop, D: Prop>(
+    p: PSemNaive<A, C, E, D>,
+    proj_ab: Imply<A, B>,
+    proj_be: Imply<B, E>,
+) -> PAndProj<A, E, C, D> {to_pand_proj(p, imply::transitivity(proj_ab, proj_be))}
+
+/// Use two projections `PAndProj` of the same product to prove `b1 ~~ b2`.
+pub fn pand_proj_eq<A: Prop, B1: Prop, B2: Prop, C: Prop, D: Prop>(
+    f: Q<A, C>,
+    g: Imply<C, D>,
+    p1: PAndProj<A, B1, C, D>,
+    p2: PAndProj<A, B2, C, D>,
+) -> Q<B1, B2> {
+    let q1 = p1((f.clone(), g.clone()));
+    let q2 = p2((f, g));
+    quality::transitivity(q1, quality::symmetry(q2))
+}
+
+/// Implemented by nested products `a ⋀ (b ⋀ (... ⋀ true))`.
+///
+/// This is used by [pand_join] to send every component of a family `f` to higher level.
+pub trait PAndList<F: Prop, C: Prop, D: Prop>: Prop {
+    /// Core axioms for every component, `psem(f, c, a, d) ⋀ (psem(f, c, b, d) ⋀ ...)`.
+    type Axioms: Prop;
+    /// Qualities of every component, `(a ~~ d) ⋀ ((b ~~ d) ⋀ ...)`.
+    type Out: Prop;
+    /// Sends every component to higher level using a projection `f => self`.
+    fn pand_list(f: Q<F, C>, g: Imply<C, D>, proj: Imply<F, Self>, p: Self::Axioms) -> Self::Out;
+}
+
+impl<F: Prop, C: Prop, D: Prop> PAndList<F, C, D> for True {
+    type Axioms = True;
+    type Out = True;
+    fn pand_list(_: Q<F, C>, _: Imply<C, D>, _: Imply<F, True>, _: True) -> True {True}
+}
+
+impl<F: Prop, C: Prop, D: Prop, A: Prop, R: PAndList<F, C, D>> PAndList<F, C, D> for And<A, R> {
+    type Axioms = And<PSemNaive<F, C, A, D>, R::Axioms>;
+    type Out = And<Q<A, D>, R::Out>;
+    fn pand_list(
+        f: Q<F, C>,
+        g: Imply<C, D>,
+        proj: Imply<F, Self>,
+        (p, ps): Self::Axioms
+    ) -> Self::Out {
+        let proj2 = proj.clone();
+        let q = to_pand_proj(p, Rc::new(move |x| proj2(x).0))((f.clone(), g.clone()));
+        (q, R::pand_list(f, g, Rc::new(move |x| proj(x).1), ps))
+    }
+}
+
+/// `(a ⋀ (b ⋀ (... ⋀ true)) ~~ c) ⋀ (c => d)  =>  (a ~~ d) ⋀ ((b ~~ d) ⋀ ...)`.
+///
+/// N-ary version of `PAndFst` and `PAndSnd`.
+pub fn pand_join<F: PAndList<F, C, D>, C: Prop, D: Prop>(
+    f: Q<F, C>,
+    g: Imply<C, D>,
+    p: F::Axioms,
+) -> F::Out {F::pand_list(f, g, imply::id(), p)}
+
 /// Use both `PAndFst` and `PAndSnd`.
 ///
 /// This results in a stronger statement than `PAnd` alone.
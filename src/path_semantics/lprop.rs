@@ -29,6 +29,10 @@ impl<U: LProp> LProp for Q<U, U> {
 pub type IncLevel<A, N> = <A as LProp>::SetLevel<<(<A as LProp>::N, N) as Add>::Out>;
 /// Increases level one step.
 pub type Inc<A> = <A as LProp>::SetLevel<S<<A as LProp>::N>>;
+/// Decreases level one step.
+pub type DecLevel<A> = <A as LProp>::SetLevel<<<A as LProp>::N as Dec>::Out>;
+/// Sets proposition level of `A` to `N`.
+pub type AtLevel<A, N> = <A as LProp>::SetLevel<N>;
 
 impl<N: 'static + Default + Clone> Decidable for LTrue<N> {
     fn decide() -> ExcM<Self> {Either::Left(LTrue(N::default()))}
@@ -127,6 +131,35 @@ pub type Mixi<A, B, C, D> = Min<MaxMin<A, B, C, D>, MinMax<A, B, C, D>>;
 /// The decided maximum of undecided middle (3rd of 4).
 pub type Maxi<A, B, C, D> = Max<MaxMin<A, B, C, D>, MinMax<A, B, C, D>>;
 
+/// `a < inc(a)`.
+pub fn pord_inc<A: LProp>() -> POrdProof<A, Inc<A>>
+    where A::N: Lt<<Inc<A> as LProp>::N>
+{POrdProof::new()}
+/// `dec(a) < a`.
+pub fn pord_dec<A: LProp>() -> POrdProof<DecLevel<A>, A>
+    where A::N: Dec, <DecLevel<A> as LProp>::N: Lt<A::N>
+{POrdProof::new()}
+/// `a < inc(inc(a))`.
+pub fn pord_inc2<A: LProp>() -> POrdProof<A, Inc<Inc<A>>>
+    where A::N: Lt<<Inc<A> as LProp>::N>,
+          <Inc<A> as LProp>::N: Lt<<Inc<Inc<A>> as LProp>::N>
+{pord_inc::<A>().transitivity(pord_inc::<Inc<A>>())}
+/// Lifts `a` to level `n`, `a < a{n}`.
+pub fn lift_to_level<N: 'static + Clone, A: LProp>() -> POrdProof<A, AtLevel<A, N>>
+    where A::N: Lt<<AtLevel<A, N> as LProp>::N>
+{POrdProof::new()}
+/// `a < b ⋀ b < c  =>  a < c` by levels.
+///
+/// Only neighbouring levels need to be ordered,
+/// so multi-level models do not need an order for every pair of levels.
+pub fn pord_levels<A: LProp, B: LProp, C: LProp>() -> POrdProof<A, C>
+    where A::N: Lt<B::N>, B::N: Lt<C::N>
+{POrdProof::<A, B>::new().transitivity(POrdProof::new())}
+/// `a < b ⋀ b < c ⋀ c < d  =>  a < d` by levels.
+pub fn pord_levels4<A: LProp, B: LProp, C: LProp, D: LProp>() -> POrdProof<A, D>
+    where A::N: Lt<B::N>, B::N: Lt<C::N>, C::N: Lt<D::N>
+{pord_levels::<A, B, C>().transitivity(POrdProof::new())}
+
 /// Checks whether two proposition levels are equal.
 pub fn eq_lev<A: LProp, B: LProp>(_a: A, _b: B) where (A::N, B::N): EqNat {}
 /// Checks whether a proposition level is less than another.
@@ -1,4 +1,5 @@
 use super::*;
+use crate::fun::{App, Lam, Tup};
 
 /// Proof of path semantical order.
 #[derive(Copy)]
@@ -47,6 +48,19 @@ impl<T, U> POrdProof<T, U> {
         self.by_imply_right(eq.0)
     }
 
+    /// Transform left argument by quality.
+    pub fn by_q_left<V: Prop>(self, q: Q<T, V>) -> POrdProof<V, U> where T: Prop {
+        self.by_eq_left(quality::to_eq(q))
+    }
+
+    /// Transform right argument by quality.
+    ///
+    /// Unlike [POrdProof::by_eq_right], this is safe,
+    /// since quality `u ~~ v` allows substitution of `u` by `v` everywhere.
+    pub fn by_q_right<V>(self, _: Q<U, V>) -> POrdProof<T, V> {
+        POrdProof(std::marker::PhantomData)
+    }
+
     /// Transform right argument by implication.
     ///
     /// # Safety
@@ -100,6 +114,21 @@ impl<T, U> POrdProof<T, U> {
         POrdProof(std::marker::PhantomData)
     }
 
+    /// Combine two proofs into one using function application.
+    pub fn app<T2, U2>(self, _: POrdProof<T2, U2>) -> POrdProof<App<T, T2>, App<U, U2>> {
+        POrdProof(std::marker::PhantomData)
+    }
+
+    /// Combine two proofs into one using tuples.
+    pub fn tup<T2, U2>(self, _: POrdProof<T2, U2>) -> POrdProof<Tup<T, T2>, Tup<U, U2>> {
+        POrdProof(std::marker::PhantomData)
+    }
+
+    /// Combine two proofs into one using lambdas.
+    pub fn lam<T2, U2>(self, _: POrdProof<T2, U2>) -> POrdProof<Lam<T, T2>, Lam<U, U2>> {
+        POrdProof(std::marker::PhantomData)
+    }
+
     /// Combine two proofs into one using path semantical quality.
     pub fn q<T2, U2>(self, x: POrdProof<T2, U2>) -> POrdProof<Q<T, T2>, Q<U, U2>> {
         self.clone().eq(x.clone()).and(self.qu().and(x.qu()))
@@ -118,6 +147,41 @@ impl<A, B, C> POrdProof<A, Or<B, C>> {
     }
 }
 
+impl<F, X> POrdProof<F, App<F, X>> {
+    /// Get proof `f < f(x)`.
+    pub fn app_fun() -> Self {
+        POrdProof(std::marker::PhantomData)
+    }
+}
+
+impl<F, X> POrdProof<X, App<F, X>> {
+    /// Get proof `x < f(x)`.
+    pub fn app_arg() -> Self {
+        POrdProof(std::marker::PhantomData)
+    }
+}
+
+impl<A, B> POrdProof<A, Tup<A, B>> {
+    /// Get proof `a < (a, b)`.
+    pub fn tup_fst() -> Self {
+        POrdProof(std::marker::PhantomData)
+    }
+}
+
+impl<A, B> POrdProof<B, Tup<A, B>> {
+    /// Get proof `b < (a, b)`.
+    pub fn tup_snd() -> Self {
+        POrdProof(std::marker::PhantomData)
+    }
+}
+
+impl<X, Y> POrdProof<Y, Lam<X, Y>> {
+    /// Get proof `b < (\(a : x) = b)`.
+    pub fn lam_body() -> Self {
+        POrdProof(std::marker::PhantomData)
+    }
+}
+
 impl<A, B> POrdProof<A, Imply<A, B>> {
     /// Get reduced proof `a < b`.
     pub fn imply_reduce(self) -> POrdProof<A, B> {
@@ -131,6 +195,10 @@ impl<A, B> POrdProof<A, Imply<A, B>> {
 /// such that symbols can not be used inconsistently.
 ///
 /// Uses a marker feature to allow overlapping impls.
+///
+/// Terms of the [fun](crate::fun) module are ordered by their parts,
+/// e.g. `f(x) < t` when `f < t` and `x < t`.
+/// This includes `Comp` and `Inv`, which are function applications.
 #[marker]
 pub trait POrd<T> {}
 
@@ -142,5 +210,17 @@ impl<A, B, T> POrd<T> for Imply<A, B>
     where A: POrd<T>, B: POrd<T> {}
 impl<A, B> POrd<Qu<B>> for Qu<A>
     where A: POrd<B> {}
+impl<F, X, T> POrd<T> for App<F, X>
+    where F: POrd<T>, X: POrd<T> {}
+impl<F, X, G, Y> POrd<App<G, Y>> for App<F, X>
+    where F: POrd<G>, X: POrd<Y> {}
+impl<A, B, T> POrd<T> for Tup<A, B>
+    where A: POrd<T>, B: POrd<T> {}
+impl<A, B, C, D> POrd<Tup<C, D>> for Tup<A, B>
+    where A: POrd<C>, B: POrd<D> {}
+impl<X, Y, T> POrd<T> for Lam<X, Y>
+    where X: POrd<T>, Y: POrd<T> {}
+impl<X, Y, Z, W> POrd<Lam<Z, W>> for Lam<X, Y>
+    where X: POrd<Z>, Y: POrd<W> {}
 
 impl<T, U> POrd<U> for T where T: LProp, U: LProp, T::N: Lt<U::N> {}
@@ -1,6 +1,26 @@
 #![deny(unsafe_op_in_unsafe_fn)]
 
 //! # Model of Types in Path Semantics
+//!
+//! A typing judgment `a : t` ([Ty]) is an implication `a => t`
+//! together with a proof of path semantical order `a < t`.
+//!
+//! ### Tactics
+//!
+//! - Substitution: [in_left_arg], [in_right_arg], [eq_left], [eq_right], [imply_right]
+//! - Substitution by quality: [q_in_left_arg], [q_in_right_arg], [q_left], [q_right]
+//! - Trivial types: [ty_true], [ty_false], [ty_rev_true], [triv], [non_triv],
+//!   [true_true], [true_ltrue]
+//! - Formation: [and()], [or()], [hom_imply], [eqq_imply], [q_formation], [qu_formation]
+//! - Introduction: [and_right], [and_left], [or_left], [or_right]
+//! - Elimination: [or_split], [or_split_da], [or_split_db], [or_split_dc]
+//! - Transitivity: [transitivity]
+//! - Uniqueness: [uniq], [neq_to_sesh], [neq_to_not_qu]
+//! - Levels: [ltrue], [lift], [lower]
+//!
+//! Tactics that transform the right side of a judgment are `unsafe`,
+//! since they depend on [POrdProof::by_imply_right].
+//! Substitution by quality is safe on both sides (see [POrdProof::by_q_right]).
 
 use super::*;
 
@@ -47,6 +67,28 @@ pub unsafe fn eq_right<A: Prop, B: Prop, C: Prop>(x: Eq<B, C>) -> Eq<Ty<A, B>, T
      Rc::new(move |ty_a| unsafe {in_right_arg(ty_a, x2.clone())}))
 }
 
+/// `(a : b) ⋀ (a ~~ c)  =>  (c : b)`.
+pub fn q_in_left_arg<A: Prop, B: Prop, C: Prop>(x: Ty<A, B>, q: Q<A, C>) -> Ty<C, B> {
+    in_left_arg(x, quality::to_eq(q))
+}
+
+/// `(a : b) ⋀ (b ~~ c)  =>  (a : c)`.
+pub fn q_in_right_arg<A: Prop, B: Prop, C: Prop>((ab, pord): Ty<A, B>, q: Q<B, C>) -> Ty<A, C> {
+    (imply::in_right_arg(ab, quality::to_eq(q.clone())), pord.by_q_right(q))
+}
+
+/// `(a ~~ b)  =>  (a : c) == (b : c)`.
+pub fn q_left<A: Prop, B: Prop, C: Prop>(x: Q<A, B>) -> Eq<Ty<A, C>, Ty<B, C>> {
+    eq_left(quality::to_eq(x))
+}
+
+/// `(b ~~ c)  =>  (a : b) == (a : c)`.
+pub fn q_right<A: Prop, B: Prop, C: Prop>(x: Q<B, C>) -> Eq<Ty<A, B>, Ty<A, C>> {
+    let x2 = quality::symmetry(x.clone());
+    (Rc::new(move |ty_a| q_in_right_arg(ty_a, x.clone())),
+     Rc::new(move |ty_a| q_in_right_arg(ty_a, x2.clone())))
+}
+
 /// `(a : b) ⋀ (b => c)  =>  (a : c)`.
 ///
 /// # Safety
@@ -173,6 +215,40 @@ pub fn eqq_imply<X: DProp, Y: DProp, A: Prop, B: Prop>(
     (xy_ab, pord)
 }
 
+/// `(x : a) ⋀ (x : b)  =>  (x : (a ⋀ b))`.
+pub fn and_right<X: Prop, A: Prop, B: Prop>(
+    (xa, pord_xa): Ty<X, A>,
+    (xb, pord_xb): Ty<X, B>,
+) -> Ty<X, And<A, B>> {
+    (Rc::new(move |x: X| (xa(x.clone()), xb(x))), pord_xa.merge_right(pord_xb))
+}
+
+/// `(x : a) ⋀ (y : a)  =>  ((x ⋀ y) : a)`.
+pub fn and_left<X: Prop, Y: Prop, A: Prop>(
+    (xa, pord_xa): Ty<X, A>,
+    (_, pord_ya): Ty<Y, A>,
+) -> Ty<And<X, Y>, A> {
+    (Rc::new(move |(x, _)| xa(x)), pord_xa.merge_left(pord_ya))
+}
+
+/// `(x : a) ⋀ (x < b)  =>  (x : (a ⋁ b))`.
+pub fn or_left<X: Prop, A: Prop, B: Prop>(
+    (xa, pord_xa): Ty<X, A>,
+    pord_xb: POrdProof<X, B>,
+) -> Ty<X, Or<A, B>> {
+    let eq: Eq<Or<X, X>, X> = (Rc::new(or::both), Rc::new(Left));
+    (Rc::new(move |x| Left(xa(x))), pord_xa.or(pord_xb).by_eq_left(eq))
+}
+
+/// `(x < a) ⋀ (x : b)  =>  (x : (a ⋁ b))`.
+pub fn or_right<X: Prop, A: Prop, B: Prop>(
+    pord_xa: POrdProof<X, A>,
+    (xb, pord_xb): Ty<X, B>,
+) -> Ty<X, Or<A, B>> {
+    let eq: Eq<Or<X, X>, X> = (Rc::new(or::both), Rc::new(Left));
+    (Rc::new(move |x| Right(xb(x))), pord_xa.or(pord_xb).by_eq_left(eq))
+}
+
 /// `(a : (b ⋁ c)) ⋀ a  =>  (a : b) ⋁ (a : c)`.
 pub fn or_split<A: Prop, B: Prop, C: Prop>(
     (ty_a, pord): Ty<A, Or<B, C>>,
@@ -249,6 +325,13 @@ pub fn neq_to_sesh<A: Prop, B: Prop, T: Prop, U: Prop>(
     imply::modus_tollens(q_formation(ty_a, ty_b).0)(quality::neq_to_sesh(neq_tu))
 }
 
+/// `(a : T) ⋀ (a : U) ⋀ ~a  =>  (T ~~ U)`.
+///
+/// Types are unique for propositions with self-quality.
+pub fn uniq<A: Prop, T: Prop, U: Prop>(ty_a_t: Ty<A, T>, ty_a_u: Ty<A, U>, qu_a: Qu<A>) -> Q<T, U> {
+    q_formation(ty_a_t, ty_a_u).0(qu_a.to_q())
+}
+
 /// `(a : T) ⋀ (a : U) ⋀ ¬(T == U)  =>  ¬~a`.
 pub fn neq_to_not_qu<A: Prop, T: Prop, U: Prop>(
     ty_a_t: Ty<A, T>,
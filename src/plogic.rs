@@ -0,0 +1,101 @@
+//! # Weighted Propositions
+//!
+//! *Notice! This module is experimental and in early stages of development.*
+//!
+//! A weighted proposition `w(a, p)` ([W]) means that proposition `a` has probability `p`.
+//! The probability is a symbolic fraction `n/d` ([Frac]) using natural numbers with types
+//! from the [nat] module. No floating point numbers are involved.
+//!
+//! The combination rules are introduced as axioms:
+//!
+//! - `w(true, 1/1)` and `w(false, 0/1)`
+//! - Complement: `w(a, n/d) ⋀ (n + m == d)  =>  w(¬a, m/d)` ([w_not])
+//! - Independent conjunction: `w(a, p) ⋀ w(b, q) ⋀ indep(a, b)  =>  w(a ⋀ b, p * q)` ([w_and])
+//!
+//! Substitution requires tautological equality `(a == b)^true` ([w_in_arg]),
+//! since any two true propositions are equal, while they might have different probabilities.
+//!
+//! Independent disjunction is derived from these rules ([w_or]),
+//! using `a ⋁ b == ¬(¬a ⋀ ¬b)` for decidable propositions.
+
+use crate::*;
+
+use hooo::Tauto;
+use nat::{Add, Mul, S, Z};
+
+/// Symbolic fraction `n/d`.
+#[derive(Copy, Clone)]
+pub struct Frac<N, D>(N, D);
+
+/// Weighted proposition `w(a, p)`.
+#[derive(Copy, Clone)]
+pub struct W<A, P>(A, P);
+
+/// Independence `indep(a, b)`.
+#[derive(Copy, Clone)]
+pub struct Indep<A, B>(A, B);
+
+/// Probability `0/1`.
+pub type Never = Frac<Z, S<Z>>;
+/// Probability `1/1`.
+pub type Always = Frac<S<Z>, S<Z>>;
+
+/// `w(true, 1/1)`.
+pub fn w_true() -> W<True, Always> {axiom!()}
+/// `w(false, 0/1)`.
+pub fn w_false() -> W<False, Never> {axiom!()}
+/// `w(a, p) ⋀ (a == b)^true  =>  w(b, p)`.
+pub fn w_in_arg<A: Prop, B: Prop, P: Prop>(_: W<A, P>, _: Tauto<Eq<A, B>>) -> W<B, P> {axiom!()}
+/// `w(a, n/d) ⋀ (n + m == d)  =>  w(¬a, m/d)`.
+pub fn w_not<A: Prop, N: Prop, M: Prop, D: Prop>(_: W<A, Frac<N, D>>) -> W<Not<A>, Frac<M, D>>
+    where (N, M): Add<Out = D>
+{axiom!()}
+/// `w(a, n1/d1) ⋀ w(b, n2/d2) ⋀ indep(a, b)  =>  w(a ⋀ b, (n1 * n2)/(d1 * d2))`.
+pub fn w_and<A: Prop, B: Prop, N1: Prop, D1: Prop, N2: Prop, D2: Prop>(
+    _: W<A, Frac<N1, D1>>,
+    _: W<B, Frac<N2, D2>>,
+    _: Indep<A, B>,
+) -> W<And<A, B>, Frac<<(N1, N2) as Mul>::Out, <(D1, D2) as Mul>::Out>>
+    where (N1, N2): Mul, (D1, D2): Mul
+{axiom!()}
+/// `indep(a, b)  =>  indep(b, a)`.
+pub fn indep_symmetry<A: Prop, B: Prop>(_: Indep<A, B>) -> Indep<B, A> {axiom!()}
+/// `indep(a, b)  =>  indep(¬a, ¬b)`.
+pub fn indep_not<A: Prop, B: Prop>(_: Indep<A, B>) -> Indep<Not<A>, Not<B>> {axiom!()}
+
+/// `w(a, n/d) ⋀ (n + m == d)  =>  w(¬¬a, n/d)`.
+pub fn w_double_not<A: Prop, N: Prop, M: Prop, D: Prop>(
+    x: W<A, Frac<N, D>>
+) -> W<Not<Not<A>>, Frac<N, D>>
+    where (N, M): Add<Out = D>, (M, N): Add<Out = D>
+{w_not::<_, M, N, D>(w_not::<_, N, M, D>(x))}
+
+/// `w(¬¬a, p)  =>  w(a, p)` for decidable `a`.
+pub fn w_rev_double_not<A: DProp, P: Prop>(x: W<Not<Not<A>>, P>) -> W<A, P> {
+    w_in_arg(x, tauto!((Rc::new(not::rev_double), Rc::new(not::double))))
+}
+
+/// `w(a, n1/d1) ⋀ w(b, n2/d2) ⋀ indep(a, b)  =>  w(a ⋁ b, k/(d1 * d2))`
+/// where `n1 + m1 == d1`, `n2 + m2 == d2` and `(m1 * m2) + k == d1 * d2`.
+///
+/// Uses `a ⋁ b == ¬(¬a ⋀ ¬b)`.
+pub fn w_or<A: DProp, B: DProp, N1: Prop, M1: Prop, D1: Prop, N2: Prop, M2: Prop, D2: Prop, K: Prop>(
+    wa: W<A, Frac<N1, D1>>,
+    wb: W<B, Frac<N2, D2>>,
+    indep: Indep<A, B>,
+) -> W<Or<A, B>, Frac<K, <(D1, D2) as Mul>::Out>>
+    where (N1, M1): Add<Out = D1>,
+          (N2, M2): Add<Out = D2>,
+          (M1, M2): Mul,
+          (D1, D2): Mul,
+          <(D1, D2) as Mul>::Out: Prop,
+          <(M1, M2) as Mul>::Out: Prop,
+          (<(M1, M2) as Mul>::Out, K): Add<Out = <(D1, D2) as Mul>::Out>,
+{
+    let wna = w_not::<_, N1, M1, D1>(wa);
+    let wnb = w_not::<_, N2, M2, D2>(wb);
+    let x = w_and::<_, _, M1, D1, M2, D2>(wna, wnb, indep_not(indep));
+    let x = w_in_arg(x, tauto!(eq::symmetry(not::eq_or())));
+    let x = w_not::<_, <(M1, M2) as Mul>::Out, K, <(D1, D2) as Mul>::Out>(x);
+    w_rev_double_not(x)
+}
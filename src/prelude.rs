@@ -0,0 +1,76 @@
+//! # Prelude
+//!
+//! Tactics in this library are organized in modules by constructs (e.g. `and` or `imply`),
+//! which gives many tactics the same name, e.g. `and::symmetry` and `eq::symmetry`.
+//! This module re-exports the most used types and tactics under disambiguated names,
+//! such that they can be imported together:
+//!
+//! ```rust
+//! use prop::prelude::*;
+//!
+//! fn proof<A: Prop, B: Prop, C: Prop>(ab: Eq<A, B>, (c, a): And<C, A>) -> And<B, C> {
+//!     and_symmetry((c, eq_trans(eq_refl(), ab).0(a)))
+//! }
+//! ```
+//!
+//! Lemmas about functions are grouped by constructs in submodules of [crate::fun]
+//! (e.g. `fun::comp`, `fun::inv`, `fun::lam`, `fun::subst` and `fun::tup`).
+
+pub use crate::{
+    And, Decidable, DProp, Dneg, Either, Eq, ExcM, False, Iff, Imply, Not, Or, Prop, True,
+};
+pub use crate::Either::{Left, Right};
+pub use crate::existence::EProp;
+pub use crate::hooo::{Para, Pow, Tauto};
+pub use crate::path_semantics::{LProp, POrdProof, Ty};
+pub use crate::quality::Q;
+pub use crate::qubit::Qu;
+
+pub use crate::and::{
+    assoc as and_assoc,
+    distrib as and_distrib,
+    fst as and_fst,
+    snd as and_snd,
+    symmetry as and_symmetry,
+};
+pub use crate::eq::{
+    in_left_arg as eq_in_left_arg,
+    refl as eq_refl,
+    symmetry as eq_symmetry,
+    transitivity as eq_trans,
+};
+pub use crate::hooo::{
+    pow_refl,
+    pow_to_imply,
+    pow_transitivity as pow_trans,
+};
+pub use crate::imply::{
+    absurd as imply_absurd,
+    chain as imply_chain,
+    id as imply_id,
+    in_left_arg as imply_in_left_arg,
+    in_right_arg as imply_in_right_arg,
+    modus_ponens,
+    modus_tollens,
+    transitivity as imply_trans,
+};
+pub use crate::not::{
+    double as not_double,
+    rev_double as not_rev_double,
+};
+pub use crate::or::{
+    assoc as or_assoc,
+    distrib as or_distrib,
+    symmetry as or_symmetry,
+};
+pub use crate::path_semantics::ty::{
+    in_left_arg as ty_in_left_arg,
+    q_in_left_arg as ty_q_in_left_arg,
+    q_in_right_arg as ty_q_in_right_arg,
+    transitivity as ty_trans,
+};
+pub use crate::quality::{
+    symmetry as q_symmetry,
+    to_eq as q_to_eq,
+    transitivity as q_trans,
+};
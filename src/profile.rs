@@ -0,0 +1,131 @@
+//! # Profiling
+//!
+//! Proofs are Rust values, so large proofs can be slow to run or build,
+//! for example when long chains of equalities create deeply nested closures (see [eq]).
+//! This module counts invocations of tactics and measures closure depth
+//! when proofs are exercised at runtime, such that hot spots can be found.
+//!
+//! Tactics in this library are instrumented when the `profile` feature is enabled,
+//! e.g. [imply::transitivity] and [eq::transitivity].
+//! Without the feature, the instrumentation compiles to nothing.
+//! Proofs can be instrumented by hand using [count], [enter] or [wrap].
+//!
+//! Profiles are recorded per thread:
+//!
+//! ```rust
+//! use prop::*;
+//! use prop::profile::{self, count, wrap, Report};
+//! use std::rc::Rc;
+//!
+//! profile::reset();
+//! let x: Eq<True, True> = eq::transitivity(eq::transitivity(eq::refl(), eq::refl()), eq::refl());
+//! let _: True = x.0(True);
+//! let r = profile::report();
+//! assert_eq!(r.counts["eq::transitivity"], 2);
+//! assert_eq!(r.max_depth, 2);
+//!
+//! let f: Imply<True, True> = wrap("lemma", Rc::new(|x| x));
+//! for _ in 0..3 {let _ = f(True);}
+//! count("other");
+//! let r = profile::report();
+//! assert_eq!(r.hot_spots(), vec![("lemma", 3), ("eq::transitivity", 2), ("other", 1)]);
+//! assert_eq!(r.max_depth, 2);
+//! println!("{}", r);
+//!
+//! profile::reset();
+//! assert_eq!(profile::report(), Report::default());
+//! ```
+
+use crate::*;
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::fmt;
+
+#[derive(Default)]
+struct State {
+    counts: BTreeMap<&'static str, u64>,
+    depth: usize,
+    max_depth: usize,
+}
+
+thread_local! {
+    static STATE: RefCell<State> = RefCell::new(State::default());
+}
+
+/// A snapshot of the profile.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Report {
+    /// The number of invocations by name.
+    pub counts: BTreeMap<&'static str, u64>,
+    /// The maximum closure depth.
+    pub max_depth: usize,
+}
+
+impl Report {
+    /// Returns the names sorted by number of invocations, most invoked first.
+    pub fn hot_spots(&self) -> Vec<(&'static str, u64)> {
+        let mut res: Vec<_> = self.counts.iter().map(|(&k, &v)| (k, v)).collect();
+        res.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(b.0)));
+        res
+    }
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, w: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(w, "max depth: {}", self.max_depth)?;
+        for (name, n) in self.hot_spots() {
+            writeln!(w, "{:>10} {}", n, name)?;
+        }
+        Ok(())
+    }
+}
+
+/// Tracks closure depth until dropped.
+pub struct Scope(());
+
+impl Drop for Scope {
+    fn drop(&mut self) {STATE.with(|s| s.borrow_mut().depth -= 1)}
+}
+
+/// Counts an invocation.
+pub fn count(name: &'static str) {
+    STATE.with(|s| *s.borrow_mut().counts.entry(name).or_insert(0) += 1)
+}
+
+/// Counts an invocation and enters a closure, which is left when the scope is dropped.
+pub fn enter(name: &'static str) -> Scope {
+    STATE.with(|s| {
+        let mut s = s.borrow_mut();
+        *s.counts.entry(name).or_insert(0) += 1;
+        s.depth += 1;
+        s.max_depth = s.max_depth.max(s.depth);
+    });
+    Scope(())
+}
+
+/// Instruments a proof `a => b`, such that calls are profiled.
+pub fn wrap<A: Prop, B: Prop>(name: &'static str, f: Imply<A, B>) -> Imply<A, B> {
+    Rc::new(move |a| {
+        let _scope = enter(name);
+        f(a)
+    })
+}
+
+/// Returns a snapshot of the profile.
+pub fn report() -> Report {
+    STATE.with(|s| {
+        let s = s.borrow();
+        Report {counts: s.counts.clone(), max_depth: s.max_depth}
+    })
+}
+
+/// Resets the profile.
+///
+/// The current depth is kept, such that open scopes are left correctly.
+pub fn reset() {
+    STATE.with(|s| {
+        let mut s = s.borrow_mut();
+        s.counts.clear();
+        s.max_depth = s.depth;
+    })
+}
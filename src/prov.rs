@@ -0,0 +1,87 @@
+//! # Provability Logic
+//!
+//! The provability modality `prov(a)` ([Prov]) means that `a` is provable in some formal system
+//! that is strong enough to reason about its own proofs.
+//!
+//! Notice that this is not the same as `□a` in the [modal] module, which uses `a^true`.
+//! For tautologies, the premise of Löb's theorem is trivial, so Löb's theorem is absurd
+//! (see [modal::lob_triv]). Therefore, the provability modality is an opaque proposition.
+//!
+//! The axioms are:
+//!
+//! - Distribution `prov(a => b)  =>  (prov(a) => prov(b))` ([prov_distrib])
+//! - Necessitation `a^true  =>  prov(a)` ([prov_nec])
+//! - Internal necessitation `prov(a)  =>  prov(prov(a))` ([prov_internal])
+//!
+//! ### Gödel–Löb logic
+//!
+//! Löb's axiom `prov(prov(a) => a)  =>  prov(a)` is enabled with the `lob` feature.
+//! Together with the axioms above, this gives the provability logic GL.
+
+use crate::*;
+use hooo::Tauto;
+
+/// Provability `prov(a)`.
+#[derive(Copy, Clone)]
+pub struct Prov<A>(A);
+
+/// `prov(a => b)  =>  (prov(a) => prov(b))`.
+pub fn prov_distrib<A: Prop, B: Prop>(_: Prov<Imply<A, B>>) -> Imply<Prov<A>, Prov<B>> {
+    axiom!()
+}
+/// `a^true  =>  prov(a)`.
+pub fn prov_nec<A: Prop>(_: Tauto<A>) -> Prov<A> {axiom!()}
+/// `prov(a)  =>  prov(prov(a))`.
+pub fn prov_internal<A: Prop>(_: Prov<A>) -> Prov<Prov<A>> {axiom!()}
+
+/// `prov(true)`.
+pub fn prov_true() -> Prov<True> {prov_nec(hooo::tr())}
+/// `prov(a => b) ⋀ prov(a)  =>  prov(b)`.
+pub fn prov_modus_ponens<A: Prop, B: Prop>(x: Prov<Imply<A, B>>, y: Prov<A>) -> Prov<B> {
+    prov_distrib(x)(y)
+}
+/// `(a => b)^true  =>  (prov(a) => prov(b))`.
+pub fn prov_imply<A: Prop, B: Prop>(x: Tauto<Imply<A, B>>) -> Imply<Prov<A>, Prov<B>> {
+    prov_distrib(prov_nec(x))
+}
+/// `prov(a) ⋀ prov(b)  =>  prov(a ⋀ b)`.
+pub fn prov_and<A: Prop, B: Prop>(a: Prov<A>, b: Prov<B>) -> Prov<And<A, B>> {
+    fn f<A: Prop, B: Prop>(_: True) -> Imply<A, Imply<B, And<A, B>>> {
+        Rc::new(|a| Rc::new(move |b| (a.clone(), b)))
+    }
+    prov_modus_ponens(prov_modus_ponens(prov_nec(f), a), b)
+}
+/// `prov(a ⋀ b)  =>  prov(a)`.
+pub fn prov_and_fst<A: Prop, B: Prop>(x: Prov<And<A, B>>) -> Prov<A> {
+    fn f<A: Prop, B: Prop>(_: True) -> Imply<And<A, B>, A> {Rc::new(and::fst)}
+    prov_imply(f)(x)
+}
+/// `prov(a ⋀ b)  =>  prov(b)`.
+pub fn prov_and_snd<A: Prop, B: Prop>(x: Prov<And<A, B>>) -> Prov<B> {
+    fn f<A: Prop, B: Prop>(_: True) -> Imply<And<A, B>, B> {Rc::new(and::snd)}
+    prov_imply(f)(x)
+}
+/// `(a == b)^true  =>  (prov(a) == prov(b))`.
+pub fn prov_eq<A: Prop, B: Prop>(x: Tauto<Eq<A, B>>) -> Eq<Prov<A>, Prov<B>> {
+    (prov_imply(hooo::pow_transitivity(x, and::fst)),
+     prov_imply(hooo::pow_transitivity(x, and::snd)))
+}
+
+/// `prov(prov(a) => a)  =>  prov(a)`.
+///
+/// Löb's axiom.
+#[cfg(feature = "lob")]
+pub fn lob<A: Prop>(_: Prov<Imply<Prov<A>, A>>) -> Prov<A> {axiom!()}
+/// `(prov(a) => a)^true  =>  a`.
+///
+/// Löb's rule.
+#[cfg(feature = "lob")]
+pub fn lob_rule<A: Prop>(x: Tauto<Imply<Prov<A>, A>>) -> A {
+    x(True)(lob(prov_nec(x)))
+}
+/// `prov(¬prov(false))  =>  prov(false)`.
+///
+/// Gödel's second incompleteness theorem:
+/// If the system proves its own consistency, then it is inconsistent.
+#[cfg(feature = "lob")]
+pub fn second_incompleteness(x: Prov<Not<Prov<False>>>) -> Prov<False> {lob(x)}
@@ -41,6 +41,7 @@
 //! - `a ~~ b`: Platonism 2 (Product Witness)
 //!
 //! Since `a ~~ b` implies `a ~~ a`, both Loop and Product Witness are rejected by Seshatism.
+//! The bias of a single proposition is modeled in the [bias] module.
 //!
 //! For more information, see [Seshatism vs Platonism](https://advancedresearch.github.io/avatar-extensions/summary.html#seshatism-vs-platonism).
 //!
@@ -62,6 +63,10 @@
 //! One can think about aquality vs quality as a perspective
 //! where Seshatism and Platonism become mirror images of each other.
 //!
+//! Aquality is the same as quality of the negated arguments, `(a ~¬~ b) == (¬a ~~ ¬b)`
+//! for decidable propositions ([eq_aq_q_inv]), and aquality is Seshatic ([aq_to_seshatic]).
+//! Quality and aquality of the same proposition are mutually exclusive ([q_aq_absurd]).
+//!
 //! The core axiom biases mathematics toward Platonism,
 //! but this choice is arbitrary since `¬~x == ~¬x` (see [qubit::eq_sesh_inv]).
 //! Therefore, "Platonism" is whatever theory/philosophy
@@ -407,3 +412,51 @@ pub fn theory_eq_to_excm_q_with_excm_eq<A: Prop, B: Prop>(
 ) -> ExcM<Q<A, B>> {
     eqq_to_excm_q_with_excm_eq(theory_eq_to_eqq(theory_eq), excm_eq)
 }
+
+/// `(a ~¬~ b) => (¬a ~~ ¬b)`.
+pub fn aq_to_q_inv<A: Prop, B: Prop>((eq, and_qu): Aq<A, B>) -> Q<Not<A>, Not<B>> {
+    (eq::symmetry(eq::modus_tollens(eq)), and_qu)
+}
+
+/// `(¬a ~~ ¬b) => (a ~¬~ b)`.
+pub fn q_inv_to_aq<A: DProp, B: DProp>((eq, and_qu): Q<Not<A>, Not<B>>) -> Aq<A, B> {
+    (eq::symmetry(eq::rev_modus_tollens(eq)), and_qu)
+}
+
+/// `(a ~¬~ b) == (¬a ~~ ¬b)`.
+pub fn eq_aq_q_inv<A: DProp, B: DProp>() -> Eq<Aq<A, B>, Q<Not<A>, Not<B>>> {
+    (Rc::new(aq_to_q_inv), Rc::new(q_inv_to_aq))
+}
+
+/// `(a ~¬~ b) => ¬~a ⋀ ¬~b`.
+pub fn aq_to_sesh_qu<A: Prop, B: Prop>(
+    (_, (qu_na, qu_nb)): Aq<A, B>
+) -> And<Not<Qu<A>>, Not<Qu<B>>> {
+    (qubit::inv_to_sesh(qu_na), qubit::inv_to_sesh(qu_nb))
+}
+
+/// `(a ~¬~ b) => ¬(a ~~ a)`.
+pub fn aq_to_sesh_left<A: Prop, B: Prop>(aq: Aq<A, B>) -> Not<Q<A, A>> {
+    let nqu = aq_to_sesh_qu(aq).0;
+    Rc::new(move |q| nqu(Qu::from_q(q)))
+}
+
+/// `(a ~¬~ b) => ¬(b ~~ b)`.
+pub fn aq_to_sesh_right<A: Prop, B: Prop>(aq: Aq<A, B>) -> Not<Q<B, B>> {
+    aq_to_sesh_left(aq_symmetry(aq))
+}
+
+/// `(a ~¬~ b) => seshatic(a, b)`.
+///
+/// Aquality is a Seshatic relation.
+pub fn aq_to_seshatic<A: Prop, B: Prop>(aq: Aq<A, B>) -> Seshatic<A, B> {Left(aq_to_sesh_left(aq))}
+
+/// `(a ~~ b) => ¬(a ~¬~ a)`.
+pub fn q_to_aq_sesh_left<A: Prop, B: Prop>((_, (qu_a, _)): Q<A, B>) -> Not<Aq<A, A>> {
+    Rc::new(move |aq| qubit::inv_to_sesh(Qu::from_aq(aq))(qu_a.clone()))
+}
+
+/// `(a ~~ b) ⋀ (a ~¬~ c) => false`.
+pub fn q_aq_absurd<A: Prop, B: Prop, C: Prop>(q: Q<A, B>, aq: Aq<A, C>) -> False {
+    q_to_aq_sesh_left(q)(aq_left(aq))
+}
@@ -1,6 +1,28 @@
 //! # Path Semantical Qubit
 //!
 //! For an implementation, see the [Pocket-Prover](https://github.com/advancedresearch/pocket_prover) library.
+//!
+//! ### Modality Structure
+//!
+//! The qubit operator `~` is functorial only along tautological equality:
+//! `(a == b)^true  =>  (~a == ~b)` ([map_eq]), which is a theorem of [in_arg].
+//! Structural laws of `⋀` lift this way, e.g. [and_symmetry] and [and_assoc].
+//!
+//! The following are deliberately not provided, since they are unsound in the model
+//! where qubits of different propositions are independent random bits:
+//!
+//! - Functoriality along implication `(a => b)^true  =>  (~a => ~b)`
+//! - Unit `a => ~a` and counit `~a => a`
+//! - Distribution `~(a ⋀ b) == (~a ⋀ ~b)`
+//!
+//! Idempotence `~~a == ~a` holds exactly for sets (see [fun::phott::IsSet]).
+//! In general, `~~a` is the second qubit level `qubit^2(a)` ([eq_normalize]).
+//!
+//! Axioms that interact with `~`:
+//!
+//! - Negation: `¬~a == ~¬a` ([eq_sesh_inv])
+//! - Composition of functions: `~f ⋀ ~g  =>  ~(g . f)` ([fun::comp_qu])
+//! - Tuples: `~a ⋀ ~b  =>  ~(a, b)` ([fun::tup_qu])
 
 use crate::*;
 use hooo::Tauto;
@@ -41,7 +63,7 @@ impl<A: Prop> Qubit<S<Z>, Not<A>> {
 pub fn decide<A: DProp>() -> ExcM<Qu<A>> {decide_tauto_excm(tauto!(A::decide()))}
 
 /// `(a ⋁ ¬a)^true  =>  (~a ⋁ ¬~a)`.
-pub fn decide_tauto_excm<A: Prop>(_: Tauto<ExcM<A>>) -> ExcM<Qu<A>> {unimplemented!()}
+pub fn decide_tauto_excm<A: Prop>(_: Tauto<ExcM<A>>) -> ExcM<Qu<A>> {axiom!()}
 
 /// `~qubit^n(a)  =>  qubit^(n+1)(a)`.
 pub fn normalize<A: Prop, N: Nat>(x: Qubit<S<Z>, Qubit<N, A>>) -> Qubit<S<N>, A> {
@@ -53,9 +75,9 @@ pub fn rev_normalize<A: Prop, N: Nat>(x: Qubit<S<N>, A>) -> Qubit<S<Z>, Qubit<N,
 }
 
 /// `¬~a => ~¬a`.
-pub fn sesh_to_inv<A: Prop>(_: Not<Qu<A>>) -> Qu<Not<A>> {unimplemented!()}
+pub fn sesh_to_inv<A: Prop>(_: Not<Qu<A>>) -> Qu<Not<A>> {axiom!()}
 /// `~¬a => ¬~a`.
-pub fn inv_to_sesh<A: Prop>(_: Qu<Not<A>>) -> Not<Qu<A>> {unimplemented!()}
+pub fn inv_to_sesh<A: Prop>(_: Qu<Not<A>>) -> Not<Qu<A>> {axiom!()}
 /// `¬~a == ~¬a`.
 pub fn eq_sesh_inv<A: Prop>() -> Eq<Not<Qu<A>>, Qu<Not<A>>> {
     (Rc::new(sesh_to_inv), Rc::new(inv_to_sesh))
@@ -123,3 +145,40 @@ pub fn eq_qu_q<A: Prop>() -> Eq<Qu<A>, Q<A, A>> {(Rc::new(Qu::to_q), Rc::new(Qu:
 
 /// `(a ~~ a)  == ~a`.
 pub fn eq_q_qu<A: Prop>() -> Eq<Q<A, A>, Qu<A>> {eq::symmetry(eq_qu_q())}
+
+/// `~qubit^n(a) == qubit^(n+1)(a)`.
+pub fn eq_normalize<A: Prop, N: Nat>() -> Eq<Qubit<S<Z>, Qubit<N, A>>, Qubit<S<N>, A>> {
+    (Rc::new(normalize), Rc::new(rev_normalize))
+}
+
+/// `(a == b)^true  =>  (~a == ~b)`.
+pub fn map_eq<A: Prop, B: Prop>(x: Tauto<Eq<A, B>>) -> Eq<Qu<A>, Qu<B>> {
+    let y = hooo::tauto_eq_symmetry(x);
+    (Rc::new(move |qu_a| in_arg(qu_a, x)), Rc::new(move |qu_b| in_arg(qu_b, y)))
+}
+
+/// `~(a ⋀ b) == ~(b ⋀ a)`.
+pub fn and_symmetry<A: Prop, B: Prop>() -> Eq<Qu<And<A, B>>, Qu<And<B, A>>> {
+    fn f<A: Prop, B: Prop>(_: True) -> Eq<And<A, B>, And<B, A>> {
+        (Rc::new(and::symmetry), Rc::new(and::symmetry))
+    }
+    map_eq(f)
+}
+
+/// `~((a ⋀ b) ⋀ c) == ~(a ⋀ (b ⋀ c))`.
+pub fn and_assoc<A: Prop, B: Prop, C: Prop>() ->
+    Eq<Qu<And<And<A, B>, C>>, Qu<And<A, And<B, C>>>>
+{
+    fn f<A: Prop, B: Prop, C: Prop>(_: True) -> Eq<And<And<A, B>, C>, And<A, And<B, C>>> {
+        (Rc::new(and::assoc), Rc::new(and::rev_assoc))
+    }
+    map_eq(f)
+}
+
+/// `~(true ⋀ a) == ~a`.
+pub fn and_true<A: Prop>() -> Eq<Qu<And<True, A>>, Qu<A>> {
+    fn f<A: Prop>(_: True) -> Eq<And<True, A>, A> {
+        (Rc::new(and::true_arg), Rc::new(|a| (True, a)))
+    }
+    map_eq(f)
+}
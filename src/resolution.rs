@@ -0,0 +1,309 @@
+//! # Resolution Prover
+//!
+//! A push-button prover for the classical fragment of reflected propositions (see [expr::Expr]).
+//!
+//! To prove a goal `a`, the negated goal `¬a` is converted to conjunctive normal form
+//! with a certificate of equivalence (see [rewrite::to_cnf]).
+//! The resulting clauses are refuted by resolution, which derives the empty clause
+//! from pairs of clauses that contain an atom with opposite polarity:
+//!
+//! `(a ⋁ b) ⋀ (¬a ⋁ c)  =>  b ⋁ c`
+//!
+//! The proof object ([Certificate]) contains both the rewrite steps and the
+//! resolution steps ([Refutation]), which are replayed by [Certificate::check].
+//! At the type level, each resolution step is [resolve], the empty clause is `false`,
+//! and the refutation of `¬a` gives `a` by [by_refutation] when `a: DProp`.
+//!
+//! ```rust
+//! use prop::expr;
+//! use prop::resolution::prove;
+//!
+//! let proof = prove(&expr!(Imply<And<Imply<A, B>, Imply<B, C>>, Imply<A, C>>)).unwrap();
+//! assert!(proof.check());
+//! assert!(prove(&expr!(Imply<A, B>)).is_none());
+//!
+//! // Peirce's law is classical.
+//! let goal = expr!(Imply<Imply<Imply<A, B>, A>, A>);
+//! let mut proof = prove(&goal).unwrap();
+//! assert_eq!(proof.goal(), Some(&goal));
+//! assert!(proof.check());
+//!
+//! // A certificate without the last step does not derive the empty clause.
+//! proof.refutation.steps.pop();
+//! assert!(!proof.check());
+//! ```
+//!
+//! At the type level, the steps are justified by [resolve] and [by_refutation]:
+//!
+//! ```rust
+//! use prop::*;
+//! use prop::resolution::{by_refutation, resolve};
+//! use std::rc::Rc;
+//! use Either::*;
+//!
+//! fn excm<A: DProp>() -> Or<A, Not<A>> {
+//!     by_refutation(Rc::new(|n: Not<Or<A, Not<A>>>| {
+//!         let (na, nna) = and::from_de_morgan(n);
+//!         match resolve::<Not<A>, False, False>(Left(na), Left(nna)) {
+//!             Left(x) | Right(x) => x,
+//!         }
+//!     }))
+//! }
+//! ```
+
+use crate::*;
+use expr::Expr;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+
+/// An atom or a negated atom.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Lit {
+    /// The name of the atom.
+    pub atom: String,
+    /// Whether the atom is positive.
+    pub pos: bool,
+}
+
+impl Lit {
+    /// Returns the literal with opposite polarity.
+    pub fn neg(&self) -> Lit {Lit {atom: self.atom.clone(), pos: !self.pos}}
+
+    /// Converts to an expression.
+    pub fn to_expr(&self) -> Expr {
+        let a = Expr::atom(&self.atom);
+        if self.pos {a} else {!a}
+    }
+}
+
+impl fmt::Display for Lit {
+    fn fmt(&self, w: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if !self.pos {write!(w, "¬")?}
+        write!(w, "{}", self.atom)
+    }
+}
+
+/// A disjunction of literals, where the empty clause is `false`.
+pub type Clause = BTreeSet<Lit>;
+
+fn fmt_clause(c: &Clause, w: &mut fmt::Formatter<'_>) -> fmt::Result {
+    if c.is_empty() {return write!(w, "false")}
+    for (i, x) in c.iter().enumerate() {
+        if i > 0 {write!(w, " ⋁ ")?}
+        write!(w, "{}", x)?;
+    }
+    Ok(())
+}
+
+/// Returns the clauses of an expression in conjunctive normal form.
+///
+/// Returns `None` if the expression is not in conjunctive normal form.
+pub fn clauses(cnf: &Expr) -> Option<Vec<Clause>> {
+    fn clause(e: &Expr, res: &mut Clause) -> Option<()> {
+        match e {
+            Expr::Or(a, b) => {clause(a, res)?; clause(b, res)}
+            Expr::Atom(x) => {res.insert(Lit {atom: x.clone(), pos: true}); Some(())}
+            Expr::Not(a) => match &**a {
+                Expr::Atom(x) => {res.insert(Lit {atom: x.clone(), pos: false}); Some(())}
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    match cnf {
+        Expr::True => Some(vec![]),
+        Expr::False => Some(vec![Clause::new()]),
+        Expr::And(a, b) => Some([clauses(a)?, clauses(b)?].concat()),
+        _ => {
+            let mut res = Clause::new();
+            clause(cnf, &mut res)?;
+            Some(vec![res])
+        }
+    }
+}
+
+/// Resolves a clause containing `atom` with a clause containing `¬atom`.
+///
+/// Returns `None` if the clauses do not contain the atom with these polarities.
+pub fn resolvent(left: &Clause, right: &Clause, atom: &str) -> Option<Clause> {
+    let p = Lit {atom: atom.into(), pos: true};
+    let n = p.neg();
+    if !left.contains(&p) || !right.contains(&n) {return None}
+    let (left, right) = (left.iter().filter(|&x| *x != p), right.iter().filter(|&x| *x != n));
+    Some(left.chain(right).cloned().collect())
+}
+
+/// A resolution step.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Resolve {
+    /// The index of the clause containing the positive atom.
+    pub left: usize,
+    /// The index of the clause containing the negated atom.
+    pub right: usize,
+    /// The atom to resolve on.
+    pub atom: String,
+}
+
+/// A derivation of the empty clause.
+///
+/// Clauses are indexed with the input clauses first,
+/// followed by the clause derived by each step.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Refutation {
+    /// The input clauses.
+    pub input: Vec<Clause>,
+    /// The resolution steps.
+    pub steps: Vec<Resolve>,
+}
+
+impl Refutation {
+    /// Returns all clauses, or `None` if some step does not apply.
+    pub fn replay(&self) -> Option<Vec<Clause>> {
+        let mut res = self.input.clone();
+        for step in &self.steps {
+            let c = resolvent(res.get(step.left)?, res.get(step.right)?, &step.atom)?;
+            res.push(c);
+        }
+        Some(res)
+    }
+
+    /// Returns `true` if the steps derive the empty clause.
+    pub fn check(&self) -> bool {
+        self.replay().is_some_and(|cs| cs.iter().any(|c| c.is_empty()))
+    }
+
+    /// Keeps the steps that the first empty clause depends on.
+    pub fn trim(self) -> Refutation {
+        let n = self.input.len();
+        let last = match self.replay().and_then(|cs| cs.iter().position(|c| c.is_empty())) {
+            Some(i) if i >= n => i - n,
+            Some(_) => return Refutation {input: self.input, steps: vec![]},
+            None => return self,
+        };
+        let Refutation {input, steps} = self;
+        let mut used = vec![false; steps.len()];
+        let mut stack = vec![last];
+        while let Some(k) = stack.pop() {
+            if used[k] {continue}
+            used[k] = true;
+            for i in [steps[k].left, steps[k].right] {
+                if i >= n {stack.push(i - n)}
+            }
+        }
+        let mut index = BTreeMap::new();
+        let mut res = vec![];
+        for (k, step) in steps.into_iter().enumerate() {
+            if !used[k] {continue}
+            let map = |i: usize| if i < n {i} else {index[&(i - n)]};
+            let step = Resolve {left: map(step.left), right: map(step.right), atom: step.atom};
+            index.insert(k, n + res.len());
+            res.push(step);
+        }
+        Refutation {input, steps: res}
+    }
+}
+
+impl fmt::Display for Refutation {
+    fn fmt(&self, w: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, c) in self.replay().unwrap_or_else(|| self.input.clone()).iter().enumerate() {
+            if i > 0 {writeln!(w)?}
+            write!(w, "{}: ", i)?;
+            fmt_clause(c, w)?;
+            if let Some(step) = i.checked_sub(self.input.len()).and_then(|k| self.steps.get(k)) {
+                write!(w, "    ({}, {} on {})", step.left, step.right, step.atom)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Searches for a refutation of the clauses by saturation.
+///
+/// Returns `None` if the clauses are satisfiable.
+/// Only the steps needed to derive the empty clause are kept.
+pub fn refute(input: Vec<Clause>) -> Option<Refutation> {
+    if input.iter().any(|c| c.is_empty()) {return Some(Refutation {input, steps: vec![]})}
+
+    let mut all = input.clone();
+    let mut steps: Vec<Resolve> = vec![];
+    let mut seen: BTreeSet<Clause> = all.iter().cloned().collect();
+    let mut given = 0;
+    while given < all.len() {
+        for other in 0..=given {
+            for (left, right) in [(given, other), (other, given)] {
+                let atoms: Vec<String> = all[left].iter().filter(|x| x.pos)
+                    .map(|x| x.atom.clone()).collect();
+                for atom in atoms {
+                    let c = match resolvent(&all[left], &all[right], &atom) {
+                        Some(c) => c,
+                        None => continue,
+                    };
+                    if c.iter().any(|x| c.contains(&x.neg())) || seen.contains(&c) {continue}
+                    let empty = c.is_empty();
+                    seen.insert(c.clone());
+                    all.push(c);
+                    steps.push(Resolve {left, right, atom});
+                    if empty {return Some(Refutation {input, steps}.trim())}
+                }
+            }
+        }
+        given += 1;
+    }
+    None
+}
+
+/// A proof of a goal by refutation of its negation.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Certificate {
+    /// The conversion of the negated goal to conjunctive normal form.
+    pub cnf: rewrite::Proof,
+    /// The refutation of the clauses.
+    pub refutation: Refutation,
+}
+
+impl Certificate {
+    /// Returns the goal that is proved.
+    pub fn goal(&self) -> Option<&Expr> {
+        match &self.cnf.from {
+            Expr::Not(a) => Some(a),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if the certificate proves its goal.
+    pub fn check(&self) -> bool {
+        self.goal().is_some() &&
+        self.cnf.check() &&
+        clauses(&self.cnf.to).as_ref() == Some(&self.refutation.input) &&
+        self.refutation.check()
+    }
+}
+
+impl fmt::Display for Certificate {
+    fn fmt(&self, w: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(w, "{}\n{}", self.cnf, self.refutation)
+    }
+}
+
+/// Proves a goal in classical logic.
+///
+/// Returns `None` if the goal is not a classical tautology.
+pub fn prove(goal: &Expr) -> Option<Certificate> {
+    profile!("resolution::prove");
+    let cnf = rewrite::to_cnf(&!goal.clone());
+    let input = clauses(&cnf.to).expect("conjunctive normal form");
+    let refutation = refute(input)?;
+    Some(Certificate {cnf, refutation})
+}
+
+/// `(a ⋁ b) ⋀ (¬a ⋁ c)  =>  b ⋁ c`.
+pub fn resolve<A: Prop, B: Prop, C: Prop>(ab: Or<A, B>, nac: Or<Not<A>, C>) -> Or<B, C> {
+    match (ab, nac) {
+        (_, Right(c)) => Right(c),
+        (Right(b), _) => Left(b),
+        (Left(a), Left(na)) => not::absurd(na, a),
+    }
+}
+/// `(¬a => false)  =>  a`.
+pub fn by_refutation<A: DProp>(f: Imply<Not<A>, False>) -> A {not::rev_double(f)}
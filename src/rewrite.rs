@@ -0,0 +1,476 @@
+//! # Certified Rewriting to Normal Forms
+//!
+//! This module converts reflected expressions (see [expr::Expr]) to
+//! negation normal form ([to_nnf]), conjunctive normal form ([to_cnf])
+//! and disjunctive normal form ([to_dnf]), together with a certificate ([Proof])
+//! of the equivalence between the original and converted forms.
+//!
+//! A certificate is a sequence of rewrite steps ([Step]),
+//! each applying a rule ([Rule]) to the sub-term at some path.
+//! [Proof::check] replays the steps, so it does not trust the converter.
+//!
+//! Every rule has a type-level lemma of the same name in this module, e.g. [Rule::DNeg]
+//! corresponds to [dneg]. These lemmas are `Eq` proofs for decidable atoms (`DProp`).
+//! The classical directions are the laws of [boolean::IsBoolean] for propositions,
+//! so the rules are justified by the same laws as the normal forms of [boolean],
+//! which use the converters of this module.
+//! Since `DProp` is closed under the logical operators and `==` is a congruence,
+//! a checked certificate corresponds to a chain of these lemmas using `eq::transitivity`.
+//!
+//! ```rust
+//! use prop::expr;
+//! use prop::check::check;
+//! use prop::rewrite::{to_cnf, Rule};
+//!
+//! let proof = to_cnf(&expr!(Imply<And<A, B>, C>));
+//! assert!(proof.check());
+//! assert!(check(&proof.statement()).is_valid());
+//! assert!(Rule::ALL.iter().all(|r| check(&r.statement()).is_valid()));
+//! ```
+
+use crate::*;
+use boolean::IsBoolean;
+use expr::Expr;
+use heyting::{IsHeyting, Props};
+use std::fmt;
+
+/// A rewrite rule, which is an equivalence for decidable propositions.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Rule {
+    /// `¬¬a  ->  a`, see [dneg].
+    DNeg,
+    /// `¬(a ⋀ b)  ->  ¬a ⋁ ¬b`, see [de_morgan_and].
+    DeMorganAnd,
+    /// `¬(a ⋁ b)  ->  ¬a ⋀ ¬b`, see [de_morgan_or].
+    DeMorganOr,
+    /// `a => b  ->  ¬a ⋁ b`, see [material_imply].
+    MaterialImply,
+    /// `a == b  ->  (a => b) ⋀ (b => a)`, see [eq_def].
+    EqDef,
+    /// `¬true  ->  false`, see [not_true].
+    NotTrue,
+    /// `¬false  ->  true`, see [not_false].
+    NotFalse,
+    /// `a ⋀ true  ->  a`, see [and_true].
+    AndTrue,
+    /// `true ⋀ a  ->  a`, see [true_and].
+    TrueAnd,
+    /// `a ⋀ false  ->  false`, see [and_false].
+    AndFalse,
+    /// `false ⋀ a  ->  false`, see [false_and].
+    FalseAnd,
+    /// `a ⋁ true  ->  true`, see [or_true].
+    OrTrue,
+    /// `true ⋁ a  ->  true`, see [true_or].
+    TrueOr,
+    /// `a ⋁ false  ->  a`, see [or_false].
+    OrFalse,
+    /// `false ⋁ a  ->  a`, see [false_or].
+    FalseOr,
+    /// `a ⋁ (b ⋀ c)  ->  (a ⋁ b) ⋀ (a ⋁ c)`, see [or_distrib_left].
+    OrDistribLeft,
+    /// `(a ⋀ b) ⋁ c  ->  (a ⋁ c) ⋀ (b ⋁ c)`, see [or_distrib_right].
+    OrDistribRight,
+    /// `a ⋀ (b ⋁ c)  ->  (a ⋀ b) ⋁ (a ⋀ c)`, see [and_distrib_left].
+    AndDistribLeft,
+    /// `(a ⋁ b) ⋀ c  ->  (a ⋀ c) ⋁ (b ⋀ c)`, see [and_distrib_right].
+    AndDistribRight,
+}
+
+impl Rule {
+    /// All rules.
+    pub const ALL: [Rule; 19] = {
+        use Rule::*;
+        [
+            DNeg, DeMorganAnd, DeMorganOr, MaterialImply, EqDef, NotTrue, NotFalse,
+            AndTrue, TrueAnd, AndFalse, FalseAnd, OrTrue, TrueOr, OrFalse, FalseOr,
+            OrDistribLeft, OrDistribRight, AndDistribLeft, AndDistribRight,
+        ]
+    };
+
+    /// Applies the rule at the root of an expression.
+    ///
+    /// Returns `None` if the rule does not match.
+    pub fn apply(&self, e: &Expr) -> Option<Expr> {
+        use Expr::*;
+
+        let c = Expr::clone;
+        Some(match (self, e) {
+            (Rule::DNeg, Not(a)) => match &**a {Not(a) => c(a), _ => return None},
+            (Rule::DeMorganAnd, Not(a)) => match &**a {
+                And(a, b) => Expr::or(!c(a), !c(b)),
+                _ => return None,
+            },
+            (Rule::DeMorganOr, Not(a)) => match &**a {
+                Or(a, b) => Expr::and(!c(a), !c(b)),
+                _ => return None,
+            },
+            (Rule::MaterialImply, Imply(a, b)) => Expr::or(!c(a), c(b)),
+            (Rule::EqDef, Eq(a, b)) => Expr::and(Expr::imply(c(a), c(b)), Expr::imply(c(b), c(a))),
+            (Rule::NotTrue, Not(a)) if **a == True => False,
+            (Rule::NotFalse, Not(a)) if **a == False => True,
+            (Rule::AndTrue, And(a, b)) if **b == True => c(a),
+            (Rule::TrueAnd, And(a, b)) if **a == True => c(b),
+            (Rule::AndFalse, And(_, b)) if **b == False => False,
+            (Rule::FalseAnd, And(a, _)) if **a == False => False,
+            (Rule::OrTrue, Or(_, b)) if **b == True => True,
+            (Rule::TrueOr, Or(a, _)) if **a == True => True,
+            (Rule::OrFalse, Or(a, b)) if **b == False => c(a),
+            (Rule::FalseOr, Or(a, b)) if **a == False => c(b),
+            (Rule::OrDistribLeft, Or(a, x)) => match &**x {
+                And(b, x) => Expr::and(Expr::or(c(a), c(b)), Expr::or(c(a), c(x))),
+                _ => return None,
+            },
+            (Rule::OrDistribRight, Or(x, b)) => match &**x {
+                And(x, a) => Expr::and(Expr::or(c(x), c(b)), Expr::or(c(a), c(b))),
+                _ => return None,
+            },
+            (Rule::AndDistribLeft, And(a, x)) => match &**x {
+                Or(b, x) => Expr::or(Expr::and(c(a), c(b)), Expr::and(c(a), c(x))),
+                _ => return None,
+            },
+            (Rule::AndDistribRight, And(x, b)) => match &**x {
+                Or(x, a) => Expr::or(Expr::and(c(x), c(b)), Expr::and(c(a), c(b))),
+                _ => return None,
+            },
+            _ => return None,
+        })
+    }
+
+    /// The left side of the rule, using atoms `a`, `b` and `c`.
+    pub fn pattern(&self) -> Expr {
+        use Rule::*;
+
+        let (a, b, c) = (Expr::atom("a"), Expr::atom("b"), Expr::atom("c"));
+        match self {
+            DNeg => !!a,
+            DeMorganAnd => !Expr::and(a, b),
+            DeMorganOr => !Expr::or(a, b),
+            MaterialImply => Expr::imply(a, b),
+            EqDef => Expr::eq(a, b),
+            NotTrue => !Expr::True,
+            NotFalse => !Expr::False,
+            AndTrue => Expr::and(a, Expr::True),
+            TrueAnd => Expr::and(Expr::True, a),
+            AndFalse => Expr::and(a, Expr::False),
+            FalseAnd => Expr::and(Expr::False, a),
+            OrTrue => Expr::or(a, Expr::True),
+            TrueOr => Expr::or(Expr::True, a),
+            OrFalse => Expr::or(a, Expr::False),
+            FalseOr => Expr::or(Expr::False, a),
+            OrDistribLeft => Expr::or(a, Expr::and(b, c)),
+            OrDistribRight => Expr::or(Expr::and(a, b), c),
+            AndDistribLeft => Expr::and(a, Expr::or(b, c)),
+            AndDistribRight => Expr::and(Expr::or(a, b), c),
+        }
+    }
+
+    /// The equivalence `pattern == result` stated by the rule.
+    pub fn statement(&self) -> Expr {
+        let lhs = self.pattern();
+        let rhs = self.apply(&lhs).expect("rule matches its pattern");
+        Expr::eq(lhs, rhs)
+    }
+
+    fn is_distrib(&self) -> bool {
+        matches!(self, Rule::OrDistribLeft | Rule::OrDistribRight |
+                       Rule::AndDistribLeft | Rule::AndDistribRight)
+    }
+}
+
+impl fmt::Display for Rule {
+    fn fmt(&self, w: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let lhs = self.pattern();
+        let rhs = self.apply(&lhs).expect("rule matches its pattern");
+        write!(w, "{}  ->  {}", lhs, rhs)
+    }
+}
+
+/// A rule applied to the sub-term at a path.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Step {
+    /// The path from the root to the sub-term.
+    pub path: Vec<diff::Step>,
+    /// The rule to apply.
+    pub rule: Rule,
+}
+
+/// A certificate of `from == to`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Proof {
+    /// The original expression.
+    pub from: Expr,
+    /// The converted expression.
+    pub to: Expr,
+    /// The rewrite steps from the original to the converted expression.
+    pub steps: Vec<Step>,
+}
+
+impl Proof {
+    /// Replays the steps from the original expression.
+    ///
+    /// Returns `None` if some step does not apply.
+    pub fn replay(&self) -> Option<Expr> {
+        let mut e = self.from.clone();
+        for step in &self.steps {
+            let x = at_mut(&mut e, &step.path)?;
+            *x = step.rule.apply(x)?;
+        }
+        Some(e)
+    }
+
+    /// Returns `true` if replaying the steps gives the converted expression.
+    pub fn check(&self) -> bool {self.replay().as_ref() == Some(&self.to)}
+
+    /// The equivalence `from == to`.
+    pub fn statement(&self) -> Expr {Expr::eq(self.from.clone(), self.to.clone())}
+}
+
+impl fmt::Display for Proof {
+    fn fmt(&self, w: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(w, "{}", self.from)?;
+        let mut e = self.from.clone();
+        for step in &self.steps {
+            if let Some(x) = at_mut(&mut e, &step.path) {
+                if let Some(y) = step.rule.apply(x) {*x = y}
+            }
+            write!(w, "\n  == {}    ({:?} at ", e, step.rule)?;
+            if step.path.is_empty() {write!(w, "root")?}
+            for (i, s) in step.path.iter().enumerate() {
+                if i > 0 {write!(w, ".")?}
+                write!(w, "{}", s)?;
+            }
+            write!(w, ")")?;
+        }
+        Ok(())
+    }
+}
+
+fn at_mut<'a>(e: &'a mut Expr, path: &[diff::Step]) -> Option<&'a mut Expr> {
+    use Expr::*;
+
+    let mut e = e;
+    for step in path {
+        e = match (step, e) {
+            (diff::Step::Not, Not(a)) => a,
+            (diff::Step::Left, And(a, _) | Or(a, _) | Imply(a, _) | Eq(a, _)) => a,
+            (diff::Step::Right, And(_, b) | Or(_, b) | Imply(_, b) | Eq(_, b)) => b,
+            _ => return None,
+        };
+    }
+    Some(e)
+}
+
+/// Records rewrite steps while converting.
+struct Trace {
+    path: Vec<diff::Step>,
+    steps: Vec<Step>,
+}
+
+impl Trace {
+    fn new() -> Trace {Trace {path: vec![], steps: vec![]}}
+
+    fn finish(self, from: &Expr, to: Expr) -> Proof {
+        Proof {from: from.clone(), to, steps: self.steps}
+    }
+
+    fn rewrite(&mut self, rule: Rule, e: &Expr) -> Expr {
+        let res = rule.apply(e).expect("rule applies");
+        self.steps.push(Step {path: self.path.clone(), rule});
+        res
+    }
+
+    fn at(&mut self, step: diff::Step, f: impl FnOnce(&mut Trace) -> Expr) -> Expr {
+        self.path.push(step);
+        let res = f(self);
+        self.path.pop();
+        res
+    }
+
+    /// Maps the arguments of `⋀` and `⋁`.
+    fn args(&mut self, e: Expr, f: impl Fn(&mut Trace, Expr) -> Expr) -> Expr {
+        match e {
+            Expr::And(a, b) => {
+                let a = self.at(diff::Step::Left, |t| f(t, *a));
+                Expr::and(a, self.at(diff::Step::Right, |t| f(t, *b)))
+            }
+            Expr::Or(a, b) => {
+                let a = self.at(diff::Step::Left, |t| f(t, *a));
+                Expr::or(a, self.at(diff::Step::Right, |t| f(t, *b)))
+            }
+            _ => e,
+        }
+    }
+
+    fn nnf(&mut self, e: Expr) -> Expr {
+        use Expr::*;
+
+        let mut e = e;
+        loop {
+            e = match &e {
+                Imply(..) => self.rewrite(Rule::MaterialImply, &e),
+                Eq(..) => self.rewrite(Rule::EqDef, &e),
+                Not(a) => match &**a {
+                    True => self.rewrite(Rule::NotTrue, &e),
+                    False => self.rewrite(Rule::NotFalse, &e),
+                    Not(_) => self.rewrite(Rule::DNeg, &e),
+                    And(..) => self.rewrite(Rule::DeMorganAnd, &e),
+                    Or(..) => self.rewrite(Rule::DeMorganOr, &e),
+                    Imply(..) => !self.at(diff::Step::Not, |t| t.rewrite(Rule::MaterialImply, a)),
+                    Eq(..) => !self.at(diff::Step::Not, |t| t.rewrite(Rule::EqDef, a)),
+                    Atom(_) => break,
+                },
+                And(..) | Or(..) => return self.args(e, Trace::nnf),
+                True | False | Atom(_) => break,
+            };
+        }
+        e
+    }
+
+    /// Normalizes a negation normal form, where `dnf` selects DNF or CNF.
+    fn normal(&mut self, e: Expr, dnf: bool) -> Expr {
+        let e = self.args(e, |t, x| t.normal(x, dnf));
+        self.node(e, dnf)
+    }
+
+    /// Normalizes an expression whose arguments are normalized.
+    fn node(&mut self, e: Expr, dnf: bool) -> Expr {
+        let rule = match node_rule(&e, dnf) {Some(x) => x, None => return e};
+        let e = self.rewrite(rule, &e);
+        if rule.is_distrib() {self.args(e, |t, x| t.node(x, dnf))} else {e}
+    }
+}
+
+/// Eliminates `true` and `false`, or distributes the inner operator over the outer one.
+fn node_rule(e: &Expr, dnf: bool) -> Option<Rule> {
+    use Expr::*;
+
+    Some(match e {
+        And(_, b) if **b == True => Rule::AndTrue,
+        And(a, _) if **a == True => Rule::TrueAnd,
+        And(_, b) if **b == False => Rule::AndFalse,
+        And(a, _) if **a == False => Rule::FalseAnd,
+        Or(_, b) if **b == True => Rule::OrTrue,
+        Or(a, _) if **a == True => Rule::TrueOr,
+        Or(_, b) if **b == False => Rule::OrFalse,
+        Or(a, _) if **a == False => Rule::FalseOr,
+        And(a, _) if dnf && matches!(**a, Or(..)) => Rule::AndDistribRight,
+        And(_, b) if dnf && matches!(**b, Or(..)) => Rule::AndDistribLeft,
+        Or(a, _) if !dnf && matches!(**a, And(..)) => Rule::OrDistribRight,
+        Or(_, b) if !dnf && matches!(**b, And(..)) => Rule::OrDistribLeft,
+        _ => return None,
+    })
+}
+
+/// Converts into negation normal form with a certificate.
+pub fn to_nnf(e: &Expr) -> Proof {
+    let mut trace = Trace::new();
+    let to = trace.nnf(e.clone());
+    trace.finish(e, to)
+}
+
+/// Converts into conjunctive normal form with a certificate.
+///
+/// The result is `true`, `false` or a conjunction of disjunctions of literals.
+pub fn to_cnf(e: &Expr) -> Proof {
+    let mut trace = Trace::new();
+    let x = trace.nnf(e.clone());
+    let to = trace.normal(x, false);
+    trace.finish(e, to)
+}
+
+/// Converts into disjunctive normal form with a certificate.
+///
+/// The result is `true`, `false` or a disjunction of conjunctions of literals.
+pub fn to_dnf(e: &Expr) -> Proof {
+    let mut trace = Trace::new();
+    let x = trace.nnf(e.clone());
+    let to = trace.normal(x, true);
+    trace.finish(e, to)
+}
+
+/// `¬¬a == a`, from [IsBoolean::dneg].
+pub fn dneg<A: DProp>() -> Eq<Not<Not<A>>, A> {(Props::dneg(), Rc::new(not::double))}
+/// `¬(a ⋀ b) == ¬a ⋁ ¬b`, from [IsBoolean::de_morgan_meet].
+pub fn de_morgan_and<A: DProp, B: DProp>() -> Eq<Not<And<A, B>>, Or<Not<A>, Not<B>>> {
+    (Props::de_morgan_meet(), Rc::new(or::to_de_morgan))
+}
+/// `¬(a ⋁ b) == ¬a ⋀ ¬b`, from [IsBoolean::de_morgan_join].
+pub fn de_morgan_or<A: DProp, B: DProp>() -> Eq<Not<Or<A, B>>, And<Not<A>, Not<B>>> {
+    (Props::de_morgan_join(), Rc::new(and::to_de_morgan))
+}
+/// `(a => b) == ¬a ⋁ b`, from [IsBoolean::material_imply].
+pub fn material_imply<A: DProp, B: DProp>() -> Eq<Imply<A, B>, Or<Not<A>, B>> {
+    (Props::material_imply(), Rc::new(imply::from_or))
+}
+/// `(a == b) == (a => b) ⋀ (b => a)`.
+pub fn eq_def<A: DProp, B: DProp>() -> Eq<Eq<A, B>, And<Imply<A, B>, Imply<B, A>>> {
+    tautos::eq_def()(True)
+}
+/// `¬true == false`.
+pub fn not_true() -> Eq<Not<True>, False> {(Rc::new(|nt| nt(True)), imply::absurd())}
+/// `¬false == true`.
+pub fn not_false() -> Eq<Not<False>, True> {(True.map_any(), Rc::new(|_| Rc::new(|f| f)))}
+/// `a ⋀ true == a`.
+pub fn and_true<A: DProp>() -> Eq<And<A, True>, A> {tautos::and_true()(True)}
+/// `true ⋀ a == a`.
+pub fn true_and<A: DProp>() -> Eq<And<True, A>, A> {(Rc::new(and::snd), Rc::new(|a| (True, a)))}
+/// `a ⋀ false == false`.
+pub fn and_false<A: DProp>() -> Eq<And<A, False>, False> {tautos::and_false()(True)}
+/// `false ⋀ a == false`.
+pub fn false_and<A: DProp>() -> Eq<And<False, A>, False> {(Rc::new(and::fst), imply::absurd())}
+/// `a ⋁ true == true`.
+pub fn or_true<A: DProp>() -> Eq<Or<A, True>, True> {tautos::or_true()(True)}
+/// `true ⋁ a == true`.
+pub fn true_or<A: DProp>() -> Eq<Or<True, A>, True> {(True.map_any(), Rc::new(Left))}
+/// `a ⋁ false == a`.
+pub fn or_false<A: DProp>() -> Eq<Or<A, False>, A> {tautos::or_false()(True)}
+/// `false ⋁ a == a`.
+pub fn false_or<A: DProp>() -> Eq<Or<False, A>, A> {
+    (Rc::new(|x| match x {
+        Left(fa) => match fa {},
+        Right(a) => a,
+    }), Rc::new(Right))
+}
+/// `a ⋁ (b ⋀ c) == (a ⋁ b) ⋀ (a ⋁ c)`, from [IsBoolean::join_distrib].
+pub fn or_distrib_left<A: DProp, B: DProp, C: DProp>() ->
+    Eq<Or<A, And<B, C>>, And<Or<A, B>, Or<A, C>>>
+{
+    (Props::join_distrib(), tautos::or_distrib()(True).1)
+}
+/// `(a ⋀ b) ⋁ c == (a ⋁ c) ⋀ (b ⋁ c)`.
+pub fn or_distrib_right<A: DProp, B: DProp, C: DProp>() ->
+    Eq<Or<And<A, B>, C>, And<Or<A, C>, Or<B, C>>>
+{
+    (
+        Rc::new(|x| match x {
+            Left((a, b)) => (Left(a), Left(b)),
+            Right(c) => (Right(c.clone()), Right(c)),
+        }),
+        Rc::new(|x| match x {
+            (Right(c), _) | (_, Right(c)) => Right(c),
+            (Left(a), Left(b)) => Left((a, b)),
+        })
+    )
+}
+/// `a ⋀ (b ⋁ c) == (a ⋀ b) ⋁ (a ⋀ c)`, from [IsHeyting::distrib].
+pub fn and_distrib_left<A: DProp, B: DProp, C: DProp>() ->
+    Eq<And<A, Or<B, C>>, Or<And<A, B>, And<A, C>>>
+{
+    (Props::distrib(), tautos::and_distrib()(True).1)
+}
+/// `(a ⋁ b) ⋀ c == (a ⋀ c) ⋁ (b ⋀ c)`.
+pub fn and_distrib_right<A: DProp, B: DProp, C: DProp>() ->
+    Eq<And<Or<A, B>, C>, Or<And<A, C>, And<B, C>>>
+{
+    (
+        Rc::new(|(ab, c)| match ab {
+            Left(a) => Left((a, c)),
+            Right(b) => Right((b, c)),
+        }),
+        Rc::new(|x| match x {
+            Left((a, c)) => (Left(a), c),
+            Right((b, c)) => (Right(b), c),
+        })
+    )
+}
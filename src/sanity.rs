@@ -0,0 +1,365 @@
+//! # Sanity Checking of Axioms
+//!
+//! Axioms in this library are functions without proofs. A bad combination of axioms
+//! might make it possible to prove `false`, such that every theorem becomes trivial.
+//!
+//! This module composes reflected statements of axioms (see [expr::Expr])
+//! and searches for combinations that collapse:
+//!
+//! - Inconsistent: The combination has no model, so `false` is derivable in classical logic
+//! - Collapsed atom: The combination forces an atom to a fixed truth value,
+//!   which is often a sign that an axiom is stronger than intended
+//!
+//! Only minimal combinations are reported, e.g. when `{a, b}` is inconsistent,
+//! then `{a, b, c}` is not reported.
+//!
+//! Downstream crates that add their own axioms can use [Sanity] with reflected statements
+//! of their axioms, together with those they depend upon.
+//!
+//! ### Library axioms
+//!
+//! [library] reflects the axioms of the modules in [MODULES], which are stated with
+//! operators on propositions, e.g. `O(a)  =>  ¬O(¬a)` in [deontic].
+//! The model is an abstraction of the operators:
+//! Every application of an operator, e.g. `O(¬a)` or `a^true`, is reflected as an atom ([app]).
+//! Axiom schemas are instantiated with `a` and `¬a` for each argument,
+//! such that the instances of different axioms share atoms.
+//!
+//! The reflected axioms are those that are stated as propositions with operators.
+//! The axioms of [fun] and [hott], which are about types and functions in type theory,
+//! and axioms without a statement as a proposition, e.g. [nat::lt_neq], are not reflected.
+//! These are listed by [uncovered].
+//!
+//! A model of the reflected axioms is not a model of the library,
+//! but when the reflected axioms are inconsistent, then so are the axioms of the library,
+//! since the abstraction only forgets what the operators mean.
+//!
+//! ```rust
+//! use prop::axiom;
+//! use prop::expr::Expr;
+//! use prop::sanity::{app, library, Finding};
+//!
+//! for (module, sanity) in library() {
+//!     for (name, _) in &sanity.axioms {
+//!         assert!(axiom::axioms().iter().any(|ax| ax.name == name), "{}", name);
+//!     }
+//!     let findings = sanity.check();
+//!     assert!(sanity.is_consistent(), "{}", module);
+//!     assert!(!findings.iter().any(|f| matches!(f, Finding::TooManyAtoms(_))));
+//! }
+//!
+//! // A conflict of obligations contradicts `deontic::obl_d`.
+//! let (_, deontic) = library().into_iter().find(|(m, _)| *m == "deontic").unwrap();
+//! let o = |x: &Expr| app("O", x);
+//! let a = Expr::atom("a");
+//! let deontic = deontic.axiom("conflict", Expr::and(o(&a), o(&!a.clone())));
+//! assert!(!deontic.is_consistent());
+//!
+//! // The enabled axioms that are not sanity checked.
+//! let covered: usize = library().iter().map(|(_, s)| s.axioms.len()).sum();
+//! let uncovered = prop::sanity::uncovered();
+//! assert_eq!(covered + uncovered.len(), axiom::axioms().len());
+//! assert!(uncovered.iter().all(|ax| !ax.name.starts_with("deontic::")));
+//! for ax in &uncovered {println!("not sanity checked: {}", ax)}
+//! ```
+
+use crate::*;
+use check::{Checker, Report};
+use expr::Expr;
+
+/// The maximum size of axiom combinations that are checked by default.
+pub const MAX_COMBINATION: usize = 3;
+
+/// A problem found with a combination of axioms.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Finding {
+    /// The axioms have no model.
+    Inconsistent(Vec<String>),
+    /// The axioms force an atom to a truth value.
+    Collapse {
+        /// The names of the axioms.
+        axioms: Vec<String>,
+        /// The atom.
+        atom: String,
+        /// The forced truth value.
+        value: bool,
+    },
+    /// There were too many atoms to check the axioms.
+    TooManyAtoms(Vec<String>),
+}
+
+impl std::fmt::Display for Finding {
+    fn fmt(&self, w: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Finding::Inconsistent(a) => write!(w, "inconsistent: {}", a.join(", ")),
+            Finding::Collapse {axioms, atom, value} =>
+                write!(w, "{} = {} is forced by: {}", atom, value, axioms.join(", ")),
+            Finding::TooManyAtoms(a) => write!(w, "too many atoms: {}", a.join(", ")),
+        }
+    }
+}
+
+/// Composes reflected axioms and searches for collapsing combinations.
+#[derive(Clone, Debug, Default)]
+pub struct Sanity {
+    /// Named reflected axioms.
+    pub axioms: Vec<(String, Expr)>,
+    /// The maximum size of axiom combinations to check.
+    pub max_combination: Option<usize>,
+    /// The counterexample checker used to decide validity.
+    pub checker: Checker,
+}
+
+impl Sanity {
+    /// Creates a new sanity checker.
+    pub fn new() -> Sanity {Sanity::default()}
+
+    /// Adds a reflected axiom.
+    pub fn axiom(mut self, name: &str, statement: Expr) -> Sanity {
+        self.axioms.push((name.into(), statement));
+        self
+    }
+
+    /// Checks all combinations of axioms, up to the maximum combination size.
+    pub fn check(&self) -> Vec<Finding> {
+        let n = self.axioms.len();
+        let max = self.max_combination.unwrap_or(MAX_COMBINATION).min(n);
+        let mut inconsistent: Vec<Vec<usize>> = vec![];
+        let mut forced: Vec<(Vec<usize>, String)> = vec![];
+        let mut res = vec![];
+        for size in 1..=max {
+            for comb in combinations(n, size) {
+                if inconsistent.iter().any(|a| a.iter().all(|i| comb.contains(i))) {continue}
+
+                let names: Vec<String> = comb.iter().map(|&i| self.axioms[i].0.clone()).collect();
+                let conj = comb.iter().map(|&i| self.axioms[i].1.clone())
+                    .reduce(Expr::and).unwrap_or(Expr::True);
+                match self.checker.check(&!conj.clone()) {
+                    Report::Valid => {
+                        inconsistent.push(comb);
+                        res.push(Finding::Inconsistent(names));
+                        continue;
+                    }
+                    Report::TooManyAtoms {..} => {
+                        res.push(Finding::TooManyAtoms(names));
+                        continue;
+                    }
+                    Report::Counterexample(_) => {}
+                }
+                for atom in conj.atoms() {
+                    let covered = |(a, x): &(Vec<usize>, String)| {
+                        x == &atom && a.iter().all(|i| comb.contains(i))
+                    };
+                    if forced.iter().any(covered) {continue}
+                    for value in [true, false] {
+                        let lit = Expr::Atom(atom.clone());
+                        let lit = if value {lit} else {!lit};
+                        if self.checker.check(&Expr::imply(conj.clone(), lit)).is_valid() {
+                            forced.push((comb.clone(), atom.clone()));
+                            res.push(Finding::Collapse {
+                                axioms: names.clone(),
+                                atom: atom.clone(),
+                                value,
+                            });
+                        }
+                    }
+                }
+            }
+        }
+        res
+    }
+
+    /// Returns `true` if no combination of axioms is inconsistent.
+    pub fn is_consistent(&self) -> bool {
+        !self.check().iter().any(|f| matches!(f, Finding::Inconsistent(_)))
+    }
+}
+
+/// Enumerates combinations of `k` indices out of `n` in lexicographic order.
+fn combinations(n: usize, k: usize) -> Vec<Vec<usize>> {
+    fn rec(start: usize, n: usize, k: usize, cur: &mut Vec<usize>, res: &mut Vec<Vec<usize>>) {
+        if cur.len() == k {return res.push(cur.clone())}
+        for i in start..n {
+            cur.push(i);
+            rec(i + 1, n, k, cur, res);
+            cur.pop();
+        }
+    }
+
+    let mut res = vec![];
+    rec(0, n, k, &mut vec![], &mut res);
+    res
+}
+
+/// The modules with axioms that are reflected by [library].
+pub const MODULES: &[&str] = &[
+    "cf", "con_qubit", "deontic", "epistemic", "fixpoint", "hooo", "modal", "plogic", "prov",
+    "queenity", "qubit", "uip", "univalence", "yoneda",
+];
+
+/// Reflects an operator applied to a proposition as an atom, e.g. `O(a)`.
+pub fn app(op: &str, a: &Expr) -> Expr {Expr::Atom(format!("{}({})", op, a))}
+
+/// Reflects `a^true` as an atom.
+pub fn tauto(a: &Expr) -> Expr {Expr::Atom(format!("({})^true", a))}
+
+/// Reflects `a^b` as an atom, where `a^true` is reflected by [tauto()].
+pub fn pow(a: &Expr, b: &Expr) -> Expr {
+    if *b == Expr::True {tauto(a)} else {Expr::Atom(format!("({})^({})", a, b))}
+}
+
+/// Returns the enabled axioms that are not reflected by [library].
+pub fn uncovered() -> Vec<&'static axiom::AxiomInfo> {
+    let lib = library();
+    axiom::axioms().into_iter()
+        .filter(|ax| !lib.iter().any(|(_, s)| s.axioms.iter().any(|(n, _)| n == ax.name)))
+        .collect()
+}
+
+/// Instantiates a schema with `a` and `¬a`.
+fn inst1(f: impl Fn(&Expr) -> Expr) -> Expr {
+    let a = Expr::atom("a");
+    Expr::and(f(&a), f(&!a.clone()))
+}
+
+/// Instantiates a schema with two arguments, using `a` and `¬a`.
+fn inst2(f: impl Fn(&Expr, &Expr) -> Expr) -> Expr {
+    let a = Expr::atom("a");
+    let na = !a.clone();
+    Expr::and(f(&a, &na), f(&na, &a))
+}
+
+/// Returns the reflected axioms of the library, grouped by module in [MODULES].
+///
+/// Only axioms enabled by the current feature set are included (see [axiom::axioms]).
+pub fn library() -> Vec<(&'static str, Sanity)> {
+    use Expr as E;
+
+    let imp = E::imply;
+    let (a, b, c) = (E::atom("a"), E::atom("b"), E::atom("c"));
+    let i = E::atom("i");
+    let o = |x: &E| app("O", x);
+    let k = |x: &E| app("K{i}", x);
+    let ann = |x: &E, y: &E| E::Atom(format!("[!{}]({})", x, y));
+    let at = |x: &E| app("@{i}", x);
+    let prov = |x: &E| app("prov", x);
+    let qu = |x: &E| app("~", x);
+    let cq = |x: &E| app(".~", x);
+    let cf = |x: &E, y: &E| E::Atom(format!("({}) □→ ({})", x, y));
+    let bin = |op: &'static str| move |x: &E, y: &E| E::Atom(format!("({}) {} ({})", x, op, y));
+    let (ty, q, nq, lt) = (bin(":"), bin("~~"), bin("¬>"), bin("<"));
+    let f = |x: &E| app("f", x);
+    let (gfp, lfp) = (E::atom("gfp(f)"), E::atom("lfp(f)"));
+    let indep = |x: &E, y: &E| E::Atom(format!("indep({}, {})", x, y));
+    let w = |x: &E, p: &str| E::Atom(format!("w({}, {})", x, p));
+    let (x, y) = (E::atom("x"), E::atom("y"));
+
+    let axioms: Vec<(&str, &str, E)> = vec![
+        ("cf", "cf::cf_and",
+            imp(E::and(cf(&a, &b), cf(&a, &c)), cf(&a, &E::and(b.clone(), c.clone())))),
+        ("cf", "cf::cf_centering", imp(E::and(a.clone(), b.clone()), cf(&a, &b))),
+        ("cf", "cf::cf_in_left_arg",
+            imp(E::and(tauto(&E::eq(a.clone(), b.clone())), cf(&a, &c)), cf(&b, &c))),
+        ("cf", "cf::cf_mp", imp(cf(&a, &b), imp(a.clone(), b.clone()))),
+        ("cf", "cf::cf_or",
+            imp(E::and(cf(&a, &c), cf(&b, &c)), cf(&E::or(a.clone(), b.clone()), &c))),
+        ("cf", "cf::cf_refl", cf(&a, &a)),
+        ("cf", "cf::cf_strict", imp(tauto(&imp(a.clone(), b.clone())), cf(&a, &b))),
+        ("cf", "cf::cf_weaken",
+            imp(E::and(cf(&a, &b), tauto(&imp(b.clone(), c.clone()))), cf(&a, &c))),
+        ("con_qubit", "con_qubit::from_neg", inst1(|x| imp(!x.clone(), !!cq(x)))),
+        ("con_qubit", "con_qubit::to_excm", inst1(|x| imp(!!cq(x), E::or(x.clone(), !x.clone())))),
+        ("deontic", "deontic::obl_d", inst1(|x| imp(o(x), !o(&!x.clone())))),
+        ("deontic", "deontic::obl_distrib",
+            inst2(|x, y| imp(o(&imp(x.clone(), y.clone())), imp(o(x), o(y))))),
+        ("deontic", "deontic::obl_nec", inst1(|x| imp(tauto(x), o(x)))),
+        ("epistemic", "epistemic::announce_and",
+            E::eq(ann(&a, &E::and(b.clone(), c.clone())), E::and(ann(&a, &b), ann(&a, &c)))),
+        ("epistemic", "epistemic::announce_atom", E::eq(ann(&a, &b), imp(a.clone(), b.clone()))),
+        ("epistemic", "epistemic::announce_comp",
+            E::eq(ann(&a, &ann(&b, &c)), ann(&E::and(a.clone(), ann(&a, &b)), &c))),
+        ("epistemic", "epistemic::announce_knows",
+            E::eq(ann(&a, &k(&b)), imp(a.clone(), k(&ann(&a, &b))))),
+        ("epistemic", "epistemic::announce_not",
+            E::eq(ann(&a, &!b.clone()), imp(a.clone(), !ann(&a, &b)))),
+        ("epistemic", "epistemic::knows_distrib",
+            inst2(|x, y| imp(k(&imp(x.clone(), y.clone())), imp(k(x), k(y))))),
+        ("epistemic", "epistemic::knows_five", inst1(|x| imp(!k(x), k(&!k(x))))),
+        ("epistemic", "epistemic::knows_four", inst1(|x| imp(k(x), k(&k(x))))),
+        ("epistemic", "epistemic::knows_nec", inst1(|x| imp(tauto(x), k(x)))),
+        ("epistemic", "epistemic::knows_t", inst1(|x| imp(k(x), x.clone()))),
+        ("fixpoint", "fixpoint::gfp_coinduction", inst1(|x| imp(pow(&f(x), x), pow(&gfp, x)))),
+        ("fixpoint", "fixpoint::gfp_unfold", pow(&f(&gfp), &gfp)),
+        ("fixpoint", "fixpoint::lfp_fold", pow(&lfp, &f(&lfp))),
+        ("fixpoint", "fixpoint::lfp_induction", inst1(|x| imp(pow(x, &f(x)), pow(x, &lfp)))),
+        ("hooo", "hooo::hooo_rev_ty",
+            imp(ty(&pow(&b, &a), &pow(&y, &x)), imp(ty(&a, &x), ty(&b, &y)))),
+        ("hooo", "hooo::lift_q",
+            inst2(|x, y| imp(E::and(E::eq(x.clone(), y.clone()),
+                app("theory", &E::eq(x.clone(), y.clone()))), q(x, y)))),
+        ("hooo", "hooo::pow_lift", inst2(|x, y| imp(pow(x, y), pow(&pow(x, y), &c)))),
+        ("hooo", "hooo::tauto_hooo_imply",
+            inst2(|x, y| imp(pow(&imp(x.clone(), y.clone()), &c),
+                tauto(&imp(pow(x, &c), pow(y, &c)))))),
+        ("hooo", "hooo::tauto_hooo_or",
+            inst2(|x, y| imp(pow(&E::or(x.clone(), y.clone()), &c),
+                tauto(&E::or(pow(x, &c), pow(y, &c)))))),
+        ("hooo", "hooo::tauto_hooo_pord",
+            inst2(|x, y| imp(pow(&lt(x, y), &c), tauto(&lt(&pow(x, &c), &pow(y, &c)))))),
+        ("hooo", "hooo::tauto_hooo_ty",
+            imp(pow(&ty(&b, &y), &ty(&a, &x)), tauto(&ty(&pow(&b, &a), &pow(&y, &x))))),
+        ("modal", "modal::n", inst1(|x| imp(tauto(x), tauto(&app("□", x))))),
+        ("modal", "modal::hybrid::at_agree", inst1(|x| imp(app("@{j}", &at(x)), at(x)))),
+        ("modal", "modal::hybrid::at_back", inst1(|x| imp(at(x), app("□", &at(x))))),
+        ("modal", "modal::hybrid::at_distrib",
+            inst2(|x, y| imp(at(&imp(x.clone(), y.clone())), imp(at(x), at(y))))),
+        ("modal", "modal::hybrid::at_intro", inst1(|x| imp(E::and(i.clone(), x.clone()), at(x)))),
+        ("modal", "modal::hybrid::at_nec", inst1(|x| imp(tauto(x), at(x)))),
+        ("modal", "modal::hybrid::at_not_to_not_at", inst1(|x| imp(at(&!x.clone()), !at(x)))),
+        ("modal", "modal::hybrid::at_refl", at(&i)),
+        ("modal", "modal::hybrid::not_at_to_at_not", inst1(|x| imp(!at(x), at(&!x.clone())))),
+        ("modal", "modal::hybrid::pos_to_at",
+            inst1(|x| imp(app("◇", &E::and(i.clone(), x.clone())), at(x)))),
+        ("plogic", "plogic::indep_not",
+            inst2(|x, y| imp(indep(x, y), indep(&!x.clone(), &!y.clone())))),
+        ("plogic", "plogic::indep_symmetry", inst2(|x, y| imp(indep(x, y), indep(y, x)))),
+        ("plogic", "plogic::w_and",
+            imp(E::and(E::and(w(&a, "1/2"), w(&b, "1/2")), indep(&a, &b)),
+                w(&E::and(a.clone(), b.clone()), "1/4"))),
+        ("plogic", "plogic::w_false", w(&E::False, "0/1")),
+        ("plogic", "plogic::w_in_arg",
+            inst2(|x, y| imp(E::and(w(x, "1/2"), tauto(&E::eq(x.clone(), y.clone()))),
+                w(y, "1/2")))),
+        ("plogic", "plogic::w_not", inst1(|x| imp(w(x, "1/2"), w(&!x.clone(), "1/2")))),
+        ("plogic", "plogic::w_true", w(&E::True, "1/1")),
+        ("prov", "prov::lob", inst1(|x| imp(prov(&imp(prov(x), x.clone())), prov(x)))),
+        ("prov", "prov::prov_distrib",
+            inst2(|x, y| imp(prov(&imp(x.clone(), y.clone())), imp(prov(x), prov(y))))),
+        ("prov", "prov::prov_internal", inst1(|x| imp(prov(x), prov(&prov(x))))),
+        ("prov", "prov::prov_nec", inst1(|x| imp(tauto(x), prov(x)))),
+        ("queenity", "queenity::nsq_left",
+            inst2(|x, y| imp(E::and(nq(x, y), imp(E::eq(x.clone(), y.clone()), q(x, y))),
+                !nq(x, x)))),
+        ("queenity", "queenity::to_sesh", inst2(|x, y| imp(nq(x, y), !q(x, y)))),
+        ("qubit", "qubit::decide_tauto_excm",
+            inst1(|x| imp(tauto(&E::or(x.clone(), !x.clone())), E::or(qu(x), !qu(x))))),
+        ("qubit", "qubit::inv_to_sesh", inst1(|x| imp(qu(&!x.clone()), !qu(x)))),
+        ("qubit", "qubit::sesh_to_inv", inst1(|x| imp(!qu(x), qu(&!x.clone())))),
+        ("uip", "uip::uip",
+            imp(E::and(ty(&x, &E::eq(a.clone(), b.clone())), ty(&y, &E::eq(a.clone(), b.clone()))),
+                E::eq(x.clone(), y.clone()))),
+        ("univalence", "univalence::eq_lift",
+            inst2(|x, y| imp(E::eq(E::eq(x.clone(), y.clone()), q(x, y)),
+                q(&E::eq(x.clone(), y.clone()), &q(x, y))))),
+        ("yoneda", "yoneda::hom_def",
+            E::eq(E::Atom(format!("hom({})({})", a, x)), imp(a.clone(), x.clone()))),
+    ];
+
+    let enabled = axiom::axioms();
+    MODULES.iter().map(|&m| {
+        let sanity = axioms.iter()
+            .filter(|(n, name, _)| *n == m && enabled.iter().any(|ax| ax.name == *name))
+            .fold(Sanity::new(), |s, (_, name, e)| s.axiom(name, e.clone()));
+        (m, sanity)
+    }).collect()
+}
@@ -0,0 +1,218 @@
+//! # Stratification Checking
+//!
+//! The cumulative hierarchy `type(0) : type(1) : type(2) : ...` (see [fun::Type])
+//! is consistent only when every level is contained in a strictly higher level.
+//! An axiom that states `type(n) : type(n)` allows Girard's paradox,
+//! which proves `false` without any other assumption.
+//!
+//! This module reads reflected statements of axioms in type-level syntax
+//! and checks every judgement `a : b` ([Ty](path_semantics::Ty)) between levels:
+//!
+//! - Type in type: A level contains itself, e.g. `type(n) : type(n)`
+//! - Descending: A level is contained in a lower level, e.g. `type(n+1) : type(n)`
+//! - Undetermined: The order depends on how levels are instantiated,
+//!   e.g. `type(n) : type(m)`
+//!
+//! The top level `type(ω)` ([fun::type_omega::TypeOmega]) contains every level,
+//! but is not contained in any level.
+//!
+//! Statements are given as type-level syntax, either written by hand
+//! or from the type of an axiom with [Strat::axiom_of]:
+//!
+//! ```rust
+//! use prop::strat::Strat;
+//!
+//! let findings = Strat::new()
+//!     .axiom("type_ty", "Ty<Type<N>, Type<S<N>>>")
+//!     .axiom("type_in_type", "Ty<Type<N>, Type<N>>")
+//!     .check();
+//! assert_eq!(findings.len(), 1);
+//! ```
+//!
+//! Downstream crates that add their own axioms about levels can use [Strat]
+//! to catch such mistakes before they are used in proofs.
+
+use std::fmt;
+
+use crate::*;
+use expr::Ty;
+
+/// A reflected level of the type hierarchy.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Level {
+    /// A constant level `k`.
+    Const(usize),
+    /// A variable level with an offset `n+k`.
+    Var(String, usize),
+    /// The top level `ω`.
+    Omega,
+}
+
+impl Level {
+    /// Returns `Some(true)` if the level is strictly less than the other for all instantiations,
+    /// `Some(false)` if it is never strictly less, and `None` otherwise.
+    pub fn lt(&self, other: &Level) -> Option<bool> {
+        use Level::*;
+
+        match (self, other) {
+            (Omega, _) => Some(false),
+            (_, Omega) => Some(true),
+            (Const(a), Const(b)) => Some(a < b),
+            (Var(x, a), Var(y, b)) if x == y => Some(a < b),
+            (Const(a), Var(_, b)) if a < b => Some(true),
+            (Var(_, a), Const(b)) if a >= b => Some(false),
+            _ => None,
+        }
+    }
+
+    fn from_ty(ty: &Ty) -> Level {
+        match ty {
+            Ty::Path(name, args) => match (name.rsplit("::").next().unwrap(), args.len()) {
+                ("Z", 0) | ("Zero", 0) => Level::Const(0),
+                ("One", 0) => Level::Const(1),
+                ("Two", 0) => Level::Const(2),
+                ("Three", 0) => Level::Const(3),
+                ("S", 1) => match Level::from_ty(&args[0]) {
+                    Level::Const(k) => Level::Const(k + 1),
+                    Level::Var(x, k) => Level::Var(x, k + 1),
+                    Level::Omega => Level::Omega,
+                },
+                _ => Level::Var(ty.to_string(), 0),
+            },
+            _ => Level::Var(ty.to_string(), 0),
+        }
+    }
+
+    /// Returns the level of a type, e.g. `n` for `type(n)`.
+    fn of_type(ty: &Ty) -> Option<Level> {
+        match ty {
+            Ty::Path(name, args) => match (name.rsplit("::").next().unwrap(), args.len()) {
+                ("Type", 1) => Some(Level::from_ty(&args[0])),
+                ("TypeOmega", 0) => Some(Level::Omega),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Level {
+    fn fmt(&self, w: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Level::Const(k) => write!(w, "{}", k),
+            Level::Var(x, 0) => write!(w, "{}", x),
+            Level::Var(x, k) => write!(w, "{}+{}", x, k),
+            Level::Omega => write!(w, "ω"),
+        }
+    }
+}
+
+/// A problem found with the stratification of an axiom.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Finding {
+    /// A level contains itself.
+    TypeInType {
+        /// The name of the axiom.
+        axiom: String,
+        /// The level.
+        level: Level,
+    },
+    /// A level is contained in a lower level.
+    Descending {
+        /// The name of the axiom.
+        axiom: String,
+        /// The level of the member.
+        member: Level,
+        /// The level of the type.
+        ty: Level,
+    },
+    /// Whether the member is at a lower level depends on the instantiation.
+    Undetermined {
+        /// The name of the axiom.
+        axiom: String,
+        /// The level of the member.
+        member: Level,
+        /// The level of the type.
+        ty: Level,
+    },
+    /// The statement could not be parsed.
+    Malformed(String),
+}
+
+impl fmt::Display for Finding {
+    fn fmt(&self, w: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Finding::TypeInType {axiom, level} =>
+                write!(w, "{}: type({}) : type({})", axiom, level, level),
+            Finding::Descending {axiom, member, ty} =>
+                write!(w, "{}: type({}) : type({}) is descending", axiom, member, ty),
+            Finding::Undetermined {axiom, member, ty} =>
+                write!(w, "{}: type({}) : type({}) is undetermined", axiom, member, ty),
+            Finding::Malformed(axiom) => write!(w, "{}: malformed statement", axiom),
+        }
+    }
+}
+
+/// Checks that reflected axioms respect the stratification of the type hierarchy.
+#[derive(Clone, Debug, Default)]
+pub struct Strat {
+    /// Named statements in type-level syntax.
+    pub axioms: Vec<(String, String)>,
+}
+
+impl Strat {
+    /// Creates a new stratification checker.
+    pub fn new() -> Strat {Strat::default()}
+
+    /// Adds a statement in type-level syntax, e.g. `Ty<Type<N>, Type<S<N>>>`.
+    pub fn axiom(mut self, name: &str, statement: &str) -> Strat {
+        self.axioms.push((name.into(), statement.into()));
+        self
+    }
+
+    /// Adds the statement of an axiom from its type.
+    ///
+    /// Judgements are recognized by their order proofs, since `Ty` is a type alias.
+    pub fn axiom_of<T>(self, name: &str) -> Strat {self.axiom(name, std::any::type_name::<T>())}
+
+    /// Checks every judgement between levels.
+    pub fn check(&self) -> Vec<Finding> {
+        let mut res = vec![];
+        for (name, statement) in &self.axioms {
+            match expr::parse(statement) {
+                None => res.push(Finding::Malformed(name.clone())),
+                Some(ty) => check_ty(name, &ty, &mut res),
+            }
+        }
+        res
+    }
+
+    /// Returns `true` if no axiom puts a level in itself or in a lower level.
+    pub fn is_stratified(&self) -> bool {
+        !self.check().iter().any(|f| matches!(f,
+            Finding::TypeInType {..} | Finding::Descending {..} | Finding::Malformed(_)))
+    }
+}
+
+fn check_ty(axiom: &str, ty: &Ty, res: &mut Vec<Finding>) {
+    match ty {
+        Ty::Path(name, args) => {
+            let judgement = matches!(name.rsplit("::").next().unwrap(), "Ty" | "POrdProof");
+            if judgement && args.len() == 2 {
+                let levels = (Level::of_type(&args[0]), Level::of_type(&args[1]));
+                if let (Some(member), Some(ty)) = levels {
+                    match member.lt(&ty) {
+                        Some(true) => {}
+                        Some(false) if member == ty =>
+                            res.push(Finding::TypeInType {axiom: axiom.into(), level: member}),
+                        Some(false) =>
+                            res.push(Finding::Descending {axiom: axiom.into(), member, ty}),
+                        None => res.push(Finding::Undetermined {axiom: axiom.into(), member, ty}),
+                    }
+                }
+            }
+            for arg in args {check_ty(axiom, arg, res)}
+        }
+        Ty::Tuple(items) | Ty::Seq(items) => for item in items {check_ty(axiom, item, res)},
+    }
+}
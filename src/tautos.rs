@@ -0,0 +1,289 @@
+//! # Common Tautologies
+//!
+//! Ready-made tautological proofs `a^true` (see [hooo::Tauto]) of standard propositional laws.
+//! These are lifted from the tactic modules using the `tauto!` macro,
+//! so users do not have to do this by hand.
+//!
+//! Laws that require Excluded Middle use `DProp`.
+//!
+//! Since tautologies are function pointers, every law is a `const fn`.
+//! This means proofs can be staged at compile time:
+//!
+//! ```rust
+//! use prop::*;
+//! use prop::hooo::Tauto;
+//!
+//! const AND_SYMMETRY: Tauto<Eq<And<True, False>, And<False, True>>> = tautos::and_symmetry();
+//! ```
+//!
+//! The tactics in [and], [or], [eq] etc. are not `const fn`,
+//! because destructuring a generic proof requires running its destructor,
+//! which is not supported in const contexts.
+
+use crate::*;
+use hooo::Tauto;
+
+/// `(a => a)^true`.
+pub const fn imply_refl<A: Prop>() -> Tauto<Imply<A, A>> {tauto!(imply::id())}
+
+/// `(a => (b => a))^true`.
+pub const fn imply_weaken<A: Prop, B: Prop>() -> Tauto<Imply<A, Imply<B, A>>> {
+    tauto!(Rc::new(imply::weaken))
+}
+
+/// `(((a => b) ⋀ (b => c)) => (a => c))^true`.
+pub const fn imply_transitivity<A: Prop, B: Prop, C: Prop>(
+) -> Tauto<Imply<And<Imply<A, B>, Imply<B, C>>, Imply<A, C>>> {
+    tauto!(Rc::new(|(ab, bc)| imply::transitivity(ab, bc)))
+}
+
+/// `(((a => b) ⋀ a) => b)^true`.
+pub const fn modus_ponens<A: Prop, B: Prop>() -> Tauto<Imply<And<Imply<A, B>, A>, B>> {
+    tauto!(Rc::new(|(ab, a)| imply::modus_ponens(ab, a)))
+}
+
+/// `(((a => b) ⋀ ¬b) => ¬a)^true`.
+pub const fn modus_tollens<A: Prop, B: Prop>() -> Tauto<Imply<And<Imply<A, B>, Not<B>>, Not<A>>> {
+    tauto!(Rc::new(|(ab, nb)| imply::modus_tollens(ab)(nb)))
+}
+
+/// `(((a ⋀ b) => c) == (a => (b => c)))^true`.
+pub const fn exportation<A: Prop, B: Prop, C: Prop>(
+) -> Tauto<Eq<Imply<And<A, B>, C>, Imply<A, Imply<B, C>>>> {tauto!(imply::exportation())}
+
+/// `((a => (b => c)) == (b => (a => c)))^true`.
+pub const fn swap_args<A: Prop, B: Prop, C: Prop>(
+) -> Tauto<Eq<Imply<A, Imply<B, C>>, Imply<B, Imply<A, C>>>> {tauto!(imply::swap_args())}
+
+/// `((a => b) == (a => (a ⋀ b)))^true`.
+pub const fn imply_absorb<A: Prop, B: Prop>() -> Tauto<Eq<Imply<A, B>, Imply<A, And<A, B>>>> {
+    tauto!(imply::eq_absorb())
+}
+
+/// `(((a => b) => a) => a)^true`.
+///
+/// Peirce's law.
+pub const fn peirce<A: DProp, B: Prop>() -> Tauto<Imply<Imply<Imply<A, B>, A>, A>> {
+    tauto!(Rc::new(|f: Imply<Imply<A, B>, A>| match A::decide() {
+        Left(a) => a,
+        Right(na) => f(Rc::new(move |a| not::absurd(na.clone(), a))),
+    }))
+}
+
+/// `((a ⋀ b) == (b ⋀ a))^true`.
+pub const fn and_symmetry<A: Prop, B: Prop>() -> Tauto<Eq<And<A, B>, And<B, A>>> {
+    tauto!((Rc::new(and::symmetry), Rc::new(and::symmetry)))
+}
+
+/// `((a ⋁ b) == (b ⋁ a))^true`.
+pub const fn or_symmetry<A: Prop, B: Prop>() -> Tauto<Eq<Or<A, B>, Or<B, A>>> {
+    tauto!((Rc::new(or::symmetry), Rc::new(or::symmetry)))
+}
+
+/// `(((a ⋀ b) ⋀ c) == (a ⋀ (b ⋀ c)))^true`.
+pub const fn and_assoc<A: Prop, B: Prop, C: Prop>() -> Tauto<Eq<And<And<A, B>, C>, And<A, And<B, C>>>> {
+    tauto!((Rc::new(and::assoc), Rc::new(and::rev_assoc)))
+}
+
+/// `(((a ⋁ b) ⋁ c) == (a ⋁ (b ⋁ c)))^true`.
+pub const fn or_assoc<A: Prop, B: Prop, C: Prop>() -> Tauto<Eq<Or<Or<A, B>, C>, Or<A, Or<B, C>>>> {
+    tauto!((Rc::new(or::assoc), Rc::new(|x: Or<A, Or<B, C>>| match x {
+        Left(a) => Left(Left(a)),
+        Right(Left(b)) => Left(Right(b)),
+        Right(Right(c)) => Right(c),
+    })))
+}
+
+/// `(a == (a ⋀ a))^true`.
+pub const fn and_idem<A: Prop>() -> Tauto<Eq<A, And<A, A>>> {
+    tauto!((Rc::new(|a: A| (a.clone(), a)), Rc::new(and::fst)))
+}
+
+/// `(a == (a ⋁ a))^true`.
+pub const fn or_idem<A: Prop>() -> Tauto<Eq<A, Or<A, A>>> {
+    tauto!((Rc::new(Left), Rc::new(or::both)))
+}
+
+/// `((a ⋀ (a ⋁ b)) == a)^true`.
+pub const fn and_or_absorb<A: Prop, B: Prop>() -> Tauto<Eq<And<A, Or<A, B>>, A>> {
+    tauto!((Rc::new(and::fst), Rc::new(|a: A| (a.clone(), Left(a)))))
+}
+
+/// `((a ⋁ (a ⋀ b)) == a)^true`.
+pub const fn or_and_absorb<A: Prop, B: Prop>() -> Tauto<Eq<Or<A, And<A, B>>, A>> {
+    tauto!((Rc::new(|x: Or<A, And<A, B>>| match x {
+        Left(a) => a,
+        Right((a, _)) => a,
+    }), Rc::new(Left)))
+}
+
+/// `((a ⋀ (b ⋁ c)) == ((a ⋀ b) ⋁ (a ⋀ c)))^true`.
+pub const fn and_distrib<A: Prop, B: Prop, C: Prop>(
+) -> Tauto<Eq<And<A, Or<B, C>>, Or<And<A, B>, And<A, C>>>> {
+    tauto!((Rc::new(and::distrib), Rc::new(and::rev_distrib)))
+}
+
+/// `((a ⋁ (b ⋀ c)) == ((a ⋁ b) ⋀ (a ⋁ c)))^true`.
+pub const fn or_distrib<A: Prop, B: Prop, C: Prop>(
+) -> Tauto<Eq<Or<A, And<B, C>>, And<Or<A, B>, Or<A, C>>>> {
+    tauto!((
+        Rc::new(|x: Or<A, And<B, C>>| match x {
+            Left(a) => (Left(a.clone()), Left(a)),
+            Right((b, c)) => (Right(b), Right(c)),
+        }),
+        Rc::new(|x: And<Or<A, B>, Or<A, C>>| match x {
+            (Left(a), _) | (_, Left(a)) => Left(a),
+            (Right(b), Right(c)) => Right((b, c)),
+        })
+    ))
+}
+
+/// `((a ⋀ true) == a)^true`.
+pub const fn and_true<A: Prop>() -> Tauto<Eq<And<A, True>, A>> {
+    tauto!((Rc::new(and::fst), Rc::new(|a: A| (a, True))))
+}
+
+/// `((a ⋀ false) == false)^true`.
+pub const fn and_false<A: Prop>() -> Tauto<Eq<And<A, False>, False>> {
+    tauto!((Rc::new(and::snd), imply::absurd()))
+}
+
+/// `((a ⋁ true) == true)^true`.
+pub const fn or_true<A: Prop>() -> Tauto<Eq<Or<A, True>, True>> {
+    tauto!((True.map_any(), Rc::new(Right)))
+}
+
+/// `((a ⋁ false) == a)^true`.
+pub const fn or_false<A: Prop>() -> Tauto<Eq<Or<A, False>, A>> {
+    tauto!((Rc::new(|x: Or<A, False>| match x {
+        Left(a) => a,
+        Right(fa) => match fa {},
+    }), Rc::new(Left)))
+}
+
+/// `(a => ¬¬a)^true`.
+pub const fn double_neg_intro<A: Prop>() -> Tauto<Imply<A, Not<Not<A>>>> {tauto!(Rc::new(not::double))}
+
+/// `(¬¬a == a)^true`.
+pub const fn double_neg<A: DProp>() -> Tauto<Eq<Not<Not<A>>, A>> {
+    tauto!((Rc::new(not::rev_double), Rc::new(not::double)))
+}
+
+/// `(¬¬¬a == ¬a)^true`.
+pub const fn triple_neg<A: Prop>() -> Tauto<Eq<Not<Not<Not<A>>>, Not<A>>> {tauto!(not::eq_triple())}
+
+/// `¬(a ⋀ ¬a)^true`.
+///
+/// Law of non-contradiction.
+pub const fn non_contradiction<A: Prop>() -> Tauto<Not<And<A, Not<A>>>> {tauto!(Rc::new(and::paradox))}
+
+/// `(a ⋁ ¬a)^true`.
+///
+/// Excluded Middle.
+pub const fn excm<A: DProp>() -> Tauto<ExcM<A>> {tauto!(A::decide())}
+
+/// `¬¬(a ⋁ ¬a)^true`.
+pub const fn nn_excm<A: Prop>() -> Tauto<Not<Not<ExcM<A>>>> {tauto!(A::nnexcm())}
+
+/// `(¬(a ⋁ b) == (¬a ⋀ ¬b))^true`.
+pub const fn de_morgan_or<A: Prop, B: Prop>() -> Tauto<Eq<Not<Or<A, B>>, And<Not<A>, Not<B>>>> {
+    tauto!(not::eq_or())
+}
+
+/// `(¬(a ⋀ b) == (¬a ⋁ ¬b))^true`.
+pub const fn de_morgan_and<A: DProp, B: DProp>() -> Tauto<Eq<Not<And<A, B>>, Or<Not<A>, Not<B>>>> {
+    tauto!(not::eq_and())
+}
+
+/// `((a => b) == (¬b => ¬a))^true`.
+pub const fn contraposition<A: DProp, B: DProp>() -> Tauto<Eq<Imply<A, B>, Imply<Not<B>, Not<A>>>> {
+    tauto!(not::contrapositive_eq())
+}
+
+/// `(false => a)^true`.
+///
+/// Principle of explosion.
+pub const fn explosion<A: Prop>() -> Tauto<Imply<False, A>> {tauto!(imply::absurd())}
+
+/// `((a => b) == (¬a ⋁ b))^true`.
+///
+/// Material implication.
+pub const fn material_imply<A: DProp, B: Prop>() -> Tauto<Eq<Imply<A, B>, Or<Not<A>, B>>> {
+    tauto!(eq::imply_to_or_da())
+}
+
+/// `(((a => c) ⋀ (b => c)) => ((a ⋁ b) => c))^true`.
+pub const fn or_elim<A: Prop, B: Prop, C: Prop>(
+) -> Tauto<Imply<And<Imply<A, C>, Imply<B, C>>, Imply<Or<A, B>, C>>> {
+    tauto!(Rc::new(|(ac, bc): And<Imply<A, C>, Imply<B, C>>| Rc::new(move |x| match x {
+        Left(a) => ac(a),
+        Right(b) => bc(b),
+    })))
+}
+
+/// `(((a => b) ⋀ (a => c)) => (a => (b ⋀ c)))^true`.
+pub const fn and_intro_imply<A: Prop, B: Prop, C: Prop>(
+) -> Tauto<Imply<And<Imply<A, B>, Imply<A, C>>, Imply<A, And<B, C>>>> {
+    tauto!(Rc::new(|(ab, ac): And<Imply<A, B>, Imply<A, C>>| {
+        Rc::new(move |a: A| (ab(a.clone()), ac(a)))
+    }))
+}
+
+/// `(a == a)^true`.
+pub const fn eq_refl<A: Prop>() -> Tauto<Eq<A, A>> {tauto!(eq::refl())}
+
+/// `((a == b) => (b == a))^true`.
+pub const fn eq_symmetry<A: Prop, B: Prop>() -> Tauto<Imply<Eq<A, B>, Eq<B, A>>> {
+    tauto!(Rc::new(eq::symmetry))
+}
+
+/// `(((a == b) ⋀ (b == c)) => (a == c))^true`.
+pub const fn eq_transitivity<A: Prop, B: Prop, C: Prop>(
+) -> Tauto<Imply<And<Eq<A, B>, Eq<B, C>>, Eq<A, C>>> {
+    tauto!(Rc::new(|(ab, bc)| eq::transitivity(ab, bc)))
+}
+
+/// `((a == b) => (a => b))^true`.
+pub const fn eq_to_imply<A: Prop, B: Prop>() -> Tauto<Imply<Eq<A, B>, Imply<A, B>>> {
+    tauto!(Rc::new(|(ab, _): Eq<A, B>| ab))
+}
+
+/// `((a == b) == ((a => b) ⋀ (b => a)))^true`.
+pub const fn eq_def<A: Prop, B: Prop>() -> Tauto<Eq<Eq<A, B>, And<Imply<A, B>, Imply<B, A>>>> {
+    tauto!(eq::refl())
+}
+
+/// `((a => (b ⋀ c)) == ((a => b) ⋀ (a => c)))^true`.
+pub const fn imply_and_distrib<A: Prop, B: Prop, C: Prop>(
+) -> Tauto<Eq<Imply<A, And<B, C>>, And<Imply<A, B>, Imply<A, C>>>> {
+    tauto!((
+        Rc::new(|f: Imply<A, And<B, C>>| {
+            let g = f.clone();
+            (Rc::new(move |a| f(a).0) as Imply<A, B>, Rc::new(move |a| g(a).1) as Imply<A, C>)
+        }),
+        Rc::new(|(ab, ac): And<Imply<A, B>, Imply<A, C>>| Rc::new(move |a: A| (ab(a.clone()), ac(a))))
+    ))
+}
+
+/// `(((a ⋁ b) => c) == ((a => c) ⋀ (b => c)))^true`.
+pub const fn or_imply_distrib<A: Prop, B: Prop, C: Prop>(
+) -> Tauto<Eq<Imply<Or<A, B>, C>, And<Imply<A, C>, Imply<B, C>>>> {
+    tauto!((
+        Rc::new(|f: Imply<Or<A, B>, C>| {
+            let g = f.clone();
+            (Rc::new(move |a| f(Left(a))) as Imply<A, C>, Rc::new(move |b| g(Right(b))) as Imply<B, C>)
+        }),
+        Rc::new(|(ac, bc): And<Imply<A, C>, Imply<B, C>>| Rc::new(move |x| match x {
+            Left(a) => ac(a),
+            Right(b) => bc(b),
+        }))
+    ))
+}
+
+/// `((true => a) == a)^true`.
+pub const fn true_imply<A: Prop>() -> Tauto<Eq<Imply<True, A>, A>> {
+    tauto!((Rc::new(|f: Imply<True, A>| f(True)), Rc::new(|a: A| a.map_any())))
+}
+
+/// `(a => true)^true`.
+pub const fn imply_true<A: Prop>() -> Tauto<Imply<A, True>> {tauto!(True.map_any())}
@@ -0,0 +1,39 @@
+//! # Uniqueness of Identity Proofs
+//!
+//! Enabled by the `uip` feature.
+//!
+//! Uniqueness of identity proofs (UIP) states that any two proofs of the same equality
+//! are equal ([uip()]):
+//!
+//! `(p : a == b) ⋀ (q : a == b)  =>  (p == q)`
+//!
+//! This makes every type behave like a set, which rules out types with non-trivial paths.
+//! Univalence (see [univalence]) requires such paths, since equivalences between types
+//! can be distinct, so the two settings are incompatible and UIP is opt-in.
+//!
+//! Without UIP, a type with decidable equality is still a set by Hedberg's theorem
+//! (see [hott::hedberg]), which is compatible with univalence.
+
+use crate::*;
+use fun::{app_eq, App};
+use path_semantics::{ty, Ty};
+
+/// `(p : a == b) ⋀ (q : a == b)  =>  (p == q)`.
+pub fn uip<A: Prop, B: Prop, P: Prop, Q: Prop>(
+    _ty_p: Ty<P, Eq<A, B>>,
+    _ty_q: Ty<Q, Eq<A, B>>
+) -> Eq<P, Q> {axiom!()}
+
+/// `(p : a == b) ⋀ (q : a == b)  =>  f(p) == f(q)`.
+pub fn uip_app<F: Prop, A: Prop, B: Prop, P: Prop, Q: Prop>(
+    ty_p: Ty<P, Eq<A, B>>,
+    ty_q: Ty<Q, Eq<A, B>>
+) -> Eq<App<F, P>, App<F, Q>> {app_eq(uip(ty_p, ty_q))}
+/// `(p : a == b) ⋀ (q : a == b) ⋀ (p : t)  =>  (q : t)`.
+///
+/// Every type of a proof of equality is also a type of any other proof of the same equality.
+pub fn uip_ty<A: Prop, B: Prop, P: Prop, Q: Prop, T: Prop>(
+    ty_p: Ty<P, Eq<A, B>>,
+    ty_q: Ty<Q, Eq<A, B>>,
+    ty_p_t: Ty<P, T>
+) -> Ty<Q, T> {ty::in_left_arg(ty_p_t, uip(ty_p, ty_q))}
@@ -0,0 +1,138 @@
+//! # Yoneda Lemma for Propositions
+//!
+//! Propositions form a category with implications `a => b` as morphisms.
+//! Functors from this category into itself are operators applied with [fun::App]
+//! that are monotone (see [galois::Monotone]).
+//! The representable functor `hom(a)` ([Hom]) maps `x` to `a => x` ([hom_def]).
+//!
+//! Since there is at most one morphism between two objects up to equality,
+//! naturality squares commute trivially,
+//! so a natural transformation `f -> g` ([NatTrans]) is a family `f(x) => g(x)`.
+//!
+//! The Yoneda lemma states that natural transformations `hom(a) -> f`
+//! correspond to proofs of `f(a)`:
+//!
+//! - `(hom(a) -> f)  =>  f(a)` ([yoneda_to])
+//! - `f(a)  =>  (hom(a) -> f)` ([Yoneda])
+//!
+//! The Yoneda embedding is full and faithful:
+//! `(hom(a) -> hom(b))` corresponds to `b => a` ([yoneda_embed] and [Precomp]).
+//! Hence representable functors determine their objects ([yoneda_eq]).
+
+use crate::*;
+
+use fun::{App, Comp, comp_to_app, app_to_comp};
+use galois::Monotone;
+
+/// Representable functor `hom(a)`.
+#[derive(Copy, Clone)]
+pub struct Hom<A>(A);
+
+/// Natural transformation `f -> g`.
+pub trait NatTrans<F: Prop, G: Prop>: Clone + 'static {
+    /// `f(x) => g(x)`.
+    fn nat<X: Prop>(&self) -> Imply<App<F, X>, App<G, X>>;
+}
+
+/// `hom(a)(x) == (a => x)`.
+pub fn hom_def<A: Prop, X: Prop>() -> Eq<App<Hom<A>, X>, Imply<A, X>> {axiom!()}
+
+/// Monotonicity of `hom(a)`.
+#[derive(Copy, Clone)]
+pub struct HomMono;
+
+impl<A: Prop> Monotone<Hom<A>> for HomMono {
+    fn mono<X: Prop, Y: Prop>(&self, xy: Imply<X, Y>) -> Imply<App<Hom<A>, X>, App<Hom<A>, Y>> {
+        Rc::new(move |ax| hom_def().1(imply::transitivity(hom_def().0(ax), xy.clone())))
+    }
+}
+
+/// Monotonicity of `g . f`.
+#[derive(Clone)]
+pub struct MonoComp<M1, M2>(pub M1, pub M2);
+
+impl<F: Prop, G: Prop, M1: Monotone<F>, M2: Monotone<G>>
+    Monotone<Comp<G, F>> for MonoComp<M1, M2>
+{
+    fn mono<A: Prop, B: Prop>(
+        &self,
+        ab: Imply<A, B>
+    ) -> Imply<App<Comp<G, F>, A>, App<Comp<G, F>, B>> {
+        let x = imply::transitivity(Rc::new(comp_to_app), self.1.mono(self.0.mono(ab)));
+        imply::transitivity(x, Rc::new(app_to_comp))
+    }
+}
+
+/// Identity natural transformation `f -> f`.
+#[derive(Copy, Clone)]
+pub struct NatId;
+
+impl<F: Prop> NatTrans<F, F> for NatId {
+    fn nat<X: Prop>(&self) -> Imply<App<F, X>, App<F, X>> {imply::id()}
+}
+
+/// Vertical composition of natural transformations `(f -> g) ⋀ (g -> h)  =>  (f -> h)`.
+#[derive(Clone)]
+pub struct NatComp<T1, T2, G>(pub T1, pub T2, pub std::marker::PhantomData<G>);
+
+impl<F: Prop, G: Prop, H: Prop, T1: NatTrans<F, G>, T2: NatTrans<G, H>>
+    NatTrans<F, H> for NatComp<T1, T2, G>
+{
+    fn nat<X: Prop>(&self) -> Imply<App<F, X>, App<H, X>> {
+        imply::transitivity(self.0.nat(), self.1.nat())
+    }
+}
+
+/// Natural transformation `hom(a) -> f` constructed from `f(a)`.
+#[derive(Clone)]
+pub struct Yoneda<M, X>(pub M, pub X);
+
+impl<F: Prop, A: Prop, M: Monotone<F>> NatTrans<Hom<A>, F> for Yoneda<M, App<F, A>> {
+    fn nat<X: Prop>(&self) -> Imply<App<Hom<A>, X>, App<F, X>> {
+        let y = self.clone();
+        Rc::new(move |ax| y.0.mono(hom_def().0(ax))(y.1.clone()))
+    }
+}
+
+/// Natural transformation `hom(a) -> hom(b)` by precomposition with `b => a`.
+#[derive(Clone)]
+pub struct Precomp<A, B>(pub Imply<B, A>);
+
+impl<A: Prop, B: Prop> NatTrans<Hom<A>, Hom<B>> for Precomp<A, B> {
+    fn nat<X: Prop>(&self) -> Imply<App<Hom<A>, X>, App<Hom<B>, X>> {
+        let ba = self.0.clone();
+        Rc::new(move |ax| hom_def().1(imply::transitivity(ba.clone(), hom_def().0(ax))))
+    }
+}
+
+/// `(hom(a) -> f)  =>  f(a)`.
+pub fn yoneda_to<A: Prop, F: Prop, T: NatTrans<Hom<A>, F>>(t: &T) -> App<F, A> {
+    t.nat()(hom_def().1(imply::id()))
+}
+
+/// `f(a)  =>  (hom(a) -> f)`.
+pub fn yoneda_from<A: Prop, F: Prop, M: Monotone<F>>(m: M, x: App<F, A>) -> Yoneda<M, App<F, A>> {
+    Yoneda(m, x)
+}
+
+/// `(hom(a) -> hom(b))  =>  (b => a)`.
+pub fn yoneda_embed<A: Prop, B: Prop, T: NatTrans<Hom<A>, Hom<B>>>(t: &T) -> Imply<B, A> {
+    hom_def().0(yoneda_to(t))
+}
+
+/// `(hom(a) -> hom(b)) ⋀ (hom(b) -> hom(a))  =>  (a == b)`.
+pub fn yoneda_eq<A: Prop, B: Prop, T1: NatTrans<Hom<A>, Hom<B>>, T2: NatTrans<Hom<B>, Hom<A>>>(
+    t1: &T1,
+    t2: &T2
+) -> Eq<A, B> {(yoneda_embed(t2), yoneda_embed(t1))}
+
+/// `f(a) ⋀ (a => b)  =>  f(b)`.
+///
+/// Follows from the Yoneda lemma by evaluating at `b`.
+pub fn yoneda_map<A: Prop, B: Prop, F: Prop, M: Monotone<F>>(
+    m: M,
+    x: App<F, A>,
+    ab: Imply<A, B>
+) -> App<F, B> {
+    NatTrans::<Hom<A>, F>::nat(&yoneda_from(m, x))(hom_def().1(ab))
+}